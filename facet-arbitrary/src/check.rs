@@ -0,0 +1,209 @@
+use core::fmt;
+use core::hash::BuildHasher;
+use core::panic::AssertUnwindSafe;
+use std::collections::hash_map::RandomState;
+use std::panic::catch_unwind;
+
+use facet_core::Facet;
+use facet_pretty::PrettyPrinter;
+
+use crate::{Config, GenerateError, Source, generate};
+
+/// The environment variable [`check`] reads its seed from, to reproduce a failure
+pub const SEED_VAR: &str = "FACET_ARBITRARY_SEED";
+
+/// Checks that `property` holds for many random values of `T`, and panics with the simplest
+/// failing value it can find otherwise.
+///
+/// The property fails if it returns an error or panics. The failing value is then shrunk:
+/// lists lose items, numbers get closer to zero, enums move to earlier variants, and so on,
+/// for as long as the property keeps failing.
+///
+/// ```
+/// # use facet_arbitrary::{Config, check};
+/// check(&Config::new(), |list: &Vec<u32>| {
+///     let mut reversed = list.clone();
+///     reversed.reverse();
+///     reversed.reverse();
+///     if reversed == *list { Ok(()) } else { Err("reversing twice changed the list") }
+/// });
+/// ```
+///
+/// # Panics
+///
+/// Panics if the property fails, or if values of `T` can't be generated.
+pub fn check<'facet, T, E, F>(config: &Config, property: F)
+where
+    T: Facet<'facet>,
+    E: fmt::Display,
+    F: FnMut(&T) -> Result<(), E>,
+{
+    if let Err(failure) = try_check(config, property) {
+        panic!("{failure}");
+    }
+}
+
+/// Like [`check`], but returns the simplest failing value instead of panicking.
+///
+/// # Panics
+///
+/// Panics if values of `T` can't be generated.
+pub fn try_check<'facet, T, E, F>(config: &Config, mut property: F) -> Result<(), Failure<T>>
+where
+    T: Facet<'facet>,
+    E: fmt::Display,
+    F: FnMut(&T) -> Result<(), E>,
+{
+    let seed = config
+        .seed
+        .or_else(|| std::env::var(SEED_VAR).ok()?.parse().ok())
+        .unwrap_or_else(|| RandomState::new().hash_one(SEED_VAR));
+
+    for case in 0..config.cases {
+        // Runs with nearby seeds shouldn't try the same values
+        let case_seed = seed
+            .wrapping_mul(0x9e37_79b9_7f4a_7c15)
+            .wrapping_add(case as u64);
+        let mut source = Source::from_seed(case_seed);
+        let value: T = match generate(&mut source, config) {
+            Ok(value) => value,
+            Err(GenerateError::Rejected(_)) => continue,
+            Err(e) => panic!("couldn't generate a value of `{}`: {e}", T::SHAPE),
+        };
+        if let Err(message) = run(&mut property, &value) {
+            let mut failure = Failure {
+                seed,
+                case,
+                shrinks: 0,
+                value,
+                message,
+            };
+            shrink(config, &mut property, source.into_choices(), &mut failure);
+            return Err(failure);
+        }
+    }
+    Ok(())
+}
+
+/// Runs the property, turning panics into failures
+fn run<T, E: fmt::Display>(
+    property: &mut impl FnMut(&T) -> Result<(), E>,
+    value: &T,
+) -> Result<(), String> {
+    match catch_unwind(AssertUnwindSafe(|| property(value))) {
+        Ok(Ok(())) => Ok(()),
+        Ok(Err(e)) => Err(e.to_string()),
+        Err(payload) => Err(payload
+            .downcast_ref::<&str>()
+            .map(|msg| msg.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "panicked".to_string())),
+    }
+}
+
+/// Replays ever simpler choices, keeping the value they generate as long as the property still
+/// fails for it.
+fn shrink<'facet, T: Facet<'facet>, E: fmt::Display>(
+    config: &Config,
+    property: &mut impl FnMut(&T) -> Result<(), E>,
+    mut choices: Vec<u64>,
+    failure: &mut Failure<T>,
+) {
+    let mut attempts = 0;
+    'shrink: loop {
+        let current = choices.clone();
+        for candidate in simpler_choices(&current) {
+            if attempts == config.max_shrinks {
+                break 'shrink;
+            }
+            attempts += 1;
+
+            let mut source = Source::replay(candidate);
+            let Ok(value) = generate::<T>(&mut source, config) else {
+                continue;
+            };
+            // Replaying may consume fewer or different choices than the candidate
+            let replayed = source.into_choices();
+            if !is_simpler(&replayed, &choices) {
+                continue;
+            }
+            if let Err(message) = run(property, &value) {
+                failure.value = value;
+                failure.message = message;
+                failure.shrinks += 1;
+                choices = replayed;
+                continue 'shrink;
+            }
+        }
+        break;
+    }
+}
+
+/// Candidate simplifications of `choices`, the most aggressive ones first
+fn simpler_choices(choices: &[u64]) -> impl Iterator<Item = Vec<u64>> + '_ {
+    let deletions = (1..=8).rev().flat_map(move |size| {
+        (0..choices.len().saturating_sub(size - 1)).map(move |start| {
+            let mut candidate = choices.to_vec();
+            candidate.drain(start..start + size);
+            candidate
+        })
+    });
+    let reductions = (0..choices.len())
+        .filter(|&index| choices[index] != 0)
+        .flat_map(move |index| {
+            // Zero first, then a binary search towards the current value
+            let value = choices[index];
+            let steps = (1..64).map(move |shift| value - (value >> shift));
+            core::iter::once(0)
+                .chain(steps.take_while(move |&simpler| simpler < value))
+                .map(move |simpler| {
+                    let mut candidate = choices.to_vec();
+                    candidate[index] = simpler;
+                    candidate
+                })
+        });
+    deletions.chain(reductions)
+}
+
+/// Shorter sequences are simpler, then lexicographically smaller ones
+fn is_simpler(a: &[u64], b: &[u64]) -> bool {
+    (a.len(), a) < (b.len(), b)
+}
+
+/// A value for which a property doesn't hold
+pub struct Failure<T> {
+    /// The seed the run started from
+    pub seed: u64,
+    /// How many values were tried before this one
+    pub case: usize,
+    /// How many times the value was simplified
+    pub shrinks: usize,
+    /// The simplest failing value that was found
+    pub value: T,
+    /// Why the property failed
+    pub message: String,
+}
+
+impl<'facet, T: Facet<'facet>> fmt::Display for Failure<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "property failed after {} case(s), shrunk {} time(s): {}",
+            self.case + 1,
+            self.shrinks,
+            self.message
+        )?;
+        writeln!(
+            f,
+            "{}",
+            PrettyPrinter::new().with_colors(false).format(&self.value)
+        )?;
+        write!(f, "reproduce with {SEED_VAR}={}", self.seed)
+    }
+}
+
+impl<'facet, T: Facet<'facet>> fmt::Debug for Failure<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
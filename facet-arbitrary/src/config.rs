@@ -0,0 +1,71 @@
+/// Settings for generating values and checking properties
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub(crate) cases: usize,
+    pub(crate) seed: Option<u64>,
+    pub(crate) max_len: usize,
+    pub(crate) max_depth: usize,
+    pub(crate) max_shrinks: usize,
+    pub(crate) special_floats: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            cases: 256,
+            seed: None,
+            max_len: 8,
+            max_depth: 6,
+            max_shrinks: 1024,
+            special_floats: false,
+        }
+    }
+}
+
+impl Config {
+    /// Creates the default configuration
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how many values [`check`](crate::check) tries (256 by default)
+    pub fn cases(mut self, cases: usize) -> Self {
+        self.cases = cases;
+        self
+    }
+
+    /// Sets the seed [`check`](crate::check) starts from. By default, it's read from the
+    /// `FACET_ARBITRARY_SEED` environment variable, or picked at random.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Sets the maximum number of items in generated lists, maps and strings (8 by default)
+    pub fn max_len(mut self, max_len: usize) -> Self {
+        self.max_len = max_len;
+        self
+    }
+
+    /// Sets how deep generated values may nest (6 by default). Past that depth, values are
+    /// as small as possible: options are `None`, lists are empty, enums pick their simplest
+    /// variant, etc.
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Sets how many simpler values [`check`](crate::check) tries when shrinking a failing
+    /// value (1024 by default)
+    pub fn max_shrinks(mut self, max_shrinks: usize) -> Self {
+        self.max_shrinks = max_shrinks;
+        self
+    }
+
+    /// Sets whether floats may be NaN, infinite, or negative zero (off by default, since
+    /// most formats can't round-trip them)
+    pub fn special_floats(mut self, special_floats: bool) -> Self {
+        self.special_floats = special_floats;
+        self
+    }
+}
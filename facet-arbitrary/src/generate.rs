@@ -0,0 +1,582 @@
+use alloc::borrow::Cow;
+use alloc::ffi::CString;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+use std::ffi::OsString;
+
+use facet_core::{Def, Facet, Field, FieldFlags, ScalarAffinity, Shape, Type, UserType};
+use facet_reflect::{HeapValue, Partial, ReflectError, ScalarType};
+
+use crate::{Config, Source};
+
+/// How many times a value rejected by its type's invariants is generated again
+const MAX_REJECTIONS: usize = 16;
+
+/// Borrowed strings can't be generated, so they're picked from this list
+const WORDS: &[&str] = &["", "a", "facet", "hello world", "naïve", "\"quoted\"", "🦀"];
+
+/// Characters that tend to trip up serializers
+const TRICKY_CHARS: &[char] = &[
+    '"', '\\', '/', '\n', '\r', '\t', '\0', '\u{1b}', '\u{7f}', 'é', '€', '\u{200b}', '🦀',
+];
+
+/// Generates a random value of `T`, drawing every choice from `source`.
+///
+/// Integers use the full range of their bit width, with a bias towards small values and edge
+/// cases like `0`, `MAX` and `MIN`. Enums pick any variant, options are `Some` most of the time,
+/// and lists, maps and strings hold up to [`Config::max_len`] items. Types that have invariants,
+/// or that convert from an inner type (like `NonZero`), are generated again until they're valid.
+pub fn generate<'facet, T: Facet<'facet>>(
+    source: &mut Source,
+    config: &Config,
+) -> Result<T, GenerateError<'static>> {
+    Ok(generate_shape(T::SHAPE, source, config)?.materialize()?)
+}
+
+/// Generates a random value of the given shape, see [`generate`]
+pub fn generate_shape<'facet, 'shape>(
+    shape: &'shape Shape<'shape>,
+    source: &mut Source,
+    config: &Config,
+) -> Result<HeapValue<'facet, 'shape>, GenerateError<'shape>> {
+    Generator { source, config }.value(shape, 0)
+}
+
+struct Generator<'a> {
+    source: &'a mut Source,
+    config: &'a Config,
+}
+
+impl Generator<'_> {
+    /// Builds a standalone value, so it can be rejected if it's invalid.
+    fn value<'facet, 'shape>(
+        &mut self,
+        shape: &'shape Shape<'shape>,
+        depth: usize,
+    ) -> Result<HeapValue<'facet, 'shape>, GenerateError<'shape>> {
+        for _ in 0..MAX_REJECTIONS {
+            let mut partial = Partial::alloc_shape(shape)?;
+            let result = self
+                .fill(&mut partial, depth)
+                .and_then(|()| Ok(partial.build()?));
+            match result {
+                Ok(value) => return Ok(value),
+                Err(GenerateError::Reflect(
+                    ReflectError::TryFromError { .. } | ReflectError::InvariantViolation { .. },
+                )) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+        Err(GenerateError::Rejected(shape))
+    }
+
+    /// Fills a nested frame, building it on its own first if it may be rejected.
+    fn fill_child<'shape>(
+        &mut self,
+        partial: &mut Partial<'_, 'shape>,
+        depth: usize,
+    ) -> Result<(), GenerateError<'shape>> {
+        let shape = partial.shape();
+        let has_invariants = shape
+            .vtable
+            .sized()
+            .is_some_and(|v| (v.invariants)().is_some());
+        if has_invariants || converts_from_inner(shape) {
+            let value = self.value(shape, depth)?;
+            partial.set_from_heap_value(value)?;
+        } else {
+            self.fill(partial, depth)?;
+        }
+        Ok(())
+    }
+
+    fn fill<'shape>(
+        &mut self,
+        partial: &mut Partial<'_, 'shape>,
+        depth: usize,
+    ) -> Result<(), GenerateError<'shape>> {
+        let shape = partial.shape();
+        if depth > self.config.max_depth {
+            partial.set_minimal()?;
+            return Ok(());
+        }
+        if let Some(scalar) = ScalarType::try_from_shape(shape) {
+            return self.fill_scalar(partial, scalar);
+        }
+        if converts_from_inner(shape) {
+            partial.begin_inner()?;
+            self.fill_child(partial, depth + 1)?;
+            partial.end()?;
+            return Ok(());
+        }
+
+        match (shape.def, shape.ty) {
+            (Def::Scalar(def), _) => self.fill_affinity(partial, def.affinity)?,
+            (Def::Option(_), _) => {
+                if self.source.draw_likely(3) {
+                    partial.begin_some()?;
+                    self.fill_child(partial, depth + 1)?;
+                    partial.end()?;
+                } else {
+                    partial.set_default()?;
+                }
+            }
+            (Def::Result(_), _) => {
+                if self.source.draw_unlikely(1) {
+                    partial.begin_err()?;
+                } else {
+                    partial.begin_ok()?;
+                }
+                self.fill_child(partial, depth + 1)?;
+                partial.end()?;
+            }
+            (Def::SmartPointer(_), _) => {
+                partial.begin_smart_ptr()?;
+                self.fill_child(partial, depth + 1)?;
+                partial.end()?;
+            }
+            (Def::List(_), _) => {
+                partial.begin_list()?;
+                let mut len = 0;
+                while self.more(len) {
+                    partial.begin_list_item()?;
+                    self.fill_child(partial, depth + 1)?;
+                    partial.end()?;
+                    len += 1;
+                }
+            }
+            (Def::Map(_), _) => {
+                partial.begin_map()?;
+                let mut len = 0;
+                while self.more(len) {
+                    partial.begin_key()?;
+                    self.fill_child(partial, depth + 1)?;
+                    partial.end()?;
+                    partial.begin_value()?;
+                    self.fill_child(partial, depth + 1)?;
+                    partial.end()?;
+                    len += 1;
+                }
+            }
+            (Def::Array(def), _) => {
+                for index in 0..def.n {
+                    partial.begin_nth_element(index)?;
+                    self.fill_child(partial, depth + 1)?;
+                    partial.end()?;
+                }
+            }
+            (_, Type::User(UserType::Struct(sd))) => {
+                if sd.fields.is_empty() {
+                    partial.set_from_function(|_| Ok(()))?;
+                }
+                self.fill_fields(partial, sd.fields, depth)?;
+            }
+            (_, Type::User(UserType::Enum(ed))) => {
+                let Some(last) = ed.variants.len().checked_sub(1) else {
+                    return Err(GenerateError::Unsupported(shape));
+                };
+                let index = self.source.draw(last as u64) as usize;
+                partial.select_nth_variant(index)?;
+                self.fill_fields(partial, ed.variants[index].data.fields, depth)?;
+            }
+            _ => self.fill_default(partial)?,
+        }
+        Ok(())
+    }
+
+    fn fill_fields<'shape>(
+        &mut self,
+        partial: &mut Partial<'_, 'shape>,
+        fields: &'shape [Field<'shape>],
+        depth: usize,
+    ) -> Result<(), GenerateError<'shape>> {
+        for (index, field) in fields.iter().enumerate() {
+            partial.begin_nth_field(index)?;
+            // Skipped fields come back as their default, so that's what they get
+            if field.flags.contains(FieldFlags::SKIP_SERIALIZING) {
+                match field.vtable.default_fn {
+                    Some(default_fn) if field.flags.contains(FieldFlags::DEFAULT) => {
+                        partial.set_field_default(default_fn)?;
+                    }
+                    _ => {
+                        partial.set_minimal()?;
+                    }
+                }
+            } else {
+                self.fill_child(partial, depth + 1)?;
+            }
+            partial.end()?;
+        }
+        Ok(())
+    }
+
+    fn fill_scalar<'shape>(
+        &mut self,
+        partial: &mut Partial<'_, 'shape>,
+        scalar: ScalarType,
+    ) -> Result<(), GenerateError<'shape>> {
+        match scalar {
+            ScalarType::Unit => partial.set(())?,
+            ScalarType::Bool => partial.set(self.source.draw(1) == 1)?,
+            ScalarType::Char => partial.set(self.char())?,
+            ScalarType::Str => partial.set(WORDS[self.pick(WORDS.len())])?,
+            ScalarType::String => partial.set(self.string())?,
+            ScalarType::CowStr => partial.set(Cow::<str>::Owned(self.string()))?,
+            ScalarType::CString => partial.set(CString::new(self.c_string()).unwrap())?,
+            ScalarType::OsString => partial.set(OsString::from(self.string()))?,
+            ScalarType::F32 => partial.set(self.f32())?,
+            ScalarType::F64 => partial.set(self.f64())?,
+            ScalarType::U8 => partial.set(self.uint(8) as u8)?,
+            ScalarType::U16 => partial.set(self.uint(16) as u16)?,
+            ScalarType::U32 => partial.set(self.uint(32) as u32)?,
+            ScalarType::U64 => partial.set(self.uint(64) as u64)?,
+            ScalarType::U128 => partial.set(self.uint(128))?,
+            ScalarType::USize => partial.set(self.uint(usize::BITS) as usize)?,
+            ScalarType::I8 => partial.set(self.int(8) as i8)?,
+            ScalarType::I16 => partial.set(self.int(16) as i16)?,
+            ScalarType::I32 => partial.set(self.int(32) as i32)?,
+            ScalarType::I64 => partial.set(self.int(64) as i64)?,
+            ScalarType::I128 => partial.set(self.int(128))?,
+            ScalarType::ISize => partial.set(self.int(isize::BITS) as isize)?,
+            ScalarType::IpAddr => partial.set(self.ip())?,
+            ScalarType::Ipv4Addr => partial.set(Ipv4Addr::from(self.uint(32) as u32))?,
+            ScalarType::Ipv6Addr => partial.set(Ipv6Addr::from(self.uint(128)))?,
+            ScalarType::SocketAddr => {
+                partial.set(SocketAddr::new(self.ip(), self.uint(16) as u16))?
+            }
+            ScalarType::SocketAddrV4 => partial.set(SocketAddrV4::new(
+                Ipv4Addr::from(self.uint(32) as u32),
+                self.uint(16) as u16,
+            ))?,
+            ScalarType::SocketAddrV6 => partial.set(SocketAddrV6::new(
+                Ipv6Addr::from(self.uint(128)),
+                self.uint(16) as u16,
+                0,
+                0,
+            ))?,
+            _ => return self.fill_default(partial),
+        };
+        Ok(())
+    }
+
+    /// Generates scalars that aren't built-in from their string representation
+    fn fill_affinity<'shape>(
+        &mut self,
+        partial: &mut Partial<'_, 'shape>,
+        affinity: &ScalarAffinity<'shape>,
+    ) -> Result<(), GenerateError<'shape>> {
+        let candidates = match affinity {
+            ScalarAffinity::Number(_) => vec![self.decimal()],
+            ScalarAffinity::String(_) => vec![self.string()],
+            ScalarAffinity::Boolean(_) => vec![String::from(["false", "true"][self.pick(2)])],
+            ScalarAffinity::Char(_) => vec![String::from(self.char())],
+            ScalarAffinity::UUID(_) => vec![self.uuid()],
+            ScalarAffinity::ULID(_) => vec![self.ulid()],
+            ScalarAffinity::Url(_) => vec![self.url()],
+            ScalarAffinity::Path(_) => vec![self.path()],
+            ScalarAffinity::PlatformString(_) => vec![self.c_string()],
+            ScalarAffinity::Time(_) => self.times(),
+            ScalarAffinity::Duration(_) => self.durations(),
+            _ => return self.fill_default(partial),
+        };
+        // Time types each accept a different subset of formats
+        let mut last_error = None;
+        for candidate in &candidates {
+            match partial.parse_from_str(candidate) {
+                Ok(_) => return Ok(()),
+                Err(e) => last_error = Some(e),
+            }
+        }
+        match last_error {
+            Some(e) => Err(e.into()),
+            None => self.fill_default(partial),
+        }
+    }
+
+    /// Falls back to the default value, for types whose values can't be generated
+    fn fill_default<'shape>(
+        &mut self,
+        partial: &mut Partial<'_, 'shape>,
+    ) -> Result<(), GenerateError<'shape>> {
+        let shape = partial.shape();
+        if shape
+            .vtable
+            .sized()
+            .is_some_and(|v| (v.default_in_place)().is_some())
+        {
+            partial.set_default()?;
+            Ok(())
+        } else {
+            Err(GenerateError::Unsupported(shape))
+        }
+    }
+
+    /// Whether a list that has `len` items gets another one
+    fn more(&mut self, len: usize) -> bool {
+        len < self.config.max_len && self.source.draw_likely(4)
+    }
+
+    /// Picks an index in `0..len`
+    fn pick(&mut self, len: usize) -> usize {
+        self.source.draw(len as u64 - 1) as usize
+    }
+
+    /// Draws an unsigned integer of up to `bits` bits.
+    ///
+    /// The bit length is drawn first, so small values are as likely as large ones, and
+    /// occasionally every bit is set, which covers `MAX`.
+    fn uint(&mut self, bits: u32) -> u128 {
+        let len = self.source.draw(bits as u64) as u32;
+        let value = if len > 64 {
+            let high = self.source.draw(ones(len - 64) as u64) as u128;
+            (high << 64) | self.source.draw(u64::MAX) as u128
+        } else {
+            self.source.draw(ones(len) as u64) as u128
+        };
+        if self.source.draw_unlikely(7) {
+            ones(len)
+        } else {
+            value
+        }
+    }
+
+    /// Draws a signed integer of `bits` bits: a magnitude, then a sign, so that both shrink
+    /// towards zero independently
+    fn int(&mut self, bits: u32) -> i128 {
+        let magnitude = self.uint(bits - 1) as i128;
+        if self.source.draw_unlikely(1) {
+            -magnitude - 1
+        } else {
+            magnitude
+        }
+    }
+
+    fn f64(&mut self) -> f64 {
+        match self.source.draw(3) {
+            0 => self.int(8) as f64,
+            1 => self.int(32) as f64 / 1024.0,
+            2 => {
+                let bits = self.uint(64) as u64;
+                self.finite(f64::from_bits(bits))
+            }
+            _ if self.config.special_floats => {
+                [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, -0.0][self.pick(4)]
+            }
+            _ => [f64::MAX, f64::MIN, f64::MIN_POSITIVE, f64::EPSILON][self.pick(4)],
+        }
+    }
+
+    fn f32(&mut self) -> f32 {
+        match self.source.draw(3) {
+            0 => self.int(8) as f32,
+            1 => self.int(16) as f32 / 64.0,
+            2 => {
+                let bits = self.uint(32) as u32;
+                self.finite(f32::from_bits(bits) as f64) as f32
+            }
+            _ if self.config.special_floats => {
+                [f32::NAN, f32::INFINITY, f32::NEG_INFINITY, -0.0][self.pick(4)]
+            }
+            _ => [f32::MAX, f32::MIN, f32::MIN_POSITIVE, f32::EPSILON][self.pick(4)],
+        }
+    }
+
+    /// Replaces floats from raw bits that most formats can't round-trip
+    fn finite(&self, value: f64) -> f64 {
+        if self.config.special_floats || (value.is_finite() && value != 0.0) {
+            value
+        } else {
+            0.0
+        }
+    }
+
+    /// Draws a decimal number literal, for number types that aren't built-in
+    fn decimal(&mut self) -> String {
+        let integer = self.int(32);
+        if self.source.draw_unlikely(1) {
+            format!("{integer}.{}", self.source.draw(9999))
+        } else {
+            format!("{integer}")
+        }
+    }
+
+    fn char(&mut self) -> char {
+        match self.source.draw(3) {
+            0 => char::from(b'a' + self.source.draw(25) as u8),
+            1 => char::from(b' ' + self.source.draw(94) as u8),
+            2 => char::from_u32(self.source.draw(0x10ffff) as u32).unwrap_or('\u{fffd}'),
+            _ => TRICKY_CHARS[self.pick(TRICKY_CHARS.len())],
+        }
+    }
+
+    fn string(&mut self) -> String {
+        let mut string = String::new();
+        let mut len = 0;
+        while self.more(len) {
+            string.push(self.char());
+            len += 1;
+        }
+        string
+    }
+
+    /// Draws a string without NUL characters, which C strings can't hold
+    fn c_string(&mut self) -> String {
+        self.string().replace('\0', "")
+    }
+
+    /// Draws a lowercase alphanumeric word of at least one character
+    fn word(&mut self) -> String {
+        let mut word = String::new();
+        let mut len = 0;
+        while len == 0 || self.more(len) {
+            word.push(char::from(
+                b"abcdefghijklmnopqrstuvwxyz0123456789"[self.pick(36)],
+            ));
+            len += 1;
+        }
+        word
+    }
+
+    fn ip(&mut self) -> IpAddr {
+        if self.source.draw_unlikely(1) {
+            IpAddr::V6(Ipv6Addr::from(self.uint(128)))
+        } else {
+            IpAddr::V4(Ipv4Addr::from(self.uint(32) as u32))
+        }
+    }
+
+    fn uuid(&mut self) -> String {
+        let hex = format!("{:032x}", self.uint(128));
+        format!(
+            "{}-{}-{}-{}-{}",
+            &hex[..8],
+            &hex[8..12],
+            &hex[12..16],
+            &hex[16..20],
+            &hex[20..]
+        )
+    }
+
+    fn ulid(&mut self) -> String {
+        const CROCKFORD: &[u8] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+        // 26 characters of 5 bits hold 130 bits, so the first one only goes up to 7
+        let mut ulid = String::from(char::from(CROCKFORD[self.pick(8)]));
+        for _ in 1..26 {
+            ulid.push(char::from(CROCKFORD[self.pick(32)]));
+        }
+        ulid
+    }
+
+    fn url(&mut self) -> String {
+        let mut url = format!("https://{}.example/", self.word());
+        let mut len = 0;
+        while self.more(len) {
+            if len > 0 {
+                url.push('/');
+            }
+            url.push_str(&self.word());
+            len += 1;
+        }
+        url
+    }
+
+    fn path(&mut self) -> String {
+        let mut path = self.word();
+        let mut len = 1;
+        while self.more(len) {
+            path.push('/');
+            path.push_str(&self.word());
+            len += 1;
+        }
+        path
+    }
+
+    /// Draws one moment, in the formats time types usually parse
+    fn times(&mut self) -> Vec<String> {
+        let date = format!(
+            "{:04}-{:02}-{:02}",
+            1970 + self.source.draw(130),
+            1 + self.source.draw(11),
+            1 + self.source.draw(27)
+        );
+        let time = format!(
+            "{:02}:{:02}:{:02}",
+            self.source.draw(23),
+            self.source.draw(59),
+            self.source.draw(59)
+        );
+        vec![
+            format!("{date}T{time}Z"),
+            format!("{date}T{time}"),
+            date.clone(),
+            time.clone(),
+            format!("{date} {time}"),
+        ]
+    }
+
+    /// Draws one span of time, in the formats duration types usually parse
+    fn durations(&mut self) -> Vec<String> {
+        let (hours, minutes, seconds, millis) = (
+            self.source.draw(99),
+            self.source.draw(59),
+            self.source.draw(59),
+            self.source.draw(999),
+        );
+        vec![
+            format!("{hours}h{minutes}m{seconds}.{millis:03}s"),
+            format!("PT{hours}H{minutes}M{seconds}.{millis:03}S"),
+        ]
+    }
+}
+
+/// The largest number of `len` bits
+fn ones(len: u32) -> u128 {
+    u128::MAX.checked_shr(128 - len).unwrap_or(0)
+}
+
+/// Whether values of this shape are converted from a value of their inner shape
+fn converts_from_inner(shape: &Shape<'_>) -> bool {
+    shape.inner.is_some()
+        && shape
+            .vtable
+            .sized()
+            .is_some_and(|v| (v.try_from)().is_some())
+}
+
+/// An error that occurred while generating a value
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum GenerateError<'shape> {
+    /// Values of this shape can't be generated
+    Unsupported(&'shape Shape<'shape>),
+    /// Every generated value of this shape was rejected by its invariants
+    Rejected(&'shape Shape<'shape>),
+    /// The value couldn't be built
+    Reflect(ReflectError<'shape>),
+}
+
+impl<'shape> From<ReflectError<'shape>> for GenerateError<'shape> {
+    fn from(e: ReflectError<'shape>) -> Self {
+        GenerateError::Reflect(e)
+    }
+}
+
+impl fmt::Display for GenerateError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GenerateError::Unsupported(shape) => {
+                write!(f, "values of `{shape}` can't be generated")
+            }
+            GenerateError::Rejected(shape) => {
+                write!(f, "every generated value of `{shape}` was rejected")
+            }
+            GenerateError::Reflect(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl core::error::Error for GenerateError<'_> {}
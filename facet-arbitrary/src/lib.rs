@@ -0,0 +1,19 @@
+#![warn(missing_docs)]
+#![warn(clippy::std_instead_of_core)]
+#![warn(clippy::std_instead_of_alloc)]
+#![forbid(unsafe_code)]
+#![doc = include_str!("../README.md")]
+
+extern crate alloc;
+
+mod check;
+pub use check::*;
+
+mod config;
+pub use config::*;
+
+mod generate;
+pub use generate::*;
+
+mod source;
+pub use source::*;
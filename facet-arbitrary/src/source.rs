@@ -0,0 +1,80 @@
+/// Where generated values get their randomness from.
+///
+/// A source records every choice the generator makes (which variant, how many items, which
+/// bits...) as a sequence of numbers. Replaying a sequence rebuilds the same value, and replaying
+/// a smaller sequence builds a simpler one: that's how failing values are shrunk.
+#[derive(Debug, Clone)]
+pub struct Source {
+    rng: Option<SplitMix64>,
+    prefix: Vec<u64>,
+    choices: Vec<u64>,
+}
+
+impl Source {
+    /// Creates a source that makes random choices, from a seed
+    pub fn from_seed(seed: u64) -> Self {
+        Self {
+            rng: Some(SplitMix64(seed)),
+            prefix: Vec::new(),
+            choices: Vec::new(),
+        }
+    }
+
+    /// Creates a source that replays `choices`, then always picks the simplest option once
+    /// they run out
+    pub fn replay(choices: Vec<u64>) -> Self {
+        Self {
+            rng: None,
+            prefix: choices,
+            choices: Vec::new(),
+        }
+    }
+
+    /// Draws a number in `0..=max`. Smaller numbers are simpler.
+    pub fn draw(&mut self, max: u64) -> u64 {
+        let value = match (self.prefix.get(self.choices.len()), &mut self.rng) {
+            (Some(&replayed), _) => replayed.min(max),
+            (None, Some(rng)) => match max.checked_add(1) {
+                Some(bound) => rng.next() % bound,
+                None => rng.next(),
+            },
+            (None, None) => 0,
+        };
+        self.choices.push(value);
+        value
+    }
+
+    /// Draws `true` with probability `1 / (1 + odds)`, and `false` when shrinking
+    pub fn draw_unlikely(&mut self, odds: u64) -> bool {
+        self.draw(odds) == 1
+    }
+
+    /// Draws `true` with probability `odds / (1 + odds)`, and `false` when shrinking
+    pub fn draw_likely(&mut self, odds: u64) -> bool {
+        self.draw(odds) != 0
+    }
+
+    /// Returns the choices made so far
+    pub fn choices(&self) -> &[u64] {
+        &self.choices
+    }
+
+    /// Consumes the source, returning the choices made so far
+    pub fn into_choices(self) -> Vec<u64> {
+        self.choices
+    }
+}
+
+/// A tiny, fast, seedable pseudo-random number generator — plenty for test data.
+#[derive(Debug, Clone)]
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
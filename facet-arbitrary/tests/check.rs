@@ -0,0 +1,67 @@
+use facet::Facet;
+use facet_arbitrary::{Config, check, try_check};
+
+#[derive(Debug, PartialEq, Facet)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, PartialEq, Facet)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Shape {
+    Dot(Point),
+    Polygon { points: Vec<Point>, label: String },
+}
+
+#[test]
+fn test_check_passes() {
+    check(&Config::new().seed(1), |shape: &Shape| {
+        let json = facet_json::to_string(shape);
+        let back: Shape = facet_json::from_str(&json).map_err(|e| e.to_string())?;
+        assert_eq!(&back, shape);
+        Ok::<_, String>(())
+    });
+}
+
+#[test]
+fn test_shrink_number() {
+    let failure = try_check(&Config::new().seed(1), |n: &u32| {
+        if *n > 1000 { Err("too big") } else { Ok(()) }
+    })
+    .unwrap_err();
+    assert_eq!(failure.value, 1001);
+    assert_eq!(failure.message, "too big");
+    assert!(failure.shrinks > 0);
+}
+
+#[test]
+fn test_shrink_structure() {
+    let failure = try_check(&Config::new().seed(2), |shape: &Shape| match shape {
+        Shape::Polygon { points, .. } if points.iter().any(|p| p.x > 10) => {
+            Err("point out of bounds")
+        }
+        _ => Ok(()),
+    })
+    .unwrap_err();
+    assert_eq!(
+        failure.value,
+        Shape::Polygon {
+            points: vec![Point { x: 11, y: 0 }],
+            label: String::new(),
+        }
+    );
+}
+
+#[test]
+fn test_panics_are_failures() {
+    let failure = try_check(&Config::new().seed(3), |s: &String| {
+        assert!(!s.contains('x'), "found an x");
+        Ok::<_, String>(())
+    })
+    .unwrap_err();
+    assert_eq!(failure.value, "x");
+    assert!(failure.message.contains("found an x"));
+    assert!(failure.to_string().contains("FACET_ARBITRARY_SEED=3"));
+}
@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+use std::num::NonZeroU16;
+
+use facet::Facet;
+use facet_arbitrary::{Config, Source, generate};
+use facet_testhelpers::test;
+
+#[derive(Debug, PartialEq, Facet)]
+struct Order {
+    id: u64,
+    customer: String,
+    lines: Vec<Line>,
+    notes: Option<String>,
+    tags: HashMap<String, bool>,
+    status: Status,
+    #[facet(skip_serializing)]
+    cached_total: Option<u32>,
+}
+
+#[derive(Debug, PartialEq, Facet)]
+struct Line {
+    sku: [u8; 4],
+    quantity: NonZeroU16,
+    discount: Percent,
+}
+
+#[derive(Debug, PartialEq, Facet)]
+#[facet(invariants = Percent::invariants)]
+struct Percent(u8);
+
+impl Percent {
+    fn invariants(&self) -> bool {
+        self.0 <= 100
+    }
+}
+
+#[derive(Debug, PartialEq, Facet)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Status {
+    Pending,
+    Shipped { carrier: String },
+    Cancelled(Option<Box<Status>>),
+}
+
+#[test]
+fn test_generate_is_deterministic() {
+    let config = Config::new();
+    let a: Order = generate(&mut Source::from_seed(42), &config)?;
+    let b: Order = generate(&mut Source::from_seed(42), &config)?;
+    assert_eq!(a, b);
+}
+
+#[test]
+fn test_generate_respects_invariants() {
+    let config = Config::new();
+    for seed in 0..200 {
+        let order: Order = generate(&mut Source::from_seed(seed), &config)?;
+        for line in &order.lines {
+            assert!(line.discount.0 <= 100);
+            assert_ne!(line.quantity.get(), 0);
+        }
+        assert_eq!(order.cached_total, None);
+    }
+}
+
+#[test]
+fn test_generate_respects_limits() {
+    let config = Config::new().max_len(2).max_depth(3);
+    for seed in 0..200 {
+        let order: Order = generate(&mut Source::from_seed(seed), &config)?;
+        assert!(order.lines.len() <= 2);
+        assert!(order.customer.chars().count() <= 2);
+        assert!(order.tags.len() <= 2);
+    }
+}
+
+#[test]
+fn test_generate_covers_variants() {
+    let config = Config::new();
+    let mut seen = [false; 3];
+    for seed in 0..100 {
+        let status: Status = generate(&mut Source::from_seed(seed), &config)?;
+        seen[match status {
+            Status::Pending => 0,
+            Status::Shipped { .. } => 1,
+            Status::Cancelled(_) => 2,
+        }] = true;
+    }
+    assert_eq!(seen, [true; 3]);
+}
+
+#[test]
+fn test_replay() {
+    let config = Config::new();
+    let mut source = Source::from_seed(7);
+    let order: Order = generate(&mut source, &config)?;
+
+    let replayed: Order = generate(&mut Source::replay(source.into_choices()), &config)?;
+    assert_eq!(replayed, order);
+
+    // Without choices, everything is as simple as can be
+    let simplest: (u32, Vec<u8>, Option<String>, Status) =
+        generate(&mut Source::replay(Vec::new()), &config)?;
+    assert_eq!(simplest, (0, vec![], None, Status::Pending));
+}
@@ -279,3 +279,20 @@ fn test_missing_required_arg() {
     let err = args_result.unwrap_err();
     insta::assert_snapshot!(err.to_string());
 }
+
+#[test]
+fn test_semver_flags() {
+    use semver::{Version, VersionReq};
+
+    #[derive(Facet, Debug)]
+    struct Args {
+        #[facet(positional)]
+        version: Version,
+        #[facet(named)]
+        requires: VersionReq,
+    }
+    let args: Args = facet_args::from_slice(&["--requires", ">=0.3, <0.5", "0.4.1-rc.1"])?;
+    assert_eq!(args.version, Version::parse("0.4.1-rc.1")?);
+    assert_eq!(args.requires, VersionReq::parse(">=0.3, <0.5")?);
+    assert!(args.requires.matches(&Version::parse("0.4.1")?));
+}
@@ -96,7 +96,7 @@ unsafe impl<'a, T: Facet<'a>> Facet<'a> for alloc::sync::Arc<T> {
                                 .borrow_fn(|this| {
                                     let arc_ptr = unsafe { this.as_ptr::<alloc::sync::Arc<T>>() };
                                     let ptr = unsafe { alloc::sync::Arc::as_ptr(&*arc_ptr) };
-                                    PtrConst::new(ptr)
+                                    PtrConst::new(ptr).into()
                                 })
                                 .new_into_fn(|this, ptr| {
                                     let t = unsafe { ptr.read::<T>() };
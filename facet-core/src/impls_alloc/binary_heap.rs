@@ -0,0 +1,147 @@
+use crate::*;
+
+use alloc::boxed::Box;
+use alloc::collections::BinaryHeap;
+
+type BinaryHeapIterator<'mem, T> = alloc::collections::binary_heap::Iter<'mem, T>;
+
+unsafe impl<'a, T> Facet<'a> for BinaryHeap<T>
+where
+    T: Facet<'a> + core::cmp::Ord,
+{
+    const VTABLE: &'static ValueVTable = &const {
+        ValueVTable::builder::<Self>()
+            .type_name(|f, opts| {
+                if let Some(opts) = opts.for_children() {
+                    write!(f, "{}<", Self::SHAPE.type_identifier)?;
+                    T::SHAPE.vtable.type_name()(f, opts)?;
+                    write!(f, ">")
+                } else {
+                    write!(f, "{}<⋯>", Self::SHAPE.type_identifier)
+                }
+            })
+            .default_in_place(|| Some(|target| unsafe { target.put(Self::default()) }))
+            .clone_into(|| {
+                if T::SHAPE.vtable.has_clone_into() {
+                    Some(|src, dst| unsafe {
+                        let mut new_heap = BinaryHeap::with_capacity(src.len());
+
+                        let t_clone_into = <VTableView<T>>::of().clone_into().unwrap();
+
+                        // Pushing the items in heap order keeps them in the same order
+                        for item in src.as_slice() {
+                            use crate::TypedPtrUninit;
+                            use core::mem::MaybeUninit;
+
+                            let mut new_item = MaybeUninit::<T>::uninit();
+                            let uninit_item = TypedPtrUninit::new(new_item.as_mut_ptr());
+
+                            (t_clone_into)(item, uninit_item);
+
+                            new_heap.push(new_item.assume_init());
+                        }
+
+                        dst.put(new_heap)
+                    })
+                } else {
+                    None
+                }
+            })
+            .debug(|| {
+                if T::SHAPE.vtable.has_debug() {
+                    Some(|value, f| {
+                        write!(f, "[")?;
+                        for (i, item) in value.iter().enumerate() {
+                            if i > 0 {
+                                write!(f, ", ")?;
+                            }
+                            (<VTableView<T>>::of().debug().unwrap())(item, f)?;
+                        }
+                        write!(f, "]")
+                    })
+                } else {
+                    None
+                }
+            })
+            .marker_traits(|| {
+                MarkerTraits::SEND
+                    .union(MarkerTraits::SYNC)
+                    .union(MarkerTraits::UNPIN)
+                    .union(MarkerTraits::UNWIND_SAFE)
+                    .union(MarkerTraits::REF_UNWIND_SAFE)
+                    .intersection(T::SHAPE.vtable.marker_traits())
+            })
+            .build()
+    };
+
+    const SHAPE: &'static Shape<'static> = &const {
+        Shape::builder_for_sized::<Self>()
+            .type_identifier("BinaryHeap")
+            .type_params(&[TypeParam {
+                name: "T",
+                shape: || T::SHAPE,
+            }])
+            .ty(Type::User(UserType::Opaque))
+            .def(Def::List(
+                ListDef::builder()
+                    .vtable(
+                        &const {
+                            // Items are exposed in the heap's internal (arbitrary) order. They
+                            // can't be mutated in place, as that could break the heap property.
+                            ListVTable::builder()
+                                .init_in_place_with_capacity(|data, capacity| unsafe {
+                                    data.put(Self::with_capacity(capacity))
+                                })
+                                .push(|ptr, item| unsafe {
+                                    let heap = ptr.as_mut::<Self>();
+                                    let item = item.read::<T>();
+                                    (*heap).push(item);
+                                })
+                                .len(|ptr| unsafe {
+                                    let heap = ptr.get::<Self>();
+                                    heap.len()
+                                })
+                                .get(|ptr, index| unsafe {
+                                    let heap = ptr.get::<Self>();
+                                    let item = heap.as_slice().get(index)?;
+                                    Some(PtrConst::new(item))
+                                })
+                                .as_ptr(|ptr| unsafe {
+                                    let heap = ptr.get::<Self>();
+                                    PtrConst::new(heap.as_slice().as_ptr())
+                                })
+                                .iter_vtable(
+                                    IterVTable::builder()
+                                        .init_with_value(|ptr| unsafe {
+                                            let heap = ptr.get::<Self>();
+                                            let iter: BinaryHeapIterator<T> = heap.iter();
+                                            let iter_state = Box::new(iter);
+                                            PtrMut::new(Box::into_raw(iter_state) as *mut u8)
+                                        })
+                                        .next(|iter_ptr| unsafe {
+                                            let state =
+                                                iter_ptr.as_mut::<BinaryHeapIterator<'_, T>>();
+                                            state.next().map(|value| PtrConst::new(value))
+                                        })
+                                        .next_back(|iter_ptr| unsafe {
+                                            let state =
+                                                iter_ptr.as_mut::<BinaryHeapIterator<'_, T>>();
+                                            state.next_back().map(|value| PtrConst::new(value))
+                                        })
+                                        .dealloc(|iter_ptr| unsafe {
+                                            drop(Box::from_raw(
+                                                iter_ptr.as_ptr::<BinaryHeapIterator<'_, T>>()
+                                                    as *mut BinaryHeapIterator<'_, T>,
+                                            ));
+                                        })
+                                        .build(),
+                                )
+                                .build()
+                        },
+                    )
+                    .t(|| T::SHAPE)
+                    .build(),
+            ))
+            .build()
+    };
+}
@@ -83,7 +83,7 @@ unsafe impl<'a, T: Facet<'a>> Facet<'a> for alloc::boxed::Box<T> {
                                     let ptr = unsafe {
                                         &raw const **this.as_ptr::<alloc::boxed::Box<T>>()
                                     };
-                                    PtrConst::new(ptr)
+                                    PtrConst::new(ptr).into()
                                 })
                                 .new_into_fn(|this, ptr| {
                                     let t = unsafe { ptr.read::<T>() };
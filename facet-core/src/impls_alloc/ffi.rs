@@ -0,0 +1,56 @@
+use alloc::ffi::CString;
+use alloc::vec::Vec;
+
+use crate::*;
+
+unsafe impl Facet<'_> for CString {
+    const VTABLE: &'static ValueVTable = &const {
+        let mut vtable = value_vtable!(CString, |f, _opts| write!(
+            f,
+            "{}",
+            Self::SHAPE.type_identifier
+        ));
+
+        {
+            let vtable = vtable.sized_mut().unwrap();
+            vtable.parse = || {
+                Some(|s, target| match CString::new(s) {
+                    Ok(s) => Ok(unsafe { target.put(s) }),
+                    Err(_) => Err(ParseError::Generic("C strings can't contain NUL bytes")),
+                })
+            };
+            // C strings that aren't valid UTF-8 are serialized as their bytes
+            vtable.try_from = || {
+                Some(|source, source_shape, target| {
+                    if source_shape.is_type::<Vec<u8>>() {
+                        let bytes = unsafe { source.read::<Vec<u8>>() };
+                        match CString::new(bytes) {
+                            Ok(s) => Ok(unsafe { target.put(s) }),
+                            Err(_) => {
+                                Err(TryFromError::Generic("C strings can't contain NUL bytes"))
+                            }
+                        }
+                    } else {
+                        Err(TryFromError::UnsupportedSourceShape {
+                            src_shape: source_shape,
+                            expected: &[<Vec<u8>>::SHAPE],
+                        })
+                    }
+                })
+            };
+        }
+        vtable
+    };
+
+    const SHAPE: &'static Shape<'static> = &const {
+        Shape::builder_for_sized::<Self>()
+            .type_identifier("CString")
+            .ty(Type::User(UserType::Opaque))
+            .def(Def::Scalar(
+                ScalarDef::builder()
+                    .affinity(&const { ScalarAffinity::platform_string().build() })
+                    .build(),
+            ))
+            .build()
+    };
+}
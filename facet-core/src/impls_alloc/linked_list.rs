@@ -0,0 +1,181 @@
+use crate::*;
+use core::hash::Hash as _;
+
+use alloc::boxed::Box;
+use alloc::collections::LinkedList;
+
+type LinkedListIterator<'mem, T> = alloc::collections::linked_list::Iter<'mem, T>;
+
+unsafe impl<'a, T> Facet<'a> for LinkedList<T>
+where
+    T: Facet<'a>,
+{
+    const VTABLE: &'static ValueVTable = &const {
+        ValueVTable::builder::<Self>()
+            .type_name(|f, opts| {
+                if let Some(opts) = opts.for_children() {
+                    write!(f, "{}<", Self::SHAPE.type_identifier)?;
+                    T::SHAPE.vtable.type_name()(f, opts)?;
+                    write!(f, ">")
+                } else {
+                    write!(f, "{}<⋯>", Self::SHAPE.type_identifier)
+                }
+            })
+            .default_in_place(|| Some(|target| unsafe { target.put(Self::default()) }))
+            .clone_into(|| {
+                if T::SHAPE.vtable.has_clone_into() {
+                    Some(|src, dst| unsafe {
+                        let mut new_list = LinkedList::new();
+
+                        let t_clone_into = <VTableView<T>>::of().clone_into().unwrap();
+
+                        for item in src {
+                            use crate::TypedPtrUninit;
+                            use core::mem::MaybeUninit;
+
+                            let mut new_item = MaybeUninit::<T>::uninit();
+                            let uninit_item = TypedPtrUninit::new(new_item.as_mut_ptr());
+
+                            (t_clone_into)(item, uninit_item);
+
+                            new_list.push_back(new_item.assume_init());
+                        }
+
+                        dst.put(new_list)
+                    })
+                } else {
+                    None
+                }
+            })
+            .debug(|| {
+                if T::SHAPE.vtable.has_debug() {
+                    Some(|value, f| {
+                        write!(f, "[")?;
+                        for (i, item) in value.iter().enumerate() {
+                            if i > 0 {
+                                write!(f, ", ")?;
+                            }
+                            (<VTableView<T>>::of().debug().unwrap())(item, f)?;
+                        }
+                        write!(f, "]")
+                    })
+                } else {
+                    None
+                }
+            })
+            .partial_eq(|| {
+                if T::SHAPE.vtable.has_partial_eq() {
+                    Some(|a, b| {
+                        if a.len() != b.len() {
+                            return false;
+                        }
+                        for (item_a, item_b) in a.iter().zip(b.iter()) {
+                            if !(<VTableView<T>>::of().partial_eq().unwrap())(item_a, item_b) {
+                                return false;
+                            }
+                        }
+                        true
+                    })
+                } else {
+                    None
+                }
+            })
+            .hash(|| {
+                if T::SHAPE.vtable.has_hash() {
+                    Some(|list, hasher_this, hasher_write_fn| unsafe {
+                        use crate::HasherProxy;
+                        let t_hash = <VTableView<T>>::of().hash().unwrap_unchecked();
+                        let mut hasher = HasherProxy::new(hasher_this, hasher_write_fn);
+                        list.len().hash(&mut hasher);
+                        for item in list {
+                            (t_hash)(item, hasher_this, hasher_write_fn);
+                        }
+                    })
+                } else {
+                    None
+                }
+            })
+            .marker_traits(|| {
+                MarkerTraits::SEND
+                    .union(MarkerTraits::SYNC)
+                    .union(MarkerTraits::EQ)
+                    .union(MarkerTraits::UNPIN)
+                    .union(MarkerTraits::UNWIND_SAFE)
+                    .union(MarkerTraits::REF_UNWIND_SAFE)
+                    .intersection(T::SHAPE.vtable.marker_traits())
+            })
+            .build()
+    };
+
+    const SHAPE: &'static Shape<'static> = &const {
+        Shape::builder_for_sized::<Self>()
+            .type_identifier("LinkedList")
+            .type_params(&[TypeParam {
+                name: "T",
+                shape: || T::SHAPE,
+            }])
+            .ty(Type::User(UserType::Opaque))
+            .def(Def::List(
+                ListDef::builder()
+                    .vtable(
+                        &const {
+                            // The items of a `LinkedList` aren't contiguous: there's no
+                            // `as_ptr`, and `get` walks the list from its front
+                            ListVTable::builder()
+                                .init_in_place_with_capacity(|data, _capacity| unsafe {
+                                    data.put(Self::new())
+                                })
+                                .push(|ptr, item| unsafe {
+                                    let list = ptr.as_mut::<Self>();
+                                    let item = item.read::<T>();
+                                    (*list).push_back(item);
+                                })
+                                .len(|ptr| unsafe {
+                                    let list = ptr.get::<Self>();
+                                    list.len()
+                                })
+                                .get(|ptr, index| unsafe {
+                                    let list = ptr.get::<Self>();
+                                    let item = list.iter().nth(index)?;
+                                    Some(PtrConst::new(item))
+                                })
+                                .get_mut(|ptr, index| unsafe {
+                                    let list = ptr.as_mut::<Self>();
+                                    let item = list.iter_mut().nth(index)?;
+                                    Some(PtrMut::new(item))
+                                })
+                                .iter_vtable(
+                                    IterVTable::builder()
+                                        .init_with_value(|ptr| unsafe {
+                                            let list = ptr.get::<Self>();
+                                            let iter: LinkedListIterator<T> = list.iter();
+                                            let iter_state = Box::new(iter);
+                                            PtrMut::new(Box::into_raw(iter_state) as *mut u8)
+                                        })
+                                        .next(|iter_ptr| unsafe {
+                                            let state =
+                                                iter_ptr.as_mut::<LinkedListIterator<'_, T>>();
+                                            state.next().map(|value| PtrConst::new(value))
+                                        })
+                                        .next_back(|iter_ptr| unsafe {
+                                            let state =
+                                                iter_ptr.as_mut::<LinkedListIterator<'_, T>>();
+                                            state.next_back().map(|value| PtrConst::new(value))
+                                        })
+                                        .dealloc(|iter_ptr| unsafe {
+                                            drop(Box::from_raw(
+                                                iter_ptr.as_ptr::<LinkedListIterator<'_, T>>()
+                                                    as *mut LinkedListIterator<'_, T>,
+                                            ));
+                                        })
+                                        .build(),
+                                )
+                                .build()
+                        },
+                    )
+                    .t(|| T::SHAPE)
+                    .build(),
+            ))
+            .build()
+    };
+}
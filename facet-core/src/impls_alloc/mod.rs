@@ -1,7 +1,13 @@
 mod arc;
+mod binary_heap;
 mod boxed;
 mod btreemap;
 mod btreeset;
+mod cow;
+mod ffi;
+mod linked_list;
 mod rc;
 mod string;
+mod unsized_smartptr;
 mod vec;
+mod vec_deque;
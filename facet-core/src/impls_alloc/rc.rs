@@ -85,7 +85,7 @@ unsafe impl<'a, T: Facet<'a>> Facet<'a> for alloc::rc::Rc<T> {
                             SmartPointerVTable::builder()
                                 .borrow_fn(|this| {
                                     let ptr = Self::as_ptr(unsafe { this.get() });
-                                    PtrConst::new(ptr)
+                                    PtrConst::new(ptr).into()
                                 })
                                 .new_into_fn(|this, ptr| {
                                     let t = unsafe { ptr.read::<T>() };
@@ -0,0 +1,204 @@
+//! Smart pointers to unsized values: `Box<str>`, `Arc<[T]>`, `Rc<str>`...
+//!
+//! These can't share the impls of their sized counterparts: the pointee can't be moved in or out
+//! of the pointer, so they're built from the owned form of the pointee (`String` for `str`,
+//! `Vec<T>` for `[T]`), and borrowed through a wide pointer.
+
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use crate::*;
+
+macro_rules! impl_facet_for_unsized_smart_pointer {
+    ($ptr:ident, $known:ident, $flags:expr, $marker_traits:expr, $slice_clone_into:expr) => {
+        unsafe impl<'a> Facet<'a> for $ptr<str> {
+            const VTABLE: &'static ValueVTable = &const {
+                value_vtable!($ptr<str>, |f, opts| {
+                    write!(f, "{}", Self::SHAPE.type_identifier)?;
+                    if let Some(opts) = opts.for_children() {
+                        write!(f, "<")?;
+                        (str::SHAPE.vtable.type_name())(f, opts)?;
+                        write!(f, ">")?;
+                    } else {
+                        write!(f, "<…>")?;
+                    }
+                    Ok(())
+                })
+            };
+
+            const SHAPE: &'static Shape<'static> = &const {
+                Shape::builder_for_sized::<Self>()
+                    .type_identifier(stringify!($ptr))
+                    .type_params(&[TypeParam {
+                        name: "T",
+                        shape: || str::SHAPE,
+                    }])
+                    .ty(Type::User(UserType::Opaque))
+                    .def(Def::SmartPointer(
+                        SmartPointerDef::builder()
+                            .pointee(|| str::SHAPE)
+                            .owned(|| String::SHAPE)
+                            .flags($flags)
+                            .known(KnownSmartPointer::$known)
+                            .vtable(
+                                &const {
+                                    SmartPointerVTable::builder()
+                                        .borrow_fn(|this| unsafe {
+                                            let s: &str = this.get::<$ptr<str>>();
+                                            PtrConstWide::new(&raw const *s).into()
+                                        })
+                                        .new_into_fn(|this, ptr| unsafe {
+                                            let s = ptr.read::<String>();
+                                            this.put(<$ptr<str>>::from(s))
+                                        })
+                                        .build()
+                                },
+                            )
+                            .build(),
+                    ))
+                    .build()
+            };
+        }
+
+        unsafe impl<'a, T: Facet<'a>> Facet<'a> for $ptr<[T]> {
+            const VTABLE: &'static ValueVTable = &const {
+                ValueVTable::builder::<Self>()
+                    .type_name(|f, opts| {
+                        write!(f, "{}", Self::SHAPE.type_identifier)?;
+                        if let Some(opts) = opts.for_children() {
+                            write!(f, "<")?;
+                            (<[T]>::SHAPE.vtable.type_name())(f, opts)?;
+                            write!(f, ">")
+                        } else {
+                            write!(f, "<…>")
+                        }
+                    })
+                    .default_in_place(|| {
+                        Some(|target| unsafe { target.put(<$ptr<[T]>>::from(Vec::new())) })
+                    })
+                    .clone_into($slice_clone_into)
+                    .debug(|| {
+                        if T::SHAPE.vtable.has_debug() {
+                            Some(|value, f| {
+                                write!(f, "[")?;
+                                for (i, item) in value.iter().enumerate() {
+                                    if i > 0 {
+                                        write!(f, ", ")?;
+                                    }
+                                    (<VTableView<T>>::of().debug().unwrap())(item, f)?;
+                                }
+                                write!(f, "]")
+                            })
+                        } else {
+                            None
+                        }
+                    })
+                    .partial_eq(|| {
+                        if T::SHAPE.vtable.has_partial_eq() {
+                            Some(|a, b| {
+                                a.len() == b.len()
+                                    && a.iter().zip(b.iter()).all(|(a, b)| {
+                                        (<VTableView<T>>::of().partial_eq().unwrap())(a, b)
+                                    })
+                            })
+                        } else {
+                            None
+                        }
+                    })
+                    .marker_traits(|| $marker_traits.intersection(T::SHAPE.vtable.marker_traits()))
+                    .build()
+            };
+
+            const SHAPE: &'static Shape<'static> = &const {
+                Shape::builder_for_sized::<Self>()
+                    .type_identifier(stringify!($ptr))
+                    .type_params(&[TypeParam {
+                        name: "T",
+                        shape: || <[T]>::SHAPE,
+                    }])
+                    .ty(Type::User(UserType::Opaque))
+                    .def(Def::SmartPointer(
+                        SmartPointerDef::builder()
+                            .pointee(|| <[T]>::SHAPE)
+                            .owned(|| <Vec<T>>::SHAPE)
+                            .flags($flags)
+                            .known(KnownSmartPointer::$known)
+                            .vtable(
+                                &const {
+                                    SmartPointerVTable::builder()
+                                        .borrow_fn(|this| unsafe {
+                                            let s: &[T] = this.get::<$ptr<[T]>>();
+                                            PtrConstWide::new(&raw const *s).into()
+                                        })
+                                        .new_into_fn(|this, ptr| unsafe {
+                                            let items = ptr.read::<Vec<T>>();
+                                            this.put(<$ptr<[T]>>::from(items))
+                                        })
+                                        .build()
+                                },
+                            )
+                            .build(),
+                    ))
+                    .build()
+            };
+        }
+    };
+}
+
+impl_facet_for_unsized_smart_pointer!(
+    Box,
+    Box,
+    SmartPointerFlags::EMPTY,
+    MarkerTraits::SEND
+        .union(MarkerTraits::SYNC)
+        .union(MarkerTraits::EQ)
+        .union(MarkerTraits::UNPIN)
+        .union(MarkerTraits::UNWIND_SAFE)
+        .union(MarkerTraits::REF_UNWIND_SAFE),
+    // Boxed slices own their items, so they're cloned one by one
+    || {
+        if T::SHAPE.vtable.has_clone_into() {
+            Some(|src, dst| unsafe {
+                let t_clone_into = <VTableView<T>>::of().clone_into().unwrap();
+                let items: Vec<T> = src
+                    .iter()
+                    .map(|item| {
+                        let mut new_item = core::mem::MaybeUninit::<T>::uninit();
+                        (t_clone_into)(item, TypedPtrUninit::new(new_item.as_mut_ptr()));
+                        new_item.assume_init()
+                    })
+                    .collect();
+                dst.put(items.into_boxed_slice())
+            })
+        } else {
+            None
+        }
+    }
+);
+
+impl_facet_for_unsized_smart_pointer!(
+    Arc,
+    Arc,
+    SmartPointerFlags::ATOMIC,
+    MarkerTraits::SEND
+        .union(MarkerTraits::SYNC)
+        .union(MarkerTraits::EQ)
+        .union(MarkerTraits::UNPIN)
+        .union(MarkerTraits::UNWIND_SAFE)
+        .union(MarkerTraits::REF_UNWIND_SAFE),
+    || Some(|src, dst| unsafe { dst.put(Arc::clone(src)) })
+);
+
+impl_facet_for_unsized_smart_pointer!(
+    Rc,
+    Rc,
+    SmartPointerFlags::EMPTY,
+    MarkerTraits::EQ
+        .union(MarkerTraits::UNPIN)
+        .union(MarkerTraits::UNWIND_SAFE)
+        .union(MarkerTraits::REF_UNWIND_SAFE),
+    || Some(|src, dst| unsafe { dst.put(Rc::clone(src)) })
+);
@@ -142,6 +142,15 @@ where
                                     let item = vec.get_mut(index)?;
                                     Some(PtrMut::new(item))
                                 })
+                                .insert(|ptr, index, item| unsafe {
+                                    let vec = ptr.as_mut::<Self>();
+                                    let item = item.read::<T>();
+                                    vec.insert(index, item);
+                                })
+                                .remove(|ptr, index, item| unsafe {
+                                    let vec = ptr.as_mut::<Self>();
+                                    item.put(vec.remove(index));
+                                })
                                 .as_ptr(|ptr| unsafe {
                                     let vec = ptr.get::<Self>();
                                     PtrConst::new(vec.as_ptr())
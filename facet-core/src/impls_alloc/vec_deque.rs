@@ -0,0 +1,190 @@
+use crate::*;
+use core::hash::Hash as _;
+
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+
+type VecDequeIterator<'mem, T> = alloc::collections::vec_deque::Iter<'mem, T>;
+
+unsafe impl<'a, T> Facet<'a> for VecDeque<T>
+where
+    T: Facet<'a>,
+{
+    const VTABLE: &'static ValueVTable = &const {
+        ValueVTable::builder::<Self>()
+            .type_name(|f, opts| {
+                if let Some(opts) = opts.for_children() {
+                    write!(f, "{}<", Self::SHAPE.type_identifier)?;
+                    T::SHAPE.vtable.type_name()(f, opts)?;
+                    write!(f, ">")
+                } else {
+                    write!(f, "{}<⋯>", Self::SHAPE.type_identifier)
+                }
+            })
+            .default_in_place(|| Some(|target| unsafe { target.put(Self::default()) }))
+            .clone_into(|| {
+                if T::SHAPE.vtable.has_clone_into() {
+                    Some(|src, dst| unsafe {
+                        let mut new_deque = VecDeque::with_capacity(src.len());
+
+                        let t_clone_into = <VTableView<T>>::of().clone_into().unwrap();
+
+                        for item in src {
+                            use crate::TypedPtrUninit;
+                            use core::mem::MaybeUninit;
+
+                            let mut new_item = MaybeUninit::<T>::uninit();
+                            let uninit_item = TypedPtrUninit::new(new_item.as_mut_ptr());
+
+                            (t_clone_into)(item, uninit_item);
+
+                            new_deque.push_back(new_item.assume_init());
+                        }
+
+                        dst.put(new_deque)
+                    })
+                } else {
+                    None
+                }
+            })
+            .debug(|| {
+                if T::SHAPE.vtable.has_debug() {
+                    Some(|value, f| {
+                        write!(f, "[")?;
+                        for (i, item) in value.iter().enumerate() {
+                            if i > 0 {
+                                write!(f, ", ")?;
+                            }
+                            (<VTableView<T>>::of().debug().unwrap())(item, f)?;
+                        }
+                        write!(f, "]")
+                    })
+                } else {
+                    None
+                }
+            })
+            .partial_eq(|| {
+                if T::SHAPE.vtable.has_partial_eq() {
+                    Some(|a, b| {
+                        if a.len() != b.len() {
+                            return false;
+                        }
+                        for (item_a, item_b) in a.iter().zip(b.iter()) {
+                            if !(<VTableView<T>>::of().partial_eq().unwrap())(item_a, item_b) {
+                                return false;
+                            }
+                        }
+                        true
+                    })
+                } else {
+                    None
+                }
+            })
+            .hash(|| {
+                if T::SHAPE.vtable.has_hash() {
+                    Some(|deque, hasher_this, hasher_write_fn| unsafe {
+                        use crate::HasherProxy;
+                        let t_hash = <VTableView<T>>::of().hash().unwrap_unchecked();
+                        let mut hasher = HasherProxy::new(hasher_this, hasher_write_fn);
+                        deque.len().hash(&mut hasher);
+                        for item in deque {
+                            (t_hash)(item, hasher_this, hasher_write_fn);
+                        }
+                    })
+                } else {
+                    None
+                }
+            })
+            .marker_traits(|| {
+                MarkerTraits::SEND
+                    .union(MarkerTraits::SYNC)
+                    .union(MarkerTraits::EQ)
+                    .union(MarkerTraits::UNPIN)
+                    .union(MarkerTraits::UNWIND_SAFE)
+                    .union(MarkerTraits::REF_UNWIND_SAFE)
+                    .intersection(T::SHAPE.vtable.marker_traits())
+            })
+            .build()
+    };
+
+    const SHAPE: &'static Shape<'static> = &const {
+        Shape::builder_for_sized::<Self>()
+            .type_identifier("VecDeque")
+            .type_params(&[TypeParam {
+                name: "T",
+                shape: || T::SHAPE,
+            }])
+            .ty(Type::User(UserType::Opaque))
+            .def(Def::List(
+                ListDef::builder()
+                    .vtable(
+                        &const {
+                            // The items of a `VecDeque` live in a ring buffer, which may wrap
+                            // around: there's no `as_ptr`, items are read with `get` or iterated
+                            ListVTable::builder()
+                                .init_in_place_with_capacity(|data, capacity| unsafe {
+                                    data.put(Self::with_capacity(capacity))
+                                })
+                                .push(|ptr, item| unsafe {
+                                    let deque = ptr.as_mut::<Self>();
+                                    let item = item.read::<T>();
+                                    (*deque).push_back(item);
+                                })
+                                .len(|ptr| unsafe {
+                                    let deque = ptr.get::<Self>();
+                                    deque.len()
+                                })
+                                .get(|ptr, index| unsafe {
+                                    let deque = ptr.get::<Self>();
+                                    let item = deque.get(index)?;
+                                    Some(PtrConst::new(item))
+                                })
+                                .get_mut(|ptr, index| unsafe {
+                                    let deque = ptr.as_mut::<Self>();
+                                    let item = deque.get_mut(index)?;
+                                    Some(PtrMut::new(item))
+                                })
+                                .insert(|ptr, index, item| unsafe {
+                                    let deque = ptr.as_mut::<Self>();
+                                    let item = item.read::<T>();
+                                    deque.insert(index, item);
+                                })
+                                .remove(|ptr, index, item| unsafe {
+                                    let deque = ptr.as_mut::<Self>();
+                                    item.put(deque.remove(index).unwrap());
+                                })
+                                .iter_vtable(
+                                    IterVTable::builder()
+                                        .init_with_value(|ptr| unsafe {
+                                            let deque = ptr.get::<Self>();
+                                            let iter: VecDequeIterator<T> = deque.iter();
+                                            let iter_state = Box::new(iter);
+                                            PtrMut::new(Box::into_raw(iter_state) as *mut u8)
+                                        })
+                                        .next(|iter_ptr| unsafe {
+                                            let state =
+                                                iter_ptr.as_mut::<VecDequeIterator<'_, T>>();
+                                            state.next().map(|value| PtrConst::new(value))
+                                        })
+                                        .next_back(|iter_ptr| unsafe {
+                                            let state =
+                                                iter_ptr.as_mut::<VecDequeIterator<'_, T>>();
+                                            state.next_back().map(|value| PtrConst::new(value))
+                                        })
+                                        .dealloc(|iter_ptr| unsafe {
+                                            drop(Box::from_raw(
+                                                iter_ptr.as_ptr::<VecDequeIterator<'_, T>>()
+                                                    as *mut VecDequeIterator<'_, T>,
+                                            ));
+                                        })
+                                        .build(),
+                                )
+                                .build()
+                        },
+                    )
+                    .t(|| T::SHAPE)
+                    .build(),
+            ))
+            .build()
+    };
+}
@@ -0,0 +1,98 @@
+use core::sync::atomic::{self, Ordering};
+
+use crate::{
+    Def, Facet, PtrConst, PtrMut, PtrUninit, ScalarDef, Shape, TryFromError, TryIntoInnerError,
+    Type, UserType, ValueVTable, value_vtable,
+};
+
+/// Implements `Facet` for an atomic, as a scalar with the same affinity as the primitive it holds.
+///
+/// Atomics can be built from and turned into that primitive, but don't implement
+/// `try_borrow_inner`, since reading them requires picking an ordering: see `ScalarDef::atomic_load`.
+macro_rules! impl_facet_for_atomic {
+    ($atomic:ident, $type:ty, $width:literal) => {
+        #[cfg(target_has_atomic = $width)]
+        unsafe impl Facet<'_> for atomic::$atomic {
+            const VTABLE: &'static ValueVTable = &const {
+                unsafe fn try_from<'shape, 'dst>(
+                    src_ptr: PtrConst<'_>,
+                    src_shape: &'shape Shape<'shape>,
+                    dst: PtrUninit<'dst>,
+                ) -> Result<PtrMut<'dst>, TryFromError<'shape>> {
+                    if src_shape != <$type as Facet>::SHAPE {
+                        return Err(TryFromError::UnsupportedSourceShape {
+                            src_shape,
+                            expected: &[<$type as Facet>::SHAPE],
+                        });
+                    }
+                    let value = unsafe { src_ptr.read::<$type>() };
+                    Ok(unsafe { dst.put(atomic::$atomic::new(value)) })
+                }
+
+                unsafe fn try_into_inner<'dst>(
+                    src_ptr: PtrMut<'_>,
+                    dst: PtrUninit<'dst>,
+                ) -> Result<PtrMut<'dst>, TryIntoInnerError> {
+                    let value = unsafe { src_ptr.read::<atomic::$atomic>() };
+                    Ok(unsafe { dst.put(value.into_inner()) })
+                }
+
+                let mut vtable = value_vtable!(atomic::$atomic, |f, _opts| write!(
+                    f,
+                    "{}",
+                    Self::SHAPE.type_identifier
+                ));
+                {
+                    let vtable = vtable.sized_mut().unwrap();
+                    vtable.try_from = || Some(try_from);
+                    vtable.try_into_inner = || Some(try_into_inner);
+                }
+                vtable
+            };
+
+            const SHAPE: &'static Shape<'static> = &const {
+                // Function to return inner type's shape
+                fn inner_shape() -> &'static Shape<'static> {
+                    <$type as Facet>::SHAPE
+                }
+
+                unsafe fn load<'dst>(
+                    src: PtrConst<'_>,
+                    ordering: Ordering,
+                    dst: PtrUninit<'dst>,
+                ) -> PtrMut<'dst> {
+                    let value = unsafe { src.get::<atomic::$atomic>() }.load(ordering);
+                    unsafe { dst.put(value) }
+                }
+
+                let Def::Scalar(inner_def) = <$type as Facet>::SHAPE.def else {
+                    panic!("atomics should hold scalars")
+                };
+
+                Shape::builder_for_sized::<Self>()
+                    .type_identifier(stringify!($atomic))
+                    .ty(Type::User(UserType::Opaque))
+                    .def(Def::Scalar(
+                        ScalarDef::builder()
+                            .affinity(inner_def.affinity)
+                            .atomic_load(load)
+                            .build(),
+                    ))
+                    .inner(inner_shape)
+                    .build()
+            };
+        }
+    };
+}
+
+impl_facet_for_atomic!(AtomicBool, bool, "8");
+impl_facet_for_atomic!(AtomicU8, u8, "8");
+impl_facet_for_atomic!(AtomicU16, u16, "16");
+impl_facet_for_atomic!(AtomicU32, u32, "32");
+impl_facet_for_atomic!(AtomicU64, u64, "64");
+impl_facet_for_atomic!(AtomicUsize, usize, "ptr");
+impl_facet_for_atomic!(AtomicI8, i8, "8");
+impl_facet_for_atomic!(AtomicI16, i16, "16");
+impl_facet_for_atomic!(AtomicI32, i32, "32");
+impl_facet_for_atomic!(AtomicI64, i64, "64");
+impl_facet_for_atomic!(AtomicIsize, isize, "ptr");
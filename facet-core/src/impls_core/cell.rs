@@ -0,0 +1,240 @@
+use core::cell::{Cell, RefCell};
+use core::mem::MaybeUninit;
+
+use crate::{
+    Def, Facet, KnownSmartPointer, MarkerTraits, Shape, SmartPointerDef, SmartPointerFlags,
+    SmartPointerVTable, Type, TypedPtrUninit, UserType, VTableView, ValueVTable,
+};
+
+unsafe impl<'a, T: Facet<'a> + Copy> Facet<'a> for Cell<T> {
+    const VTABLE: &'static ValueVTable = &const {
+        ValueVTable::builder::<Self>()
+            .type_name(|f, opts| {
+                if let Some(opts) = opts.for_children() {
+                    write!(f, "{}<", Self::SHAPE.type_identifier)?;
+                    T::SHAPE.vtable.type_name()(f, opts)?;
+                    write!(f, ">")
+                } else {
+                    write!(f, "{}<⋯>", Self::SHAPE.type_identifier)
+                }
+            })
+            .default_in_place(|| {
+                if T::SHAPE.vtable.has_default_in_place() {
+                    Some(|target| unsafe {
+                        let mut t = MaybeUninit::<T>::uninit();
+                        (<VTableView<T>>::of().default_in_place().unwrap())(TypedPtrUninit::new(
+                            t.as_mut_ptr(),
+                        ));
+                        target.put(Cell::new(t.assume_init()))
+                    })
+                } else {
+                    None
+                }
+            })
+            .clone_into(|| Some(|src, dst| unsafe { dst.put(Cell::new(src.get())) }))
+            .debug(|| {
+                if T::SHAPE.vtable.has_debug() {
+                    Some(|value, f| {
+                        write!(f, "Cell {{ value: ")?;
+                        (<VTableView<T>>::of().debug().unwrap())(&value.get(), f)?;
+                        write!(f, " }}")
+                    })
+                } else {
+                    None
+                }
+            })
+            .marker_traits(|| {
+                MarkerTraits::SEND
+                    .union(MarkerTraits::EQ)
+                    .union(MarkerTraits::UNPIN)
+                    .union(MarkerTraits::UNWIND_SAFE)
+                    .intersection(T::SHAPE.vtable.marker_traits())
+            })
+            .build()
+    };
+
+    const SHAPE: &'static Shape<'static> = &const {
+        Shape::builder_for_sized::<Self>()
+            .type_identifier("Cell")
+            .type_params(&[crate::TypeParam {
+                name: "T",
+                shape: || T::SHAPE,
+            }])
+            .ty(Type::User(UserType::Opaque))
+            .def(Def::SmartPointer(
+                SmartPointerDef::builder()
+                    .pointee(|| T::SHAPE)
+                    .flags(SmartPointerFlags::EMPTY)
+                    .known(KnownSmartPointer::Cell)
+                    .vtable(
+                        &const {
+                            let vtable =
+                                SmartPointerVTable::builder().new_into_fn(|this, ptr| unsafe {
+                                    this.put(Cell::new(ptr.read::<T>()))
+                                });
+
+                            // A `Cell` can be written to through a shared reference, so its value
+                            // is never borrowed: reading it copies the value into a box, which
+                            // serves as the guard
+                            #[cfg(feature = "alloc")]
+                            let vtable = vtable.read_fn(|this| unsafe {
+                                let copy = alloc::boxed::Box::into_raw(alloc::boxed::Box::new(
+                                    this.get::<Cell<T>>().get(),
+                                ));
+                                Ok(crate::LockResult::new(
+                                    crate::PtrMut::new(copy),
+                                    crate::PtrConst::new(copy),
+                                    &const {
+                                        crate::LockGuardVTable {
+                                            drop_in_place: |guard| {
+                                                drop(alloc::boxed::Box::from_raw(
+                                                    guard.as_ptr::<T>() as *mut T,
+                                                ))
+                                            },
+                                        }
+                                    },
+                                ))
+                            });
+
+                            vtable.build()
+                        },
+                    )
+                    .build(),
+            ))
+            .build()
+    };
+}
+
+unsafe impl<'a, T: Facet<'a>> Facet<'a> for RefCell<T> {
+    const VTABLE: &'static ValueVTable = &const {
+        ValueVTable::builder::<Self>()
+            .type_name(|f, opts| {
+                if let Some(opts) = opts.for_children() {
+                    write!(f, "{}<", Self::SHAPE.type_identifier)?;
+                    T::SHAPE.vtable.type_name()(f, opts)?;
+                    write!(f, ">")
+                } else {
+                    write!(f, "{}<⋯>", Self::SHAPE.type_identifier)
+                }
+            })
+            .default_in_place(|| {
+                if T::SHAPE.vtable.has_default_in_place() {
+                    Some(|target| unsafe {
+                        let mut t = MaybeUninit::<T>::uninit();
+                        (<VTableView<T>>::of().default_in_place().unwrap())(TypedPtrUninit::new(
+                            t.as_mut_ptr(),
+                        ));
+                        target.put(RefCell::new(t.assume_init()))
+                    })
+                } else {
+                    None
+                }
+            })
+            .debug(|| {
+                if T::SHAPE.vtable.has_debug() {
+                    Some(|value, f| {
+                        write!(f, "RefCell {{ value: ")?;
+                        match value.try_borrow() {
+                            Ok(borrowed) => {
+                                (<VTableView<T>>::of().debug().unwrap())(&borrowed, f)?;
+                            }
+                            Err(_) => write!(f, "<borrowed>")?,
+                        }
+                        write!(f, " }}")
+                    })
+                } else {
+                    None
+                }
+            })
+            .marker_traits(|| {
+                MarkerTraits::SEND
+                    .union(MarkerTraits::EQ)
+                    .union(MarkerTraits::UNPIN)
+                    .union(MarkerTraits::UNWIND_SAFE)
+                    .intersection(T::SHAPE.vtable.marker_traits())
+            })
+            .build()
+    };
+
+    const SHAPE: &'static Shape<'static> = &const {
+        Shape::builder_for_sized::<Self>()
+            .type_identifier("RefCell")
+            .type_params(&[crate::TypeParam {
+                name: "T",
+                shape: || T::SHAPE,
+            }])
+            .ty(Type::User(UserType::Opaque))
+            .def(Def::SmartPointer(
+                SmartPointerDef::builder()
+                    .pointee(|| T::SHAPE)
+                    .flags(SmartPointerFlags::EMPTY)
+                    .known(KnownSmartPointer::RefCell)
+                    .vtable(
+                        &const {
+                            let vtable =
+                                SmartPointerVTable::builder().new_into_fn(|this, ptr| unsafe {
+                                    this.put(RefCell::new(ptr.read::<T>()))
+                                });
+
+                            // `Ref` and `RefMut` guards are boxed, so that they can be type-erased
+                            #[cfg(feature = "alloc")]
+                            let vtable = vtable
+                                .read_fn(|this| unsafe {
+                                    use core::cell::Ref;
+
+                                    let borrowed =
+                                        this.get::<RefCell<T>>().try_borrow().map_err(|_| ())?;
+                                    let data = crate::PtrMut::new(&*borrowed as *const T as *mut T);
+                                    let guard = alloc::boxed::Box::into_raw(
+                                        alloc::boxed::Box::new(borrowed),
+                                    );
+                                    Ok(crate::LockResult::new(
+                                        data,
+                                        crate::PtrConst::new(guard),
+                                        &const {
+                                            crate::LockGuardVTable {
+                                                drop_in_place: |guard| {
+                                                    drop(alloc::boxed::Box::from_raw(
+                                                        guard.as_ptr::<Ref<'_, T>>()
+                                                            as *mut Ref<'_, T>,
+                                                    ))
+                                                },
+                                            }
+                                        },
+                                    ))
+                                })
+                                .write_fn(|this| unsafe {
+                                    use core::cell::RefMut;
+
+                                    let mut borrowed = this
+                                        .get::<RefCell<T>>()
+                                        .try_borrow_mut()
+                                        .map_err(|_| ())?;
+                                    let data = crate::PtrMut::new(&mut *borrowed as *mut T);
+                                    let guard = alloc::boxed::Box::into_raw(
+                                        alloc::boxed::Box::new(borrowed),
+                                    );
+                                    Ok(crate::LockResult::new(
+                                        data,
+                                        crate::PtrConst::new(guard),
+                                        &const {
+                                            crate::LockGuardVTable {
+                                                drop_in_place: |guard| {
+                                                    drop(alloc::boxed::Box::from_raw(
+                                                        guard.as_ptr::<RefMut<'_, T>>()
+                                                            as *mut RefMut<'_, T>,
+                                                    ))
+                                                },
+                                            }
+                                        },
+                                    ))
+                                });
+
+                            vtable.build()
+                        },
+                    )
+                    .build(),
+            ))
+            .build()
+    };
+}
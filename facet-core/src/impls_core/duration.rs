@@ -0,0 +1,256 @@
+use core::fmt;
+use core::time::Duration;
+
+use crate::{
+    Def, Facet, ParseError, PtrConst, PtrUninit, ScalarAffinity, ScalarDef, Shape, Type, UserType,
+    ValueVTable, value_vtable,
+};
+
+const DURATION_ERROR: &str =
+    "could not parse duration, expected something like `1h30m`, `1.5s`, `250ms` or `PT1.5S`";
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+static DURATION_MIN: Duration = Duration::ZERO;
+static DURATION_MAX: Duration = Duration::MAX;
+static DURATION_GRANULARITY: Duration = Duration::from_nanos(1);
+
+unsafe impl Facet<'_> for Duration {
+    const VTABLE: &'static ValueVTable = &const {
+        let mut vtable = value_vtable!(Duration, |f, _opts| write!(
+            f,
+            "{}",
+            Self::SHAPE.type_identifier
+        ));
+        {
+            let vtable = vtable.sized_mut().unwrap();
+            #[cfg(feature = "alloc")]
+            {
+                vtable.try_from = || {
+                    Some(
+                        |source: PtrConst, source_shape: &Shape, target: PtrUninit| {
+                            if source_shape.is_type::<alloc::string::String>() {
+                                let source = unsafe { source.read::<alloc::string::String>() };
+                                match parse_duration(&source) {
+                                    Some(val) => Ok(unsafe { target.put(val) }),
+                                    None => Err(crate::TryFromError::Generic(DURATION_ERROR)),
+                                }
+                            } else {
+                                Err(crate::TryFromError::UnsupportedSourceShape {
+                                    src_shape: source_shape,
+                                    expected: &[<alloc::string::String as Facet>::SHAPE],
+                                })
+                            }
+                        },
+                    )
+                };
+            }
+            vtable.parse = || {
+                Some(|s: &str, target: PtrUninit| {
+                    let parsed = parse_duration(s).ok_or(ParseError::Generic(DURATION_ERROR))?;
+                    Ok(unsafe { target.put(parsed) })
+                })
+            };
+            vtable.display =
+                || Some(|value, f| unsafe { fmt_duration(*value.get::<Duration>(), f) });
+        }
+        vtable
+    };
+
+    const SHAPE: &'static Shape<'static> = &const {
+        Shape::builder_for_sized::<Self>()
+            .type_identifier("Duration")
+            .ty(Type::User(UserType::Opaque))
+            .def(Def::Scalar(
+                ScalarDef::builder()
+                    .affinity(
+                        &const {
+                            ScalarAffinity::duration()
+                                .min(PtrConst::new(&raw const DURATION_MIN))
+                                .max(PtrConst::new(&raw const DURATION_MAX))
+                                .granularity(PtrConst::new(&raw const DURATION_GRANULARITY))
+                                .build()
+                        },
+                    )
+                    .build(),
+            ))
+            .build()
+    };
+}
+
+/// Formats a duration as hours, minutes and (fractional) seconds, e.g. `1h30m`, `1.5s`, or in the
+/// largest sub-second unit for durations shorter than a second, e.g. `250ms`, `1.5µs`, `42ns`.
+fn fmt_duration(duration: Duration, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let secs = duration.as_secs();
+    let nanos = duration.subsec_nanos();
+
+    if secs == 0 {
+        return match nanos {
+            0 => f.write_str("0s"),
+            1_000_000.. => fmt_decimal(f, nanos / 1_000_000, nanos % 1_000_000, 6, "ms"),
+            1_000.. => fmt_decimal(f, nanos / 1_000, nanos % 1_000, 3, "µs"),
+            _ => write!(f, "{nanos}ns"),
+        };
+    }
+
+    let (hours, minutes, secs) = (secs / 3600, secs / 60 % 60, secs % 60);
+    if hours > 0 {
+        write!(f, "{hours}h")?;
+    }
+    if minutes > 0 {
+        write!(f, "{minutes}m")?;
+    }
+    if secs > 0 || nanos > 0 {
+        fmt_decimal(f, secs, nanos, 9, "s")?;
+    }
+    Ok(())
+}
+
+/// Writes `int.frac` followed by `unit`, where `frac` has `digits` digits (before trimming
+/// trailing zeros)
+fn fmt_decimal(
+    f: &mut fmt::Formatter<'_>,
+    int: impl fmt::Display,
+    mut frac: u32,
+    mut digits: usize,
+    unit: &str,
+) -> fmt::Result {
+    if frac == 0 {
+        return write!(f, "{int}{unit}");
+    }
+    while frac % 10 == 0 {
+        frac /= 10;
+        digits -= 1;
+    }
+    write!(f, "{int}.{frac:0digits$}{unit}")
+}
+
+/// Parses a duration made of one or more `<number><unit>` components (`ns`, `us`/`µs`, `ms`,
+/// `s`, `m`, `h` or `d`, numbers may have a fractional part), e.g. `1h30m` or `1.5s`, or an ISO
+/// 8601 duration made of days, hours, minutes and seconds, e.g. `PT1H30M` or `P1DT0.5S`.
+fn parse_duration(s: &str) -> Option<Duration> {
+    let s = s.trim();
+    if let Some(iso) = s.strip_prefix('P') {
+        return parse_iso8601_duration(iso);
+    }
+    if s == "0" {
+        return Some(Duration::ZERO);
+    }
+    if s.is_empty() {
+        return None;
+    }
+
+    let mut rest = s;
+    let mut total: u128 = 0;
+    while !rest.is_empty() {
+        let (number, after) = Decimal::parse(rest)?;
+        let after = after.trim_start();
+        let unit_len = after
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(after.len());
+        let unit_nanos = match &after[..unit_len] {
+            "ns" => 1,
+            "us" | "µs" | "μs" => 1_000,
+            "ms" => 1_000_000,
+            "s" => NANOS_PER_SEC,
+            "m" => 60 * NANOS_PER_SEC,
+            "h" => 3600 * NANOS_PER_SEC,
+            "d" => 86400 * NANOS_PER_SEC,
+            _ => return None,
+        };
+        total = total.checked_add(number.scale(unit_nanos)?)?;
+        rest = after[unit_len..].trim_start();
+    }
+    nanos_to_duration(total)
+}
+
+/// Parses the part of an ISO 8601 duration after the leading `P`. Years and months are rejected,
+/// since they don't have a fixed length.
+fn parse_iso8601_duration(s: &str) -> Option<Duration> {
+    let (date, time) = match s.split_once('T') {
+        Some((date, time)) if !time.is_empty() => (date, Some(time)),
+        Some(_) => return None,
+        None => (s, None),
+    };
+    if date.is_empty() && time.is_none() {
+        return None;
+    }
+
+    let mut total: u128 = 0;
+    for (part, designators) in [
+        (date, &[('W', 7 * 86400), ('D', 86400)][..]),
+        (time.unwrap_or(""), &[('H', 3600), ('M', 60), ('S', 1)][..]),
+    ] {
+        let mut rest = part;
+        let mut designators = designators.iter();
+        while !rest.is_empty() {
+            let (number, after) = Decimal::parse(rest)?;
+            let designator = after.chars().next()?;
+            // Designators must come in order, and each at most once
+            let (_, secs) = designators.find(|(d, _)| *d == designator)?;
+            total = total.checked_add(number.scale(secs * NANOS_PER_SEC)?)?;
+            rest = &after[designator.len_utf8()..];
+        }
+    }
+    nanos_to_duration(total)
+}
+
+fn nanos_to_duration(nanos: u128) -> Option<Duration> {
+    let secs = u64::try_from(nanos / NANOS_PER_SEC).ok()?;
+    Some(Duration::new(secs, (nanos % NANOS_PER_SEC) as u32))
+}
+
+/// A non-negative decimal number, as written in a duration
+struct Decimal {
+    int: u128,
+    frac: u128,
+    frac_digits: u32,
+}
+
+impl Decimal {
+    /// Fractional digits past this many are ignored: they're below the nanosecond for any unit
+    const MAX_FRAC_DIGITS: u32 = 18;
+
+    /// Parses a number at the start of `s`, returning it with the rest of the string
+    fn parse(s: &str) -> Option<(Self, &str)> {
+        let int_len = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        let int = if int_len == 0 {
+            0
+        } else {
+            s[..int_len].parse().ok()?
+        };
+        let mut number = Decimal {
+            int,
+            frac: 0,
+            frac_digits: 0,
+        };
+        let mut rest = &s[int_len..];
+
+        if let Some(frac) = rest.strip_prefix(['.', ',']) {
+            let frac_len = frac
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(frac.len());
+            if frac_len == 0 {
+                return None;
+            }
+            for digit in frac[..frac_len]
+                .bytes()
+                .take(Self::MAX_FRAC_DIGITS as usize)
+            {
+                number.frac = number.frac * 10 + u128::from(digit - b'0');
+                number.frac_digits += 1;
+            }
+            rest = &frac[frac_len..];
+        } else if int_len == 0 {
+            return None;
+        }
+        Some((number, rest))
+    }
+
+    /// This number of `unit`s, in `unit`'s own scale (truncating)
+    fn scale(&self, unit: u128) -> Option<u128> {
+        let int = self.int.checked_mul(unit)?;
+        let frac = self.frac.checked_mul(unit)? / 10u128.pow(self.frac_digits);
+        int.checked_add(frac)
+    }
+}
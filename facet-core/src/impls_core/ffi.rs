@@ -0,0 +1,25 @@
+use core::ffi::CStr;
+
+use crate::*;
+
+unsafe impl Facet<'_> for CStr {
+    const VTABLE: &'static ValueVTable = &const {
+        value_vtable_unsized!(CStr, |f, _opts| write!(
+            f,
+            "{}",
+            Self::SHAPE.type_identifier
+        ))
+    };
+
+    const SHAPE: &'static Shape<'static> = &const {
+        Shape::builder_for_unsized::<Self>()
+            .type_identifier("CStr")
+            .ty(Type::User(UserType::Opaque))
+            .def(Def::Scalar(
+                ScalarDef::builder()
+                    .affinity(&const { ScalarAffinity::platform_string().build() })
+                    .build(),
+            ))
+            .build()
+    };
+}
@@ -1,13 +1,19 @@
 mod array;
+mod atomic;
+mod cell;
 
 #[cfg(feature = "fn-ptr")]
 mod fn_ptr;
 
+mod duration;
 mod dyn_;
+mod ffi;
 mod ops;
 mod option;
 mod pointer;
+mod result;
 mod scalar;
 mod slice;
 mod smartptr;
 mod tuple;
+mod wrapper;
@@ -245,6 +245,8 @@ macro_rules! impl_facet_for_integer {
                     vtable_sized.try_from = || Some(try_from);
                     vtable_sized.try_into_inner = || Some(try_into_inner);
                     vtable_sized.try_borrow_inner = || Some(try_borrow_inner);
+                    // The vtable is the integer's, but `NonZero` has no `Default`
+                    vtable_sized.default_in_place = || None;
                 }
 
                 vtable
@@ -695,6 +697,50 @@ unsafe impl Facet<'_> for core::net::SocketAddr {
     };
 }
 
+unsafe impl Facet<'_> for core::net::SocketAddrV4 {
+    const VTABLE: &'static ValueVTable = &const {
+        value_vtable!(core::net::SocketAddrV4, |f, _opts| write!(
+            f,
+            "{}",
+            Self::SHAPE.type_identifier
+        ))
+    };
+
+    const SHAPE: &'static Shape<'static> = &const {
+        Shape::builder_for_sized::<Self>()
+            .type_identifier("SocketAddrV4")
+            .ty(Type::User(UserType::Opaque))
+            .def(Def::Scalar(
+                ScalarDef::builder()
+                    .affinity(&const { ScalarAffinity::socket_addr().build() })
+                    .build(),
+            ))
+            .build()
+    };
+}
+
+unsafe impl Facet<'_> for core::net::SocketAddrV6 {
+    const VTABLE: &'static ValueVTable = &const {
+        value_vtable!(core::net::SocketAddrV6, |f, _opts| write!(
+            f,
+            "{}",
+            Self::SHAPE.type_identifier
+        ))
+    };
+
+    const SHAPE: &'static Shape<'static> = &const {
+        Shape::builder_for_sized::<Self>()
+            .type_identifier("SocketAddrV6")
+            .ty(Type::User(UserType::Opaque))
+            .def(Def::Scalar(
+                ScalarDef::builder()
+                    .affinity(&const { ScalarAffinity::socket_addr().build() })
+                    .build(),
+            ))
+            .build()
+    };
+}
+
 unsafe impl Facet<'_> for core::net::IpAddr {
     const VTABLE: &'static ValueVTable = &const {
         value_vtable!(core::net::IpAddr, |f, _opts| write!(
@@ -0,0 +1,153 @@
+use core::cmp::Reverse;
+use core::mem;
+use core::num::{Saturating, Wrapping};
+
+use crate::{
+    Facet, Field, FieldFlags, PtrConst, PtrMut, PtrUninit, Repr, Shape, ShapeAttribute, StructKind,
+    StructType, TryBorrowInnerError, TryFromError, TryIntoInnerError, Type, TypeNameOpts, UserType,
+    ValueVTable,
+};
+
+/// Implements `Facet` for a `repr(transparent)` wrapper around a single `T`.
+///
+/// The wrapper starts from `T`'s vtable (which is sound since both have the same layout), and
+/// `$adjust` can then override whatever behaves differently for the wrapper.
+macro_rules! impl_facet_for_wrapper {
+    ($wrapper:ident) => {
+        impl_facet_for_wrapper!($wrapper, |_vtable| {});
+    };
+    ($wrapper:ident, |$vtable:ident| $adjust:block) => {
+        unsafe impl<'a, T: Facet<'a>> Facet<'a> for $wrapper<T> {
+            const VTABLE: &'static ValueVTable = &const {
+                fn type_name<'a, T: Facet<'a>>(
+                    f: &mut core::fmt::Formatter,
+                    opts: TypeNameOpts,
+                ) -> core::fmt::Result {
+                    write!(f, stringify!($wrapper))?;
+                    if let Some(opts) = opts.for_children() {
+                        write!(f, "<")?;
+                        (T::SHAPE.vtable.type_name())(f, opts)?;
+                        write!(f, ">")
+                    } else {
+                        write!(f, "<…>")
+                    }
+                }
+
+                unsafe fn try_from<'a, 'shape, 'dst, T: Facet<'a>>(
+                    src_ptr: PtrConst<'_>,
+                    src_shape: &'shape Shape<'shape>,
+                    dst: PtrUninit<'dst>,
+                ) -> Result<PtrMut<'dst>, TryFromError<'shape>> {
+                    if src_shape.id == T::SHAPE.id {
+                        let t = unsafe { src_ptr.read::<T>() };
+                        return Ok(unsafe { dst.put($wrapper(t)) });
+                    }
+
+                    // fallback to inner's try_from, which writes a `T` where the wrapper goes:
+                    // that's fine because the wrapper is `repr(transparent)`
+                    let inner_try_from = (T::SHAPE.vtable.sized().unwrap().try_from)().ok_or(
+                        TryFromError::UnsupportedSourceShape {
+                            src_shape,
+                            expected: &[T::SHAPE],
+                        },
+                    )?;
+                    unsafe { inner_try_from(src_ptr, src_shape, dst) }
+                }
+
+                unsafe fn try_into_inner<'a, 'dst, T: Facet<'a>>(
+                    src_ptr: PtrMut<'_>,
+                    dst: PtrUninit<'dst>,
+                ) -> Result<PtrMut<'dst>, TryIntoInnerError> {
+                    let wrapper = unsafe { src_ptr.read::<$wrapper<T>>() };
+                    Ok(unsafe { dst.put(wrapper.0) })
+                }
+
+                unsafe fn try_borrow_inner<'a, 'src, T: Facet<'a>>(
+                    src_ptr: PtrConst<'src>,
+                ) -> Result<PtrConst<'src>, TryBorrowInnerError> {
+                    let wrapper = unsafe { src_ptr.get::<$wrapper<T>>() };
+                    Ok(PtrConst::new(&wrapper.0))
+                }
+
+                let mut vtable = *T::SHAPE.vtable;
+                {
+                    let vtable = vtable.sized_mut().unwrap();
+                    vtable.type_name = type_name::<T>;
+                    vtable.try_from = || Some(try_from::<T>);
+                    vtable.try_into_inner = || Some(try_into_inner::<T>);
+                    vtable.try_borrow_inner = || Some(try_borrow_inner::<T>);
+                    let $vtable = vtable;
+                    $adjust
+                }
+                vtable
+            };
+
+            const SHAPE: &'static Shape<'static> = &const {
+                // Function to return inner type's shape
+                fn inner_shape<'a, T: Facet<'a>>() -> &'static Shape<'static> {
+                    T::SHAPE
+                }
+
+                Shape::builder_for_sized::<Self>()
+                    .type_identifier(stringify!($wrapper))
+                    .type_params(&[crate::TypeParam {
+                        name: "T",
+                        shape: || T::SHAPE,
+                    }])
+                    .ty(Type::User(UserType::Struct(StructType {
+                        repr: Repr::transparent(),
+                        kind: StructKind::TupleStruct,
+                        fields: &const {
+                            [Field::builder()
+                                .name("0")
+                                .shape(T::SHAPE)
+                                .offset(mem::offset_of!($wrapper<T>, 0))
+                                .flags(FieldFlags::EMPTY)
+                                .build()]
+                        },
+                    })))
+                    .inner(inner_shape::<T>)
+                    .attributes(&[ShapeAttribute::Transparent])
+                    .build()
+            };
+        }
+    };
+}
+
+impl_facet_for_wrapper!(Wrapping);
+impl_facet_for_wrapper!(Saturating);
+
+// Unlike the other wrappers, `Reverse` isn't `Display`, debug-prints as a tuple struct, and
+// reverses the ordering of `T`
+impl_facet_for_wrapper!(Reverse, |vtable| {
+    vtable.display = || None;
+    vtable.debug = || {
+        if T::SHAPE.vtable.has_debug() {
+            Some(|value, f| unsafe {
+                write!(f, "Reverse(")?;
+                ((T::SHAPE.vtable.sized().unwrap().debug)().unwrap())(value, f)?;
+                write!(f, ")")
+            })
+        } else {
+            None
+        }
+    };
+    vtable.partial_ord = || {
+        if T::SHAPE.vtable.has_partial_ord() {
+            Some(|left, right| unsafe {
+                ((T::SHAPE.vtable.sized().unwrap().partial_ord)().unwrap())(right, left)
+            })
+        } else {
+            None
+        }
+    };
+    vtable.ord = || {
+        if T::SHAPE.vtable.has_ord() {
+            Some(|left, right| unsafe {
+                ((T::SHAPE.vtable.sized().unwrap().ord)().unwrap())(right, left)
+            })
+        } else {
+            None
+        }
+    };
+});
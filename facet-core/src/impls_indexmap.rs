@@ -0,0 +1,359 @@
+use core::hash::BuildHasher;
+
+use alloc::boxed::Box;
+use indexmap::{IndexMap, IndexSet};
+
+use crate::{
+    Def, Facet, IterVTable, MapDef, MapVTable, MarkerTraits, PtrConst, PtrMut, SetDef, SetVTable,
+    Shape, Type, TypeParam, UserType, VTableView, ValueVTable,
+};
+
+type IndexMapIterator<'mem, K, V> = indexmap::map::Iter<'mem, K, V>;
+type IndexSetIterator<'mem, T> = indexmap::set::Iter<'mem, T>;
+
+// Like `IndexMap` itself, these don't implement `Hash`: equality ignores insertion order, so
+// hashing entries in order wouldn't be consistent with it.
+
+unsafe impl<'a, K, V, S> Facet<'a> for IndexMap<K, V, S>
+where
+    K: Facet<'a> + core::cmp::Eq + core::hash::Hash,
+    V: Facet<'a>,
+    S: Facet<'a> + Default + BuildHasher,
+{
+    const VTABLE: &'static ValueVTable = &const {
+        ValueVTable::builder::<Self>()
+            .marker_traits(|| {
+                let arg_dependent_traits = MarkerTraits::SEND
+                    .union(MarkerTraits::SYNC)
+                    .union(MarkerTraits::EQ)
+                    .union(MarkerTraits::UNPIN)
+                    .union(MarkerTraits::UNWIND_SAFE)
+                    .union(MarkerTraits::REF_UNWIND_SAFE);
+                arg_dependent_traits
+                    .intersection(V::SHAPE.vtable.marker_traits())
+                    .intersection(K::SHAPE.vtable.marker_traits())
+            })
+            .type_name(|f, opts| {
+                if let Some(opts) = opts.for_children() {
+                    write!(f, "{}<", Self::SHAPE.type_identifier)?;
+                    K::SHAPE.vtable.type_name()(f, opts)?;
+                    write!(f, ", ")?;
+                    V::SHAPE.vtable.type_name()(f, opts)?;
+                    write!(f, ">")
+                } else {
+                    write!(f, "{}<⋯>", Self::SHAPE.type_identifier)
+                }
+            })
+            .debug(|| {
+                if K::SHAPE.vtable.has_debug() && V::SHAPE.vtable.has_debug() {
+                    Some(|value, f| {
+                        let k_debug = <VTableView<K>>::of().debug().unwrap();
+                        let v_debug = <VTableView<V>>::of().debug().unwrap();
+                        write!(f, "{{")?;
+                        for (i, (key, val)) in value.iter().enumerate() {
+                            if i > 0 {
+                                write!(f, ", ")?;
+                            }
+                            (k_debug)(key, f)?;
+                            write!(f, ": ")?;
+                            (v_debug)(val, f)?;
+                        }
+                        write!(f, "}}")
+                    })
+                } else {
+                    None
+                }
+            })
+            .default_in_place(|| Some(|target| unsafe { target.put(Self::default()) }))
+            .clone_into(|| {
+                if K::SHAPE.vtable.has_clone_into() && V::SHAPE.vtable.has_clone_into() {
+                    Some(|src, dst| unsafe {
+                        let map = src;
+                        let mut new_map =
+                            IndexMap::with_capacity_and_hasher(map.len(), S::default());
+
+                        let k_clone_into = <VTableView<K>>::of().clone_into().unwrap();
+                        let v_clone_into = <VTableView<V>>::of().clone_into().unwrap();
+
+                        for (k, v) in map {
+                            use crate::TypedPtrUninit;
+                            use core::mem::MaybeUninit;
+
+                            let mut new_k = MaybeUninit::<K>::uninit();
+                            let mut new_v = MaybeUninit::<V>::uninit();
+
+                            let uninit_k = TypedPtrUninit::new(new_k.as_mut_ptr());
+                            let uninit_v = TypedPtrUninit::new(new_v.as_mut_ptr());
+
+                            (k_clone_into)(k, uninit_k);
+                            (v_clone_into)(v, uninit_v);
+
+                            new_map.insert(new_k.assume_init(), new_v.assume_init());
+                        }
+
+                        dst.put(new_map)
+                    })
+                } else {
+                    None
+                }
+            })
+            .partial_eq(|| {
+                if V::SHAPE.vtable.has_partial_eq() {
+                    Some(|a, b| {
+                        let v_eq = <VTableView<V>>::of().partial_eq().unwrap();
+                        a.len() == b.len()
+                            && a.iter().all(|(key_a, val_a)| {
+                                b.get(key_a).is_some_and(|val_b| (v_eq)(val_a, val_b))
+                            })
+                    })
+                } else {
+                    None
+                }
+            })
+            .build()
+    };
+
+    const SHAPE: &'static Shape<'static> = &const {
+        Shape::builder_for_sized::<Self>()
+            .type_identifier("IndexMap")
+            .type_params(&[
+                TypeParam {
+                    name: "K",
+                    shape: || K::SHAPE,
+                },
+                TypeParam {
+                    name: "V",
+                    shape: || V::SHAPE,
+                },
+                TypeParam {
+                    name: "S",
+                    shape: || S::SHAPE,
+                },
+            ])
+            .ty(Type::User(UserType::Opaque))
+            .def(Def::Map(
+                MapDef::builder()
+                    .k(|| K::SHAPE)
+                    .v(|| V::SHAPE)
+                    .vtable(
+                        &const {
+                            MapVTable::builder()
+                                .init_in_place_with_capacity(|uninit, capacity| unsafe {
+                                    uninit
+                                        .put(Self::with_capacity_and_hasher(capacity, S::default()))
+                                })
+                                .insert(|ptr, key, value| unsafe {
+                                    let map = ptr.as_mut::<Self>();
+                                    let key = key.read::<K>();
+                                    let value = value.read::<V>();
+                                    map.insert(key, value);
+                                })
+                                .len(|ptr| unsafe {
+                                    let map = ptr.get::<Self>();
+                                    map.len()
+                                })
+                                .contains_key(|ptr, key| unsafe {
+                                    let map = ptr.get::<Self>();
+                                    map.contains_key(key.get::<K>())
+                                })
+                                .get_value_ptr(|ptr, key| unsafe {
+                                    let map = ptr.get::<Self>();
+                                    map.get(key.get::<K>()).map(|v| PtrConst::new(v))
+                                })
+                                .get_value_ptr_mut(|ptr, key| unsafe {
+                                    let map = ptr.as_mut::<Self>();
+                                    map.get_mut(key.get::<K>()).map(|v| PtrMut::new(v))
+                                })
+                                .remove(|ptr, key, value| unsafe {
+                                    let map = ptr.as_mut::<Self>();
+                                    // Shifting keeps the remaining entries in insertion order
+                                    match map.shift_remove(key.get::<K>()) {
+                                        Some(v) => {
+                                            value.put(v);
+                                            true
+                                        }
+                                        None => false,
+                                    }
+                                })
+                                .iter_vtable(
+                                    IterVTable::builder()
+                                        .init_with_value(|ptr| unsafe {
+                                            let map = ptr.get::<Self>();
+                                            let iter: IndexMapIterator<'_, K, V> = map.iter();
+                                            let iter_state = Box::new(iter);
+                                            PtrMut::new(Box::into_raw(iter_state) as *mut u8)
+                                        })
+                                        .next(|iter_ptr| unsafe {
+                                            let state =
+                                                iter_ptr.as_mut::<IndexMapIterator<'_, K, V>>();
+                                            state.next().map(|(key, value)| {
+                                                (PtrConst::new(key), PtrConst::new(value))
+                                            })
+                                        })
+                                        .next_back(|iter_ptr| unsafe {
+                                            let state =
+                                                iter_ptr.as_mut::<IndexMapIterator<'_, K, V>>();
+                                            state.next_back().map(|(key, value)| {
+                                                (PtrConst::new(key), PtrConst::new(value))
+                                            })
+                                        })
+                                        .dealloc(|iter_ptr| unsafe {
+                                            drop(Box::from_raw(
+                                                iter_ptr.as_ptr::<IndexMapIterator<'_, K, V>>()
+                                                    as *mut IndexMapIterator<'_, K, V>,
+                                            ));
+                                        })
+                                        .build(),
+                                )
+                                .build()
+                        },
+                    )
+                    .build(),
+            ))
+            .build()
+    };
+}
+
+unsafe impl<'a, T, S> Facet<'a> for IndexSet<T, S>
+where
+    T: Facet<'a> + core::cmp::Eq + core::hash::Hash,
+    S: Facet<'a> + Default + BuildHasher,
+{
+    const VTABLE: &'static ValueVTable = &const {
+        ValueVTable::builder::<Self>()
+            .marker_traits(|| {
+                MarkerTraits::SEND
+                    .union(MarkerTraits::SYNC)
+                    .union(MarkerTraits::EQ)
+                    .union(MarkerTraits::UNPIN)
+                    .intersection(T::SHAPE.vtable.marker_traits())
+            })
+            .type_name(|f, opts| {
+                if let Some(opts) = opts.for_children() {
+                    write!(f, "{}<", Self::SHAPE.type_identifier)?;
+                    (T::SHAPE.vtable.type_name())(f, opts)?;
+                    write!(f, ">")
+                } else {
+                    write!(f, "{}<⋯>", Self::SHAPE.type_identifier)
+                }
+            })
+            .default_in_place(|| Some(|target| unsafe { target.put(Self::default()) }))
+            .partial_eq(|| Some(|a, b| a == b))
+            .debug(|| {
+                if T::SHAPE.vtable.has_debug() {
+                    Some(|value, f| {
+                        let t_debug = <VTableView<T>>::of().debug().unwrap();
+                        write!(f, "{{")?;
+                        for (i, item) in value.iter().enumerate() {
+                            if i > 0 {
+                                write!(f, ", ")?;
+                            }
+                            (t_debug)(item, f)?;
+                        }
+                        write!(f, "}}")
+                    })
+                } else {
+                    None
+                }
+            })
+            .clone_into(|| {
+                if T::SHAPE.vtable.has_clone_into() {
+                    Some(|src, dst| unsafe {
+                        let set = src;
+                        let mut new_set =
+                            IndexSet::with_capacity_and_hasher(set.len(), S::default());
+
+                        let t_clone_into = <VTableView<T>>::of().clone_into().unwrap();
+
+                        for item in set {
+                            use crate::TypedPtrUninit;
+                            use core::mem::MaybeUninit;
+
+                            let mut new_item = MaybeUninit::<T>::uninit();
+                            let uninit_item = TypedPtrUninit::new(new_item.as_mut_ptr());
+
+                            (t_clone_into)(item, uninit_item);
+
+                            new_set.insert(new_item.assume_init());
+                        }
+
+                        dst.put(new_set)
+                    })
+                } else {
+                    None
+                }
+            })
+            .build()
+    };
+
+    const SHAPE: &'static Shape<'static> = &const {
+        Shape::builder_for_sized::<Self>()
+            .type_identifier("IndexSet")
+            .type_params(&[
+                TypeParam {
+                    name: "T",
+                    shape: || T::SHAPE,
+                },
+                TypeParam {
+                    name: "S",
+                    shape: || S::SHAPE,
+                },
+            ])
+            .ty(Type::User(UserType::Opaque))
+            .def(Def::Set(
+                SetDef::builder()
+                    .t(|| T::SHAPE)
+                    .vtable(
+                        &const {
+                            SetVTable::builder()
+                                .init_in_place_with_capacity(|uninit, capacity| unsafe {
+                                    uninit
+                                        .put(Self::with_capacity_and_hasher(capacity, S::default()))
+                                })
+                                .insert(|ptr, item| unsafe {
+                                    let set = ptr.as_mut::<Self>();
+                                    let item = item.read::<T>();
+                                    set.insert(item)
+                                })
+                                .len(|ptr| unsafe {
+                                    let set = ptr.get::<Self>();
+                                    set.len()
+                                })
+                                .contains(|ptr, item| unsafe {
+                                    let set = ptr.get::<Self>();
+                                    set.contains(item.get::<T>())
+                                })
+                                .iter_vtable(
+                                    IterVTable::builder()
+                                        .init_with_value(|ptr| unsafe {
+                                            let set = ptr.get::<Self>();
+                                            let iter: IndexSetIterator<'_, T> = set.iter();
+                                            let iter_state = Box::new(iter);
+                                            PtrMut::new(Box::into_raw(iter_state) as *mut u8)
+                                        })
+                                        .next(|iter_ptr| unsafe {
+                                            let state =
+                                                iter_ptr.as_mut::<IndexSetIterator<'_, T>>();
+                                            state.next().map(|value| PtrConst::new(value))
+                                        })
+                                        .next_back(|iter_ptr| unsafe {
+                                            let state =
+                                                iter_ptr.as_mut::<IndexSetIterator<'_, T>>();
+                                            state.next_back().map(|value| PtrConst::new(value))
+                                        })
+                                        .dealloc(|iter_ptr| unsafe {
+                                            drop(Box::from_raw(
+                                                iter_ptr.as_ptr::<IndexSetIterator<'_, T>>()
+                                                    as *mut IndexSetIterator<'_, T>,
+                                            ));
+                                        })
+                                        .build(),
+                                )
+                                .build()
+                        },
+                    )
+                    .build(),
+            ))
+            .build()
+    };
+}
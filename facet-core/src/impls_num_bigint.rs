@@ -0,0 +1,62 @@
+use num_bigint::{BigInt, BigUint};
+
+use crate::{
+    Def, Facet, ScalarAffinity, ScalarDef, Shape, Signedness, Type, UserType, ValueVTable,
+    value_vtable,
+};
+
+unsafe impl Facet<'_> for BigInt {
+    const VTABLE: &'static ValueVTable = &const {
+        value_vtable!(BigInt, |f, _opts| write!(
+            f,
+            "{}",
+            Self::SHAPE.type_identifier
+        ))
+    };
+
+    const SHAPE: &'static Shape<'static> = &const {
+        Shape::builder_for_sized::<Self>()
+            .type_identifier("BigInt")
+            .ty(Type::User(UserType::Opaque))
+            .def(Def::Scalar(
+                ScalarDef::builder()
+                    .affinity(
+                        &const {
+                            ScalarAffinity::number()
+                                .big_integer(Signedness::Signed)
+                                .build()
+                        },
+                    )
+                    .build(),
+            ))
+            .build()
+    };
+}
+
+unsafe impl Facet<'_> for BigUint {
+    const VTABLE: &'static ValueVTable = &const {
+        value_vtable!(BigUint, |f, _opts| write!(
+            f,
+            "{}",
+            Self::SHAPE.type_identifier
+        ))
+    };
+
+    const SHAPE: &'static Shape<'static> = &const {
+        Shape::builder_for_sized::<Self>()
+            .type_identifier("BigUint")
+            .ty(Type::User(UserType::Opaque))
+            .def(Def::Scalar(
+                ScalarDef::builder()
+                    .affinity(
+                        &const {
+                            ScalarAffinity::number()
+                                .big_integer(Signedness::Unsigned)
+                                .build()
+                        },
+                    )
+                    .build(),
+            ))
+            .build()
+    };
+}
@@ -0,0 +1,42 @@
+use rust_decimal::Decimal;
+
+use crate::{
+    Def, Facet, PtrConst, ScalarAffinity, ScalarDef, Shape, Type, UserType, ValueVTable,
+    value_vtable,
+};
+
+static MIN_DECIMAL: Decimal = Decimal::MIN;
+static MAX_DECIMAL: Decimal = Decimal::MAX;
+static ZERO_DECIMAL: Decimal = Decimal::ZERO;
+
+unsafe impl Facet<'_> for Decimal {
+    const VTABLE: &'static ValueVTable = &const {
+        value_vtable!(Decimal, |f, _opts| write!(
+            f,
+            "{}",
+            Self::SHAPE.type_identifier
+        ))
+    };
+
+    const SHAPE: &'static Shape<'static> = &const {
+        Shape::builder_for_sized::<Self>()
+            .type_identifier("Decimal")
+            .ty(Type::User(UserType::Opaque))
+            .def(Def::Scalar(
+                ScalarDef::builder()
+                    .affinity(
+                        &const {
+                            // A 96-bit integer, scaled down by a power of 10 between 0 and 28
+                            ScalarAffinity::number()
+                                .decimal(1, 96, 5)
+                                .min(PtrConst::new(&raw const MIN_DECIMAL))
+                                .max(PtrConst::new(&raw const MAX_DECIMAL))
+                                .positive_zero(PtrConst::new(&raw const ZERO_DECIMAL))
+                                .build()
+                        },
+                    )
+                    .build(),
+            ))
+            .build()
+    };
+}
@@ -0,0 +1,83 @@
+use alloc::string::String;
+use alloc::string::ToString;
+
+use semver::{Version, VersionReq};
+
+use crate::{
+    Def, Facet, ParseError, PtrConst, PtrMut, PtrUninit, ScalarAffinity, ScalarDef, Shape,
+    TryFromError, TryIntoInnerError, Type, UserType, ValueVTable, value_vtable,
+};
+
+macro_rules! impl_facet_for_semver {
+    ($type:ident, $parse_error:literal) => {
+        unsafe impl Facet<'_> for $type {
+            const VTABLE: &'static ValueVTable = &const {
+                // Functions to transparently convert between the semver type and String
+                unsafe fn try_from<'shape, 'dst>(
+                    src_ptr: PtrConst<'_>,
+                    src_shape: &'shape Shape<'shape>,
+                    dst: PtrUninit<'dst>,
+                ) -> Result<PtrMut<'dst>, TryFromError<'shape>> {
+                    if src_shape.id != <String as Facet>::SHAPE.id {
+                        return Err(TryFromError::UnsupportedSourceShape {
+                            src_shape,
+                            expected: &[<String as Facet>::SHAPE],
+                        });
+                    }
+                    let s = unsafe { src_ptr.read::<String>() };
+                    match $type::parse(&s) {
+                        Ok(value) => Ok(unsafe { dst.put(value) }),
+                        Err(_) => Err(TryFromError::Generic($parse_error)),
+                    }
+                }
+
+                unsafe fn try_into_inner<'dst>(
+                    src_ptr: PtrMut<'_>,
+                    dst: PtrUninit<'dst>,
+                ) -> Result<PtrMut<'dst>, TryIntoInnerError> {
+                    let value = unsafe { src_ptr.read::<$type>() };
+                    Ok(unsafe { dst.put(value.to_string()) })
+                }
+
+                let mut vtable = value_vtable!($type, |f, _opts| write!(
+                    f,
+                    "{}",
+                    Self::SHAPE.type_identifier
+                ));
+                {
+                    let vtable = vtable.sized_mut().unwrap();
+                    vtable.parse = || {
+                        Some(|s, target| match $type::parse(s) {
+                            Ok(value) => Ok(unsafe { target.put(value) }),
+                            Err(_) => Err(ParseError::Generic($parse_error)),
+                        })
+                    };
+                    vtable.try_from = || Some(try_from);
+                    vtable.try_into_inner = || Some(try_into_inner);
+                }
+                vtable
+            };
+
+            const SHAPE: &'static Shape<'static> = &const {
+                // Return the Shape of the inner type (String)
+                fn inner_shape() -> &'static Shape<'static> {
+                    <String as Facet>::SHAPE
+                }
+
+                Shape::builder_for_sized::<Self>()
+                    .type_identifier(stringify!($type))
+                    .ty(Type::User(UserType::Opaque))
+                    .def(Def::Scalar(
+                        ScalarDef::builder()
+                            .affinity(&const { ScalarAffinity::string().build() })
+                            .build(),
+                    ))
+                    .inner(inner_shape)
+                    .build()
+            };
+        }
+    };
+}
+
+impl_facet_for_semver!(Version, "semver version parsing failed");
+impl_facet_for_semver!(VersionReq, "semver version requirement parsing failed");
@@ -0,0 +1,76 @@
+use std::ffi::{OsStr, OsString};
+
+use crate::*;
+
+unsafe impl Facet<'_> for OsString {
+    const VTABLE: &'static ValueVTable = &const {
+        let mut vtable = value_vtable!(OsString, |f, _opts| write!(
+            f,
+            "{}",
+            Self::SHAPE.type_identifier
+        ));
+
+        {
+            let vtable = vtable.sized_mut().unwrap();
+            // Strings that aren't valid UTF-8 are serialized as the bytes of
+            // `OsStr::as_encoded_bytes`, which can only be read back on Unix
+            vtable.try_from = || {
+                Some(|source, source_shape, target| {
+                    if source_shape.is_type::<Vec<u8>>() {
+                        let bytes = unsafe { source.read::<Vec<u8>>() };
+                        #[cfg(unix)]
+                        let s = {
+                            use std::os::unix::ffi::OsStringExt;
+                            Ok::<_, TryFromError>(OsString::from_vec(bytes))
+                        };
+                        #[cfg(not(unix))]
+                        let s = String::from_utf8(bytes).map(OsString::from).map_err(|_| {
+                            TryFromError::Generic("OS strings must be valid UTF-8 on this platform")
+                        });
+                        Ok(unsafe { target.put(s?) })
+                    } else {
+                        Err(TryFromError::UnsupportedSourceShape {
+                            src_shape: source_shape,
+                            expected: &[<Vec<u8>>::SHAPE],
+                        })
+                    }
+                })
+            };
+        }
+        vtable
+    };
+
+    const SHAPE: &'static Shape<'static> = &const {
+        Shape::builder_for_sized::<Self>()
+            .type_identifier("OsString")
+            .ty(Type::User(UserType::Opaque))
+            .def(Def::Scalar(
+                ScalarDef::builder()
+                    .affinity(&const { ScalarAffinity::platform_string().build() })
+                    .build(),
+            ))
+            .build()
+    };
+}
+
+unsafe impl Facet<'_> for OsStr {
+    const VTABLE: &'static ValueVTable = &const {
+        value_vtable_unsized!(OsStr, |f, _opts| write!(
+            f,
+            "{}",
+            Self::SHAPE.type_identifier
+        ))
+    };
+
+    const SHAPE: &'static Shape<'static> = &const {
+        Shape::builder_for_unsized::<Self>()
+            .type_identifier("OsStr")
+            .ty(Type::User(UserType::Opaque))
+            .def(Def::Scalar(
+                ScalarDef::builder()
+                    .affinity(&const { ScalarAffinity::platform_string().build() })
+                    .build(),
+            ))
+            .build()
+    };
+}
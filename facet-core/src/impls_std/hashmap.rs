@@ -173,6 +173,20 @@ where
                                     let map = ptr.get::<HashMap<K, V>>();
                                     map.get(key.get()).map(|v| PtrConst::new(v))
                                 })
+                                .get_value_ptr_mut(|ptr, key| unsafe {
+                                    let map = ptr.as_mut::<Self>();
+                                    map.get_mut(key.get()).map(|v| PtrMut::new(v))
+                                })
+                                .remove(|ptr, key, value| unsafe {
+                                    let map = ptr.as_mut::<Self>();
+                                    match map.remove(key.get()) {
+                                        Some(v) => {
+                                            value.put(v);
+                                            true
+                                        }
+                                        None => false,
+                                    }
+                                })
                                 .iter_vtable(
                                     IterVTable::builder()
                                         .init_with_value(|ptr| unsafe {
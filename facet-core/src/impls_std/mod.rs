@@ -1,3 +1,6 @@
+mod ffi;
 mod hashmap;
 mod hashset;
 mod path;
+mod sync;
+mod system_time;
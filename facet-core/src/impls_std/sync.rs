@@ -0,0 +1,316 @@
+use core::mem::MaybeUninit;
+use std::sync::{Mutex, MutexGuard, OnceLock, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use alloc::boxed::Box;
+
+use crate::*;
+
+/// Marker traits of a `Mutex<T>` or an `RwLock<T>`, given those of `T`
+fn lock_marker_traits(t: MarkerTraits, sync_needs_sync: bool) -> MarkerTraits {
+    let mut traits = MarkerTraits::UNWIND_SAFE | MarkerTraits::REF_UNWIND_SAFE;
+    if t.contains(MarkerTraits::SEND) {
+        traits |= MarkerTraits::SEND;
+        if !sync_needs_sync || t.contains(MarkerTraits::SYNC) {
+            traits |= MarkerTraits::SYNC;
+        }
+    }
+    if t.contains(MarkerTraits::UNPIN) {
+        traits |= MarkerTraits::UNPIN;
+    }
+    traits
+}
+
+unsafe impl<'a, T: Facet<'a>> Facet<'a> for Mutex<T> {
+    const VTABLE: &'static ValueVTable = &const {
+        ValueVTable::builder::<Self>()
+            .type_name(|f, opts| {
+                if let Some(opts) = opts.for_children() {
+                    write!(f, "{}<", Self::SHAPE.type_identifier)?;
+                    T::SHAPE.vtable.type_name()(f, opts)?;
+                    write!(f, ">")
+                } else {
+                    write!(f, "{}<⋯>", Self::SHAPE.type_identifier)
+                }
+            })
+            .default_in_place(|| {
+                if T::SHAPE.vtable.has_default_in_place() {
+                    Some(|target| unsafe {
+                        let mut t = MaybeUninit::<T>::uninit();
+                        (<VTableView<T>>::of().default_in_place().unwrap())(TypedPtrUninit::new(
+                            t.as_mut_ptr(),
+                        ));
+                        target.put(Mutex::new(t.assume_init()))
+                    })
+                } else {
+                    None
+                }
+            })
+            .debug(|| {
+                if T::SHAPE.vtable.has_debug() {
+                    Some(|value, f| {
+                        write!(f, "Mutex {{ data: ")?;
+                        match value.try_lock() {
+                            Ok(guard) => (<VTableView<T>>::of().debug().unwrap())(&guard, f)?,
+                            Err(_) => write!(f, "<locked>")?,
+                        }
+                        write!(f, ", .. }}")
+                    })
+                } else {
+                    None
+                }
+            })
+            .marker_traits(|| lock_marker_traits(T::SHAPE.vtable.marker_traits(), false))
+            .build()
+    };
+
+    const SHAPE: &'static Shape<'static> = &const {
+        Shape::builder_for_sized::<Self>()
+            .type_identifier("Mutex")
+            .type_params(&[TypeParam {
+                name: "T",
+                shape: || T::SHAPE,
+            }])
+            .ty(Type::User(UserType::Opaque))
+            .def(Def::SmartPointer(
+                SmartPointerDef::builder()
+                    .pointee(|| T::SHAPE)
+                    .flags(SmartPointerFlags::LOCK)
+                    .known(KnownSmartPointer::Mutex)
+                    .vtable(
+                        &const {
+                            SmartPointerVTable::builder()
+                                .new_into_fn(|this, ptr| unsafe {
+                                    this.put(Mutex::new(ptr.read::<T>()))
+                                })
+                                .lock_fn(|this| unsafe {
+                                    // Fails if the mutex is poisoned
+                                    let mut guard =
+                                        this.get::<Mutex<T>>().lock().map_err(|_| ())?;
+                                    let data = PtrMut::new(&mut *guard as *mut T);
+                                    let guard = Box::into_raw(Box::new(guard));
+                                    Ok(LockResult::new(
+                                        data,
+                                        PtrConst::new(guard),
+                                        &const {
+                                            LockGuardVTable {
+                                                drop_in_place: |guard| {
+                                                    drop(Box::from_raw(
+                                                        guard.as_ptr::<MutexGuard<'_, T>>()
+                                                            as *mut MutexGuard<'_, T>,
+                                                    ))
+                                                },
+                                            }
+                                        },
+                                    ))
+                                })
+                                .build()
+                        },
+                    )
+                    .build(),
+            ))
+            .build()
+    };
+}
+
+unsafe impl<'a, T: Facet<'a>> Facet<'a> for RwLock<T> {
+    const VTABLE: &'static ValueVTable = &const {
+        ValueVTable::builder::<Self>()
+            .type_name(|f, opts| {
+                if let Some(opts) = opts.for_children() {
+                    write!(f, "{}<", Self::SHAPE.type_identifier)?;
+                    T::SHAPE.vtable.type_name()(f, opts)?;
+                    write!(f, ">")
+                } else {
+                    write!(f, "{}<⋯>", Self::SHAPE.type_identifier)
+                }
+            })
+            .default_in_place(|| {
+                if T::SHAPE.vtable.has_default_in_place() {
+                    Some(|target| unsafe {
+                        let mut t = MaybeUninit::<T>::uninit();
+                        (<VTableView<T>>::of().default_in_place().unwrap())(TypedPtrUninit::new(
+                            t.as_mut_ptr(),
+                        ));
+                        target.put(RwLock::new(t.assume_init()))
+                    })
+                } else {
+                    None
+                }
+            })
+            .debug(|| {
+                if T::SHAPE.vtable.has_debug() {
+                    Some(|value, f| {
+                        write!(f, "RwLock {{ data: ")?;
+                        match value.try_read() {
+                            Ok(guard) => (<VTableView<T>>::of().debug().unwrap())(&guard, f)?,
+                            Err(_) => write!(f, "<locked>")?,
+                        }
+                        write!(f, ", .. }}")
+                    })
+                } else {
+                    None
+                }
+            })
+            .marker_traits(|| lock_marker_traits(T::SHAPE.vtable.marker_traits(), true))
+            .build()
+    };
+
+    const SHAPE: &'static Shape<'static> = &const {
+        Shape::builder_for_sized::<Self>()
+            .type_identifier("RwLock")
+            .type_params(&[TypeParam {
+                name: "T",
+                shape: || T::SHAPE,
+            }])
+            .ty(Type::User(UserType::Opaque))
+            .def(Def::SmartPointer(
+                SmartPointerDef::builder()
+                    .pointee(|| T::SHAPE)
+                    .flags(SmartPointerFlags::LOCK)
+                    .known(KnownSmartPointer::RwLock)
+                    .vtable(
+                        &const {
+                            SmartPointerVTable::builder()
+                                .new_into_fn(|this, ptr| unsafe {
+                                    this.put(RwLock::new(ptr.read::<T>()))
+                                })
+                                .read_fn(|this| unsafe {
+                                    // Fails if the lock is poisoned
+                                    let guard = this.get::<RwLock<T>>().read().map_err(|_| ())?;
+                                    let data = PtrMut::new(&*guard as *const T as *mut T);
+                                    let guard = Box::into_raw(Box::new(guard));
+                                    Ok(LockResult::new(
+                                        data,
+                                        PtrConst::new(guard),
+                                        &const {
+                                            LockGuardVTable {
+                                                drop_in_place: |guard| {
+                                                    drop(Box::from_raw(
+                                                        guard.as_ptr::<RwLockReadGuard<'_, T>>()
+                                                            as *mut RwLockReadGuard<'_, T>,
+                                                    ))
+                                                },
+                                            }
+                                        },
+                                    ))
+                                })
+                                .write_fn(|this| unsafe {
+                                    // Fails if the lock is poisoned
+                                    let mut guard =
+                                        this.get::<RwLock<T>>().write().map_err(|_| ())?;
+                                    let data = PtrMut::new(&mut *guard as *mut T);
+                                    let guard = Box::into_raw(Box::new(guard));
+                                    Ok(LockResult::new(
+                                        data,
+                                        PtrConst::new(guard),
+                                        &const {
+                                            LockGuardVTable {
+                                                drop_in_place: |guard| {
+                                                    drop(Box::from_raw(
+                                                        guard.as_ptr::<RwLockWriteGuard<'_, T>>()
+                                                            as *mut RwLockWriteGuard<'_, T>,
+                                                    ))
+                                                },
+                                            }
+                                        },
+                                    ))
+                                })
+                                .build()
+                        },
+                    )
+                    .build(),
+            ))
+            .build()
+    };
+}
+
+unsafe impl<'a, T: Facet<'a>> Facet<'a> for OnceLock<T> {
+    const VTABLE: &'static ValueVTable = &const {
+        ValueVTable::builder::<Self>()
+            .type_name(|f, opts| {
+                if let Some(opts) = opts.for_children() {
+                    write!(f, "{}<", Self::SHAPE.type_identifier)?;
+                    T::SHAPE.vtable.type_name()(f, opts)?;
+                    write!(f, ">")
+                } else {
+                    write!(f, "{}<⋯>", Self::SHAPE.type_identifier)
+                }
+            })
+            .default_in_place(|| Some(|target| unsafe { target.put(OnceLock::new()) }))
+            .debug(|| {
+                if T::SHAPE.vtable.has_debug() {
+                    Some(|value, f| {
+                        write!(f, "OnceLock(")?;
+                        match value.get() {
+                            Some(value) => (<VTableView<T>>::of().debug().unwrap())(value, f)?,
+                            None => write!(f, "<uninit>")?,
+                        }
+                        write!(f, ")")
+                    })
+                } else {
+                    None
+                }
+            })
+            .marker_traits(|| {
+                let t = T::SHAPE.vtable.marker_traits();
+                let mut traits = MarkerTraits::EQ
+                    .union(MarkerTraits::UNPIN)
+                    .union(MarkerTraits::UNWIND_SAFE)
+                    .intersection(t);
+                if t.contains(MarkerTraits::SEND) {
+                    traits |= MarkerTraits::SEND;
+                    if t.contains(MarkerTraits::SYNC) {
+                        traits |= MarkerTraits::SYNC;
+                    }
+                }
+                if t.contains(MarkerTraits::UNWIND_SAFE.union(MarkerTraits::REF_UNWIND_SAFE)) {
+                    traits |= MarkerTraits::REF_UNWIND_SAFE;
+                }
+                traits
+            })
+            .build()
+    };
+
+    const SHAPE: &'static Shape<'static> = &const {
+        // A `OnceLock` is either empty or holds a value forever, so it's handled like an
+        // `Option`: empty locks are (de)serialized as `None`
+        Shape::builder_for_sized::<Self>()
+            .type_identifier("OnceLock")
+            .type_params(&[TypeParam {
+                name: "T",
+                shape: || T::SHAPE,
+            }])
+            .ty(Type::User(UserType::Opaque))
+            .def(Def::Option(
+                OptionDef::builder()
+                    .t(T::SHAPE)
+                    .vtable(
+                        const {
+                            &OptionVTable::builder()
+                                .is_some(|lock| unsafe {
+                                    lock.get::<OnceLock<T>>().get().is_some()
+                                })
+                                .get_value(|lock| unsafe {
+                                    lock.get::<OnceLock<T>>()
+                                        .get()
+                                        .map(|t| PtrConst::new(t as *const T))
+                                })
+                                .init_some(|lock, value| unsafe {
+                                    lock.put(OnceLock::from(value.read::<T>()))
+                                })
+                                .init_none(|lock| unsafe { lock.put(<OnceLock<T>>::new()) })
+                                .replace_with(|lock, value| unsafe {
+                                    let lock = lock.as_mut::<OnceLock<T>>();
+                                    lock.take();
+                                    if let Some(value) = value {
+                                        let _ = lock.set(value.read::<T>());
+                                    }
+                                })
+                                .build()
+                        },
+                    )
+                    .build(),
+            ))
+            .build()
+    };
+}
@@ -0,0 +1,215 @@
+use core::fmt;
+use core::time::Duration;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use alloc::string::String;
+
+use crate::*;
+
+const SYSTEM_TIME_ERROR: &str =
+    "could not parse timestamp, expected an RFC 3339 timestamp like `2024-01-15T12:30:00Z`";
+
+static EPOCH: SystemTime = UNIX_EPOCH;
+
+unsafe impl Facet<'_> for SystemTime {
+    const VTABLE: &'static ValueVTable = &const {
+        let mut vtable = value_vtable!(SystemTime, |f, _opts| write!(
+            f,
+            "{}",
+            Self::SHAPE.type_identifier
+        ));
+        {
+            let vtable = vtable.sized_mut().unwrap();
+            vtable.try_from = || {
+                Some(
+                    |source: PtrConst, source_shape: &Shape, target: PtrUninit| {
+                        if source_shape.is_type::<String>() {
+                            let source = unsafe { source.read::<String>() };
+                            match parse_system_time(&source) {
+                                Some(val) => Ok(unsafe { target.put(val) }),
+                                None => Err(TryFromError::Generic(SYSTEM_TIME_ERROR)),
+                            }
+                        } else {
+                            Err(TryFromError::UnsupportedSourceShape {
+                                src_shape: source_shape,
+                                expected: &[String::SHAPE],
+                            })
+                        }
+                    },
+                )
+            };
+            vtable.parse = || {
+                Some(|s: &str, target: PtrUninit| {
+                    let parsed =
+                        parse_system_time(s).ok_or(ParseError::Generic(SYSTEM_TIME_ERROR))?;
+                    Ok(unsafe { target.put(parsed) })
+                })
+            };
+            vtable.display =
+                || Some(|value, f| unsafe { fmt_system_time(*value.get::<SystemTime>(), f) });
+        }
+        vtable
+    };
+
+    const SHAPE: &'static Shape<'static> = &const {
+        Shape::builder_for_sized::<Self>()
+            .type_identifier("SystemTime")
+            .ty(Type::User(UserType::Opaque))
+            .def(Def::Scalar(
+                ScalarDef::builder()
+                    .affinity(
+                        &const {
+                            ScalarAffinity::time()
+                                .epoch(PtrConst::new(&raw const EPOCH))
+                                .build()
+                        },
+                    )
+                    .build(),
+            ))
+            .build()
+    };
+}
+
+/// Formats a system time as an RFC 3339 timestamp in UTC, e.g. `2024-01-15T12:30:00.5Z`
+fn fmt_system_time(time: SystemTime, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let (secs, nanos) = match time.duration_since(UNIX_EPOCH) {
+        Ok(since) => (since.as_secs() as i128, since.subsec_nanos()),
+        Err(e) => {
+            let before = e.duration();
+            match before.subsec_nanos() {
+                0 => (-(before.as_secs() as i128), 0),
+                nanos => (-(before.as_secs() as i128) - 1, 1_000_000_000 - nanos),
+            }
+        }
+    };
+
+    let (year, month, day) = civil_from_days(secs.div_euclid(86400));
+    let secs_of_day = secs.rem_euclid(86400);
+    write!(
+        f,
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}",
+        secs_of_day / 3600,
+        secs_of_day / 60 % 60,
+        secs_of_day % 60
+    )?;
+    if nanos > 0 {
+        let mut frac = nanos;
+        let mut digits = 9;
+        while frac % 10 == 0 {
+            frac /= 10;
+            digits -= 1;
+        }
+        write!(f, ".{frac:0digits$}")?;
+    }
+    f.write_str("Z")
+}
+
+/// Parses an RFC 3339 timestamp, e.g. `2024-01-15T12:30:00Z` or `2024-01-15T13:30:00.5+01:00`
+fn parse_system_time(s: &str) -> Option<SystemTime> {
+    let s = s.trim();
+    let (year, s) = digits(s, 4)?;
+    let (month, s) = digits(s.strip_prefix('-')?, 2)?;
+    let (day, s) = digits(s.strip_prefix('-')?, 2)?;
+    let (hour, s) = digits(s.strip_prefix(['T', 't', ' '])?, 2)?;
+    let (minute, s) = digits(s.strip_prefix(':')?, 2)?;
+    let (second, mut s) = digits(s.strip_prefix(':')?, 2)?;
+
+    if !(1..=12).contains(&month)
+        || !(1..=days_in_month(year, month)).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 59
+    {
+        return None;
+    }
+
+    let mut nanos = 0;
+    if let Some(frac) = s.strip_prefix('.') {
+        let len = frac
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(frac.len());
+        if len == 0 {
+            return None;
+        }
+        // Digits past the nanosecond are truncated
+        for (i, digit) in frac[..len].bytes().enumerate().take(9) {
+            nanos += u32::from(digit - b'0') * 10u32.pow(8 - i as u32);
+        }
+        s = &frac[len..];
+    }
+
+    let offset = match s {
+        "Z" | "z" => 0,
+        _ => {
+            let sign = match s.as_bytes().first()? {
+                b'+' => 1,
+                b'-' => -1,
+                _ => return None,
+            };
+            let (offset_hours, s) = digits(&s[1..], 2)?;
+            let (offset_minutes, s) = digits(s.strip_prefix(':')?, 2)?;
+            if !s.is_empty() || offset_hours > 23 || offset_minutes > 59 {
+                return None;
+            }
+            sign * (offset_hours * 3600 + offset_minutes * 60)
+        }
+    };
+
+    let secs =
+        days_from_civil(year, month, day) * 86400 + hour * 3600 + minute * 60 + second - offset;
+    if secs >= 0 {
+        UNIX_EPOCH.checked_add(Duration::new(u64::try_from(secs).ok()?, nanos))
+    } else {
+        UNIX_EPOCH
+            .checked_sub(Duration::from_secs(u64::try_from(-secs).ok()?))?
+            .checked_add(Duration::from_nanos(u64::from(nanos)))
+    }
+}
+
+/// Reads exactly `n` ASCII digits at the start of `s`
+fn digits(s: &str, n: usize) -> Option<(i128, &str)> {
+    let digits = s.get(..n)?;
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    Some((digits.parse().ok()?, &s[n..]))
+}
+
+fn days_in_month(year: i128, month: i128) -> i128 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01 of a date in the proleptic Gregorian calendar
+/// (see <https://howardhinnant.github.io/date_algorithms.html>)
+fn days_from_civil(year: i128, month: i128, day: i128) -> i128 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// The date in the proleptic Gregorian calendar that's `days` days after 1970-01-01
+fn civil_from_days(days: i128) -> (i128, i128, i128) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i128::from(month <= 2);
+    (year, month, day)
+}
@@ -18,6 +18,10 @@ pub use ptr::*;
 mod opaque;
 pub use opaque::*;
 
+// Numeric proxy for durations and timestamps
+mod seconds;
+pub use seconds::*;
+
 // Specialization utilities
 pub mod spez;
 
@@ -59,6 +63,18 @@ mod impls_url;
 #[cfg(feature = "jiff02")]
 mod impls_jiff;
 
+#[cfg(feature = "indexmap")]
+mod impls_indexmap;
+
+#[cfg(feature = "rust_decimal")]
+mod impls_rust_decimal;
+
+#[cfg(feature = "num-bigint")]
+mod impls_num_bigint;
+
+#[cfg(feature = "semver")]
+mod impls_semver;
+
 // Const type Id
 mod typeid;
 pub use typeid::*;
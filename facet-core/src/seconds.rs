@@ -0,0 +1,157 @@
+use core::fmt;
+use core::time::Duration;
+
+use crate::{
+    Facet, Field, FieldFlags, PtrConst, PtrMut, PtrUninit, Repr, Shape, ShapeAttribute, StructKind,
+    StructType, TryBorrowInnerError, TryFromError, TryIntoInnerError, Type, UserType, ValueVTable,
+    value_vtable,
+};
+
+/// A number of seconds, to (de)serialize durations and timestamps as plain numbers rather than
+/// as strings.
+///
+/// [`Duration`] and `SystemTime` are written as `"1h30m"` and `"2024-01-15T12:30:00Z"` by default.
+/// Use `Seconds` as their proxy to write them as `5400.0` and `1705321800.0` instead: the length
+/// of the duration, or the time elapsed since the Unix epoch (negative before it).
+///
+/// ```ignore
+/// #[derive(Facet)]
+/// struct Config {
+///     #[facet(proxy = facet::Seconds)]
+///     timeout: Duration,
+/// }
+/// ```
+///
+/// Durations are stored as `f64`, so they're only exact down to the nanosecond for about 104 days.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Default)]
+#[repr(transparent)]
+pub struct Seconds(pub f64);
+
+/// Error returned when a number of [`Seconds`] can't be represented by the target type
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SecondsOutOfRange(pub f64);
+
+impl fmt::Display for SecondsOutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} seconds is out of range", self.0)
+    }
+}
+
+impl core::error::Error for SecondsOutOfRange {}
+
+impl From<&Duration> for Seconds {
+    fn from(duration: &Duration) -> Self {
+        Seconds(duration.as_secs_f64())
+    }
+}
+
+impl TryFrom<Seconds> for Duration {
+    type Error = SecondsOutOfRange;
+
+    fn try_from(seconds: Seconds) -> Result<Self, Self::Error> {
+        Duration::try_from_secs_f64(seconds.0).map_err(|_| SecondsOutOfRange(seconds.0))
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<&std::time::SystemTime> for Seconds {
+    fn from(time: &std::time::SystemTime) -> Self {
+        match time.duration_since(std::time::UNIX_EPOCH) {
+            Ok(since) => Seconds(since.as_secs_f64()),
+            Err(e) => Seconds(-e.duration().as_secs_f64()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<Seconds> for std::time::SystemTime {
+    type Error = SecondsOutOfRange;
+
+    fn try_from(seconds: Seconds) -> Result<Self, Self::Error> {
+        let out_of_range = || SecondsOutOfRange(seconds.0);
+        let since = Duration::try_from_secs_f64(seconds.0.abs()).map_err(|_| out_of_range())?;
+        if seconds.0 >= 0.0 {
+            std::time::UNIX_EPOCH.checked_add(since)
+        } else {
+            std::time::UNIX_EPOCH.checked_sub(since)
+        }
+        .ok_or_else(out_of_range)
+    }
+}
+
+unsafe impl Facet<'_> for Seconds {
+    const VTABLE: &'static ValueVTable = &const {
+        unsafe fn try_from<'shape, 'dst>(
+            src_ptr: PtrConst<'_>,
+            src_shape: &'shape Shape<'shape>,
+            dst: PtrUninit<'dst>,
+        ) -> Result<PtrMut<'dst>, TryFromError<'shape>> {
+            if src_shape == f64::SHAPE {
+                return Ok(unsafe { dst.put(Seconds(*src_ptr.get::<f64>())) });
+            }
+            // Fall back to f64's conversions (e.g. from integers): `Seconds` is
+            // `repr(transparent)`, so an `f64` can be written in its place
+            let inner_try_from = (f64::SHAPE.vtable.sized().unwrap().try_from)().ok_or(
+                TryFromError::UnsupportedSourceShape {
+                    src_shape,
+                    expected: &[f64::SHAPE],
+                },
+            )?;
+            unsafe { inner_try_from(src_ptr, src_shape, dst) }
+        }
+
+        unsafe fn try_into_inner<'dst>(
+            src_ptr: PtrMut<'_>,
+            dst: PtrUninit<'dst>,
+        ) -> Result<PtrMut<'dst>, TryIntoInnerError> {
+            Ok(unsafe { dst.put(src_ptr.get::<Seconds>().0) })
+        }
+
+        unsafe fn try_borrow_inner(
+            src_ptr: PtrConst<'_>,
+        ) -> Result<PtrConst<'_>, TryBorrowInnerError> {
+            Ok(src_ptr)
+        }
+
+        let mut vtable = value_vtable!(Seconds, |f, _opts| write!(
+            f,
+            "{}",
+            Self::SHAPE.type_identifier
+        ));
+        {
+            let vtable_sized = vtable.sized_mut().unwrap();
+            vtable_sized.try_from = || Some(try_from);
+            vtable_sized.try_into_inner = || Some(try_into_inner);
+            vtable_sized.try_borrow_inner = || Some(try_borrow_inner);
+        }
+        vtable
+    };
+
+    const SHAPE: &'static Shape<'static> = &const {
+        fn inner_shape() -> &'static Shape<'static> {
+            f64::SHAPE
+        }
+
+        Shape::builder_for_sized::<Self>()
+            .type_identifier("Seconds")
+            .ty(Type::User(UserType::Struct(
+                StructType::builder()
+                    .repr(Repr::transparent())
+                    .kind(StructKind::TupleStruct)
+                    .fields(
+                        &const {
+                            [Field::builder()
+                                .name("0")
+                                .shape(f64::SHAPE)
+                                .offset(0)
+                                .flags(FieldFlags::EMPTY)
+                                .build()]
+                        },
+                    )
+                    .build(),
+            )))
+            .inner(inner_shape)
+            .attributes(&[ShapeAttribute::Transparent])
+            .build()
+    };
+}
@@ -104,6 +104,26 @@ pub type ListGetFn = unsafe fn(list: PtrConst, index: usize) -> Option<PtrConst>
 /// The `list` parameter must point to aligned, initialized memory of the correct type.
 pub type ListGetMutFn = unsafe fn(list: PtrMut, index: usize) -> Option<PtrMut>;
 
+/// Insert an item at `index`, shifting all items after it to the right
+///
+/// # Safety
+///
+/// The `list` parameter must point to aligned, initialized memory of the correct type, and
+/// `index` must be at most the length of the list.
+/// `item` is moved out of (with [`core::ptr::read`]) — it should be deallocated afterwards (e.g.
+/// with [`core::mem::forget`]) but NOT dropped.
+pub type ListInsertFn = unsafe fn(list: PtrMut, index: usize, item: PtrMut);
+
+/// Remove the item at `index`, shifting all items after it to the left, and move it into `item`
+///
+/// # Safety
+///
+/// The `list` parameter must point to aligned, initialized memory of the correct type, and
+/// `index` must be less than the length of the list.
+/// `item` must point to uninitialized memory of sufficient size for an item: it is
+/// initialized by this function, and the caller is responsible for dropping it.
+pub type ListRemoveFn = unsafe fn(list: PtrMut, index: usize, item: PtrUninit);
+
 /// Get pointer to the data buffer of the list.
 ///
 /// # Safety
@@ -141,6 +161,14 @@ pub struct ListVTable {
     /// Only available for mutable lists
     pub get_mut: Option<ListGetMutFn>,
 
+    /// cf. [`ListInsertFn`]
+    /// Only available for growable lists
+    pub insert: Option<ListInsertFn>,
+
+    /// cf. [`ListRemoveFn`]
+    /// Only available for shrinkable lists
+    pub remove: Option<ListRemoveFn>,
+
     /// cf. [`ListAsPtrFn`]
     /// Only available for types that can be accessed as a contiguous array
     pub as_ptr: Option<ListAsPtrFn>,
@@ -167,6 +195,8 @@ pub struct ListVTableBuilder {
     len: Option<ListLenFn>,
     get: Option<ListGetFn>,
     get_mut: Option<ListGetMutFn>,
+    insert: Option<ListInsertFn>,
+    remove: Option<ListRemoveFn>,
     as_ptr: Option<ListAsPtrFn>,
     as_mut_ptr: Option<ListAsMutPtrFn>,
     iter_vtable: Option<IterVTable<PtrConst<'static>>>,
@@ -182,6 +212,8 @@ impl ListVTableBuilder {
             len: None,
             get: None,
             get_mut: None,
+            insert: None,
+            remove: None,
             as_ptr: None,
             as_mut_ptr: None,
             iter_vtable: None,
@@ -218,6 +250,18 @@ impl ListVTableBuilder {
         self
     }
 
+    /// Sets the insert field
+    pub const fn insert(mut self, f: ListInsertFn) -> Self {
+        self.insert = Some(f);
+        self
+    }
+
+    /// Sets the remove field
+    pub const fn remove(mut self, f: ListRemoveFn) -> Self {
+        self.remove = Some(f);
+        self
+    }
+
     /// Sets the as_ptr field
     pub const fn as_ptr(mut self, f: ListAsPtrFn) -> Self {
         self.as_ptr = Some(f);
@@ -240,15 +284,16 @@ impl ListVTableBuilder {
     ///
     /// # Panics
     ///
-    /// Panic if any of the required fields (len, get, as_ptr, iter_vtable) are `None`.
+    /// Panic if any of the required fields (len, get, iter_vtable) are `None`.
     pub const fn build(self) -> ListVTable {
-        assert!(self.as_ptr.is_some());
         ListVTable {
             init_in_place_with_capacity: self.init_in_place_with_capacity,
             push: self.push,
             len: self.len.unwrap(),
             get: self.get.unwrap(),
             get_mut: self.get_mut,
+            insert: self.insert,
+            remove: self.remove,
             as_ptr: self.as_ptr,
             as_mut_ptr: self.as_mut_ptr,
             iter_vtable: self.iter_vtable.unwrap(),
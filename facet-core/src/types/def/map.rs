@@ -120,6 +120,28 @@ pub type MapContainsKeyFn =
 pub type MapGetValuePtrFn =
     for<'map, 'key> unsafe fn(map: PtrConst<'map>, key: PtrConst<'key>) -> Option<PtrConst<'map>>;
 
+/// Get mutable pointer to a value for a given key, returns None if not found
+///
+/// # Safety
+///
+/// The `map` parameter must point to aligned, initialized memory of the correct type.
+pub type MapGetValuePtrMutFn =
+    for<'map, 'key> unsafe fn(map: PtrMut<'map>, key: PtrConst<'key>) -> Option<PtrMut<'map>>;
+
+/// Remove the entry for a given key, moving its value into `value`. Returns false (and leaves
+/// `value` uninitialized) if the key was not found.
+///
+/// # Safety
+///
+/// The `map` parameter must point to aligned, initialized memory of the correct type.
+/// `value` must point to uninitialized memory of sufficient size for a value: when this
+/// function returns true, it is initialized, and the caller is responsible for dropping it.
+pub type MapRemoveFn = for<'map, 'key, 'value> unsafe fn(
+    map: PtrMut<'map>,
+    key: PtrConst<'key>,
+    value: PtrUninit<'value>,
+) -> bool;
+
 /// Virtual table for a Map<K, V>
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
 #[repr(C)]
@@ -139,6 +161,14 @@ pub struct MapVTable {
     /// cf. [`MapGetValuePtrFn`]
     pub get_value_ptr_fn: MapGetValuePtrFn,
 
+    /// cf. [`MapGetValuePtrMutFn`]
+    /// Only available for maps that hand out mutable access to their values
+    pub get_value_ptr_mut_fn: Option<MapGetValuePtrMutFn>,
+
+    /// cf. [`MapRemoveFn`]
+    /// Only available for maps that support removing entries
+    pub remove_fn: Option<MapRemoveFn>,
+
     /// Virtual table for map iterator operations
     pub iter_vtable: IterVTable<(PtrConst<'static>, PtrConst<'static>)>,
 }
@@ -157,6 +187,8 @@ pub struct MapVTableBuilder {
     len_fn: Option<MapLenFn>,
     contains_key_fn: Option<MapContainsKeyFn>,
     get_value_ptr_fn: Option<MapGetValuePtrFn>,
+    get_value_ptr_mut_fn: Option<MapGetValuePtrMutFn>,
+    remove_fn: Option<MapRemoveFn>,
     iter_vtable: Option<IterVTable<(PtrConst<'static>, PtrConst<'static>)>>,
 }
 
@@ -170,6 +202,8 @@ impl MapVTableBuilder {
             len_fn: None,
             contains_key_fn: None,
             get_value_ptr_fn: None,
+            get_value_ptr_mut_fn: None,
+            remove_fn: None,
             iter_vtable: None,
         }
     }
@@ -204,6 +238,18 @@ impl MapVTableBuilder {
         self
     }
 
+    /// Sets the get_value_ptr_mut_fn field
+    pub const fn get_value_ptr_mut(mut self, f: MapGetValuePtrMutFn) -> Self {
+        self.get_value_ptr_mut_fn = Some(f);
+        self
+    }
+
+    /// Sets the remove_fn field
+    pub const fn remove(mut self, f: MapRemoveFn) -> Self {
+        self.remove_fn = Some(f);
+        self
+    }
+
     /// Sets the iter_vtable field
     pub const fn iter_vtable(
         mut self,
@@ -225,6 +271,8 @@ impl MapVTableBuilder {
             len_fn: self.len_fn.unwrap(),
             contains_key_fn: self.contains_key_fn.unwrap(),
             get_value_ptr_fn: self.get_value_ptr_fn.unwrap(),
+            get_value_ptr_mut_fn: self.get_value_ptr_mut_fn,
+            remove_fn: self.remove_fn,
             iter_vtable: self.iter_vtable.unwrap(),
         }
     }
@@ -1,4 +1,6 @@
-use crate::PtrConst;
+use core::sync::atomic::Ordering;
+
+use crate::{PtrConst, PtrMut, PtrUninit};
 
 /// Definition for scalar types
 #[derive(Clone, Copy, Debug)]
@@ -8,8 +10,25 @@ pub struct ScalarDef<'shape> {
     /// Affinity of the scalar — is spiritually more like a number, more like a string, something else?
     /// example: an IPv4 address is both. good luck.
     pub affinity: &'shape ScalarAffinity<'shape>,
+
+    /// For atomics: loads the current value, as the non-atomic type described by `Shape::inner`
+    pub atomic_load: Option<AtomicLoadFn>,
 }
 
+/// Loads the value of an atomic with the given ordering, and writes it into `dst` as the
+/// corresponding non-atomic type (e.g. `u64` for `AtomicU64`).
+///
+/// # Safety
+///
+/// `src` must point to an atomic of the shape this function belongs to, and `dst` must be
+/// valid for writes of its inner shape. Like `AtomicU64::load`, this panics if `ordering` is
+/// `Release` or `AcqRel`.
+pub type AtomicLoadFn = for<'src, 'dst> unsafe fn(
+    src: PtrConst<'src>,
+    ordering: Ordering,
+    dst: PtrUninit<'dst>,
+) -> PtrMut<'dst>;
+
 impl<'shape> ScalarDef<'shape> {
     /// Returns a builder for ScalarDef
     pub const fn builder() -> ScalarDefBuilder<'shape> {
@@ -21,13 +40,17 @@ impl<'shape> ScalarDef<'shape> {
 #[derive(Default)]
 pub struct ScalarDefBuilder<'shape> {
     affinity: Option<&'shape ScalarAffinity<'shape>>,
+    atomic_load: Option<AtomicLoadFn>,
 }
 
 impl<'shape> ScalarDefBuilder<'shape> {
     /// Creates a new ScalarDefBuilder
     #[allow(clippy::new_without_default)]
     pub const fn new() -> Self {
-        Self { affinity: None }
+        Self {
+            affinity: None,
+            atomic_load: None,
+        }
     }
 
     /// Sets the affinity for the ScalarDef
@@ -36,10 +59,17 @@ impl<'shape> ScalarDefBuilder<'shape> {
         self
     }
 
+    /// Sets the function used to load the value of an atomic
+    pub const fn atomic_load(mut self, atomic_load: AtomicLoadFn) -> Self {
+        self.atomic_load = Some(atomic_load);
+        self
+    }
+
     /// Builds the ScalarDef
     pub const fn build(self) -> ScalarDef<'shape> {
         ScalarDef {
             affinity: self.affinity.unwrap(),
+            atomic_load: self.atomic_load,
         }
     }
 }
@@ -72,6 +102,8 @@ pub enum ScalarAffinity<'shape> {
     ULID(UlidAffinity),
     /// Timestamp or Datetime-like scalar affinity
     Time(TimeAffinity<'shape>),
+    /// Duration or time-span-like scalar affinity
+    Duration(DurationAffinity<'shape>),
     /// Something you're not supposed to look inside of
     Opaque(OpaqueAffinity),
     /// Other scalar affinity
@@ -80,6 +112,8 @@ pub enum ScalarAffinity<'shape> {
     Char(CharAffinity),
     /// Path scalar affinity (file/disk paths)
     Path(PathAffinity),
+    /// String that isn't necessarily valid UTF-8, like OS strings or C strings
+    PlatformString(PlatformStringAffinity),
 }
 
 impl<'shape> ScalarAffinity<'shape> {
@@ -138,6 +172,11 @@ impl<'shape> ScalarAffinity<'shape> {
         TimeAffinityBuilder::new()
     }
 
+    /// Returns a DurationAffinityBuilder
+    pub const fn duration() -> DurationAffinityBuilder<'shape> {
+        DurationAffinityBuilder::new()
+    }
+
     /// Returns an OpaqueAffinityBuilder
     pub const fn opaque() -> OpaqueAffinityBuilder {
         OpaqueAffinityBuilder::new()
@@ -157,6 +196,11 @@ impl<'shape> ScalarAffinity<'shape> {
     pub const fn path() -> PathAffinityBuilder {
         PathAffinityBuilder::new()
     }
+
+    /// Returns a PlatformStringAffinityBuilder
+    pub const fn platform_string() -> PlatformStringAffinityBuilder {
+        PlatformStringAffinityBuilder::new()
+    }
 }
 
 //////////////////////////////////////////////////////////////////////////////////////////
@@ -171,11 +215,13 @@ pub struct NumberAffinity<'shape> {
     /// Bit representation of numbers
     pub bits: NumberBits,
 
-    /// Minimum representable value
-    pub min: PtrConst<'shape>,
+    /// Minimum representable value, or `None` for numbers without one, like
+    /// [`NumberBits::BigInteger`]
+    pub min: Option<PtrConst<'shape>>,
 
-    /// Maximum representable value
-    pub max: PtrConst<'shape>,
+    /// Maximum representable value, or `None` for numbers without one, like
+    /// [`NumberBits::BigInteger`]
+    pub max: Option<PtrConst<'shape>>,
 
     /// Positive infinity representable value
     pub positive_infinity: Option<PtrConst<'shape>>,
@@ -272,6 +318,22 @@ pub enum NumberBits {
         /// Number of bits used for the scale part
         scale_bits: usize,
     },
+    /// Integer whose size is only limited by available memory, like `num_bigint::BigInt`
+    BigInteger {
+        /// Whether the integer is signed or unsigned
+        sign: Signedness,
+    },
+}
+
+impl NumberBits {
+    /// Whether numbers of this kind can hold values that machine numbers (`u128`, `i128`,
+    /// `f64`...) can't represent exactly, and should be read from their decimal literal instead.
+    pub const fn is_arbitrary_precision(&self) -> bool {
+        matches!(
+            self,
+            NumberBits::Decimal { .. } | NumberBits::BigInteger { .. }
+        )
+    }
 }
 
 impl<'shape> NumberAffinity<'shape> {
@@ -381,6 +443,28 @@ impl<'shape> NumberAffinityBuilder<'shape> {
         self
     }
 
+    /// Sets the number limits as decimal with specified bits: the value is
+    /// `integer / 10^scale`, with the sign stored separately
+    pub const fn decimal(
+        mut self,
+        sign_bits: usize,
+        integer_bits: usize,
+        scale_bits: usize,
+    ) -> Self {
+        self.limits = Some(NumberBits::Decimal {
+            sign_bits,
+            integer_bits,
+            scale_bits,
+        });
+        self
+    }
+
+    /// Sets the number limits as an integer of unbounded size
+    pub const fn big_integer(mut self, sign: Signedness) -> Self {
+        self.limits = Some(NumberBits::BigInteger { sign });
+        self
+    }
+
     /// Sets the min value for the NumberAffinity
     pub const fn min(mut self, min: PtrConst<'shape>) -> Self {
         self.min = Some(min);
@@ -433,8 +517,8 @@ impl<'shape> NumberAffinityBuilder<'shape> {
     pub const fn build(self) -> ScalarAffinity<'shape> {
         ScalarAffinity::Number(NumberAffinity {
             bits: self.limits.unwrap(),
-            min: self.min.unwrap(),
-            max: self.max.unwrap(),
+            min: self.min,
+            max: self.max,
             positive_infinity: self.positive_infinity,
             negative_infinity: self.negative_infinity,
             nan_sample: self.nan_sample,
@@ -923,6 +1007,75 @@ impl<'shape> TimeAffinityBuilder<'shape> {
     }
 }
 
+/// Definition for duration scalar affinities: spans of time that aren't anchored to any moment
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[repr(C)]
+#[non_exhaustive]
+pub struct DurationAffinity<'shape> {
+    /// The shortest span representable (typically zero, unless negative spans are allowed)
+    pub min: Option<PtrConst<'shape>>,
+
+    /// The longest span representable
+    pub max: Option<PtrConst<'shape>>,
+
+    /// The smallest non-zero span representable
+    pub granularity: Option<PtrConst<'shape>>,
+}
+
+impl<'shape> DurationAffinity<'shape> {
+    /// Returns a builder for DurationAffinity
+    pub const fn builder() -> DurationAffinityBuilder<'shape> {
+        DurationAffinityBuilder::new()
+    }
+}
+
+/// Builder for DurationAffinity
+#[repr(C)]
+pub struct DurationAffinityBuilder<'shape> {
+    min: Option<PtrConst<'shape>>,
+    max: Option<PtrConst<'shape>>,
+    granularity: Option<PtrConst<'shape>>,
+}
+
+impl<'shape> DurationAffinityBuilder<'shape> {
+    /// Creates a new DurationAffinityBuilder
+    #[allow(clippy::new_without_default)]
+    pub const fn new() -> Self {
+        Self {
+            min: None,
+            max: None,
+            granularity: None,
+        }
+    }
+
+    /// Sets the min value for the DurationAffinity
+    pub const fn min(mut self, min: PtrConst<'shape>) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the max value for the DurationAffinity
+    pub const fn max(mut self, max: PtrConst<'shape>) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the granularity for the DurationAffinity
+    pub const fn granularity(mut self, granularity: PtrConst<'shape>) -> Self {
+        self.granularity = Some(granularity);
+        self
+    }
+
+    /// Builds the ScalarAffinity
+    pub const fn build(self) -> ScalarAffinity<'shape> {
+        ScalarAffinity::Duration(DurationAffinity {
+            min: self.min,
+            max: self.max,
+            granularity: self.granularity,
+        })
+    }
+}
+
 /// Definition for opaque scalar affinities
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 #[repr(C)]
@@ -1042,3 +1195,33 @@ impl PathAffinityBuilder {
         ScalarAffinity::Path(PathAffinity {})
     }
 }
+
+/// Definition for platform string scalar affinities
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[repr(C)]
+#[non_exhaustive]
+pub struct PlatformStringAffinity {}
+
+impl PlatformStringAffinity {
+    /// Returns a builder for PlatformStringAffinity
+    pub const fn builder() -> PlatformStringAffinityBuilder {
+        PlatformStringAffinityBuilder::new()
+    }
+}
+
+/// Builder for PlatformStringAffinity
+#[repr(C)]
+pub struct PlatformStringAffinityBuilder {}
+
+impl PlatformStringAffinityBuilder {
+    /// Creates a new PlatformStringAffinityBuilder
+    #[allow(clippy::new_without_default)]
+    pub const fn new() -> Self {
+        Self {}
+    }
+
+    /// Builds the ScalarAffinity
+    pub const fn build(self) -> ScalarAffinity<'static> {
+        ScalarAffinity::PlatformString(PlatformStringAffinity {})
+    }
+}
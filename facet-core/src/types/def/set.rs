@@ -20,7 +20,7 @@ impl<'shape> SetDef<'shape> {
     }
 
     /// Returns the shape of the items in the set
-    pub fn t(&self) -> &'shape Shape {
+    pub fn t(&self) -> &'shape Shape<'shape> {
         (self.t)()
     }
 }
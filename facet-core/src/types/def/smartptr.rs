@@ -1,6 +1,6 @@
 use bitflags::bitflags;
 
-use crate::{PtrConst, PtrMut, PtrUninit};
+use crate::{GenericPtr, PtrConst, PtrMut, PtrUninit};
 
 use super::Shape;
 
@@ -16,6 +16,12 @@ pub struct SmartPointerDef<'shape> {
     /// shape of the inner type of the smart pointer, if not opaque
     pub pointee: Option<fn() -> &'shape Shape<'shape>>,
 
+    /// shape of the owned form of the pointee, if the pointee is unsized (like `String` for
+    /// `str`, or `Vec<T>` for `[T]`)
+    ///
+    /// When set, [`NewIntoFn`] takes a value of this shape instead of the pointee.
+    pub owned: Option<fn() -> &'shape Shape<'shape>>,
+
     /// shape of the corresponding strong pointer, if this pointer is weak
     pub weak: Option<fn() -> &'shape Shape<'shape>>,
 
@@ -36,6 +42,7 @@ impl<'shape> SmartPointerDef<'shape> {
         SmartPointerDefBuilder {
             vtable: None,
             pointee: None,
+            owned: None,
             flags: None,
             known: None,
             weak: None,
@@ -48,6 +55,11 @@ impl<'shape> SmartPointerDef<'shape> {
         self.pointee.map(|v| v())
     }
 
+    /// Returns shape of the owned form of the pointee, if the pointee is unsized
+    pub fn owned(&self) -> Option<&'shape Shape<'shape>> {
+        self.owned.map(|v| v())
+    }
+
     /// Returns shape of the corresponding strong pointer, if this pointer is weak
     pub fn weak(&self) -> Option<&'shape Shape<'shape>> {
         self.weak.map(|v| v())
@@ -64,6 +76,7 @@ impl<'shape> SmartPointerDef<'shape> {
 pub struct SmartPointerDefBuilder<'shape> {
     vtable: Option<&'shape SmartPointerVTable>,
     pointee: Option<fn() -> &'shape Shape<'shape>>,
+    owned: Option<fn() -> &'shape Shape<'shape>>,
     flags: Option<SmartPointerFlags>,
     known: Option<KnownSmartPointer>,
     weak: Option<fn() -> &'shape Shape<'shape>>,
@@ -78,6 +91,7 @@ impl<'shape> SmartPointerDefBuilder<'shape> {
         Self {
             vtable: None,
             pointee: None,
+            owned: None,
             flags: None,
             known: None,
             weak: None,
@@ -99,6 +113,13 @@ impl<'shape> SmartPointerDefBuilder<'shape> {
         self
     }
 
+    /// Sets the shape of the owned form of the pointee, for unsized pointees.
+    #[must_use]
+    pub const fn owned(mut self, owned: fn() -> &'shape Shape<'shape>) -> Self {
+        self.owned = Some(owned);
+        self
+    }
+
     /// Sets the flags for the smart pointer.
     #[must_use]
     pub const fn flags(mut self, flags: SmartPointerFlags) -> Self {
@@ -137,6 +158,7 @@ impl<'shape> SmartPointerDefBuilder<'shape> {
         SmartPointerDef {
             vtable: self.vtable.unwrap(),
             pointee: self.pointee,
+            owned: self.owned,
             weak: self.weak,
             strong: self.strong,
             flags: self.flags.unwrap(),
@@ -200,10 +222,12 @@ pub type DowngradeIntoFn =
 ///
 /// This can only be used with strong pointers (like [`std::sync::Arc`] or [`std::rc::Rc`]).
 ///
+/// The returned pointer is wide if the pointee is unsized (like the `str` in an `Arc<str>`).
+///
 /// # Safety
 ///
 /// `this` must be a valid strong smart pointer (like [`std::sync::Arc`] or [`std::rc::Rc`]).
-pub type BorrowFn = for<'ptr> unsafe fn(this: PtrConst<'ptr>) -> PtrConst<'ptr>;
+pub type BorrowFn = for<'ptr> unsafe fn(this: PtrConst<'ptr>) -> GenericPtr<'ptr>;
 
 /// Creates a new smart pointer wrapping the given value.
 ///
@@ -218,7 +242,8 @@ pub type BorrowFn = for<'ptr> unsafe fn(this: PtrConst<'ptr>) -> PtrConst<'ptr>;
 ///
 /// `this` must not have been initialized yet.
 ///
-/// `ptr` must point to a value of type `T`.
+/// `ptr` must point to a value of type `T`, or of the owned form of `T` if the pointee is unsized
+/// (see [`SmartPointerDef::owned`]).
 ///
 /// `ptr` is moved out of (with [`core::ptr::read`]) — it should be deallocated afterwards (e.g.
 /// with [`core::mem::forget`]) but NOT dropped).
@@ -235,6 +260,27 @@ pub struct LockResult<'ptr> {
 }
 
 impl<'ptr> LockResult<'ptr> {
+    /// Creates a new `LockResult` from the locked data and its guard.
+    ///
+    /// # Safety
+    ///
+    /// `data` must point to the locked value, and stay valid for as long as the guard is alive.
+    /// `guard` must point to a guard that `guard_vtable.drop_in_place` can drop (which releases
+    /// the lock). If the lock only grants shared access (like a read lock), `data` must not be
+    /// written to.
+    #[must_use]
+    pub unsafe fn new(
+        data: PtrMut<'ptr>,
+        guard: PtrConst<'ptr>,
+        guard_vtable: &'static LockGuardVTable,
+    ) -> Self {
+        Self {
+            data,
+            guard,
+            guard_vtable,
+        }
+    }
+
     /// Returns a reference to the locked data
     #[must_use]
     pub fn data(&self) -> &PtrMut<'ptr> {
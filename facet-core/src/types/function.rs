@@ -0,0 +1,141 @@
+use crate::{PtrMut, PtrUninit, Shape};
+
+/// Calls a function with its arguments, and writes its return value into `ret`
+///
+/// # Safety
+///
+/// `args` must point to an initialized tuple of the function's arguments (of shape
+/// [`FunctionShape::args_shape`]). It is moved out of (with [`core::ptr::read`]) — it should be
+/// deallocated afterwards (e.g. with [`core::mem::forget`]) but NOT dropped.
+///
+/// `ret` must point to uninitialized memory of sufficient size for the return value (of shape
+/// [`FunctionShape::return_shape`]). The function initializes it.
+pub type FunctionCallFn =
+    for<'args, 'ret> unsafe fn(args: PtrMut<'args>, ret: PtrUninit<'ret>) -> PtrMut<'ret>;
+
+/// Describes a function annotated with `#[facet_fn(dynamic)]`: its name, parameters, return type
+/// and documentation, along with a type-erased way to call it.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct FunctionShape {
+    /// The function's name
+    pub name: &'static str,
+
+    /// The number of parameters
+    pub param_count: usize,
+
+    /// Parameter names, in declaration order
+    pub param_names: &'static [&'static str],
+
+    /// Parameter shapes, in declaration order
+    pub param_shapes: &'static [&'static Shape<'static>],
+
+    /// Shape of the tuple of all parameters, e.g. `(i32, String)` for `fn f(a: i32, b: String)`.
+    /// This is what arguments are passed as, to [`FunctionShape::call`].
+    pub args_shape: &'static Shape<'static>,
+
+    /// Shape of the return value (`()` for functions that don't return anything)
+    pub return_shape: &'static Shape<'static>,
+
+    /// Doc comment lines
+    pub documentation: &'static [&'static str],
+
+    /// cf. [`FunctionCallFn`]
+    pub call: FunctionCallFn,
+}
+
+impl FunctionShape {
+    /// Returns a builder for FunctionShape
+    pub const fn builder() -> FunctionShapeBuilder {
+        FunctionShapeBuilder::new()
+    }
+}
+
+/// Builder for [`FunctionShape`]
+pub struct FunctionShapeBuilder {
+    name: Option<&'static str>,
+    param_names: &'static [&'static str],
+    param_shapes: &'static [&'static Shape<'static>],
+    args_shape: Option<&'static Shape<'static>>,
+    return_shape: Option<&'static Shape<'static>>,
+    documentation: &'static [&'static str],
+    call: Option<FunctionCallFn>,
+}
+
+impl FunctionShapeBuilder {
+    /// Creates a new FunctionShapeBuilder
+    #[allow(clippy::new_without_default)]
+    pub const fn new() -> Self {
+        Self {
+            name: None,
+            param_names: &[],
+            param_shapes: &[],
+            args_shape: None,
+            return_shape: None,
+            documentation: &[],
+            call: None,
+        }
+    }
+
+    /// Sets the name for the FunctionShape
+    pub const fn name(mut self, name: &'static str) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Sets the parameter names for the FunctionShape
+    pub const fn param_names(mut self, param_names: &'static [&'static str]) -> Self {
+        self.param_names = param_names;
+        self
+    }
+
+    /// Sets the parameter shapes for the FunctionShape
+    pub const fn param_shapes(mut self, param_shapes: &'static [&'static Shape<'static>]) -> Self {
+        self.param_shapes = param_shapes;
+        self
+    }
+
+    /// Sets the shape of the tuple of parameters for the FunctionShape
+    pub const fn args_shape(mut self, args_shape: &'static Shape<'static>) -> Self {
+        self.args_shape = Some(args_shape);
+        self
+    }
+
+    /// Sets the return shape for the FunctionShape
+    pub const fn return_shape(mut self, return_shape: &'static Shape<'static>) -> Self {
+        self.return_shape = Some(return_shape);
+        self
+    }
+
+    /// Sets the documentation for the FunctionShape
+    pub const fn documentation(mut self, documentation: &'static [&'static str]) -> Self {
+        self.documentation = documentation;
+        self
+    }
+
+    /// Sets the call function for the FunctionShape
+    pub const fn call(mut self, call: FunctionCallFn) -> Self {
+        self.call = Some(call);
+        self
+    }
+
+    /// Builds the FunctionShape
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name, args shape, return shape or call function are missing, or if there
+    /// aren't as many parameter shapes as parameter names.
+    pub const fn build(self) -> FunctionShape {
+        assert!(self.param_names.len() == self.param_shapes.len());
+        FunctionShape {
+            name: self.name.unwrap(),
+            param_count: self.param_names.len(),
+            param_names: self.param_names,
+            param_shapes: self.param_shapes,
+            args_shape: self.args_shape.unwrap(),
+            return_shape: self.return_shape.unwrap(),
+            documentation: self.documentation,
+            call: self.call.unwrap(),
+        }
+    }
+}
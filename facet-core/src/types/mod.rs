@@ -17,6 +17,9 @@ pub use def::*;
 mod ty;
 pub use ty::*;
 
+mod function;
+pub use function::*;
+
 use crate::{ConstTypeId, Facet};
 
 /// Schema for reflection of a type
@@ -155,7 +158,7 @@ impl<'shape> Shape<'shape> {
     }
 
     /// Check if this shape is of the given type
-    pub fn is_type<Other: Facet<'shape>>(&self) -> bool {
+    pub fn is_type<Other: Facet<'shape> + ?Sized>(&self) -> bool {
         let l = self;
         let r = Other::SHAPE;
         l == r
@@ -1,4 +1,4 @@
-use crate::PtrConst;
+use crate::{Facet, PtrConst, PtrMut, PtrUninit};
 
 use super::{DefaultInPlaceFn, Shape};
 use bitflags::bitflags;
@@ -61,12 +61,179 @@ pub struct FieldVTable {
 
     /// Function to get the default value for this field
     pub default_fn: Option<DefaultInPlaceFn>,
+
+    /// Converts the field into another type to serialize it, set with
+    /// `#[facet(serialize_with = ..)]` or `#[facet(proxy = ..)]`
+    pub serialize_with: Option<SerializeWith>,
+
+    /// Converts the field from another type after deserializing that, set with
+    /// `#[facet(deserialize_with = ..)]` or `#[facet(proxy = ..)]`
+    pub deserialize_with: Option<DeserializeWith>,
 }
 
 /// A function that, if present, determines whether field should be included in the serialization
 /// step.
 pub type SkipSerializingIfFn = for<'mem> unsafe fn(value: PtrConst<'mem>) -> bool;
 
+/// Serializes a field as another type: the field's value is converted with `convert`, and the
+/// result is serialized in its stead.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[repr(C)]
+#[non_exhaustive]
+pub struct SerializeWith {
+    /// The shape of the type the field is serialized as
+    pub shape: fn() -> &'static Shape<'static>,
+
+    /// Builds a value of that type from the field's value
+    pub convert: SerializeWithFn,
+}
+
+/// Writes the value a field is serialized as into `target`, from the value of the field.
+pub type SerializeWithFn =
+    for<'src, 'dst> unsafe fn(field: PtrConst<'src>, target: PtrUninit<'dst>) -> PtrMut<'dst>;
+
+impl SerializeWith {
+    /// Creates a `SerializeWith` from the shape of the type a field is serialized as, and the
+    /// function that converts the field to it
+    pub const fn new(shape: fn() -> &'static Shape<'static>, convert: SerializeWithFn) -> Self {
+        Self { shape, convert }
+    }
+
+    /// Serializes fields of type `T` as `P`, converting them with `From<&T>`
+    pub const fn proxy<'f, T, P>() -> Self
+    where
+        P: Facet<'f> + for<'a> From<&'a T>,
+    {
+        Self::new(shape_of_proxy::<P>, |field, target| unsafe {
+            target.put(P::from(field.get::<T>()))
+        })
+    }
+
+    /// The shape of the type returned by `f`, a `#[facet(serialize_with = ..)]` function
+    pub fn shape_returned_by<'f, T, P: Facet<'f>>(_f: fn(&T) -> P) -> &'static Shape<'static> {
+        P::SHAPE
+    }
+
+    /// Calls `f`, a `#[facet(serialize_with = ..)]` function, on the field and writes the result
+    /// into `target`
+    ///
+    /// # Safety
+    ///
+    /// `field` must point to a `T`, and `target` must be valid for writes of a `P`
+    pub unsafe fn call<'dst, T, P>(
+        f: fn(&T) -> P,
+        field: PtrConst<'_>,
+        target: PtrUninit<'dst>,
+    ) -> PtrMut<'dst> {
+        unsafe { target.put(f(field.get::<T>())) }
+    }
+}
+
+/// Deserializes a field from another type: a value of that type is deserialized, then moved
+/// into the field by `convert`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[repr(C)]
+#[non_exhaustive]
+pub struct DeserializeWith {
+    /// The shape of the type the field is deserialized from
+    pub shape: fn() -> &'static Shape<'static>,
+
+    /// Builds the field's value from a value of that type
+    pub convert: DeserializeWithFn,
+}
+
+/// Moves the value out of `source`, which holds a value of the type a field is deserialized from,
+/// and writes the field's value into `field`.
+///
+/// `source` is moved out of even if the conversion fails.
+pub type DeserializeWithFn =
+    for<'src, 'dst> unsafe fn(
+        source: PtrMut<'src>,
+        field: PtrUninit<'dst>,
+    ) -> Result<PtrMut<'dst>, FieldConversionError>;
+
+impl DeserializeWith {
+    /// Creates a `DeserializeWith` from the shape of the type a field is deserialized from, and
+    /// the function that converts it into the field
+    pub const fn new(shape: fn() -> &'static Shape<'static>, convert: DeserializeWithFn) -> Self {
+        Self { shape, convert }
+    }
+
+    /// Deserializes fields of type `T` from `P`, converting them with `TryFrom<P>`
+    pub const fn proxy<'f, T, P>() -> Self
+    where
+        P: Facet<'f>,
+        T: TryFrom<P>,
+        T::Error: core::fmt::Display,
+    {
+        Self::new(shape_of_proxy::<P>, |source, field| unsafe {
+            let value = T::try_from(source.read::<P>()).map_err(FieldConversionError::new)?;
+            Ok(field.put(value))
+        })
+    }
+
+    /// The shape of the type taken by `f`, a `#[facet(deserialize_with = ..)]` function
+    pub fn shape_taken_by<'f, T, P: Facet<'f>, E>(
+        _f: fn(P) -> Result<T, E>,
+    ) -> &'static Shape<'static> {
+        P::SHAPE
+    }
+
+    /// Calls `f`, a `#[facet(deserialize_with = ..)]` function, on the value moved out of
+    /// `source` and writes the result into `field`
+    ///
+    /// # Safety
+    ///
+    /// `source` must point to a `P`, which is moved out of, and `field` must be valid for
+    /// writes of a `T`
+    pub unsafe fn call<'dst, T, P, E: core::fmt::Display>(
+        f: fn(P) -> Result<T, E>,
+        source: PtrMut<'_>,
+        field: PtrUninit<'dst>,
+    ) -> Result<PtrMut<'dst>, FieldConversionError> {
+        let value = f(unsafe { source.read::<P>() }).map_err(FieldConversionError::new)?;
+        Ok(unsafe { field.put(value) })
+    }
+}
+
+fn shape_of_proxy<'f, P: Facet<'f>>() -> &'static Shape<'static> {
+    P::SHAPE
+}
+
+/// The error returned by a `#[facet(deserialize_with = ..)]` function, or by the `TryFrom`
+/// implementation of a `#[facet(proxy = ..)]`
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct FieldConversionError {
+    #[cfg(feature = "alloc")]
+    message: alloc::string::String,
+}
+
+impl FieldConversionError {
+    /// Creates an error from the error returned by a conversion. Its message is kept if the
+    /// `alloc` feature is enabled.
+    #[cfg_attr(not(feature = "alloc"), allow(unused_variables))]
+    pub fn new(error: impl core::fmt::Display) -> Self {
+        Self {
+            #[cfg(feature = "alloc")]
+            message: alloc::string::ToString::to_string(&error),
+        }
+    }
+}
+
+impl core::fmt::Display for FieldConversionError {
+    #[cfg(feature = "alloc")]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.message)
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("conversion failed")
+    }
+}
+
+impl core::error::Error for FieldConversionError {}
+
 impl<'shape> Field<'shape> {
     /// Returns the shape of the inner type
     pub const fn shape(&self) -> &'shape Shape<'shape> {
@@ -86,17 +253,91 @@ impl<'shape> Field<'shape> {
 
 /// An attribute that can be set on a field
 #[non_exhaustive]
-#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[derive(Clone, Copy, Debug)]
 #[repr(C)]
 pub enum FieldAttribute<'shape> {
     /// Custom field attribute containing arbitrary text
     Arbitrary(&'shape str),
+
+    /// `#[facet(min = ..)]`: the smallest value a number may take. Bounds are stored as `f64`,
+    /// so integer bounds are exact up to 2^53.
+    Min(f64),
+
+    /// `#[facet(max = ..)]`: the largest value a number may take
+    Max(f64),
+
+    /// `#[facet(min_len = ..)]`: the fewest characters a string may have, or items a list,
+    /// array or map may have
+    MinLen(usize),
+
+    /// `#[facet(max_len = ..)]`: the most characters a string may have, or items a list,
+    /// array or map may have
+    MaxLen(usize),
+
+    /// `#[facet(pattern = "..")]`: a regular expression that strings must match
+    Pattern(&'shape str),
+
+    /// `#[facet(non_empty)]`: strings, lists, arrays and maps must have at least one
+    /// character or item
+    NonEmpty,
+}
+
+impl FieldAttribute<'_> {
+    /// Returns true for attributes that constrain the values a field may take
+    pub const fn is_constraint(&self) -> bool {
+        !matches!(self, FieldAttribute::Arbitrary(_))
+    }
+}
+
+impl PartialEq for FieldAttribute<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Arbitrary(a), Self::Arbitrary(b)) => a == b,
+            (Self::Min(a), Self::Min(b)) | (Self::Max(a), Self::Max(b)) => {
+                a.to_bits() == b.to_bits()
+            }
+            (Self::MinLen(a), Self::MinLen(b)) | (Self::MaxLen(a), Self::MaxLen(b)) => a == b,
+            (Self::Pattern(a), Self::Pattern(b)) => a == b,
+            (Self::NonEmpty, Self::NonEmpty) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for FieldAttribute<'_> {}
+
+impl core::hash::Hash for FieldAttribute<'_> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        core::mem::discriminant(self).hash(state);
+        match self {
+            Self::Arbitrary(s) | Self::Pattern(s) => s.hash(state),
+            Self::Min(bound) | Self::Max(bound) => bound.to_bits().hash(state),
+            Self::MinLen(len) | Self::MaxLen(len) => len.hash(state),
+            Self::NonEmpty => {}
+        }
+    }
+}
+
+impl core::fmt::Display for FieldAttribute<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FieldAttribute::Arbitrary(content) => write!(f, "{content}"),
+            FieldAttribute::Min(min) => write!(f, "min = {min}"),
+            FieldAttribute::Max(max) => write!(f, "max = {max}"),
+            FieldAttribute::MinLen(len) => write!(f, "min_len = {len}"),
+            FieldAttribute::MaxLen(len) => write!(f, "max_len = {len}"),
+            FieldAttribute::Pattern(pattern) => write!(f, "pattern = {pattern:?}"),
+            FieldAttribute::NonEmpty => write!(f, "non_empty"),
+        }
+    }
 }
 
 /// Builder for FieldVTable
 pub struct FieldVTableBuilder {
     skip_serializing_if: Option<SkipSerializingIfFn>,
     default_fn: Option<DefaultInPlaceFn>,
+    serialize_with: Option<SerializeWith>,
+    deserialize_with: Option<DeserializeWith>,
 }
 
 impl FieldVTableBuilder {
@@ -106,6 +347,8 @@ impl FieldVTableBuilder {
         Self {
             skip_serializing_if: None,
             default_fn: None,
+            serialize_with: None,
+            deserialize_with: None,
         }
     }
 
@@ -121,11 +364,25 @@ impl FieldVTableBuilder {
         self
     }
 
+    /// Sets how the field is serialized as another type
+    pub const fn serialize_with(mut self, serialize_with: SerializeWith) -> Self {
+        self.serialize_with = Some(serialize_with);
+        self
+    }
+
+    /// Sets how the field is deserialized from another type
+    pub const fn deserialize_with(mut self, deserialize_with: DeserializeWith) -> Self {
+        self.deserialize_with = Some(deserialize_with);
+        self
+    }
+
     /// Builds the FieldVTable
     pub const fn build(self) -> FieldVTable {
         FieldVTable {
             skip_serializing_if: self.skip_serializing_if,
             default_fn: self.default_fn,
+            serialize_with: self.serialize_with,
+            deserialize_with: self.deserialize_with,
         }
     }
 }
@@ -163,6 +420,8 @@ impl<'shape> FieldBuilder<'shape> {
                 FieldVTable {
                     skip_serializing_if: None,
                     default_fn: None,
+                    serialize_with: None,
+                    deserialize_with: None,
                 }
             },
         }
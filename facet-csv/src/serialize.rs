@@ -1,5 +1,5 @@
 use facet_core::Facet;
-use facet_reflect::Peek;
+use facet_reflect::{Peek, ReflectError};
 use facet_serialize::{Serializer, serialize_iterative};
 use std::io::{self, Write};
 
@@ -100,6 +100,10 @@ where
 {
     type Error = io::Error;
 
+    fn serialize_unreadable(&mut self, error: ReflectError<'shape>) -> Result<(), Self::Error> {
+        Err(io::Error::other(error.to_plain_string()))
+    }
+
     fn start_object(&mut self, len: Option<usize>) -> Result<(), Self::Error> {
         self.set_n_fields(len.expect("Must know the length of the object for CSV"));
         Ok(())
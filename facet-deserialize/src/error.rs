@@ -3,7 +3,7 @@ use ariadne::{Color, Config, IndexType, Label, Report, ReportKind, Source};
 
 use alloc::string::String;
 
-use facet_core::{Shape, Type, UserType};
+use facet_core::{Def, Shape, Type, UserType};
 use facet_reflect::{ReflectError, VariantError};
 use owo_colors::OwoColorize;
 
@@ -94,6 +94,9 @@ pub enum DeserErrorKind<'shape> {
     /// A number is out of range.
     NumberOutOfRange(f64),
 
+    /// A number literal that isn't valid in the input format.
+    InvalidNumber(String),
+
     /// An unexpected String was encountered in the input.
     StringAsNumber(String),
 
@@ -230,6 +233,9 @@ impl core::fmt::Display for DeserErrorMessage<'_, '_> {
             DeserErrorKind::NumberOutOfRange(n) => {
                 write!(f, "Number out of range: {}", n.red())
             }
+            DeserErrorKind::InvalidNumber(literal) => {
+                write!(f, "Invalid number: {}", literal.red())
+            }
             DeserErrorKind::StringAsNumber(s) => {
                 write!(f, "Expected a string but got number: {}", s.red())
             }
@@ -274,6 +280,15 @@ impl core::fmt::Display for DeserErrorMessage<'_, '_> {
 
                     write!(f, "]")?;
                     Ok(())
+                } else if let Def::Result(_) = enum_shape.def {
+                    write!(
+                        f,
+                        "Enum variant not found: {} in {}. Available variants: [{}, {}]",
+                        name.red(),
+                        enum_shape.yellow(),
+                        "Ok".green(),
+                        "Err".green()
+                    )
                 } else {
                     write!(
                         f,
@@ -46,6 +46,11 @@ pub enum Scalar<'input> {
     U128(u128),
     /// 128-bit signed integer scalar.
     I128(i128),
+    /// A number as its literal in the input, like `-12.50` or `1e400`. Formats emit this for
+    /// numbers that the variants above may not represent exactly, so that arbitrary-precision
+    /// types (like decimals or big integers) can parse it directly, while machine numbers are
+    /// converted as usual.
+    Number(Cow<'input, str>),
     /// Boolean scalar.
     Bool(bool),
     /// Null scalar (e.g. for formats supporting explicit null).
@@ -114,6 +119,7 @@ impl fmt::Display for Scalar<'_> {
             Scalar::F64(val) => write!(f, "f64 {}", val),
             Scalar::U128(val) => write!(f, "u128 {}", val),
             Scalar::I128(val) => write!(f, "i128 {}", val),
+            Scalar::Number(literal) => write!(f, "number {}", literal),
             Scalar::Bool(val) => write!(f, "bool {}", val),
             Scalar::Null => write!(f, "null"),
         }
@@ -131,6 +137,7 @@ impl Outcome<'_> {
                     Scalar::F64(val) => Scalar::F64(val),
                     Scalar::U128(val) => Scalar::U128(val),
                     Scalar::I128(val) => Scalar::I128(val),
+                    Scalar::Number(literal) => Scalar::Number(Cow::Owned(literal.into_owned())),
                     Scalar::Bool(val) => Scalar::Bool(val),
                     Scalar::Null => Scalar::Null,
                 };
@@ -159,7 +166,6 @@ impl Outcome<'_> {
 /// This bundles the mutable context that must be threaded through parsing steps.
 pub struct NextData<'input, 'facet, 'shape, C = Cooked, I = [u8]>
 where
-    'input: 'facet,
     I: ?Sized + 'input,
 {
     /// The offset we're supposed to start parsing from
@@ -174,7 +180,6 @@ where
 
 impl<'input, 'facet, 'shape, C, I> NextData<'input, 'facet, 'shape, C, I>
 where
-    'input: 'facet,
     I: ?Sized + 'input,
 {
     /// Returns the input (from the start! not from the current position)
@@ -333,6 +338,11 @@ pub enum PopReason {
     SmartPointer,
     /// Ending a wrapper value such as a newtype
     Wrapper,
+    /// Ending the value a field is deserialized from (see `#[facet(deserialize_with = ..)]`),
+    /// which converts it into the field
+    DeserializeWith,
+    /// Ending a value that's converted into its parent (see `Partial::begin_try_from`)
+    TryFrom,
 }
 
 mod deser_impl {
@@ -444,7 +454,7 @@ where
 /// Deserializes a working-in-progress value into a fully materialized heap value.
 /// This function drives the parsing loop until the entire input is consumed and the value is complete.
 pub fn deserialize_wip<'input, 'facet, 'shape, F>(
-    mut wip: Partial<'facet, 'shape>,
+    wip: Partial<'facet, 'shape>,
     input: &'input F::Input<'input>,
     format: &mut F,
 ) -> Result<HeapValue<'facet, 'shape>, DeserError<'input, 'shape, Cooked>>
@@ -455,6 +465,103 @@ where
     Span<F::SpanType>: ToCooked<'input, F>,
     'input: 'facet,
     'shape: 'input,
+{
+    deserialize_wip_inner::<F, BorrowInput>(wip, input, format)
+}
+
+/// Like [`deserialize_wip`], but never borrows from the input: strings are copied out of it,
+/// so the value doesn't depend on the input and can outlive it (with `'facet = 'static`, for
+/// example). Deserializing a `&str` fails.
+pub fn deserialize_wip_owned<'input, 'facet, 'shape, F>(
+    wip: Partial<'facet, 'shape>,
+    input: &'input F::Input<'input>,
+    format: &mut F,
+) -> Result<HeapValue<'facet, 'shape>, DeserError<'input, 'shape, Cooked>>
+where
+    F: Format + 'shape,
+    F::SpanType: SubstackBehavior,
+    F::Input<'input>: InputDebug,
+    Span<F::SpanType>: ToCooked<'input, F>,
+    'shape: 'input,
+{
+    deserialize_wip_inner::<F, CopyInput>(wip, input, format)
+}
+
+/// How strings from the input are put into the value being built
+trait InputStrings<'input, 'facet, 'shape> {
+    /// Sets a `&str`
+    fn set_str(
+        wip: &mut Partial<'facet, 'shape>,
+        s: Cow<'input, str>,
+    ) -> Result<(), ReflectError<'shape>>;
+
+    /// Sets a `Cow<str>`
+    fn set_cow(
+        wip: &mut Partial<'facet, 'shape>,
+        s: Cow<'input, str>,
+    ) -> Result<(), ReflectError<'shape>>;
+}
+
+/// Strings are borrowed from the input whenever possible
+struct BorrowInput;
+
+impl<'input: 'facet, 'facet, 'shape> InputStrings<'input, 'facet, 'shape> for BorrowInput {
+    fn set_str(
+        wip: &mut Partial<'facet, 'shape>,
+        s: Cow<'input, str>,
+    ) -> Result<(), ReflectError<'shape>> {
+        // The Cow may be Borrowed (we may have an owned string but need a &str)
+        match s {
+            Cow::Borrowed(s) => wip.set(s)?,
+            Cow::Owned(s) => wip.set(s)?,
+        };
+        Ok(())
+    }
+
+    fn set_cow(
+        wip: &mut Partial<'facet, 'shape>,
+        s: Cow<'input, str>,
+    ) -> Result<(), ReflectError<'shape>> {
+        wip.set(s)?;
+        Ok(())
+    }
+}
+
+/// Strings are always copied out of the input
+struct CopyInput;
+
+impl<'input, 'facet, 'shape> InputStrings<'input, 'facet, 'shape> for CopyInput {
+    fn set_str(
+        wip: &mut Partial<'facet, 'shape>,
+        _s: Cow<'input, str>,
+    ) -> Result<(), ReflectError<'shape>> {
+        Err(ReflectError::OperationFailed {
+            shape: wip.innermost_shape(),
+            operation: "borrowing a string from an input the value must not depend on",
+        })
+    }
+
+    fn set_cow(
+        wip: &mut Partial<'facet, 'shape>,
+        s: Cow<'input, str>,
+    ) -> Result<(), ReflectError<'shape>> {
+        wip.set(Cow::<str>::Owned(s.into_owned()))?;
+        Ok(())
+    }
+}
+
+fn deserialize_wip_inner<'input, 'facet, 'shape, F, S>(
+    mut wip: Partial<'facet, 'shape>,
+    input: &'input F::Input<'input>,
+    format: &mut F,
+) -> Result<HeapValue<'facet, 'shape>, DeserError<'input, 'shape, Cooked>>
+where
+    F: Format + 'shape,
+    F::SpanType: SubstackBehavior,
+    F::Input<'input>: InputDebug,
+    Span<F::SpanType>: ToCooked<'input, F>,
+    S: InputStrings<'input, 'facet, 'shape>,
+    'shape: 'input,
 {
     // This struct is just a bundle of the state that we need to pass around all the time.
     let mut runner = StackRunner {
@@ -468,12 +575,13 @@ where
         last_span: Span::new(0, 0),
         format_source: format.source(),
         array_indices: Vec::new(),
+        result_keys: Vec::new(),
         enum_tuple_field_count: None,
         enum_tuple_current_field: None,
     };
 
     macro_rules! next {
-        ($runner:ident, $wip:ident, $expectation:expr, $method:ident) => {{
+        ($runner:ident, $wip:ident, $expectation:expr, $method:ident $(::<$s:ty>)?) => {{
             let nd = NextData {
                 start: $runner.last_span.end(), // or supply the appropriate start value if available
                 runner: $runner,
@@ -506,7 +614,7 @@ where
                     $runner.substack = subspans.clone().into();
                 }
             }
-            $wip = $runner.$method($wip, outcome).map_err(|error| {
+            $wip = $runner.$method$(::<$s>)?($wip, outcome).map_err(|error| {
                 DeserError {
                     input:  error.input,
                     span:   error.span.to_cooked(format, input),
@@ -582,7 +690,7 @@ where
                     ValueReason::TopLevel => Expectation::Value,
                     ValueReason::ObjectVal => Expectation::ObjectVal,
                 };
-                next!(runner, wip, expectation, value);
+                next!(runner, wip, expectation, value::<S>);
             }
             Instruction::ObjectKeyOrObjectClose => {
                 next!(
@@ -597,7 +705,7 @@ where
                     runner,
                     wip,
                     Expectation::ListItemOrListClose,
-                    list_item_or_list_close
+                    list_item_or_list_close::<S>
                 );
             }
             Instruction::SubstackClose => {
@@ -981,6 +1089,9 @@ pub struct StackRunner<'input, C = Cooked, I: ?Sized + 'input = [u8]> {
     /// Array index tracking - maps depth to current index for arrays
     pub array_indices: Vec<usize>,
 
+    /// Result tracking - whether each open `{"Ok": ..}` or `{"Err": ..}` object had its key
+    pub result_keys: Vec<bool>,
+
     /// Tuple variant field tracking - number of fields in current enum tuple variant
     pub enum_tuple_field_count: Option<usize>,
 
@@ -1293,8 +1404,7 @@ where
         value: N,
     ) -> Result<(), DeserError<'input, 'shape, C>>
     where
-        'input: 'facet,
-        N: NumericConvert,
+        N: NumericConvert + fmt::Display,
     {
         let shape = wip.innermost_shape();
 
@@ -1375,11 +1485,20 @@ where
                             }
                         }
                     }
+                    bits if bits.is_arbitrary_precision() => {
+                        // Decimals and big integers are parsed from the number's decimal form
+                        wip.parse_from_str(&value.to_string()).map_err(|_| {
+                            self.err(DeserErrorKind::NumericConversion {
+                                from: N::TYPE_NAME,
+                                to: "arbitrary-precision number",
+                            })
+                        })?;
+                    }
                     _ => {
-                        // Fixed-point, Decimal, or other numeric types not supported
+                        // Fixed-point or other numeric types not supported
                         return Err(self.err(DeserErrorKind::NumericConversion {
                             from: N::TYPE_NAME,
-                            to: "fixed-point or decimal",
+                            to: "fixed-point",
                         }));
                     }
                 }
@@ -1401,13 +1520,52 @@ where
         Ok(())
     }
 
-    fn handle_scalar<'facet>(
+    /// Sets a number given as its literal in the input (see [`Scalar::Number`]).
+    fn set_number_literal<'facet>(
+        &self,
+        wip: &mut Partial<'facet, 'shape>,
+        literal: &str,
+    ) -> Result<(), DeserError<'input, 'shape, C>> {
+        // Arbitrary-precision numbers parse the literal themselves, so they don't lose any
+        // precision to a machine number in-between
+        if let Def::Scalar(sd) = wip.innermost_shape().def {
+            if let ScalarAffinity::Number(num_affinity) = sd.affinity {
+                if num_affinity.bits.is_arbitrary_precision() {
+                    wip.parse_from_str(literal)
+                        .map_err(|e| self.reflect_err(e))?;
+                    return Ok(());
+                }
+            }
+        }
+
+        // Everything else goes through the smallest machine number that holds the literal
+        if literal.contains(['.', 'e', 'E']) {
+            match literal.parse::<f64>() {
+                Ok(value) => self.set_numeric_value(wip, value),
+                Err(_) => Err(self.err(DeserErrorKind::NumberOutOfRange(0.0))),
+            }
+        } else if let Ok(value) = literal.parse::<u64>() {
+            self.set_numeric_value(wip, value)
+        } else if let Ok(value) = literal.parse::<i64>() {
+            self.set_numeric_value(wip, value)
+        } else if let Ok(value) = literal.parse::<u128>() {
+            self.set_numeric_value(wip, value)
+        } else if let Ok(value) = literal.parse::<i128>() {
+            self.set_numeric_value(wip, value)
+        } else {
+            // An integer too large for `u128` / `i128`
+            let value = literal.parse::<f64>().unwrap_or(0.0);
+            Err(self.err(DeserErrorKind::NumberOutOfRange(value)))
+        }
+    }
+
+    fn handle_scalar<'facet, S>(
         &self,
         wip: &mut Partial<'facet, 'shape>,
         scalar: Scalar<'input>,
     ) -> Result<(), DeserError<'input, 'shape, C>>
     where
-        'input: 'facet, // 'input outlives 'facet
+        S: InputStrings<'input, 'facet, 'shape>,
     {
         match scalar {
             Scalar::String(cow) => {
@@ -1415,7 +1573,7 @@ where
                     Type::User(UserType::Enum(_)) => {
                         if wip.selected_variant().is_some() {
                             // If we already have a variant selected, just put the string
-                            wip.set(cow).map_err(|e| self.reflect_err(e))?;
+                            S::set_cow(wip, cow).map_err(|e| self.reflect_err(e))?;
                         } else {
                             // Try to select the variant
                             match wip.find_variant(&cow) {
@@ -1436,11 +1594,7 @@ where
                         if wip.innermost_shape().is_type::<&str>() =>
                     {
                         // This is for handling the &str type
-                        // The Cow may be Borrowed (we may have an owned string but need a &str)
-                        match cow {
-                            Cow::Borrowed(s) => wip.set(s).map_err(|e| self.reflect_err(e))?,
-                            Cow::Owned(s) => wip.set(s).map_err(|e| self.reflect_err(e))?,
-                        }; // Add semicolon to ignore the return value
+                        S::set_str(wip, cow).map_err(|e| self.reflect_err(e))?;
                     }
                     _ => {
                         // Check if this is a scalar type that can be parsed from a string
@@ -1505,6 +1659,9 @@ where
             Scalar::I128(value) => {
                 self.set_numeric_value(wip, value)?;
             }
+            Scalar::Number(literal) => {
+                self.set_number_literal(wip, &literal)?;
+            }
             Scalar::Bool(value) => {
                 wip.set(value).map_err(|e| self.reflect_err(e))?;
             }
@@ -1516,13 +1673,13 @@ where
     }
 
     /// Handle value parsing
-    fn value<'facet>(
+    fn value<'facet, S>(
         &mut self,
         mut wip: Partial<'facet, 'shape>,
         outcome: Spanned<Outcome<'input>, C>,
     ) -> Result<Partial<'facet, 'shape>, DeserError<'input, 'shape, C>>
     where
-        'input: 'facet, // 'input must outlive 'facet
+        S: InputStrings<'input, 'facet, 'shape>,
     {
         trace!(
             "--- STACK has {:?} {}",
@@ -1530,6 +1687,20 @@ where
             "(VALUE)".bright_yellow()
         );
 
+        // Fields with `#[facet(deserialize_with = ..)]` or `#[facet(proxy = ..)]` are
+        // deserialized from another type, then converted
+        if let Some(shape) = wip.deserialize_with_shape() {
+            trace!(
+                "  Deserializing {} from {}",
+                wip.shape().blue(),
+                shape.yellow()
+            );
+            wip.begin_deserialize_with()
+                .map_err(|e| self.reflect_err(e))?;
+            self.stack
+                .push(Instruction::Pop(PopReason::DeserializeWith));
+        }
+
         let original_shape = wip.shape();
         trace!("Handling value of type {}", original_shape.blue());
 
@@ -1574,6 +1745,20 @@ where
             }
         }
 
+        // Platform strings that aren't valid UTF-8 are serialized as their bytes
+        if matches!(outcome.node, Outcome::ListStarted)
+            && matches!(wip.shape().def, Def::Scalar(sd)
+                if matches!(sd.affinity, ScalarAffinity::PlatformString(_)))
+        {
+            trace!(
+                "  Starting the bytes of platform string {}",
+                wip.shape().blue()
+            );
+            wip.begin_try_from(<Vec<u8>>::SHAPE)
+                .map_err(|e| self.reflect_err(e))?;
+            self.stack.push(Instruction::Pop(PopReason::TryFrom));
+        }
+
         if wip.shape() != original_shape {
             trace!(
                 "Handling shape {} as innermost {}",
@@ -1585,7 +1770,7 @@ where
         match outcome.node {
             Outcome::Scalar(s) => {
                 trace!("Parsed scalar value: {}", s.cyan());
-                self.handle_scalar(&mut wip, s)?;
+                self.handle_scalar::<S>(&mut wip, s)?;
             }
             Outcome::ListStarted => {
                 let shape = wip.innermost_shape();
@@ -1619,6 +1804,9 @@ where
                         trace!("Array starting for list ({})!", shape.blue());
                         wip.set_default().map_err(|e| self.reflect_err(e))?;
                     }
+                    Def::Set(_) => {
+                        trace!("Array starting for set ({})!", shape.blue());
+                    }
                     _ => {
                         // For non-collection types, check the Type enum
                         if let Type::User(user_ty) = shape.ty {
@@ -1678,6 +1866,9 @@ where
                     Def::List(_) => {
                         wip.begin_list().map_err(|e| self.reflect_err(e))?;
                     }
+                    Def::Set(_) => {
+                        wip.begin_set().map_err(|e| self.reflect_err(e))?;
+                    }
                     Def::Array(_) => {
                         // Arrays don't need begin_list()
                         // Initialize index tracking for this array
@@ -1708,6 +1899,11 @@ where
                         trace!("Object starting for map value ({})!", shape.blue());
                         wip.begin_map().map_err(|e| self.reflect_err(e))?;
                     }
+                    Def::Result(_) => {
+                        trace!("Object starting for result value ({})!", shape.blue());
+                        // The key selects the variant
+                        self.result_keys.push(false);
+                    }
                     _ => {
                         // For non-collection types, check the Type enum
                         if let Type::User(user_ty) = shape.ty {
@@ -1767,10 +1963,7 @@ where
         &mut self,
         mut wip: Partial<'facet, 'shape>,
         outcome: Spanned<Outcome<'input>, C>,
-    ) -> Result<Partial<'facet, 'shape>, DeserError<'input, 'shape, C>>
-    where
-        'input: 'facet,
-    {
+    ) -> Result<Partial<'facet, 'shape>, DeserError<'input, 'shape, C>> {
         trace!(
             "STACK: {:?} {}",
             self.stack.green(),
@@ -1912,8 +2105,28 @@ where
                         }
                     },
                     _ => {
-                        // Check if it's a map
-                        if let Def::Map(map_def) = shape.def {
+                        // Results are externally tagged, like enums: `{"Ok": ..}` or `{"Err": ..}`
+                        if let Def::Result(_) = shape.def {
+                            if let Some(seen) = self.result_keys.last_mut() {
+                                if *seen {
+                                    return Err(self.err(DeserErrorKind::UnexpectedOutcome {
+                                        got: Outcome::Scalar(Scalar::String(key)).into_owned(),
+                                        wanted: "a single `Ok` or `Err` key",
+                                    }));
+                                }
+                                *seen = true;
+                            }
+                            match key.as_ref() {
+                                "Ok" => wip.begin_ok().map_err(|e| self.reflect_err(e))?,
+                                "Err" => wip.begin_err().map_err(|e| self.reflect_err(e))?,
+                                _ => {
+                                    return Err(self.err(DeserErrorKind::NoSuchVariant {
+                                        name: key.to_string(),
+                                        enum_shape: wip.shape(),
+                                    }));
+                                }
+                            };
+                        } else if let Def::Map(map_def) = shape.def {
                             wip.begin_key().map_err(|e| self.reflect_err(e))?;
 
                             // Check if the map key type is transparent (has an inner shape)
@@ -1967,6 +2180,14 @@ where
             }
             Outcome::ObjectEnded => {
                 trace!("Object closing");
+                if let Def::Result(_) = wip.shape().def {
+                    if self.result_keys.pop() == Some(false) {
+                        return Err(self.err(DeserErrorKind::UnexpectedOutcome {
+                            got: Outcome::ObjectEnded,
+                            wanted: "`Ok` or `Err`",
+                        }));
+                    }
+                }
                 Ok(wip)
             }
             Outcome::Resegmented(subspans) => {
@@ -1986,13 +2207,13 @@ where
         }
     }
 
-    fn list_item_or_list_close<'facet>(
+    fn list_item_or_list_close<'facet, S>(
         &mut self,
         mut wip: Partial<'facet, 'shape>,
         outcome: Spanned<Outcome<'input>, C>,
     ) -> Result<Partial<'facet, 'shape>, DeserError<'input, 'shape, C>>
     where
-        'input: 'facet,
+        S: InputStrings<'input, 'facet, 'shape>,
     {
         trace!(
             "--- STACK has {:?} {}",
@@ -2070,6 +2291,9 @@ where
                     Def::List(_) => {
                         wip.begin_list_item().map_err(|e| self.reflect_err(e))?;
                     }
+                    Def::Set(_) => {
+                        wip.begin_set_item().map_err(|e| self.reflect_err(e))?;
+                    }
                     _ => {
                         // Check if this is an enum tuple variant
                         if let Type::User(UserType::Enum(_)) = shape.ty {
@@ -2153,7 +2377,7 @@ where
                     }
                 }
 
-                wip = self.value(wip, outcome)?;
+                wip = self.value::<S>(wip, outcome)?;
                 Ok(wip)
             }
         }
@@ -74,10 +74,6 @@ impl Format for crate::Json {
                     node: Outcome::Scalar(Scalar::String(s)),
                     span,
                 }),
-                Token::F64(n) => Ok(Spanned {
-                    node: Outcome::Scalar(Scalar::F64(n)),
-                    span,
-                }),
                 Token::I64(n) => Ok(Spanned {
                     node: Outcome::Scalar(Scalar::I64(n)),
                     span,
@@ -94,6 +90,10 @@ impl Format for crate::Json {
                     node: Outcome::Scalar(Scalar::I128(n)),
                     span,
                 }),
+                Token::Number(literal) => Ok(Spanned {
+                    node: Outcome::Scalar(Scalar::Number(literal.into())),
+                    span,
+                }),
                 Token::True => Ok(Spanned {
                     node: Outcome::Scalar(Scalar::Bool(true)),
                     span,
@@ -243,9 +243,9 @@ impl Format for crate::Json {
                     (nd, Ok(last_span))
                 }
                 Token::String(_)
-                | Token::F64(_)
                 | Token::I64(_)
                 | Token::U64(_)
+                | Token::Number(_)
                 | Token::True
                 | Token::False
                 | Token::Null => (nd, Ok(token.span)),
@@ -272,7 +272,7 @@ impl Format for crate::Json {
     }
 }
 
-fn convert_token_error(err: TokenError) -> Spanned<DeserErrorKind<'static>> {
+pub(crate) fn convert_token_error(err: TokenError) -> Spanned<DeserErrorKind<'static>> {
     match err.kind {
         TokenErrorKind::UnexpectedCharacter(c) => DeserErrorKind::UnexpectedChar {
             got: c,
@@ -283,8 +283,8 @@ fn convert_token_error(err: TokenError) -> Spanned<DeserErrorKind<'static>> {
             DeserErrorKind::UnexpectedEof { wanted: why }.with_span(err.span)
         }
         TokenErrorKind::InvalidUtf8(s) => DeserErrorKind::InvalidUtf8(s).with_span(err.span),
-        TokenErrorKind::NumberOutOfRange(number) => {
-            DeserErrorKind::NumberOutOfRange(number).with_span(err.span)
+        TokenErrorKind::InvalidNumber(literal) => {
+            DeserErrorKind::InvalidNumber(literal).with_span(err.span)
         }
     }
 }
@@ -0,0 +1,272 @@
+//! Dispatches JSON-RPC-style requests to functions annotated with `#[facet_fn(dynamic)]`.
+//!
+//! A request names a method and its parameters, either positionally or by name:
+//!
+//! ```json
+//! { "method": "add", "params": [1, 2] }
+//! { "method": "add", "params": { "x": 1, "y": 2 } }
+//! ```
+//!
+//! Each parameter is deserialized straight into its own type, the function is called with the
+//! resulting tuple of arguments, and its return value is serialized back to JSON. Parameters
+//! can't borrow from the request, so strings are copied out of it: functions taking `&str` are
+//! rejected when called, and should take `String` or `Cow<str>` instead.
+
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use facet_core::{Def, FunctionShape, Shape};
+use facet_reflect::{HeapValue, Partial, ReflectError, call_function};
+
+use crate::node::{Node, NodeKind, parse_document};
+
+/// A set of functions that can be called by name, with JSON parameters
+#[derive(Debug, Default)]
+pub struct FunctionRegistry {
+    functions: BTreeMap<&'static str, FunctionShape>,
+}
+
+impl FunctionRegistry {
+    /// Creates an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a function under its name, replacing any function with the same name
+    pub fn register(&mut self, function: FunctionShape) -> &mut Self {
+        self.functions.insert(function.name, function);
+        self
+    }
+
+    /// Returns the function registered under `name`, if any
+    pub fn get(&self, name: &str) -> Option<&FunctionShape> {
+        self.functions.get(name)
+    }
+
+    /// Returns all registered functions, sorted by name
+    pub fn functions(&self) -> impl Iterator<Item = &FunctionShape> {
+        self.functions.values()
+    }
+
+    /// Handles a request of the form `{"method": "...", "params": ...}`, and returns the
+    /// function's return value as JSON.
+    ///
+    /// `params` may be an array (positional parameters), an object (named parameters), or
+    /// omitted for functions without parameters. Other members of the request, like `"id"` or
+    /// `"jsonrpc"`, are ignored.
+    pub fn dispatch(&self, request: &str) -> Result<String, DispatchError> {
+        let input = request.as_bytes();
+        let document = parse_document(input).map_err(|msg| DispatchError {
+            method: None,
+            param: None,
+            kind: DispatchErrorKind::InvalidRequest(msg),
+        })?;
+        let NodeKind::Object(members) = &document.kind else {
+            return Err(DispatchError {
+                method: None,
+                param: None,
+                kind: DispatchErrorKind::InvalidRequest("expected an object".to_string()),
+            });
+        };
+
+        let mut method = None;
+        let mut params = None;
+        for (key, value) in members {
+            match (&**key, &value.kind) {
+                ("method", NodeKind::String(name)) => method = Some(name),
+                ("method", _) => {
+                    return Err(DispatchError {
+                        method: None,
+                        param: None,
+                        kind: DispatchErrorKind::InvalidRequest(
+                            "`method` must be a string".to_string(),
+                        ),
+                    });
+                }
+                ("params", _) => params = Some(value),
+                _ => {}
+            }
+        }
+        let Some(method) = method else {
+            return Err(DispatchError {
+                method: None,
+                param: None,
+                kind: DispatchErrorKind::InvalidRequest("missing `method`".to_string()),
+            });
+        };
+
+        self.call_node(method, params, input)
+    }
+
+    /// Calls the function registered under `method`, with `params` given as a JSON array
+    /// (positional parameters) or object (named parameters), and returns its return value as JSON.
+    pub fn call(&self, method: &str, params: &str) -> Result<String, DispatchError> {
+        let input = params.as_bytes();
+        let params = parse_document(input).map_err(|msg| DispatchError {
+            method: Some(method.to_string()),
+            param: None,
+            kind: DispatchErrorKind::InvalidRequest(msg),
+        })?;
+        self.call_node(method, Some(&params), input)
+    }
+
+    fn call_node(
+        &self,
+        method: &str,
+        params: Option<&Node<'_>>,
+        input: &[u8],
+    ) -> Result<String, DispatchError> {
+        let error = |param: Option<&str>, kind| DispatchError {
+            method: Some(method.to_string()),
+            param: param.map(ToString::to_string),
+            kind,
+        };
+
+        let function = self
+            .get(method)
+            .ok_or_else(|| error(None, DispatchErrorKind::MethodNotFound))?;
+
+        // Find the JSON value of each parameter, in declaration order
+        let mut values: Vec<Option<&Node<'_>>> = Vec::new();
+        match params.map(|params| &params.kind) {
+            None | Some(NodeKind::Null) => values.resize(function.param_count, None),
+            Some(NodeKind::Array(items)) => {
+                if items.len() > function.param_count {
+                    return Err(error(
+                        None,
+                        DispatchErrorKind::WrongParamCount {
+                            expected: function.param_count,
+                            got: items.len(),
+                        },
+                    ));
+                }
+                values.extend(items.iter().map(Some));
+                values.resize(function.param_count, None);
+            }
+            Some(NodeKind::Object(members)) => {
+                values.resize(function.param_count, None);
+                for (name, value) in members {
+                    let index = function
+                        .param_names
+                        .iter()
+                        .position(|param| *param == &**name)
+                        .ok_or_else(|| error(Some(name), DispatchErrorKind::UnknownParam))?;
+                    values[index] = Some(value);
+                }
+            }
+            Some(_) => {
+                return Err(error(
+                    None,
+                    DispatchErrorKind::InvalidRequest(
+                        "`params` must be an array or an object".to_string(),
+                    ),
+                ));
+            }
+        }
+
+        let reflect_error =
+            |param, e: ReflectError<'_>| error(param, DispatchErrorKind::Call(e.to_plain_string()));
+        let mut args =
+            Partial::alloc_shape(function.args_shape).map_err(|e| reflect_error(None, e))?;
+        for (index, value) in values.into_iter().enumerate() {
+            let name = function.param_names[index];
+            let shape = function.param_shapes[index];
+            args.begin_nth_field(index)
+                .map_err(|e| reflect_error(Some(name), e))?;
+            match value {
+                Some(value) => {
+                    let value = deserialize_param(shape, &input[value.start..value.end])
+                        .map_err(|msg| error(Some(name), DispatchErrorKind::Deserialize(msg)))?;
+                    args.set_from_heap_value(value)
+                }
+                // Optional parameters may be left out
+                None if matches!(shape.def, Def::Option(_)) => args.set_default(),
+                None => return Err(error(Some(name), DispatchErrorKind::MissingParam)),
+            }
+            .and_then(|args| args.end())
+            .map_err(|e| reflect_error(Some(name), e))?;
+        }
+        let args = args.build().map_err(|e| reflect_error(None, e))?;
+
+        let ret = call_function(function, args).map_err(|e| reflect_error(None, e))?;
+        Ok(crate::peek_to_string(ret.peek()))
+    }
+}
+
+fn deserialize_param(
+    shape: &'static Shape<'static>,
+    json: &[u8],
+) -> Result<HeapValue<'static, 'static>, String> {
+    let partial = Partial::alloc_shape(shape).map_err(|e| e.to_plain_string())?;
+    // The function may keep its arguments around: they must not point into the request
+    facet_deserialize::deserialize_wip_owned(partial, json, &mut crate::Json)
+        .map_err(|e| e.message().to_string())
+}
+
+/// An error that occurred while dispatching a request
+#[derive(Debug, Clone, PartialEq)]
+pub struct DispatchError {
+    /// The method that was called, if the request got that far
+    pub method: Option<String>,
+    /// The offending parameter, if any
+    pub param: Option<String>,
+    /// What went wrong
+    pub kind: DispatchErrorKind,
+}
+
+/// The kind of error that occurred while dispatching a request
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum DispatchErrorKind {
+    /// The request isn't valid JSON, or isn't shaped like a request
+    InvalidRequest(String),
+    /// No function is registered under that name
+    MethodNotFound,
+    /// A required parameter wasn't given
+    MissingParam,
+    /// A named parameter doesn't exist on the function
+    UnknownParam,
+    /// More positional parameters were given than the function takes
+    WrongParamCount {
+        /// The number of parameters the function takes
+        expected: usize,
+        /// The number of parameters that were given
+        got: usize,
+    },
+    /// A parameter couldn't be deserialized into its type
+    Deserialize(String),
+    /// The arguments couldn't be assembled, or the function couldn't be called
+    Call(String),
+}
+
+impl fmt::Display for DispatchErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DispatchErrorKind::InvalidRequest(msg) => write!(f, "invalid request: {msg}"),
+            DispatchErrorKind::MethodNotFound => write!(f, "method not found"),
+            DispatchErrorKind::MissingParam => write!(f, "missing parameter"),
+            DispatchErrorKind::UnknownParam => write!(f, "unknown parameter"),
+            DispatchErrorKind::WrongParamCount { expected, got } => {
+                write!(f, "expected at most {expected} parameters, got {got}")
+            }
+            DispatchErrorKind::Deserialize(msg) => write!(f, "invalid value: {msg}"),
+            DispatchErrorKind::Call(msg) => write!(f, "call failed: {msg}"),
+        }
+    }
+}
+
+impl fmt::Display for DispatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(method) = &self.method {
+            write!(f, "{method}: ")?;
+        }
+        if let Some(param) = &self.param {
+            write!(f, "parameter `{param}`: ")?;
+        }
+        write!(f, "{}", self.kind)
+    }
+}
+
+impl core::error::Error for DispatchError {}
@@ -16,6 +16,17 @@ pub use deserialize::*;
 mod serialize;
 pub use serialize::*;
 
+mod patch;
+pub use patch::*;
+
+mod dispatch;
+pub use dispatch::*;
+
+mod tagged;
+pub use tagged::*;
+
+mod node;
+
 mod tokenizer;
 
 /// The JSON format
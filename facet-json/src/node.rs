@@ -0,0 +1,131 @@
+//! A lightweight JSON tree, for documents whose shape isn't known up front (patches, RPC
+//! requests). Only the structure is kept: scalar values are remembered by their span, so they can
+//! be deserialized into the right type later on.
+
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use facet_deserialize::Spanned;
+
+use crate::tokenizer::{Token, Tokenizer};
+
+/// How deeply arrays and objects may nest, so that parsing (and walking the tree afterwards)
+/// can't overflow the stack
+const MAX_DEPTH: usize = 128;
+
+/// A parsed JSON value, remembering where it is in the input
+pub(crate) struct Node<'input> {
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+    pub(crate) kind: NodeKind<'input>,
+}
+
+pub(crate) enum NodeKind<'input> {
+    Null,
+    String(Cow<'input, str>),
+    Object(Vec<(Cow<'input, str>, Node<'input>)>),
+    Array(Vec<Node<'input>>),
+    Other,
+}
+
+pub(crate) fn parse_document(input: &[u8]) -> Result<Node<'_>, String> {
+    let mut tokenizer = Tokenizer::new(input);
+    let node = parse_node(&mut tokenizer, None, 0)?;
+    let token = next_token(&mut tokenizer)?;
+    match token.node {
+        Token::Eof => Ok(node),
+        token => Err(format!("unexpected {token} after the document")),
+    }
+}
+
+fn next_token<'input>(tokenizer: &mut Tokenizer<'input>) -> Result<Spanned<Token<'input>>, String> {
+    tokenizer
+        .next_token()
+        .map_err(|e| format!("{} at byte {}", e.kind, e.span.start()))
+}
+
+fn parse_node<'input>(
+    tokenizer: &mut Tokenizer<'input>,
+    first: Option<Spanned<Token<'input>>>,
+    depth: usize,
+) -> Result<Node<'input>, String> {
+    let token = match first {
+        Some(token) => token,
+        None => next_token(tokenizer)?,
+    };
+    if matches!(token.node, Token::LBrace | Token::LBracket) && depth == MAX_DEPTH {
+        return Err(format!(
+            "nesting deeper than {MAX_DEPTH} levels at byte {}",
+            token.span.start()
+        ));
+    }
+    let start = token.span.start();
+    let mut end = token.span.end();
+    let kind = match token.node {
+        Token::Null => NodeKind::Null,
+        Token::String(s) => NodeKind::String(s),
+        Token::True
+        | Token::False
+        | Token::I64(_)
+        | Token::U64(_)
+        | Token::U128(_)
+        | Token::I128(_)
+        | Token::Number(_) => NodeKind::Other,
+        Token::LBrace => {
+            let mut members = Vec::new();
+            loop {
+                let token = next_token(tokenizer)?;
+                let key = match token.node {
+                    Token::RBrace if members.is_empty() => {
+                        end = token.span.end();
+                        break;
+                    }
+                    Token::String(key) => key,
+                    token => return Err(format!("expected an object key, got {token}")),
+                };
+                match next_token(tokenizer)?.node {
+                    Token::Colon => {}
+                    token => return Err(format!("expected `:`, got {token}")),
+                }
+                members.push((key, parse_node(tokenizer, None, depth + 1)?));
+                let token = next_token(tokenizer)?;
+                match token.node {
+                    Token::Comma => {}
+                    Token::RBrace => {
+                        end = token.span.end();
+                        break;
+                    }
+                    token => return Err(format!("expected `,` or `}}`, got {token}")),
+                }
+            }
+            NodeKind::Object(members)
+        }
+        Token::LBracket => {
+            let mut items = Vec::new();
+            loop {
+                let token = next_token(tokenizer)?;
+                if let Token::RBracket = token.node {
+                    if items.is_empty() {
+                        end = token.span.end();
+                        break;
+                    }
+                }
+                items.push(parse_node(tokenizer, Some(token), depth + 1)?);
+                let token = next_token(tokenizer)?;
+                match token.node {
+                    Token::Comma => {}
+                    Token::RBracket => {
+                        end = token.span.end();
+                        break;
+                    }
+                    token => return Err(format!("expected `,` or `]`, got {token}")),
+                }
+            }
+            NodeKind::Array(items)
+        }
+        token => return Err(format!("unexpected {token}")),
+    };
+    Ok(Node { start, end, kind })
+}
@@ -0,0 +1,666 @@
+//! Applies JSON Patch ([RFC 6902]) and JSON Merge Patch ([RFC 7396]) documents to typed values.
+//!
+//! Paths are resolved against the value itself, the way it would be serialized to JSON: through
+//! field names (after renames), list and array indices, map keys, the active variant of an enum,
+//! and the contents of `Some` options. Values found in the patch are deserialized straight into
+//! the type that lives at their path.
+//!
+//! Patches are **not** applied atomically: when an operation fails, the changes made before it
+//! are kept. Patch a clone of the value and swap it in on success if you need all-or-nothing
+//! semantics, as RFC 6902 prescribes.
+//!
+//! [RFC 6902]: https://www.rfc-editor.org/rfc/rfc6902
+//! [RFC 7396]: https://www.rfc-editor.org/rfc/rfc7396
+
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use facet_core::{Def, Facet, FieldError, Shape, StructKind, Type, UserType};
+use facet_reflect::{Diff, HeapValue, Partial, Poke, ReflectError};
+
+use crate::node::{Node, NodeKind, parse_document};
+
+/// An error that occurred while applying a patch
+#[derive(Debug, Clone, PartialEq)]
+pub struct PatchError {
+    /// Index of the failing operation in a JSON Patch document (`None` for merge patches)
+    pub op: Option<usize>,
+    /// JSON pointer to the location at which the patch failed
+    pub path: String,
+    /// What went wrong
+    pub kind: PatchErrorKind,
+}
+
+/// The different ways applying a patch can fail
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum PatchErrorKind {
+    /// The patch document is malformed
+    InvalidPatch(String),
+    /// There is no value at the path
+    NotFound,
+    /// A `test` operation found a different value at the path
+    TestFailed,
+    /// A value from the patch could not be deserialized into the type found at the path
+    Deserialize(String),
+    /// The operation can't be applied to the value at the path, e.g. removing a required field
+    Unsupported(String),
+}
+
+impl fmt::Display for PatchErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatchErrorKind::InvalidPatch(msg) => write!(f, "invalid patch: {msg}"),
+            PatchErrorKind::NotFound => write!(f, "no value at path"),
+            PatchErrorKind::TestFailed => write!(f, "test failed"),
+            PatchErrorKind::Deserialize(msg) => write!(f, "invalid value: {msg}"),
+            PatchErrorKind::Unsupported(msg) => write!(f, "unsupported operation: {msg}"),
+        }
+    }
+}
+
+impl fmt::Display for PatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(op) = self.op {
+            write!(f, "operation #{op} ")?;
+        }
+        write!(f, "at \"{}\": {}", self.path, self.kind)
+    }
+}
+
+impl core::error::Error for PatchError {}
+
+/// Applies a JSON Patch (RFC 6902) document to a value.
+///
+/// All six operations are supported: `add`, `remove`, `replace`, `move`, `copy` and `test`.
+/// Removing a struct field is only possible for `Option` fields, which are set to `None`, and
+/// `copy` requires the copied value to implement `Clone`.
+///
+/// Operations are applied in order. If one fails, the error reports its index and path.
+///
+/// # Atomicity
+///
+/// Unlike what RFC 6902 prescribes, a failing patch is not rolled back: the operations before
+/// the failing one stay applied, leaving `value` partially patched. Patch a clone of the value
+/// and only keep it on success if you need all-or-nothing semantics.
+pub fn apply_patch<'input, 'facet, T: Facet<'facet>>(
+    value: &mut T,
+    patch: &'input str,
+) -> Result<(), PatchError>
+where
+    'input: 'facet,
+{
+    let input = patch.as_bytes();
+    let invalid = |op: Option<usize>, msg: String| PatchError {
+        op,
+        path: String::new(),
+        kind: PatchErrorKind::InvalidPatch(msg),
+    };
+
+    let document = parse_document(input).map_err(|msg| invalid(None, msg))?;
+    let NodeKind::Array(items) = document.kind else {
+        return Err(invalid(None, "expected an array of operations".to_string()));
+    };
+    let operations = items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| Operation::parse(item, input).map_err(|msg| invalid(Some(index), msg)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut root = Poke::new(value);
+    for (index, operation) in operations.iter().enumerate() {
+        operation
+            .apply(root.reborrow())
+            .map_err(|(path, kind)| PatchError {
+                op: Some(index),
+                path: path.to_string(),
+                kind,
+            })?;
+    }
+    Ok(())
+}
+
+/// Applies a JSON Merge Patch (RFC 7396) document to a value.
+///
+/// Objects in the patch are merged into structs and maps, member by member: `null` removes a map
+/// entry or sets an `Option` field to `None`. Any other value replaces the value at its location.
+///
+/// # Atomicity
+///
+/// A failing merge patch is not rolled back: the members merged before the failing one stay
+/// applied, leaving `value` partially patched. Patch a clone of the value and only keep it on
+/// success if you need all-or-nothing semantics.
+pub fn apply_merge_patch<'input, 'facet, T: Facet<'facet>>(
+    value: &mut T,
+    patch: &'input str,
+) -> Result<(), PatchError>
+where
+    'input: 'facet,
+{
+    let input = patch.as_bytes();
+    let document = parse_document(input).map_err(|msg| PatchError {
+        op: None,
+        path: String::new(),
+        kind: PatchErrorKind::InvalidPatch(msg),
+    })?;
+
+    let mut path = Vec::new();
+    merge(Poke::new(value), &document, input, &mut path).map_err(|kind| PatchError {
+        op: None,
+        path: Pointer(path.iter().map(|token| token.to_string()).collect()).to_string(),
+        kind,
+    })
+}
+
+/// A parsed JSON Patch operation
+struct Operation<'input> {
+    kind: OperationKind<'input>,
+    path: Pointer,
+}
+
+enum OperationKind<'input> {
+    Add(&'input [u8]),
+    Remove,
+    Replace(&'input [u8]),
+    Move(Pointer),
+    Copy(Pointer),
+    Test(&'input [u8]),
+}
+
+impl<'input> Operation<'input> {
+    fn parse(node: &Node<'input>, input: &'input [u8]) -> Result<Self, String> {
+        let NodeKind::Object(members) = &node.kind else {
+            return Err("expected an operation object".to_string());
+        };
+        let member = |name: &str| {
+            members
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value)
+        };
+        let string = |name: &str| match member(name).map(|node| &node.kind) {
+            Some(NodeKind::String(s)) => Ok(s.clone()),
+            Some(_) => Err(format!("`{name}` must be a string")),
+            None => Err(format!("missing `{name}`")),
+        };
+        let value = || {
+            member("value")
+                .map(|node| &input[node.start..node.end])
+                .ok_or_else(|| "missing `value`".to_string())
+        };
+        let from = || string("from").and_then(|from| Pointer::parse(&from));
+
+        let kind = match string("op")?.as_ref() {
+            "add" => OperationKind::Add(value()?),
+            "remove" => OperationKind::Remove,
+            "replace" => OperationKind::Replace(value()?),
+            "move" => OperationKind::Move(from()?),
+            "copy" => OperationKind::Copy(from()?),
+            "test" => OperationKind::Test(value()?),
+            op => return Err(format!("unknown operation `{op}`")),
+        };
+        let path = Pointer::parse(&string("path")?)?;
+        Ok(Operation { kind, path })
+    }
+
+    fn apply<'facet, 'shape>(
+        &self,
+        mut root: Poke<'_, 'facet, 'shape>,
+    ) -> Result<(), (&Pointer, PatchErrorKind)>
+    where
+        'input: 'facet,
+        'shape: 'input,
+    {
+        let at = |path| move |kind| (path, kind);
+        let path = &self.path;
+        match &self.kind {
+            OperationKind::Add(value) => add(root, path, Source::Json(value)).map_err(at(path)),
+            OperationKind::Remove => remove(root, path).map(drop).map_err(at(path)),
+            OperationKind::Replace(value) => put(
+                resolve_slot(root, path).map_err(at(path))?,
+                Source::Json(value),
+            )
+            .map_err(at(path)),
+            OperationKind::Test(value) => {
+                let slot = resolve_slot(root, path).map_err(at(path))?;
+                let expected = deserialize_shape(slot.shape(), value).map_err(at(path))?;
+                if Diff::new(slot.as_peek(), expected.peek()).is_empty() {
+                    Ok(())
+                } else {
+                    Err((path, PatchErrorKind::TestFailed))
+                }
+            }
+            OperationKind::Copy(from) => {
+                let source = resolve_slot(root.reborrow(), from).map_err(at(from))?;
+                let value = HeapValue::clone_from_peek(source.as_peek())
+                    .map_err(|e| (from, reflect_error(e)))?;
+                add(root, path, Source::Value(value)).map_err(at(path))
+            }
+            OperationKind::Move(from) => {
+                if from.0 == path.0 {
+                    return Ok(());
+                }
+                if path.0.starts_with(&from.0) {
+                    return Err((
+                        path,
+                        PatchErrorKind::Unsupported(
+                            "cannot move a value into one of its children".to_string(),
+                        ),
+                    ));
+                }
+                let value = remove(root.reborrow(), from).map_err(at(from))?;
+                add(root, path, Source::Value(value)).map_err(at(path))
+            }
+        }
+    }
+}
+
+/// A JSON pointer (RFC 6901), split into unescaped reference tokens
+struct Pointer(Vec<String>);
+
+impl Pointer {
+    fn parse(pointer: &str) -> Result<Self, String> {
+        if pointer.is_empty() {
+            return Ok(Pointer(Vec::new()));
+        }
+        let Some(tokens) = pointer.strip_prefix('/') else {
+            return Err(format!("JSON pointer `{pointer}` must start with `/`"));
+        };
+        let tokens = tokens
+            .split('/')
+            .map(|token| token.replace("~1", "/").replace("~0", "~"))
+            .collect();
+        Ok(Pointer(tokens))
+    }
+}
+
+impl fmt::Display for Pointer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for token in &self.0 {
+            write!(f, "/{}", token.replace('~', "~0").replace('/', "~1"))?;
+        }
+        Ok(())
+    }
+}
+
+/// Where a pointer leads: to a value, or into the fields of an enum's active variant
+enum Cursor<'mem, 'facet, 'shape> {
+    Value(Poke<'mem, 'facet, 'shape>),
+    Variant(Poke<'mem, 'facet, 'shape>),
+}
+
+/// A value to write somewhere: either JSON from the patch, or a value taken from elsewhere
+enum Source<'input, 'facet, 'shape> {
+    Json(&'input [u8]),
+    Value(HeapValue<'facet, 'shape>),
+}
+
+impl<'input, 'facet, 'shape> Source<'input, 'facet, 'shape>
+where
+    'input: 'facet,
+    'shape: 'input,
+{
+    fn into_shape(
+        self,
+        shape: &'shape Shape<'shape>,
+    ) -> Result<HeapValue<'facet, 'shape>, PatchErrorKind> {
+        match self {
+            Source::Json(json) => deserialize_shape(shape, json),
+            Source::Value(value) if value.shape() == shape => Ok(value),
+            Source::Value(value) => Err(PatchErrorKind::Unsupported(format!(
+                "cannot put a {} where a {} is expected",
+                value.shape(),
+                shape
+            ))),
+        }
+    }
+}
+
+/// Walks down a pointer, one reference token at a time
+fn resolve<'mem, 'facet, 'shape>(
+    root: Poke<'mem, 'facet, 'shape>,
+    tokens: &[String],
+) -> Result<Cursor<'mem, 'facet, 'shape>, PatchErrorKind> {
+    tokens
+        .iter()
+        .try_fold(Cursor::Value(root), |cursor, token| step(cursor, token))
+}
+
+/// Walks down a pointer that must lead to a value
+fn resolve_slot<'mem, 'facet, 'shape>(
+    root: Poke<'mem, 'facet, 'shape>,
+    path: &Pointer,
+) -> Result<Poke<'mem, 'facet, 'shape>, PatchErrorKind> {
+    match resolve(root, &path.0)? {
+        Cursor::Value(slot) => Ok(slot),
+        Cursor::Variant(_) => Err(PatchErrorKind::Unsupported(
+            "the path leads to an enum variant, not to a value".to_string(),
+        )),
+    }
+}
+
+fn step<'mem, 'facet, 'shape>(
+    cursor: Cursor<'mem, 'facet, 'shape>,
+    token: &str,
+) -> Result<Cursor<'mem, 'facet, 'shape>, PatchErrorKind> {
+    let poke = match cursor {
+        Cursor::Variant(poke) => {
+            return poke
+                .into_field(token)
+                .map(Cursor::Value)
+                .map_err(reflect_error);
+        }
+        Cursor::Value(poke) => unwrap_options(poke)?,
+    };
+
+    match poke.shape().def {
+        Def::List(_) | Def::Array(_) => poke
+            .into_index(parse_index(token)?)
+            .map(Cursor::Value)
+            .map_err(reflect_error),
+        Def::Map(def) => {
+            let key = map_key(def.k(), token)?;
+            poke.into_map_value(key.peek())
+                .map_err(reflect_error)?
+                .map(Cursor::Value)
+                .ok_or(PatchErrorKind::NotFound)
+        }
+        _ => match poke.shape().ty {
+            Type::User(UserType::Struct(_)) => poke
+                .into_field(token)
+                .map(Cursor::Value)
+                .map_err(reflect_error),
+            Type::User(UserType::Enum(_)) => {
+                let variant = poke
+                    .as_peek()
+                    .into_enum()
+                    .map_err(reflect_error)?
+                    .active_variant()
+                    .map_err(|_| {
+                        PatchErrorKind::Unsupported("cannot read the active variant".to_string())
+                    })?;
+                if variant.name != token {
+                    return Err(PatchErrorKind::NotFound);
+                }
+                // Enums are externally tagged: `{"Variant": ...}`
+                match variant.data.kind {
+                    StructKind::Unit => Err(PatchErrorKind::NotFound),
+                    StructKind::TupleStruct | StructKind::Tuple
+                        if variant.data.fields.len() == 1 =>
+                    {
+                        poke.into_field(variant.data.fields[0].name)
+                            .map(Cursor::Value)
+                            .map_err(reflect_error)
+                    }
+                    _ => Ok(Cursor::Variant(poke)),
+                }
+            }
+            _ => Err(PatchErrorKind::NotFound),
+        },
+    }
+}
+
+/// Options are transparent in JSON: walk through `Some`, and treat `None` as absent
+fn unwrap_options<'mem, 'facet, 'shape>(
+    mut poke: Poke<'mem, 'facet, 'shape>,
+) -> Result<Poke<'mem, 'facet, 'shape>, PatchErrorKind> {
+    while let Def::Option(_) = poke.shape().def {
+        poke = poke
+            .into_some()
+            .map_err(reflect_error)?
+            .ok_or(PatchErrorKind::NotFound)?;
+    }
+    Ok(poke)
+}
+
+fn add<'input, 'facet, 'shape>(
+    root: Poke<'_, 'facet, 'shape>,
+    path: &Pointer,
+    value: Source<'input, 'facet, 'shape>,
+) -> Result<(), PatchErrorKind>
+where
+    'input: 'facet,
+    'shape: 'input,
+{
+    let Some((last, parent)) = path.0.split_last() else {
+        return put(root, value);
+    };
+    let mut poke = match resolve(root, parent)? {
+        Cursor::Value(poke) => unwrap_options(poke)?,
+        variant => {
+            return match step(variant, last)? {
+                Cursor::Value(slot) => put(slot, value),
+                Cursor::Variant(_) => unreachable!(),
+            };
+        }
+    };
+
+    match poke.shape().def {
+        Def::List(def) => {
+            let len = list_len(&poke)?;
+            let index = match last.as_ref() {
+                "-" => len,
+                token => parse_index(token)?,
+            };
+            if index > len {
+                return Err(PatchErrorKind::NotFound);
+            }
+            let value = value.into_shape(def.t())?;
+            poke.list_insert(index, value).map_err(reflect_error)
+        }
+        Def::Map(def) => {
+            let key = map_key(def.k(), last)?;
+            let value = value.into_shape(def.v())?;
+            poke.map_insert(key, value).map_err(reflect_error)
+        }
+        _ => match step(Cursor::Value(poke), last)? {
+            Cursor::Value(slot) => put(slot, value),
+            Cursor::Variant(_) => Err(PatchErrorKind::Unsupported(
+                "cannot add an enum variant".to_string(),
+            )),
+        },
+    }
+}
+
+fn remove<'facet, 'shape>(
+    root: Poke<'_, 'facet, 'shape>,
+    path: &Pointer,
+) -> Result<HeapValue<'facet, 'shape>, PatchErrorKind> {
+    let Some((last, parent)) = path.0.split_last() else {
+        return Err(PatchErrorKind::Unsupported(
+            "cannot remove the root value".to_string(),
+        ));
+    };
+    let mut poke = match resolve(root, parent)? {
+        Cursor::Value(poke) => unwrap_options(poke)?,
+        variant => {
+            return match step(variant, last)? {
+                Cursor::Value(slot) => take_field(slot),
+                Cursor::Variant(_) => unreachable!(),
+            };
+        }
+    };
+
+    match poke.shape().def {
+        Def::List(_) => poke.list_remove(parse_index(last)?).map_err(reflect_error),
+        Def::Map(def) => {
+            let key = map_key(def.k(), last)?;
+            poke.map_remove(key.peek())
+                .map_err(reflect_error)?
+                .ok_or(PatchErrorKind::NotFound)
+        }
+        Def::Array(_) => Err(PatchErrorKind::Unsupported(
+            "cannot remove an element from a fixed-size array".to_string(),
+        )),
+        _ => match step(Cursor::Value(poke), last)? {
+            Cursor::Value(slot) => take_field(slot),
+            Cursor::Variant(_) => Err(PatchErrorKind::Unsupported(
+                "cannot remove an enum variant".to_string(),
+            )),
+        },
+    }
+}
+
+/// Removes a field's value: only possible for optional fields, which are left as `None`
+fn take_field<'facet, 'shape>(
+    mut slot: Poke<'_, 'facet, 'shape>,
+) -> Result<HeapValue<'facet, 'shape>, PatchErrorKind> {
+    match slot.shape().def {
+        Def::Option(_) => slot
+            .option_take()
+            .map_err(reflect_error)?
+            .ok_or(PatchErrorKind::NotFound),
+        _ => Err(PatchErrorKind::Unsupported(format!(
+            "cannot remove a required {} value",
+            slot.shape()
+        ))),
+    }
+}
+
+/// Writes a value over an existing one
+fn put<'input, 'facet, 'shape>(
+    mut slot: Poke<'_, 'facet, 'shape>,
+    value: Source<'input, 'facet, 'shape>,
+) -> Result<(), PatchErrorKind>
+where
+    'input: 'facet,
+    'shape: 'input,
+{
+    // A value taken out of an option can be put back into another one
+    if let (Source::Value(inner), Def::Option(def)) = (&value, slot.shape().def) {
+        if inner.shape() == def.t() {
+            let Source::Value(inner) = value else {
+                unreachable!()
+            };
+            return slot.option_replace(Some(inner)).map_err(reflect_error);
+        }
+    }
+    let value = value.into_shape(slot.shape())?;
+    slot.set(value).map_err(reflect_error)
+}
+
+fn merge<'input, 'facet, 'shape>(
+    mut poke: Poke<'_, 'facet, 'shape>,
+    patch: &Node<'input>,
+    input: &'input [u8],
+    path: &mut Vec<Cow<'input, str>>,
+) -> Result<(), PatchErrorKind>
+where
+    'input: 'facet,
+    'shape: 'input,
+{
+    let raw = &input[patch.start..patch.end];
+    let NodeKind::Object(members) = &patch.kind else {
+        return put(poke, Source::Json(raw));
+    };
+    if let Def::Option(_) = poke.shape().def {
+        return match poke.reborrow().into_some().map_err(reflect_error)? {
+            Some(inner) => merge(inner, patch, input, path),
+            None => put(poke, Source::Json(raw)),
+        };
+    }
+
+    match (poke.shape().def, poke.shape().ty) {
+        (Def::Map(def), _) => {
+            for (key, value) in members {
+                path.push(key.clone());
+                let key = map_key(def.k(), key)?;
+                if let NodeKind::Null = value.kind {
+                    poke.map_remove(key.peek()).map_err(reflect_error)?;
+                } else if let Some(slot) = poke
+                    .reborrow()
+                    .into_map_value(key.peek())
+                    .map_err(reflect_error)?
+                {
+                    merge(slot, value, input, path)?;
+                } else {
+                    let value = deserialize_shape(def.v(), &input[value.start..value.end])?;
+                    poke.map_insert(key, value).map_err(reflect_error)?;
+                }
+                path.pop();
+            }
+            Ok(())
+        }
+        (_, Type::User(UserType::Struct(_))) => {
+            for (key, value) in members {
+                path.push(key.clone());
+                let mut slot = poke.reborrow().into_field(key).map_err(reflect_error)?;
+                if let NodeKind::Null = value.kind {
+                    match slot.shape().def {
+                        Def::Option(_) => slot.option_replace(None).map_err(reflect_error)?,
+                        _ => {
+                            return Err(PatchErrorKind::Unsupported(format!(
+                                "cannot remove a required {} value",
+                                slot.shape()
+                            )));
+                        }
+                    }
+                } else {
+                    merge(slot, value, input, path)?;
+                }
+                path.pop();
+            }
+            Ok(())
+        }
+        _ => put(poke, Source::Json(raw)),
+    }
+}
+
+fn deserialize_shape<'input, 'facet, 'shape>(
+    shape: &'shape Shape<'shape>,
+    json: &'input [u8],
+) -> Result<HeapValue<'facet, 'shape>, PatchErrorKind>
+where
+    'input: 'facet,
+    'shape: 'input,
+{
+    let partial = Partial::alloc_shape(shape).map_err(reflect_error)?;
+    facet_deserialize::deserialize_wip(partial, json, &mut crate::Json)
+        .map_err(|e| PatchErrorKind::Deserialize(e.message().to_string()))
+}
+
+/// Builds a map key from a reference token
+fn map_key<'facet, 'shape>(
+    shape: &'shape Shape<'shape>,
+    token: &str,
+) -> Result<HeapValue<'facet, 'shape>, PatchErrorKind> {
+    let mut partial = Partial::alloc_shape(shape).map_err(reflect_error)?;
+    if shape.is_type::<String>() {
+        partial.set(token.to_string()).map_err(reflect_error)?;
+    } else {
+        partial
+            .parse_from_str(token)
+            .map_err(|_| PatchErrorKind::NotFound)?;
+    }
+    partial.build().map_err(reflect_error)
+}
+
+fn list_len(poke: &Poke<'_, '_, '_>) -> Result<usize, PatchErrorKind> {
+    poke.as_peek()
+        .into_list()
+        .map(|list| list.len())
+        .map_err(reflect_error)
+}
+
+/// Parses an array index: decimal digits, without leading zeros
+fn parse_index(token: &str) -> Result<usize, PatchErrorKind> {
+    let digits = !token.is_empty() && token.bytes().all(|b| b.is_ascii_digit());
+    if !digits || (token.len() > 1 && token.starts_with('0')) {
+        return Err(PatchErrorKind::NotFound);
+    }
+    token.parse().map_err(|_| PatchErrorKind::NotFound)
+}
+
+fn reflect_error(error: ReflectError<'_>) -> PatchErrorKind {
+    match error {
+        ReflectError::FieldError {
+            field_error: FieldError::NoSuchField | FieldError::IndexOutOfBounds { .. },
+            ..
+        }
+        | ReflectError::ArrayIndexOutOfBounds { .. } => PatchErrorKind::NotFound,
+        error => PatchErrorKind::Unsupported(error.to_plain_string()),
+    }
+}
@@ -1,19 +1,54 @@
 use alloc::string::String;
 use alloc::vec::Vec;
 use facet_core::Facet;
-use facet_reflect::Peek;
-use facet_serialize::{Serializer, serialize_iterative};
+use facet_reflect::{Peek, ReflectError};
+pub use facet_serialize::{REDACTED, Redaction, SerializeOptions};
+use facet_serialize::{Serializer, serialize_iterative_with_options};
 use log::debug;
 
 /// Serializes a value implementing `Facet` to a JSON string.
+///
+/// Values that can't be read are written as `null`: a lock that's poisoned or already held,
+/// or a field whose `serialize_with` conversion fails. Use [`to_writer`] to get an error
+/// instead.
 pub fn to_string<'facet, T: Facet<'facet>>(value: &T) -> String {
     peek_to_string(Peek::new(value))
 }
 
+/// Serializes a value implementing `Facet` to a JSON string, as configured by `options`.
+///
+/// Values that can't be read are written as `null`, like with [`to_string`].
+pub fn to_string_with_options<'facet, T: Facet<'facet>>(
+    value: &T,
+    options: &SerializeOptions,
+) -> String {
+    peek_to_string_with_options(Peek::new(value), options)
+}
+
 /// Serializes a `Peek` instance to a JSON string.
+///
+/// Values that can't be read are written as `null`, like with [`to_string`].
 pub fn peek_to_string<'input, 'facet, 'shape>(peek: Peek<'input, 'facet, 'shape>) -> String {
+    peek_to_string_with_options(peek, &SerializeOptions::default())
+}
+
+/// Serializes a `Peek` instance to a JSON string, as configured by `options`.
+///
+/// Values that can't be read are written as `null`, like with [`to_string`].
+pub fn peek_to_string_with_options<'input, 'facet, 'shape>(
+    peek: Peek<'input, 'facet, 'shape>,
+    options: &SerializeOptions,
+) -> String {
     let mut s = Vec::new();
-    peek_to_writer(peek, &mut s).unwrap();
+    let mut serializer = JsonSerializer {
+        unreadable_as_null: true,
+        ..JsonSerializer::new(&mut s)
+    };
+    match serialize_iterative_with_options(peek, &mut serializer, options) {
+        Ok(()) => {}
+        // Only unreadable values fail, and they were written as `null`
+        Err(SerializeError::Reflect(_)) => unreachable!(),
+    }
     String::from_utf8(s).unwrap()
 }
 
@@ -29,14 +64,38 @@ pub fn to_writer<'mem, 'facet, T: Facet<'facet>, W: crate::JsonWrite>(
 pub fn peek_to_writer<'mem, 'facet, 'shape, W: crate::JsonWrite>(
     peek: Peek<'mem, 'facet, 'shape>,
     writer: W,
+) -> Result<(), SerializeError> {
+    peek_to_writer_with_options(peek, writer, &SerializeOptions::default())
+}
+
+/// Serializes a `Peek` value to JSON and writes it to the given writer, as configured by
+/// `options`.
+pub fn peek_to_writer_with_options<'mem, 'facet, 'shape, W: crate::JsonWrite>(
+    peek: Peek<'mem, 'facet, 'shape>,
+    writer: W,
+    options: &SerializeOptions,
 ) -> Result<(), SerializeError> {
     let mut serializer = JsonSerializer::new(writer);
-    serialize_iterative(peek, &mut serializer)
+    serialize_iterative_with_options(peek, &mut serializer, options)
 }
 
-/// Serialization error for json, which cannot fail.
+/// Serialization error for json, which only fails when the value can't be read (see
+/// [`to_string`] for writing `null` instead).
 #[derive(Debug)]
-pub enum SerializeError {}
+pub enum SerializeError {
+    /// Could not read the value being serialized (e.g. a poisoned `Mutex`).
+    Reflect(String),
+}
+
+impl core::fmt::Display for SerializeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SerializeError::Reflect(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl core::error::Error for SerializeError {}
 
 #[derive(Debug)]
 enum StackItem {
@@ -55,6 +114,8 @@ enum ObjectItemState {
 pub struct JsonSerializer<W: crate::JsonWrite> {
     writer: W,
     stack: Vec<StackItem>,
+    /// Whether values that can't be read are written as `null`, rather than failing
+    unreadable_as_null: bool,
 }
 
 impl<W: crate::JsonWrite> JsonSerializer<W> {
@@ -63,6 +124,7 @@ impl<W: crate::JsonWrite> JsonSerializer<W> {
         Self {
             writer,
             stack: Vec::new(),
+            unreadable_as_null: false,
         }
     }
 
@@ -109,6 +171,14 @@ impl<W: crate::JsonWrite> JsonSerializer<W> {
 impl<'shape, W: crate::JsonWrite> Serializer<'shape> for JsonSerializer<W> {
     type Error = SerializeError;
 
+    fn serialize_unreadable(&mut self, error: ReflectError<'shape>) -> Result<(), Self::Error> {
+        if self.unreadable_as_null {
+            self.serialize_none()
+        } else {
+            Err(SerializeError::Reflect(error.to_plain_string()))
+        }
+    }
+
     fn serialize_u8(&mut self, value: u8) -> Result<(), Self::Error> {
         self.start_value()?;
         self.writer
@@ -209,6 +279,12 @@ impl<'shape, W: crate::JsonWrite> Serializer<'shape> for JsonSerializer<W> {
         self.end_value()
     }
 
+    fn serialize_number(&mut self, literal: &str) -> Result<(), Self::Error> {
+        self.start_value()?;
+        self.writer.write(literal.as_bytes());
+        self.end_value()
+    }
+
     fn serialize_bool(&mut self, value: bool) -> Result<(), Self::Error> {
         self.start_value()?;
         self.writer.write(if value { b"true" } else { b"false" });
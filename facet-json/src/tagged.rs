@@ -0,0 +1,217 @@
+//! Deserializes polymorphic payloads, whose concrete type is named by a tag member:
+//!
+//! ```json
+//! { "@type": "com.acme.Order", "id": 42, "total": 9.99 }
+//! ```
+//!
+//! The tag is resolved through a [`ShapeRegistry`], and the rest of the object is deserialized
+//! into the shape it names: the tag member itself is left out, so it doesn't count as an unknown
+//! field of types marked `deny_unknown_fields`.
+
+use alloc::string::{String, ToString};
+use core::fmt;
+
+use facet_deserialize::{
+    Cooked, DeserError, DeserErrorKind, Expectation, Format, NextData, NextResult, Outcome, Scalar,
+    Span, Spannable, Spanned,
+};
+use facet_reflect::{HeapValue, Partial, ShapeRegistry};
+
+use crate::deserialize::convert_token_error;
+use crate::node::{NodeKind, parse_document};
+use crate::tokenizer::{Token, Tokenizer};
+
+/// The member holding the type tag, for [`from_str_tagged`]
+pub const DEFAULT_TAG_KEY: &str = "@type";
+
+/// Deserializes a JSON object into the type named by its `"@type"` member
+pub fn from_str_tagged<'input>(
+    registry: &ShapeRegistry<'static>,
+    input: &'input str,
+) -> Result<HeapValue<'input, 'static>, TaggedDeserError<'input>> {
+    from_str_tagged_with_key(registry, DEFAULT_TAG_KEY, input)
+}
+
+/// Deserializes a JSON object into the type named by its `tag_key` member
+///
+/// The tag is looked up as a type tag first, then as a type name.
+pub fn from_str_tagged_with_key<'input>(
+    registry: &ShapeRegistry<'static>,
+    tag_key: &str,
+    input: &'input str,
+) -> Result<HeapValue<'input, 'static>, TaggedDeserError<'input>> {
+    let document = parse_document(input.as_bytes()).map_err(TaggedDeserError::InvalidJson)?;
+    let NodeKind::Object(members) = &document.kind else {
+        return Err(TaggedDeserError::MissingTag);
+    };
+    let tag = members
+        .iter()
+        .find(|(key, _)| key == tag_key)
+        .and_then(|(_, value)| match &value.kind {
+            NodeKind::String(tag) => Some(tag),
+            _ => None,
+        })
+        .ok_or(TaggedDeserError::MissingTag)?;
+    let shape = registry
+        .lookup(tag)
+        .ok_or_else(|| TaggedDeserError::UnknownTag(tag.to_string()))?;
+
+    let partial = Partial::alloc_shape(shape).map_err(|e| {
+        TaggedDeserError::Deserialize(DeserError::new(
+            DeserErrorKind::ReflectError(e),
+            input.as_bytes(),
+            Span::new(document.start, document.end - document.start),
+            "json",
+        ))
+    })?;
+    let mut format = WithoutTag {
+        tag_key: tag_key.to_string(),
+        depth: 0,
+    };
+    facet_deserialize::deserialize_wip(partial, input.as_bytes(), &mut format)
+        .map_err(TaggedDeserError::Deserialize)
+}
+
+/// JSON, without the tag member of the outermost object
+struct WithoutTag {
+    tag_key: String,
+    /// How many objects and lists are open
+    depth: usize,
+}
+
+impl Format for WithoutTag {
+    type Input<'input> = [u8];
+    type SpanType = Cooked;
+
+    fn source(&self) -> &'static str {
+        crate::Json.source()
+    }
+
+    fn next<'input, 'facet, 'shape>(
+        &mut self,
+        nd: NextData<'input, 'facet, 'shape>,
+        expectation: Expectation,
+    ) -> NextResult<
+        'input,
+        'facet,
+        'shape,
+        Spanned<Outcome<'input>, Self::SpanType>,
+        Spanned<DeserErrorKind<'shape>, Self::SpanType>,
+        Self::SpanType,
+        Self::Input<'input>,
+    >
+    where
+        'shape: 'input,
+    {
+        let outermost_key = self.depth == 1 && expectation == Expectation::ObjectKeyOrObjectClose;
+        let (nd, res) = crate::Json.next(nd, expectation);
+        let res = match res {
+            Ok(Spanned {
+                node: Outcome::Scalar(Scalar::String(key)),
+                span,
+            }) if outermost_key && key == self.tag_key => after_tag(nd.input(), span.end()),
+            res => res,
+        };
+        if let Ok(outcome) = &res {
+            match outcome.node {
+                Outcome::ObjectStarted | Outcome::ListStarted => self.depth += 1,
+                Outcome::ObjectEnded | Outcome::ListEnded => self.depth -= 1,
+                _ => {}
+            }
+        }
+        (nd, res)
+    }
+
+    fn skip<'input, 'facet, 'shape>(
+        &mut self,
+        nd: NextData<'input, 'facet, 'shape>,
+    ) -> NextResult<
+        'input,
+        'facet,
+        'shape,
+        Span,
+        Spanned<DeserErrorKind<'shape>>,
+        Self::SpanType,
+        Self::Input<'input>,
+    >
+    where
+        'shape: 'input,
+    {
+        crate::Json.skip(nd)
+    }
+}
+
+/// Skips the value of the tag member whose key ends at `pos`, and returns what follows it: the
+/// next key, or the end of the object.
+fn after_tag<'input, 'shape>(
+    input: &'input [u8],
+    pos: usize,
+) -> Result<Spanned<Outcome<'input>>, Spanned<DeserErrorKind<'shape>>> {
+    let mut tokenizer = Tokenizer::new(&input[pos..]);
+    let mut next = || match tokenizer.next_token() {
+        Ok(token) => Ok((
+            token.node,
+            Span::new(token.span.start() + pos, token.span.len()),
+        )),
+        Err(mut err) => {
+            err.span = Span::new(err.span.start() + pos, err.span.len());
+            Err(convert_token_error(err))
+        }
+    };
+    let unexpected = |span: Span| {
+        DeserErrorKind::UnexpectedChar {
+            got: input[span.start()] as char,
+            wanted: "the rest of the object",
+        }
+        .with_span(span)
+    };
+
+    // Like the tag that was resolved, the value of the tag member must be a string
+    for expected in [Token::Colon, Token::String(Default::default())] {
+        let (token, span) = next()?;
+        if core::mem::discriminant(&token) != core::mem::discriminant(&expected) {
+            return Err(unexpected(span));
+        }
+    }
+    match next()? {
+        (Token::RBrace, span) => Ok(Spanned {
+            node: Outcome::ObjectEnded,
+            span,
+        }),
+        (Token::Comma, _) => match next()? {
+            (Token::String(key), span) => Ok(Spanned {
+                node: Outcome::Scalar(Scalar::String(key)),
+                span,
+            }),
+            (_, span) => Err(unexpected(span)),
+        },
+        (_, span) => Err(unexpected(span)),
+    }
+}
+
+/// An error that occurred while deserializing a tagged payload
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum TaggedDeserError<'input> {
+    /// The input isn't valid JSON
+    InvalidJson(String),
+    /// The input isn't an object with a string tag member
+    MissingTag,
+    /// No shape is registered for the tag
+    UnknownTag(String),
+    /// The object couldn't be deserialized into the shape registered for its tag
+    Deserialize(DeserError<'input, 'static>),
+}
+
+impl fmt::Display for TaggedDeserError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TaggedDeserError::InvalidJson(msg) => write!(f, "invalid JSON: {msg}"),
+            TaggedDeserError::MissingTag => write!(f, "expected an object with a type tag"),
+            TaggedDeserError::UnknownTag(tag) => write!(f, "unknown type tag `{tag}`"),
+            TaggedDeserError::Deserialize(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl core::error::Error for TaggedDeserError<'_> {}
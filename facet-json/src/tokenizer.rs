@@ -22,8 +22,8 @@ pub enum TokenErrorKind {
     UnexpectedEof(&'static str),
     /// Invalid UTF-8 sequence
     InvalidUtf8(String),
-    /// Number is out of range
-    NumberOutOfRange(f64),
+    /// Number literal that doesn't follow the JSON grammar (e.g. `1.`, `1e+` or `01`)
+    InvalidNumber(String),
 }
 
 use alloc::borrow::Cow;
@@ -37,7 +37,7 @@ impl Display for TokenErrorKind {
             TokenErrorKind::UnexpectedCharacter(c) => write!(f, "unexpected character: '{}'", c),
             TokenErrorKind::UnexpectedEof(context) => write!(f, "unexpected EOF {}", context),
             TokenErrorKind::InvalidUtf8(detail) => write!(f, "invalid UTF-8: {}", detail),
-            TokenErrorKind::NumberOutOfRange(n) => write!(f, "number out of range: {}", n),
+            TokenErrorKind::InvalidNumber(literal) => write!(f, "invalid number: {}", literal),
         }
     }
 }
@@ -63,8 +63,6 @@ pub enum Token<'input> {
     /// A JSON string value
     /// TODO: should be a &[u8], lazily de-escaped if/when needed
     String(Cow<'input, str>),
-    /// A 64-bit floating point number value — used if the value contains a decimal point
-    F64(f64),
     /// A signed 64-bit integer number value — used if the value does not contain a decimal point but contains a sign
     I64(i64),
     /// An unsigned 64-bit integer number value — used if the value does not contain a decimal point and does not contain a sign
@@ -73,6 +71,10 @@ pub enum Token<'input> {
     U128(u128),
     /// A 128-bit signed integer number value
     I128(i128),
+    /// Any other number, as its literal — used if the value contains a decimal point or an
+    /// exponent, or is too large for the integer tokens. It's parsed later, once we know what
+    /// it's deserialized into, so arbitrary-precision types don't lose precision to an `f64`.
+    Number(&'input str),
     /// The JSON boolean value 'true'
     True,
     /// The JSON boolean value 'false'
@@ -93,11 +95,11 @@ impl Display for Token<'_> {
             Token::Colon => write!(f, ":"),
             Token::Comma => write!(f, ","),
             Token::String(s) => write!(f, "\"{}\"", s),
-            Token::F64(n) => write!(f, "{}", n),
             Token::I64(n) => write!(f, "{}", n),
             Token::U64(n) => write!(f, "{}", n),
             Token::U128(n) => write!(f, "{}", n),
             Token::I128(n) => write!(f, "{}", n),
+            Token::Number(literal) => write!(f, "{}", literal),
             Token::True => write!(f, "true"),
             Token::False => write!(f, "false"),
             Token::Null => write!(f, "null"),
@@ -383,27 +385,38 @@ impl<'input> Tokenizer<'input> {
 
     #[inline(never)]
     fn parse_number(&mut self, start: Pos) -> TokenizeResult<'input> {
+        // Number literals are only parsed once we know what they're deserialized into, so they're
+        // checked against the JSON grammar here:
+        // `-? (0 | [1-9][0-9]*) ('.' [0-9]+)? ([eE] [+-]? [0-9]+)?`
+        let skip_digits = |mut end: usize| {
+            while end < self.input.len() && self.input[end].is_ascii_digit() {
+                end += 1;
+            }
+            end
+        };
+        let mut valid = true;
+
         let mut end = self.pos;
         if self.input[end] == b'-' {
             end += 1;
         }
-        while end < self.input.len() && self.input[end].is_ascii_digit() {
-            end += 1;
-        }
+        let int_start = end;
+        end = skip_digits(end);
+        // At least one digit, and no leading zeros
+        valid &= end > int_start && (self.input[int_start] != b'0' || end == int_start + 1);
         if end < self.input.len() && self.input[end] == b'.' {
-            end += 1;
-            while end < self.input.len() && self.input[end].is_ascii_digit() {
-                end += 1;
-            }
+            let frac_start = end + 1;
+            end = skip_digits(frac_start);
+            valid &= end > frac_start;
         }
         if end < self.input.len() && (self.input[end] == b'e' || self.input[end] == b'E') {
             end += 1;
             if end < self.input.len() && (self.input[end] == b'+' || self.input[end] == b'-') {
                 end += 1;
             }
-            while end < self.input.len() && self.input[end].is_ascii_digit() {
-                end += 1;
-            }
+            let exp_start = end;
+            end = skip_digits(exp_start);
+            valid &= end > exp_start;
         }
         let slice = &self.input[start..end];
         let span = Span::new(start, end - start);
@@ -418,54 +431,32 @@ impl<'input> Tokenizer<'input> {
             }
         };
 
-        let token = if text.contains('.') || text.contains('e') || text.contains('E') {
-            // If the number contains a decimal point or exponent, parse as f64
-            match text.parse::<f64>() {
-                Ok(n) => Token::F64(n),
-                Err(_) => {
-                    return Err(TokenError {
-                        kind: TokenErrorKind::NumberOutOfRange(0.0),
-                        span,
-                    });
-                }
-            }
+        if !valid {
+            return Err(TokenError {
+                kind: TokenErrorKind::InvalidNumber(text.to_string()),
+                span,
+            });
+        }
+
+        let token = if text.contains(['.', 'e', 'E']) {
+            Token::Number(text)
         } else if text.starts_with('-') {
-            // If the number starts with a negative sign, parse as i64
+            // If the number starts with a negative sign, parse as i64, then i128
             match text.parse::<i64>() {
                 Ok(n) => Token::I64(n),
-                Err(_) => {
-                    // If i64 parsing fails, try to parse as i128
-                    match text.parse::<i128>() {
-                        Ok(n) => Token::I128(n),
-                        Err(_) => {
-                            // If i128 parsing fails, try to parse as f64 for error reporting
-                            let num = text.parse::<f64>().unwrap_or(0.0);
-                            return Err(TokenError {
-                                kind: TokenErrorKind::NumberOutOfRange(num),
-                                span,
-                            });
-                        }
-                    }
-                }
+                Err(_) => match text.parse::<i128>() {
+                    Ok(n) => Token::I128(n),
+                    Err(_) => Token::Number(text),
+                },
             }
         } else {
-            // Otherwise, parse as u64
+            // Otherwise, parse as u64, then u128
             match text.parse::<u64>() {
                 Ok(n) => Token::U64(n),
-                Err(_) => {
-                    // If u64 parsing fails, try to parse as u128
-                    match text.parse::<u128>() {
-                        Ok(n) => Token::U128(n),
-                        Err(_) => {
-                            // If u128 parsing fails, try to parse as f64 for error reporting
-                            let num = text.parse::<f64>().unwrap_or(0.0);
-                            return Err(TokenError {
-                                kind: TokenErrorKind::NumberOutOfRange(num),
-                                span,
-                            });
-                        }
-                    }
-                }
+                Err(_) => match text.parse::<u128>() {
+                    Ok(n) => Token::U128(n),
+                    Err(_) => Token::Number(text),
+                },
             }
         };
 
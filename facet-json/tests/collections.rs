@@ -0,0 +1,41 @@
+use std::collections::{BinaryHeap, LinkedList, VecDeque};
+
+use facet::Facet;
+use facet_json::{from_str, to_string};
+use facet_testhelpers::test;
+
+#[test]
+fn vec_deque_round_trip() {
+    #[derive(Facet, Debug, PartialEq)]
+    struct Snapshot {
+        queue: VecDeque<u32>,
+    }
+
+    let mut queue = VecDeque::with_capacity(4);
+    queue.push_back(3);
+    queue.push_front(2);
+    queue.push_front(1);
+    let snapshot = Snapshot { queue };
+
+    let json = to_string(&snapshot);
+    assert_eq!(json, r#"{"queue":[1,2,3]}"#);
+    assert_eq!(from_str::<Snapshot>(&json)?, snapshot);
+}
+
+#[test]
+fn linked_list_round_trip() {
+    let list: LinkedList<String> = ["a", "b"].into_iter().map(String::from).collect();
+    let json = to_string(&list);
+    assert_eq!(json, r#"["a","b"]"#);
+    assert_eq!(from_str::<LinkedList<String>>(&json)?, list);
+}
+
+#[test]
+fn binary_heap_round_trip() {
+    let heap: BinaryHeap<i32> = from_str("[3, 1, 4, 1, 5]")?;
+    assert_eq!(heap.peek(), Some(&5));
+
+    let json = to_string(&heap);
+    let back: BinaryHeap<i32> = from_str(&json)?;
+    assert_eq!(back.into_sorted_vec(), [1, 1, 3, 4, 5]);
+}
@@ -0,0 +1,56 @@
+use std::str::FromStr;
+
+use facet::Facet;
+use facet_json::{from_str, to_string};
+use facet_testhelpers::test;
+use num_bigint::{BigInt, BigUint};
+use rust_decimal::Decimal;
+
+#[derive(Facet, Debug, PartialEq)]
+struct Payment {
+    amount: Decimal,
+    fee: Decimal,
+    balance: BigInt,
+    supply: BigUint,
+}
+
+#[test]
+fn arbitrary_precision_numbers_keep_their_digits() {
+    let json = r#"{"amount":12.3456789012345678901234567,"fee":-1.10,"balance":-115792089237316195423570985008687907853269984665640564039457584007913129639935,"supply":340282366920938463463374607431768211456}"#;
+    let payment = from_str::<Payment>(json)?;
+    assert_eq!(
+        payment.amount,
+        Decimal::from_str("12.3456789012345678901234567")?
+    );
+    assert_eq!(payment.fee.to_string(), "-1.10");
+    assert_eq!(
+        payment.balance,
+        -BigInt::from_str(
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+        )?
+    );
+    assert_eq!(payment.supply, BigUint::from(u128::MAX) + 1u32);
+    assert_eq!(to_string(&payment), json);
+}
+
+#[test]
+fn arbitrary_precision_numbers_from_strings_and_integers() {
+    let payment = from_str::<Payment>(
+        r#"{"amount":"0.1","fee":3,"balance":-42,"supply":"18446744073709551616"}"#,
+    )?;
+    assert_eq!(payment.amount, Decimal::from_str("0.1")?);
+    assert_eq!(payment.fee, Decimal::from(3));
+    assert_eq!(payment.balance, BigInt::from(-42));
+    assert_eq!(payment.supply, BigUint::from(u64::MAX) + 1u32);
+}
+
+#[test]
+fn negative_big_unsigned_integer_is_an_error() {
+    assert!(from_str::<BigUint>("-3").is_err());
+}
+
+#[test]
+fn integer_too_large_for_machine_numbers_is_out_of_range() {
+    let err = from_str::<u64>("340282366920938463463374607431768211456").unwrap_err();
+    assert!(err.to_string().contains("out of range"));
+}
@@ -0,0 +1,138 @@
+use std::borrow::Cow;
+
+use facet::{Facet, facet_fn, fn_shape};
+use facet_json::{DispatchErrorKind, FunctionRegistry};
+use facet_testhelpers::test;
+
+#[derive(Debug, PartialEq, Facet)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[facet_fn(dynamic)]
+fn add(x: i32, y: i32) -> i32 {
+    x + y
+}
+
+#[facet_fn(dynamic)]
+fn translate(point: Point, dx: i32, dy: Option<i32>) -> Point {
+    Point {
+        x: point.x + dx,
+        y: point.y + dy.unwrap_or(0),
+    }
+}
+
+#[facet_fn(dynamic)]
+fn version() -> String {
+    "1.0".to_string()
+}
+
+#[facet_fn(dynamic)]
+fn shout(word: &str) -> String {
+    word.to_uppercase()
+}
+
+#[facet_fn(dynamic)]
+fn greet(name: Cow<'_, str>) -> String {
+    format!("hello {name}")
+}
+
+fn registry() -> FunctionRegistry {
+    let mut registry = FunctionRegistry::new();
+    registry
+        .register(fn_shape!(add))
+        .register(fn_shape!(translate))
+        .register(fn_shape!(version))
+        .register(fn_shape!(shout))
+        .register(fn_shape!(greet));
+    registry
+}
+
+#[test]
+fn test_dispatch_named_and_positional() {
+    let registry = registry();
+    assert_eq!(
+        registry.dispatch(r#"{"method": "add", "params": {"x": 1, "y": 2}}"#)?,
+        "3"
+    );
+    assert_eq!(
+        registry.dispatch(r#"{"jsonrpc": "2.0", "id": 7, "method": "add", "params": [40, 2]}"#)?,
+        "42"
+    );
+    assert_eq!(
+        registry.dispatch(
+            r#"{"method": "translate", "params": {"dx": 1, "point": {"x": 1, "y": 1}, "dy": 2}}"#
+        )?,
+        r#"{"x":2,"y":3}"#
+    );
+    assert_eq!(
+        registry.call("translate", r#"[{"x": 0, "y": 5}, 3]"#)?,
+        r#"{"x":3,"y":5}"#
+    );
+    assert_eq!(registry.dispatch(r#"{"method": "version"}"#)?, r#""1.0""#);
+}
+
+#[test]
+fn test_dispatch_errors_name_the_param() {
+    let registry = registry();
+
+    let err = registry
+        .dispatch(r#"{"method": "add", "params": {"x": 1, "y": "two"}}"#)
+        .unwrap_err();
+    assert_eq!(err.method.as_deref(), Some("add"));
+    assert_eq!(err.param.as_deref(), Some("y"));
+    assert!(matches!(err.kind, DispatchErrorKind::Deserialize(_)));
+
+    let err = registry
+        .dispatch(r#"{"method": "add", "params": [1]}"#)
+        .unwrap_err();
+    assert_eq!(err.param.as_deref(), Some("y"));
+    assert_eq!(err.kind, DispatchErrorKind::MissingParam);
+
+    let err = registry
+        .dispatch(r#"{"method": "add", "params": {"x": 1, "y": 2, "z": 3}}"#)
+        .unwrap_err();
+    assert_eq!(err.param.as_deref(), Some("z"));
+    assert_eq!(err.kind, DispatchErrorKind::UnknownParam);
+
+    let err = registry
+        .dispatch(r#"{"method": "add", "params": [1, 2, 3]}"#)
+        .unwrap_err();
+    assert_eq!(
+        err.kind,
+        DispatchErrorKind::WrongParamCount {
+            expected: 2,
+            got: 3
+        }
+    );
+
+    let err = registry
+        .dispatch(r#"{"method": "translate", "params": [{"x": 0}, 1]}"#)
+        .unwrap_err();
+    assert_eq!(err.param.as_deref(), Some("point"));
+
+    let err = registry
+        .dispatch(r#"{"method": "sub", "params": [1, 2]}"#)
+        .unwrap_err();
+    assert_eq!(err.kind, DispatchErrorKind::MethodNotFound);
+
+    let err = registry.dispatch(r#"{"params": [1, 2]}"#).unwrap_err();
+    assert!(matches!(err.kind, DispatchErrorKind::InvalidRequest(_)));
+}
+
+#[test]
+fn test_dispatch_rejects_borrowed_params() {
+    let registry = registry();
+    let request = String::from(r#"{"method": "shout", "params": ["hi"]}"#);
+    let err = registry.dispatch(&request).unwrap_err();
+    assert_eq!(err.param.as_deref(), Some("word"));
+    assert!(matches!(err.kind, DispatchErrorKind::Deserialize(_)));
+}
+
+#[test]
+fn test_dispatch_copies_cow_params() {
+    let registry = registry();
+    let request = String::from(r#"{"method": "greet", "params": ["you"]}"#);
+    assert_eq!(registry.dispatch(&request)?, r#""hello you""#);
+}
@@ -0,0 +1,96 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use facet::Facet;
+use facet_json::{from_str, to_string};
+use facet_testhelpers::test;
+
+#[derive(Facet, Debug, PartialEq)]
+struct Timeouts {
+    connect: Duration,
+    idle: Duration,
+    retry: Duration,
+}
+
+#[test]
+fn write_duration() {
+    let timeouts = Timeouts {
+        connect: Duration::from_millis(1500),
+        idle: Duration::from_secs(5400),
+        retry: Duration::from_millis(250),
+    };
+    assert_eq!(
+        to_string(&timeouts),
+        r#"{"connect":"1.5s","idle":"1h30m","retry":"250ms"}"#
+    );
+    assert_eq!(from_str::<Timeouts>(&to_string(&timeouts))?, timeouts);
+}
+
+#[test]
+fn read_duration() {
+    let json = r#"{"connect":"PT1.5S","idle":"1h 30m","retry":"0.25s"}"#;
+    assert_eq!(
+        from_str::<Timeouts>(json)?,
+        Timeouts {
+            connect: Duration::from_millis(1500),
+            idle: Duration::from_secs(5400),
+            retry: Duration::from_millis(250),
+        }
+    );
+}
+
+#[test]
+fn read_invalid_duration() {
+    let json = r#"{"connect":"soon","idle":"1h","retry":"1s"}"#;
+    let err = from_str::<Timeouts>(json).unwrap_err();
+    assert!(err.to_string().contains("Failed to parse"), "{err}");
+}
+
+#[test]
+fn system_time_round_trip() {
+    #[derive(Facet, Debug, PartialEq)]
+    struct Event {
+        at: SystemTime,
+    }
+
+    let event = Event {
+        at: UNIX_EPOCH + Duration::new(1_705_321_800, 500_000_000),
+    };
+    let json = to_string(&event);
+    assert_eq!(json, r#"{"at":"2024-01-15T12:30:00.5Z"}"#);
+    assert_eq!(from_str::<Event>(&json)?, event);
+
+    let with_offset: Event = from_str(r#"{"at":"2024-01-15T13:30:00.5+01:00"}"#)?;
+    assert_eq!(with_offset, event);
+}
+
+#[test]
+fn numeric_seconds() {
+    #[derive(Facet, Debug, PartialEq)]
+    struct Job {
+        #[facet(proxy = facet::Seconds)]
+        timeout: Duration,
+        #[facet(proxy = facet::Seconds)]
+        started_at: SystemTime,
+    }
+
+    let job = Job {
+        timeout: Duration::from_millis(1500),
+        started_at: UNIX_EPOCH + Duration::from_secs(1_705_321_800),
+    };
+    let json = to_string(&job);
+    assert_eq!(json, r#"{"timeout":1.5,"started_at":1705321800.0}"#);
+    assert_eq!(from_str::<Job>(&json)?, job);
+
+    let before_epoch: Job = from_str(r#"{"timeout":2,"started_at":-1.5}"#)?;
+    assert_eq!(before_epoch.timeout, Duration::from_secs(2));
+    assert_eq!(
+        before_epoch.started_at,
+        UNIX_EPOCH - Duration::from_millis(1500)
+    );
+
+    let err = from_str::<Job>(r#"{"timeout":-1,"started_at":0}"#).unwrap_err();
+    assert!(
+        err.to_string().contains("-1 seconds is out of range"),
+        "{err}"
+    );
+}
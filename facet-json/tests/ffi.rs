@@ -0,0 +1,46 @@
+use std::ffi::{CString, OsString};
+
+use facet::Facet;
+use facet_json::{from_str, to_string};
+use facet_testhelpers::test;
+
+#[test]
+fn platform_strings_round_trip() {
+    #[derive(Facet, Debug, PartialEq)]
+    struct Process {
+        program: OsString,
+        name: CString,
+    }
+
+    let process = Process {
+        program: OsString::from("/usr/bin/héllo"),
+        name: CString::new("hello").unwrap(),
+    };
+    let json = to_string(&process);
+    assert_eq!(json, r#"{"program":"/usr/bin/héllo","name":"hello"}"#);
+    assert_eq!(from_str::<Process>(&json)?, process);
+}
+
+#[test]
+fn platform_strings_that_are_not_utf8_are_bytes() {
+    let name = CString::new(vec![b'a', 0xff]).unwrap();
+    let json = to_string(&name);
+    assert_eq!(json, "[97,255]");
+    assert_eq!(from_str::<CString>(&json)?, name);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStringExt;
+
+        let program = OsString::from_vec(vec![b'a', 0xfe]);
+        let json = to_string(&program);
+        assert_eq!(json, "[97,254]");
+        assert_eq!(from_str::<OsString>(&json)?, program);
+    }
+}
+
+#[test]
+fn c_string_with_nul_is_rejected() {
+    assert!(from_str::<CString>(r#""a\u0000b""#).is_err());
+    assert!(from_str::<CString>("[97,0,98]").is_err());
+}
@@ -0,0 +1,35 @@
+use facet::Facet;
+use facet_json::{from_str, to_string};
+use facet_testhelpers::test;
+use indexmap::{IndexMap, IndexSet};
+
+#[derive(Facet, Debug, PartialEq)]
+struct Config {
+    env: IndexMap<String, String>,
+    features: IndexSet<String>,
+}
+
+#[test]
+fn indexmap_keeps_insertion_order() {
+    let json = r#"{"env":{"ZETA":"1","ALPHA":"2","MID":"3"},"features":["zstd","alloc","mid"]}"#;
+    let config = from_str::<Config>(json)?;
+    assert_eq!(
+        config.env.keys().collect::<Vec<_>>(),
+        ["ZETA", "ALPHA", "MID"]
+    );
+    assert_eq!(
+        config.features.iter().collect::<Vec<_>>(),
+        ["zstd", "alloc", "mid"]
+    );
+    assert_eq!(to_string(&config), json);
+}
+
+#[test]
+fn indexmap_removal_keeps_order() {
+    let mut env = IndexMap::new();
+    env.insert("b".to_string(), 1);
+    env.insert("a".to_string(), 2);
+    env.insert("c".to_string(), 3);
+    env.shift_remove("a");
+    assert_eq!(to_string(&env), r#"{"b":1,"c":3}"#);
+}
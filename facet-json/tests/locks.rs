@@ -0,0 +1,72 @@
+use std::cell::{Cell, RefCell};
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+
+use facet::Facet;
+use facet_json::{from_str, to_string, to_writer};
+use facet_testhelpers::test;
+
+#[test]
+fn interior_mutability_round_trip() {
+    #[derive(Facet, Debug)]
+    struct Counters {
+        hits: Cell<u32>,
+        names: RefCell<Vec<String>>,
+        total: Mutex<u64>,
+        label: RwLock<String>,
+        cached: OnceLock<u8>,
+        missing: OnceLock<u8>,
+    }
+
+    let counters = Counters {
+        hits: Cell::new(3),
+        names: RefCell::new(vec!["a".to_string(), "b".to_string()]),
+        total: Mutex::new(42),
+        label: RwLock::new("hello".to_string()),
+        cached: OnceLock::from(7),
+        missing: OnceLock::new(),
+    };
+    let json = to_string(&counters);
+    assert_eq!(
+        json,
+        r#"{"hits":3,"names":["a","b"],"total":42,"label":"hello","cached":7,"missing":null}"#
+    );
+
+    let back = from_str::<Counters>(&json)?;
+    assert_eq!(back.hits.get(), 3);
+    assert_eq!(*back.names.borrow(), ["a", "b"]);
+    assert_eq!(*back.total.lock().unwrap(), 42);
+    assert_eq!(*back.label.read().unwrap(), "hello");
+    assert_eq!(back.cached.get(), Some(&7));
+    assert_eq!(back.missing.get(), None);
+}
+
+#[test]
+fn nested_locks() {
+    let locks = vec![Mutex::new(RwLock::new(1u8)), Mutex::new(RwLock::new(2u8))];
+    let json = to_string(&locks);
+    assert_eq!(json, "[1,2]");
+
+    let back = from_str::<Vec<Mutex<RwLock<u8>>>>(&json)?;
+    assert_eq!(*back[1].lock().unwrap().read().unwrap(), 2);
+}
+
+#[test]
+fn shared_lock_is_released_between_values() {
+    let shared = Arc::new(Mutex::new(5u8));
+    assert_eq!(to_string(&vec![shared.clone(), shared]), "[5,5]");
+}
+
+#[test]
+fn borrowed_refcell_fails_to_serialize() {
+    let cell = RefCell::new(1u8);
+    let _borrowed = cell.borrow_mut();
+    let mut out = Vec::new();
+    assert!(to_writer(&cell, &mut out).is_err());
+}
+
+#[test]
+fn borrowed_refcell_is_null_in_to_string() {
+    let cell = RefCell::new(1u8);
+    let _borrowed = cell.borrow_mut();
+    assert_eq!(to_string(&(2u8, &cell)), "[2,null]");
+}
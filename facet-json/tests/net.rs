@@ -0,0 +1,39 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+use facet::Facet;
+use facet_json::{from_str, to_string};
+use facet_testhelpers::test;
+
+#[test]
+fn network_addresses_round_trip() {
+    #[derive(Facet, Debug, PartialEq)]
+    struct Listener {
+        ip: IpAddr,
+        v4: Ipv4Addr,
+        v6: Ipv6Addr,
+        addr: SocketAddr,
+        addr_v4: SocketAddrV4,
+        addr_v6: SocketAddrV6,
+    }
+
+    let listener = Listener {
+        ip: "::1".parse().unwrap(),
+        v4: "10.0.0.1".parse().unwrap(),
+        v6: "fe80::1".parse().unwrap(),
+        addr: "127.0.0.1:8080".parse().unwrap(),
+        addr_v4: "192.168.1.1:53".parse().unwrap(),
+        addr_v6: "[::1]:443".parse().unwrap(),
+    };
+    let json = to_string(&listener);
+    assert_eq!(
+        json,
+        r#"{"ip":"::1","v4":"10.0.0.1","v6":"fe80::1","addr":"127.0.0.1:8080","addr_v4":"192.168.1.1:53","addr_v6":"[::1]:443"}"#
+    );
+    assert_eq!(from_str::<Listener>(&json)?, listener);
+}
+
+#[test]
+fn malformed_socket_addr_is_rejected() {
+    assert!(from_str::<SocketAddrV4>(r#""[::1]:443""#).is_err());
+    assert!(from_str::<SocketAddrV6>(r#""127.0.0.1""#).is_err());
+}
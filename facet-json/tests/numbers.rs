@@ -56,3 +56,15 @@ fn json_read_more_types() {
     assert!((test_struct.f32_val - std::f32::consts::PI).abs() < f32::EPSILON);
     assert!((test_struct.f64_val - std::f64::consts::PI).abs() < f64::EPSILON);
 }
+
+#[test]
+fn json_rejects_malformed_numbers() {
+    for json in ["-", "1e", "1e+", "1.", "-.5", "01", "-01.5", "1.e3"] {
+        let err = from_str::<f64>(json).unwrap_err().to_string();
+        assert!(err.contains("Invalid number"), "{json}: {err}");
+    }
+
+    for (json, expected) in [("0", 0.0), ("-0.5", -0.5), ("1E+2", 100.0), ("10e-1", 1.0)] {
+        assert_eq!(from_str::<f64>(json)?, expected);
+    }
+}
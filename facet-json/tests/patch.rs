@@ -0,0 +1,225 @@
+use std::collections::HashMap;
+
+use facet::Facet;
+use facet_json::{PatchErrorKind, apply_merge_patch, apply_patch};
+use facet_testhelpers::test;
+
+#[derive(Debug, Clone, PartialEq, Facet)]
+struct Address {
+    street: String,
+    city: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Facet)]
+#[repr(u8)]
+enum Contact {
+    Email(String),
+    Phone { country: u16, number: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Facet)]
+struct User {
+    #[facet(rename = "userName")]
+    name: String,
+    age: u32,
+    tags: Vec<String>,
+    address: Option<Address>,
+    nickname: Option<String>,
+    scores: HashMap<String, u32>,
+    contact: Contact,
+}
+
+fn user() -> User {
+    User {
+        name: "alice".to_string(),
+        age: 30,
+        tags: vec!["admin".to_string(), "ops".to_string()],
+        address: Some(Address {
+            street: "1 Main St".to_string(),
+            city: "Lyon".to_string(),
+        }),
+        nickname: None,
+        scores: HashMap::from([("chess".to_string(), 1200)]),
+        contact: Contact::Phone {
+            country: 33,
+            number: "0102030405".to_string(),
+        },
+    }
+}
+
+#[test]
+fn test_add_and_replace() {
+    let mut user = user();
+    apply_patch(
+        &mut user,
+        r#"[
+            { "op": "replace", "path": "/userName", "value": "bob" },
+            { "op": "add", "path": "/tags/1", "value": "dev" },
+            { "op": "add", "path": "/tags/-", "value": "oncall" },
+            { "op": "add", "path": "/scores/go", "value": 3 },
+            { "op": "add", "path": "/nickname", "value": "bobby" },
+            { "op": "replace", "path": "/address/city", "value": "Paris" },
+            { "op": "replace", "path": "/contact/Phone/country", "value": 1 }
+        ]"#,
+    )?;
+
+    assert_eq!(user.name, "bob");
+    assert_eq!(user.tags, ["admin", "dev", "ops", "oncall"]);
+    assert_eq!(user.scores["go"], 3);
+    assert_eq!(user.nickname.as_deref(), Some("bobby"));
+    assert_eq!(user.address.unwrap().city, "Paris");
+    assert_eq!(
+        user.contact,
+        Contact::Phone {
+            country: 1,
+            number: "0102030405".to_string()
+        }
+    );
+}
+
+#[test]
+fn test_remove_move_copy() {
+    let mut user = user();
+    apply_patch(
+        &mut user,
+        r#"[
+            { "op": "remove", "path": "/tags/0" },
+            { "op": "remove", "path": "/scores/chess" },
+            { "op": "copy", "from": "/address/city", "path": "/nickname" },
+            { "op": "move", "from": "/nickname", "path": "/tags/0" },
+            { "op": "remove", "path": "/address" }
+        ]"#,
+    )?;
+
+    assert_eq!(user.tags, ["Lyon", "ops"]);
+    assert!(user.scores.is_empty());
+    assert_eq!(user.nickname, None);
+    assert_eq!(user.address, None);
+}
+
+#[test]
+fn test_test_operation() {
+    let mut user = user();
+    apply_patch(
+        &mut user,
+        r#"[
+            { "op": "test", "path": "/address", "value": { "street": "1 Main St", "city": "Lyon" } },
+            { "op": "test", "path": "/tags/1", "value": "ops" }
+        ]"#,
+    )?;
+
+    let err = apply_patch(
+        &mut user,
+        r#"[
+            { "op": "replace", "path": "/age", "value": 31 },
+            { "op": "test", "path": "/age", "value": 30 }
+        ]"#,
+    )
+    .unwrap_err();
+    assert_eq!(err.op, Some(1));
+    assert_eq!(err.path, "/age");
+    assert_eq!(err.kind, PatchErrorKind::TestFailed);
+}
+
+#[test]
+fn test_errors_report_path() {
+    let mut user = user();
+
+    let err = apply_patch(
+        &mut user,
+        r#"[{ "op": "replace", "path": "/name", "value": "x" }]"#,
+    )
+    .unwrap_err();
+    assert_eq!(err.path, "/name");
+    assert_eq!(err.kind, PatchErrorKind::NotFound);
+
+    let err = apply_patch(
+        &mut user,
+        r#"[{ "op": "add", "path": "/tags/7", "value": "x" }]"#,
+    )
+    .unwrap_err();
+    assert_eq!(err.path, "/tags/7");
+    assert_eq!(err.kind, PatchErrorKind::NotFound);
+
+    let err = apply_patch(
+        &mut user,
+        r#"[{ "op": "replace", "path": "/age", "value": "old" }]"#,
+    )
+    .unwrap_err();
+    assert_eq!(err.path, "/age");
+    assert!(matches!(err.kind, PatchErrorKind::Deserialize(_)));
+
+    let err = apply_patch(&mut user, r#"[{ "op": "remove", "path": "/age" }]"#).unwrap_err();
+    assert!(matches!(err.kind, PatchErrorKind::Unsupported(_)));
+
+    let err = apply_patch(&mut user, r#"[{ "op": "frobnicate", "path": "" }]"#).unwrap_err();
+    assert_eq!(err.op, Some(0));
+    assert!(matches!(err.kind, PatchErrorKind::InvalidPatch(_)));
+
+    assert_eq!(user, self::user());
+}
+
+#[test]
+fn test_deeply_nested_patch() {
+    let mut user = user();
+    let patch = format!("{}{}", "[".repeat(100_000), "]".repeat(100_000));
+    let err = apply_patch(&mut user, &patch).unwrap_err();
+    assert!(matches!(err.kind, PatchErrorKind::InvalidPatch(_)));
+
+    let patch = format!("{}{}", r#"{"a":"#.repeat(100_000), "}".repeat(100_000));
+    let err = apply_merge_patch(&mut user, &patch).unwrap_err();
+    assert!(matches!(err.kind, PatchErrorKind::InvalidPatch(_)));
+}
+
+#[test]
+fn test_pointer_escapes() {
+    let mut scores: HashMap<String, u32> = HashMap::new();
+    apply_patch(
+        &mut scores,
+        r#"[
+            { "op": "add", "path": "/a~1b", "value": 1 },
+            { "op": "add", "path": "/m~0n", "value": 2 }
+        ]"#,
+    )?;
+    assert_eq!(scores["a/b"], 1);
+    assert_eq!(scores["m~n"], 2);
+}
+
+#[test]
+fn test_merge_patch() {
+    let mut user = user();
+    apply_merge_patch(
+        &mut user,
+        r#"{
+            "userName": "carol",
+            "address": { "city": "Nantes" },
+            "nickname": "caro",
+            "scores": { "chess": null, "go": 5 },
+            "tags": ["new"]
+        }"#,
+    )?;
+
+    assert_eq!(user.name, "carol");
+    assert_eq!(
+        user.address,
+        Some(Address {
+            street: "1 Main St".to_string(),
+            city: "Nantes".to_string()
+        })
+    );
+    assert_eq!(user.nickname.as_deref(), Some("caro"));
+    assert_eq!(user.scores, HashMap::from([("go".to_string(), 5)]));
+    assert_eq!(user.tags, ["new"]);
+
+    apply_merge_patch(
+        &mut user,
+        r#"{ "address": null, "contact": { "Email": "c@example.com" } }"#,
+    )?;
+    assert_eq!(user.address, None);
+    assert_eq!(user.contact, Contact::Email("c@example.com".to_string()));
+
+    let err =
+        apply_merge_patch(&mut user, r#"{ "address": { "zip": 1 }, "age": null }"#).unwrap_err();
+    assert_eq!(err.op, None);
+    assert_eq!(err.path, "/address");
+}
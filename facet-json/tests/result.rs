@@ -0,0 +1,49 @@
+use facet::Facet;
+use facet_json::{from_str, to_string};
+use facet_testhelpers::test;
+
+#[test]
+fn result_round_trip() {
+    #[derive(Facet, Debug, PartialEq)]
+    struct Outcome {
+        first: Result<u32, String>,
+        second: Result<u32, String>,
+    }
+
+    let outcome = Outcome {
+        first: Ok(42),
+        second: Err("boom".to_string()),
+    };
+    let json = to_string(&outcome);
+    assert_eq!(json, r#"{"first":{"Ok":42},"second":{"Err":"boom"}}"#);
+    assert_eq!(from_str::<Outcome>(&json)?, outcome);
+}
+
+#[test]
+fn result_nested() {
+    let results: Vec<Result<Option<u8>, Vec<u8>>> = vec![Ok(None), Ok(Some(1)), Err(vec![2, 3])];
+    let json = to_string(&results);
+    assert_eq!(json, r#"[{"Ok":null},{"Ok":1},{"Err":[2,3]}]"#);
+    assert_eq!(
+        from_str::<Vec<Result<Option<u8>, Vec<u8>>>>(&json)?,
+        results
+    );
+}
+
+#[test]
+fn result_unknown_variant() {
+    let err = from_str::<Result<u32, String>>(r#"{"Nope":1}"#).unwrap_err();
+    assert!(err.to_string().contains("Nope"));
+}
+
+#[test]
+fn result_both_variants() {
+    let err = from_str::<Result<u32, String>>(r#"{"Ok":1,"Err":"x"}"#).unwrap_err();
+    assert!(err.to_string().contains("a single `Ok` or `Err` key"));
+}
+
+#[test]
+fn result_empty_object() {
+    let err = from_str::<Result<u32, String>>("{}").unwrap_err();
+    assert!(err.to_string().contains("`Ok` or `Err`"));
+}
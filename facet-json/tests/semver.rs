@@ -0,0 +1,30 @@
+use facet::Facet;
+use facet_json::{from_str, to_string};
+use facet_testhelpers::test;
+use semver::{Version, VersionReq};
+
+#[derive(Facet, Debug, PartialEq)]
+struct PluginManifest {
+    version: Version,
+    requires: VersionReq,
+}
+
+#[test]
+fn semver_round_trip() {
+    let json = r#"{"version":"1.2.3-beta.1+build.5","requires":"^1.2, <2"}"#;
+    let manifest = from_str::<PluginManifest>(json)?;
+    assert_eq!(
+        manifest,
+        PluginManifest {
+            version: Version::parse("1.2.3-beta.1+build.5")?,
+            requires: VersionReq::parse("^1.2, <2")?,
+        }
+    );
+    assert_eq!(to_string(&manifest), json);
+}
+
+#[test]
+fn invalid_semver_version() {
+    let err = from_str::<PluginManifest>(r#"{"version":"1.2","requires":"*"}"#).unwrap_err();
+    assert!(err.to_string().contains("semver version parsing failed"));
+}
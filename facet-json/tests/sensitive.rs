@@ -0,0 +1,90 @@
+use facet::Facet;
+use facet_json::{SerializeOptions, to_string, to_string_with_options};
+use facet_testhelpers::test;
+
+#[derive(Debug, Facet)]
+struct Credentials {
+    user: &'static str,
+    #[facet(sensitive)]
+    token: &'static str,
+}
+
+#[derive(Debug, Facet)]
+struct Request {
+    path: &'static str,
+    credentials: Credentials,
+    #[facet(sensitive)]
+    session: Option<u64>,
+}
+
+fn request() -> Request {
+    Request {
+        path: "/login",
+        credentials: Credentials {
+            user: "amos",
+            token: "hunter2",
+        },
+        session: Some(42),
+    }
+}
+
+#[test]
+fn test_sensitive_not_redacted_by_default() {
+    assert_eq!(
+        to_string(&request()),
+        r#"{"path":"/login","credentials":{"user":"amos","token":"hunter2"},"session":42}"#
+    );
+}
+
+#[test]
+fn test_redact_sensitive() {
+    let json = to_string_with_options(&request(), &SerializeOptions::new().redact_sensitive());
+    assert_eq!(
+        json,
+        r#"{"path":"/login","credentials":{"user":"amos","token":"[REDACTED]"},"session":"[REDACTED]"}"#
+    );
+}
+
+#[test]
+fn test_omit_sensitive() {
+    let json = to_string_with_options(&request(), &SerializeOptions::new().omit_sensitive());
+    assert_eq!(json, r#"{"path":"/login","credentials":{"user":"amos"}}"#);
+}
+
+#[test]
+fn test_omit_sensitive_positional() {
+    #[derive(Debug, Facet)]
+    struct Pair(&'static str, #[facet(sensitive)] &'static str);
+
+    #[derive(Debug, Facet)]
+    #[repr(u8)]
+    #[allow(dead_code)]
+    enum Auth {
+        Basic {
+            user: &'static str,
+            #[facet(sensitive)]
+            password: &'static str,
+        },
+        Bearer(#[facet(sensitive)] &'static str),
+    }
+
+    let options = SerializeOptions::new().omit_sensitive();
+    assert_eq!(
+        to_string_with_options(&Pair("amos", "hunter2"), &options),
+        r#"["amos",null]"#
+    );
+    assert_eq!(
+        to_string_with_options(
+            &Auth::Basic {
+                user: "amos",
+                password: "hunter2"
+            },
+            &options
+        ),
+        r#"{"Basic":{"user":"amos"}}"#
+    );
+    assert_eq!(
+        to_string_with_options(&Auth::Bearer("hunter2"), &options),
+        r#"{"Bearer":null}"#
+    );
+}
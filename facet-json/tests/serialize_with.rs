@@ -0,0 +1,99 @@
+use std::num::ParseIntError;
+
+use facet::Facet;
+use facet_json::{from_str, to_string};
+use facet_testhelpers::test;
+
+/// A `u64` written as a hexadecimal string
+#[derive(Debug, Facet)]
+#[facet(transparent)]
+struct Hex(String);
+
+impl From<&u64> for Hex {
+    fn from(value: &u64) -> Self {
+        Hex(format!("{value:x}"))
+    }
+}
+
+impl TryFrom<Hex> for u64 {
+    type Error = ParseIntError;
+
+    fn try_from(hex: Hex) -> Result<Self, Self::Error> {
+        u64::from_str_radix(&hex.0, 16)
+    }
+}
+
+// `serialize_with` functions take a reference to the field's type
+#[allow(clippy::ptr_arg)]
+fn join(tags: &Vec<String>) -> String {
+    tags.join(",")
+}
+
+fn split(tags: String) -> Result<Vec<String>, &'static str> {
+    if tags.is_empty() {
+        return Err("expected at least one tag");
+    }
+    Ok(tags.split(',').map(String::from).collect())
+}
+
+#[derive(Debug, PartialEq, Facet)]
+struct Commit {
+    #[facet(proxy = Hex)]
+    id: u64,
+    #[facet(serialize_with = join, deserialize_with = split)]
+    tags: Vec<String>,
+    message: String,
+}
+
+#[test]
+fn test_proxy_and_with_functions_round_trip() {
+    let commit = Commit {
+        id: 0xbeef,
+        tags: vec!["fix".to_string(), "docs".to_string()],
+        message: "Fix typos".to_string(),
+    };
+    let json = to_string(&commit);
+    assert_eq!(
+        json,
+        r#"{"id":"beef","tags":"fix,docs","message":"Fix typos"}"#
+    );
+    assert_eq!(from_str::<Commit>(&json)?, commit);
+}
+
+#[test]
+fn test_with_functions_on_option() {
+    #[derive(Debug, PartialEq, Facet)]
+    struct Parent {
+        #[facet(serialize_with = hex_or_none, deserialize_with = parse_hex_or_none)]
+        parent: Option<u64>,
+    }
+
+    fn hex_or_none(value: &Option<u64>) -> Option<String> {
+        value.map(|value| format!("{value:x}"))
+    }
+
+    fn parse_hex_or_none(hex: Option<String>) -> Result<Option<u64>, ParseIntError> {
+        hex.map(|hex| u64::from_str_radix(&hex, 16)).transpose()
+    }
+
+    let json = to_string(&Parent { parent: None });
+    assert_eq!(json, r#"{"parent":null}"#);
+    assert_eq!(from_str::<Parent>(&json)?, Parent { parent: None });
+    assert_eq!(
+        from_str::<Parent>(r#"{"parent":"ff"}"#)?,
+        Parent { parent: Some(255) }
+    );
+}
+
+#[test]
+fn test_failed_conversion_is_reported() {
+    let err = from_str::<Commit>(r#"{"id":"xyz","tags":"a","message":""}"#)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("invalid digit found in string"), "{err}");
+
+    let err = from_str::<Commit>(r#"{"id":"1","tags":"","message":""}"#)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("expected at least one tag"), "{err}");
+}
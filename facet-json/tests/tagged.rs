@@ -0,0 +1,141 @@
+use facet::Facet;
+use facet_json::{TaggedDeserError, from_str_tagged, from_str_tagged_with_key};
+use facet_reflect::ShapeRegistry;
+use facet_testhelpers::test;
+
+#[derive(Debug, PartialEq, Facet)]
+#[facet(type_tag = "com.acme.Order")]
+struct Order {
+    id: u64,
+    items: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Facet)]
+#[facet(type_tag = "com.acme.Refund")]
+struct Refund<'a> {
+    order_id: u64,
+    reason: &'a str,
+}
+
+#[derive(Debug, PartialEq, Facet)]
+struct Ping {
+    seq: u32,
+}
+
+#[derive(Debug, PartialEq, Facet)]
+#[facet(deny_unknown_fields)]
+struct Pong {
+    seq: u32,
+    late: bool,
+}
+
+#[derive(Debug, PartialEq, Facet)]
+struct Page<T> {
+    items: Vec<T>,
+}
+
+fn registry() -> ShapeRegistry<'static> {
+    let mut registry = ShapeRegistry::new();
+    registry
+        .register::<Order>()
+        .register::<Refund>()
+        .register::<Ping>()
+        .register::<Pong>()
+        .register::<Page<u32>>()
+        .register::<Page<String>>();
+    registry
+}
+
+#[test]
+fn test_registry_lookup() {
+    let registry = registry();
+    assert_eq!(registry.by_tag("com.acme.Order"), Some(Order::SHAPE));
+    assert_eq!(registry.by_type_name("Order"), Some(Order::SHAPE));
+    assert_eq!(registry.lookup("Ping"), Some(Ping::SHAPE));
+    assert_eq!(registry.by_tag("Ping"), None);
+    assert_eq!(registry.lookup("com.acme.Invoice"), None);
+}
+
+#[test]
+fn test_registry_generic_instantiations() {
+    let registry = registry();
+    assert_eq!(registry.by_type_name("Page<u32>"), Some(Page::<u32>::SHAPE));
+    assert_eq!(
+        registry.by_type_name("Page<String>"),
+        Some(Page::<String>::SHAPE)
+    );
+    assert_eq!(registry.by_type_name("Page"), None);
+
+    let value = from_str_tagged(&registry, r#"{"@type": "Page<u32>", "items": [1, 2]}"#)?;
+    assert_eq!(
+        value.downcast::<Page<u32>>().unwrap(),
+        Page { items: vec![1, 2] }
+    );
+}
+
+#[test]
+fn test_deserialize_tagged_deny_unknown_fields() {
+    let registry = registry();
+    let expected = Pong { seq: 3, late: true };
+
+    for input in [
+        r#"{"@type": "Pong", "seq": 3, "late": true}"#,
+        r#"{"seq": 3, "@type": "Pong", "late": true}"#,
+        r#"{"seq": 3, "late": true, "@type": "Pong"}"#,
+    ] {
+        let value = from_str_tagged(&registry, input)?;
+        assert_eq!(value.downcast::<Pong>().unwrap(), expected);
+    }
+
+    // Only the tag is skipped, other unknown fields are still rejected
+    let input = r#"{"@type": "Pong", "seq": 3, "late": true, "extra": 1}"#;
+    let err = from_str_tagged(&registry, input).unwrap_err();
+    assert!(matches!(err, TaggedDeserError::Deserialize(_)));
+}
+
+#[test]
+fn test_deserialize_tagged() {
+    let registry = registry();
+
+    let value = from_str_tagged(
+        &registry,
+        r#"{"@type": "com.acme.Order", "id": 7, "items": ["tea"]}"#,
+    )?;
+    assert_eq!(
+        value.downcast::<Order>().unwrap(),
+        Order {
+            id: 7,
+            items: vec!["tea".to_string()]
+        }
+    );
+
+    let input = r#"{"order_id": 7, "@type": "com.acme.Refund", "reason": "cold"}"#;
+    let value = from_str_tagged(&registry, input)?;
+    assert_eq!(
+        value.downcast_ref::<Refund>(),
+        Some(&Refund {
+            order_id: 7,
+            reason: "cold"
+        })
+    );
+
+    let value = from_str_tagged_with_key(&registry, "kind", r#"{"kind": "Ping", "seq": 3}"#)?;
+    assert_eq!(value.downcast::<Ping>().unwrap(), Ping { seq: 3 });
+}
+
+#[test]
+fn test_deserialize_tagged_errors() {
+    let registry = registry();
+
+    let err = from_str_tagged(&registry, r#"{"@type": "com.acme.Invoice"}"#).unwrap_err();
+    assert!(matches!(err, TaggedDeserError::UnknownTag(tag) if tag == "com.acme.Invoice"));
+
+    let err = from_str_tagged(&registry, r#"{"id": 7, "items": []}"#).unwrap_err();
+    assert!(matches!(err, TaggedDeserError::MissingTag));
+
+    let err = from_str_tagged(&registry, r#"[1, 2]"#).unwrap_err();
+    assert!(matches!(err, TaggedDeserError::MissingTag));
+
+    let err = from_str_tagged(&registry, r#"{"@type": "com.acme.Order", "id": "x"}"#).unwrap_err();
+    assert!(matches!(err, TaggedDeserError::Deserialize(_)));
+}
@@ -0,0 +1,44 @@
+use std::borrow::Cow;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use facet::Facet;
+use facet_json::{from_str, to_string};
+use facet_testhelpers::test;
+
+#[test]
+fn unsized_pointers_round_trip() {
+    #[derive(Facet, Debug, PartialEq)]
+    struct Interned<'a> {
+        name: Arc<str>,
+        alias: Box<str>,
+        ids: Rc<[u32]>,
+        tags: Box<[String]>,
+        raw: Cow<'a, [u8]>,
+    }
+
+    let interned = Interned {
+        name: Arc::from("facet"),
+        alias: "fct".into(),
+        ids: Rc::from(vec![1, 2, 3]),
+        tags: vec!["a".to_string(), "b".to_string()].into_boxed_slice(),
+        raw: Cow::Borrowed(&[4, 5]),
+    };
+    let json = to_string(&interned);
+    assert_eq!(
+        json,
+        r#"{"name":"facet","alias":"fct","ids":[1,2,3],"tags":["a","b"],"raw":[4,5]}"#
+    );
+    assert_eq!(from_str::<Interned>(&json)?, interned);
+}
+
+#[test]
+fn shared_strings_in_collections() {
+    let names: Vec<Arc<str>> = vec![Arc::from("a"), Arc::from("b")];
+    let json = to_string(&names);
+    assert_eq!(json, r#"["a","b"]"#);
+    assert_eq!(from_str::<Vec<Arc<str>>>(&json)?, names);
+
+    let empty: Box<[u8]> = Box::new([]);
+    assert_eq!(from_str::<Box<[u8]>>(&to_string(&empty))?, empty);
+}
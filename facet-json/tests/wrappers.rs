@@ -0,0 +1,61 @@
+use std::cmp::Reverse;
+use std::num::{Saturating, Wrapping};
+use std::sync::atomic::{AtomicBool, AtomicI32, AtomicU64, Ordering};
+
+use facet::Facet;
+use facet_json::{SerializeOptions, from_str, to_string, to_string_with_options};
+use facet_testhelpers::test;
+
+#[derive(Facet, Debug)]
+struct Metrics {
+    requests: AtomicU64,
+    delta: AtomicI32,
+    healthy: AtomicBool,
+}
+
+#[test]
+fn atomics_round_trip() {
+    let metrics = Metrics {
+        requests: AtomicU64::new(1234),
+        delta: AtomicI32::new(-5),
+        healthy: AtomicBool::new(true),
+    };
+    let json = to_string(&metrics);
+    assert_eq!(json, r#"{"requests":1234,"delta":-5,"healthy":true}"#);
+
+    let options = SerializeOptions::new().atomic_ordering(Ordering::Relaxed);
+    assert_eq!(to_string_with_options(&metrics, &options), json);
+
+    let metrics = from_str::<Metrics>(&json)?;
+    assert_eq!(metrics.requests.load(Ordering::SeqCst), 1234);
+    assert_eq!(metrics.delta.load(Ordering::SeqCst), -5);
+    assert!(metrics.healthy.load(Ordering::SeqCst));
+}
+
+#[test]
+#[should_panic(expected = "atomics can't be loaded with a `Release` or `AcqRel` ordering")]
+fn atomic_ordering_must_be_valid_for_loads() {
+    let _ = SerializeOptions::new().atomic_ordering(Ordering::Release);
+}
+
+#[test]
+fn wrappers_are_transparent() {
+    #[derive(Facet, Debug, PartialEq)]
+    struct Counters {
+        wrapping: Wrapping<u32>,
+        saturating: Saturating<i8>,
+        priority: Reverse<u8>,
+    }
+
+    let counters = Counters {
+        wrapping: Wrapping(u32::MAX),
+        saturating: Saturating(-3),
+        priority: Reverse(2),
+    };
+    let json = to_string(&counters);
+    assert_eq!(
+        json,
+        r#"{"wrapping":4294967295,"saturating":-3,"priority":2}"#
+    );
+    assert_eq!(from_str::<Counters>(&json)?, counters);
+}
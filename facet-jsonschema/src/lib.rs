@@ -6,7 +6,7 @@
 
 extern crate facet_core as facet;
 use facet::{PointerType, SmartPointerDef};
-use facet_core::{Def, Facet, ScalarDef, Shape, Type, UserType};
+use facet_core::{Def, Facet, FieldAttribute, ScalarDef, Shape, Type, UserType};
 
 use std::io::Write;
 
@@ -47,7 +47,7 @@ pub fn to_string<'a, T: Facet<'a>>() -> String {
         }
     }
 
-    serialize(T::SHAPE, &[], &mut buffer).unwrap();
+    serialize(T::SHAPE, &[], &[], &mut buffer).unwrap();
     write!(buffer, "}}").unwrap();
     String::from_utf8(buffer).unwrap()
 }
@@ -55,6 +55,7 @@ pub fn to_string<'a, T: Facet<'a>>() -> String {
 fn serialize<'shape, W: Write>(
     shape: &'shape Shape<'shape>,
     doc: &[&str],
+    constraints: &[FieldAttribute],
     writer: &mut W,
 ) -> std::io::Result<()> {
     serialize_doc(&[shape.doc, doc].concat(), writer)?;
@@ -92,16 +93,17 @@ fn serialize<'shape, W: Write>(
 
     // Then check the def system (Def)
     match shape.def {
-        Def::Scalar(ref scalar_def) => serialize_scalar(scalar_def, writer)?,
+        Def::Scalar(ref scalar_def) => serialize_scalar(scalar_def, constraints, writer)?,
         Def::Map(_map_def) => todo!("Map"),
         Def::List(list_def) => serialize_list(list_def, writer)?,
         Def::Slice(slice_def) => serialize_slice(slice_def, writer)?,
         Def::Array(array_def) => serialize_array(array_def, writer)?,
         Def::Option(option_def) => serialize_option(option_def, writer)?,
+        Def::Result(result_def) => serialize_result(result_def, writer)?,
         Def::SmartPointer(SmartPointerDef {
             pointee: Some(inner_shape),
             ..
-        }) => serialize(inner_shape(), &[], writer)?,
+        }) => serialize(inner_shape(), &[], constraints, writer)?,
         Def::Undefined => {
             // Handle the case when not yet migrated to the Type enum
             // For primitives, we can try to infer the type
@@ -124,7 +126,7 @@ fn serialize<'shape, W: Write>(
                     }
                 }
                 Type::Pointer(PointerType::Reference(pt) | PointerType::Raw(pt)) => {
-                    serialize((pt.target)(), &[], writer)?
+                    serialize((pt.target)(), &[], constraints, writer)?
                 }
                 _ => {
                     write!(writer, "\"type\": \"unknown\"")?;
@@ -148,7 +150,11 @@ fn serialize_doc<W: Write>(doc: &[&str], writer: &mut W) -> Result<(), std::io::
 }
 
 /// Serialize a scalar definition to JSON schema format.
-fn serialize_scalar<W: Write>(scalar_def: &ScalarDef, writer: &mut W) -> std::io::Result<()> {
+fn serialize_scalar<W: Write>(
+    scalar_def: &ScalarDef,
+    constraints: &[FieldAttribute],
+    writer: &mut W,
+) -> std::io::Result<()> {
     match scalar_def.affinity {
         facet_core::ScalarAffinity::Number(number_affinity) => {
             match number_affinity.bits {
@@ -161,7 +167,13 @@ fn serialize_scalar<W: Write>(scalar_def: &ScalarDef, writer: &mut W) -> std::io
                     match sign {
                         facet_core::Signedness::Unsigned => {
                             write!(writer, ", \"format\": \"uint{bits}\"")?;
-                            write!(writer, ", \"minimum\": 0")?;
+                            // A `min` constraint replaces the implicit minimum
+                            if !constraints
+                                .iter()
+                                .any(|c| matches!(c, FieldAttribute::Min(_)))
+                            {
+                                write!(writer, ", \"minimum\": 0")?;
+                            }
                         }
                         facet_core::Signedness::Signed => {
                             write!(writer, ", \"format\": \"int{bits}\"")?;
@@ -210,18 +222,62 @@ fn serialize_struct<W: Write>(
         }
         first = false;
         write!(writer, "\"{}\": {{", field.name)?;
-        serialize(field.shape(), field.doc, writer)?;
+        serialize(field.shape(), field.doc, field.attributes, writer)?;
+        serialize_constraints(field.shape(), field.attributes, writer)?;
         write!(writer, "}}")?;
     }
     write!(writer, "}}")?;
     Ok(())
 }
 
+/// Serialize the constraints set on a field, like `#[facet(max_len = 10)]`, as the matching
+/// JSON schema keywords.
+fn serialize_constraints<W: Write>(
+    shape: &Shape,
+    constraints: &[FieldAttribute],
+    writer: &mut W,
+) -> std::io::Result<()> {
+    let (min_len, max_len) = length_keywords(shape);
+    for constraint in constraints {
+        match constraint {
+            FieldAttribute::Min(min) => write!(writer, ", \"minimum\": {min}")?,
+            FieldAttribute::Max(max) => write!(writer, ", \"maximum\": {max}")?,
+            FieldAttribute::MinLen(len) => write!(writer, ", \"{min_len}\": {len}")?,
+            FieldAttribute::MaxLen(len) => write!(writer, ", \"{max_len}\": {len}")?,
+            FieldAttribute::NonEmpty => write!(writer, ", \"{min_len}\": 1")?,
+            FieldAttribute::Pattern(pattern) => {
+                let pattern = pattern.replace('\\', "\\\\").replace('"', "\\\"");
+                write!(writer, ", \"pattern\": \"{pattern}\"")?
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// The keywords bounding the length of values of this shape: characters for strings, items
+/// for arrays and properties for objects.
+fn length_keywords(shape: &Shape) -> (&'static str, &'static str) {
+    match shape.def {
+        Def::List(_) | Def::Slice(_) | Def::Array(_) | Def::Set(_) => ("minItems", "maxItems"),
+        Def::Map(_) => ("minProperties", "maxProperties"),
+        Def::SmartPointer(SmartPointerDef {
+            pointee: Some(inner_shape),
+            ..
+        }) => length_keywords(inner_shape()),
+        _ => match shape.ty {
+            Type::Pointer(PointerType::Reference(pt)) => length_keywords((pt.target)()),
+            Type::Sequence(_) => ("minItems", "maxItems"),
+            _ => ("minLength", "maxLength"),
+        },
+    }
+}
+
 /// Serialize a list definition to JSON schema format.
 fn serialize_list<W: Write>(list_def: facet_core::ListDef, writer: &mut W) -> std::io::Result<()> {
     write!(writer, "\"type\": \"array\",")?;
     write!(writer, "\"items\": {{")?;
-    serialize(list_def.t(), &[], writer)?;
+    serialize(list_def.t(), &[], &[], writer)?;
     write!(writer, "}}")?;
     Ok(())
 }
@@ -233,7 +289,7 @@ fn serialize_slice<W: Write>(
 ) -> std::io::Result<()> {
     write!(writer, "\"type\": \"array\",")?;
     write!(writer, "\"items\": {{")?;
-    serialize(slice_def.t(), &[], writer)?;
+    serialize(slice_def.t(), &[], &[], writer)?;
     write!(writer, "}}")?;
     Ok(())
 }
@@ -247,7 +303,7 @@ fn serialize_array<W: Write>(
     write!(writer, "\"minItems\": {},", array_def.n)?;
     write!(writer, "\"maxItems\": {},", array_def.n)?;
     write!(writer, "\"items\": {{")?;
-    serialize(array_def.t(), &[], writer)?;
+    serialize(array_def.t(), &[], &[], writer)?;
     write!(writer, "}}")?;
     Ok(())
 }
@@ -261,6 +317,33 @@ fn serialize_option<W: Write>(
     unimplemented!("serialize_option");
 }
 
+/// Serialize a result definition to JSON schema format: an object with either an `Ok` or an
+/// `Err` property.
+fn serialize_result<W: Write>(
+    result_def: facet_core::ResultDef,
+    writer: &mut W,
+) -> std::io::Result<()> {
+    write!(writer, "\"oneOf\": [")?;
+    for (i, (name, shape)) in [("Ok", result_def.t()), ("Err", result_def.e())]
+        .into_iter()
+        .enumerate()
+    {
+        if i > 0 {
+            write!(writer, ",")?;
+        }
+        write!(writer, "{{")?;
+        write!(writer, "\"type\": \"object\",")?;
+        write!(writer, "\"properties\": {{\"{name}\": {{")?;
+        serialize(shape, &[], &[], writer)?;
+        write!(writer, "}}}},")?;
+        write!(writer, "\"required\": [\"{name}\"],")?;
+        write!(writer, "\"additionalProperties\": false")?;
+        write!(writer, "}}")?;
+    }
+    write!(writer, "]")?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     extern crate alloc;
@@ -306,4 +389,22 @@ mod tests {
         let schema = to_string::<TestStruct>();
         assert_snapshot!(schema);
     }
+
+    #[test]
+    fn test_constraints() {
+        #[derive(Facet)]
+        struct TestStruct {
+            #[facet(min = 1, max = 65535)]
+            port: u16,
+            #[facet(min = -1.5)]
+            offset: f64,
+            #[facet(non_empty, max_len = 32, pattern = "^[a-z]+\\d*$")]
+            name: String,
+            #[facet(min_len = 1, max_len = 4)]
+            tags: Vec<String>,
+        }
+
+        let schema = to_string::<TestStruct>();
+        assert_snapshot!(schema);
+    }
 }
@@ -1,12 +1,20 @@
 use facet_macros_parse::function::*;
-use facet_macros_parse::{FunctionSignature, Ident, Span, TokenStream, extract_type_params};
+use facet_macros_parse::{
+    Delimiter, FunctionSignature, Group, Ident, Span, TokenStream, TokenTree, extract_type_params,
+};
 use quote::quote;
 
 /// Entry point for the facet_fn attribute macro
-pub fn facet_fn(_attr: TokenStream, item: TokenStream) -> TokenStream {
+pub fn facet_fn(attr: TokenStream, item: TokenStream) -> TokenStream {
     // Convert to proc_macro2 for parsing (quote requires it)
     let parsed = parse_function_signature(item);
-    let result = generate_function_shape(parsed);
+    let result = match attr.to_string().trim() {
+        "" => generate_function_shape(parsed),
+        "dynamic" => generate_dynamic_function_shape(parsed),
+        _ => quote! {
+            compile_error!("expected `#[facet_fn]` or `#[facet_fn(dynamic)]`");
+        },
+    };
 
     // Convert back
     result.to_string().parse().unwrap()
@@ -21,45 +29,21 @@ pub fn fn_shape(input: TokenStream) -> TokenStream {
 }
 
 pub fn generate_function_shape(parsed: FunctionSignature) -> TokenStream {
-    let fn_name = parsed.name;
-    let generics = parsed.generics;
-    let params = parsed.parameters;
-    let return_type = parsed.return_type;
-    let body = parsed.body;
-
-    let hidden_mod = Ident::new(&format!("__fn_shape_{}", fn_name), Span::call_site());
-    let shape_name = Ident::new(
-        &format!("{}_SHAPE", fn_name.to_string().to_uppercase()),
-        Span::call_site(),
-    );
-    let defs: Vec<_> = params
-        .iter()
-        .map(|p| {
-            let name = &p.name;
-            let ty = &p.param_type_tokens();
-            quote! { #name: #ty }
-        })
-        .collect();
-    let idents: Vec<_> = params
-        .iter()
-        .map(|p| {
-            let name = &p.name;
-            quote! { #name }
-        })
-        .collect();
-    let types: Vec<_> = params
+    let generics = parsed.generics.clone();
+    // Elided lifetimes can't appear in the return type of `shape`, which takes no arguments
+    let types: Vec<_> = parsed
+        .parameters
         .iter()
-        .map(|p| {
-            let ty = &p.param_type_tokens();
-            quote! { #ty }
-        })
+        .map(|p| with_static_lifetimes(p.param_type_tokens()))
         .collect();
-    let names: Vec<_> = params
+    let return_type = with_static_lifetimes(parsed.return_type.clone());
+    let names: Vec<_> = parsed
+        .parameters
         .iter()
         .map(|p| p.name.to_string())
         .collect::<Vec<_>>();
-    let arity = params.len();
-    let fn_name_str = fn_name.to_string();
+    let arity = parsed.parameters.len();
+    let fn_name_str = parsed.name.to_string();
 
     // Extract  type parameters for PhantomData using unsynn parsing
     let generics_type = if let Some(ref generics_ts) = generics {
@@ -75,6 +59,36 @@ pub fn generate_function_shape(parsed: FunctionSignature) -> TokenStream {
         .collect();
 
     let shape_definition = quote! {
+        #[derive(Debug, Clone)]
+        pub struct FunctionShape<Args, Ret, Generics = ()> {
+            pub name: &'static str,
+            pub param_count: usize,
+            pub param_names: &'static [&'static str],
+            pub documentation: &'static [&'static str],
+            _args: core::marker::PhantomData<Args>,
+            _ret: core::marker::PhantomData<Ret>,
+            _generics: core::marker::PhantomData<Generics>,
+        }
+
+        impl<Args, Ret, Generics> FunctionShape<Args, Ret, Generics> {
+            pub const fn new(
+                name: &'static str,
+                param_count: usize,
+                param_names: &'static [&'static str],
+                documentation: &'static [&'static str],
+            ) -> Self {
+                Self {
+                    name,
+                    param_count,
+                    param_names,
+                    documentation,
+                    _args: core::marker::PhantomData,
+                    _ret: core::marker::PhantomData,
+                    _generics: core::marker::PhantomData,
+                }
+            }
+        }
+
         pub fn shape #generics () -> FunctionShape<( #( #types ),* ), #return_type, #generics_type> {
             FunctionShape::new(
                 #fn_name_str,
@@ -85,6 +99,111 @@ pub fn generate_function_shape(parsed: FunctionSignature) -> TokenStream {
         }
     };
 
+    wrap_function(parsed, shape_definition)
+}
+
+/// Like [`generate_function_shape`], but the shape is a `::facet::FunctionShape`, which records
+/// the parameter and return shapes and can call the function through type erasure.
+///
+/// This is what `#[facet_fn(dynamic)]` emits. Every parameter and the return type must implement
+/// `Facet`.
+pub fn generate_dynamic_function_shape(parsed: FunctionSignature) -> TokenStream {
+    let generics = parsed.generics.clone();
+    let idents: Vec<_> = parsed
+        .parameters
+        .iter()
+        .map(|p| {
+            let name = &p.name;
+            quote! { #name }
+        })
+        .collect();
+    // The shape describes the function with `'static` arguments: elided lifetimes can't be
+    // named in a `where` clause, and a type-erased call can't tie its arguments to a caller's
+    // lifetime anyway.
+    let types: Vec<_> = parsed
+        .parameters
+        .iter()
+        .map(|p| with_static_lifetimes(p.param_type_tokens()))
+        .collect();
+    let static_return_type = with_static_lifetimes(parsed.return_type.clone());
+    let names: Vec<_> = parsed
+        .parameters
+        .iter()
+        .map(|p| p.name.to_string())
+        .collect::<Vec<_>>();
+    let fn_name_str = parsed.name.to_string();
+
+    let documentation_lines: Vec<_> = parsed
+        .documentation
+        .iter()
+        .map(|doc| quote! { #doc })
+        .collect();
+
+    // Every parameter and the return type must implement `Facet`, so their shapes can be
+    // recorded and so arguments can be passed as a tuple through a type-erased call.
+    let mut bounded = Vec::new();
+    for ty in types.iter().chain(core::iter::once(&static_return_type)) {
+        if !bounded
+            .iter()
+            .any(|b: &TokenStream| b.to_string() == ty.to_string())
+        {
+            bounded.push(ty.clone());
+        }
+    }
+    let where_clause = quote! {
+        where #( #bounded: ::facet::Facet<'static> ),*
+    };
+
+    let shape_definition = quote! {
+        pub fn shape #generics () -> ::facet::FunctionShape #where_clause {
+            ::facet::FunctionShape::builder()
+                .name(#fn_name_str)
+                .param_names(&[ #( #names ),* ])
+                .param_shapes(const { &[ #( <#types as ::facet::Facet<'static>>::SHAPE ),* ] })
+                .args_shape(<( #( #types, )* ) as ::facet::Facet<'static>>::SHAPE)
+                .return_shape(<#static_return_type as ::facet::Facet<'static>>::SHAPE)
+                .documentation(&[ #( #documentation_lines ),* ])
+                .call(|args, ret| unsafe {
+                    let ( #( #idents, )* ) = args.read::<( #( #types, )* )>();
+                    ret.put::<#static_return_type>(inner( #( #idents ),* ))
+                })
+                .build()
+        }
+    };
+
+    wrap_function(parsed, shape_definition)
+}
+
+/// Moves the function into a hidden module next to its `shape` function (defined by
+/// `shape_definition`), and re-exports both under their public names.
+fn wrap_function(parsed: FunctionSignature, shape_definition: TokenStream) -> TokenStream {
+    let fn_name = parsed.name;
+    let generics = parsed.generics;
+    let params = parsed.parameters;
+    let return_type = parsed.return_type;
+    let body = parsed.body;
+
+    let hidden_mod = Ident::new(&format!("__fn_shape_{}", fn_name), Span::call_site());
+    let shape_name = Ident::new(
+        &format!("{}_SHAPE", fn_name.to_string().to_uppercase()),
+        Span::call_site(),
+    );
+    let defs: Vec<_> = params
+        .iter()
+        .map(|p| {
+            let name = &p.name;
+            let ty = &p.param_type_tokens();
+            quote! { #name: #ty }
+        })
+        .collect();
+    let idents: Vec<_> = params
+        .iter()
+        .map(|p| {
+            let name = &p.name;
+            quote! { #name }
+        })
+        .collect();
+
     let out = quote! {
         // 1) Move the real implementation into a private module
         #[allow(non_snake_case)]
@@ -92,36 +211,6 @@ pub fn generate_function_shape(parsed: FunctionSignature) -> TokenStream {
             use super::*;
             pub(super) fn inner #generics ( #( #defs ),* ) -> #return_type #body
 
-            #[derive(Debug, Clone)]
-            pub struct FunctionShape<Args, Ret, Generics = ()> {
-                pub name: &'static str,
-                pub param_count: usize,
-                pub param_names: &'static [&'static str],
-                pub documentation: &'static [&'static str],
-                _args: core::marker::PhantomData<Args>,
-                _ret: core::marker::PhantomData<Ret>,
-                _generics: core::marker::PhantomData<Generics>,
-            }
-
-            impl<Args, Ret, Generics> FunctionShape<Args, Ret, Generics> {
-                pub const fn new(
-                    name: &'static str,
-                    param_count: usize,
-                    param_names: &'static [&'static str],
-                    documentation: &'static [&'static str],
-                ) -> Self {
-                    Self {
-                        name,
-                        param_count,
-                        param_names,
-                        documentation,
-                        _args: core::marker::PhantomData,
-                        _ret: core::marker::PhantomData,
-                        _generics: core::marker::PhantomData,
-                    }
-                }
-            }
-
             #shape_definition
         }
 
@@ -137,6 +226,76 @@ pub fn generate_function_shape(parsed: FunctionSignature) -> TokenStream {
     out
 }
 
+/// Gives every elided (`&T`) and anonymous (`'_`) lifetime in a type the name `'static`.
+///
+/// Function pointer and `Fn` trait signatures (`fn(&str) -> &str`) are left alone, since their
+/// lifetimes are late-bound.
+fn with_static_lifetimes(ty: TokenStream) -> TokenStream {
+    let mut out = Vec::new();
+    let mut tokens = ty.into_iter().peekable();
+    let mut after_ident = false;
+    // Inside a signature: how many `<` are open since it started
+    let mut signature_depth: Option<usize> = None;
+    let mut prev_dash = false;
+    while let Some(token) = tokens.next() {
+        let is_ident =
+            matches!(&token, TokenTree::Ident(ident) if ident != "mut" && ident != "dyn");
+        let is_dash = matches!(&token, TokenTree::Punct(punct) if punct.as_char() == '-');
+        if let Some(depth) = signature_depth.as_mut() {
+            // Copy the signature verbatim, up to the end of the type it's part of
+            if let TokenTree::Punct(punct) = &token {
+                match punct.as_char() {
+                    '<' => *depth += 1,
+                    '>' if prev_dash => {}
+                    '>' if *depth == 0 => signature_depth = None,
+                    '>' => *depth -= 1,
+                    ',' | '+' if *depth == 0 => signature_depth = None,
+                    _ => {}
+                }
+            }
+            if signature_depth.is_some() {
+                out.push(token);
+                prev_dash = is_dash;
+                after_ident = is_ident;
+                continue;
+            }
+        }
+        match token {
+            TokenTree::Group(group)
+                if group.delimiter() == Delimiter::Parenthesis && after_ident =>
+            {
+                out.push(TokenTree::Group(group));
+                signature_depth = Some(0);
+            }
+            TokenTree::Group(group) => {
+                let mut rewritten =
+                    Group::new(group.delimiter(), with_static_lifetimes(group.stream()));
+                rewritten.set_span(group.span());
+                out.push(TokenTree::Group(rewritten));
+            }
+            TokenTree::Punct(punct) if punct.as_char() == '&' => {
+                out.push(TokenTree::Punct(punct));
+                let named =
+                    matches!(tokens.peek(), Some(TokenTree::Punct(p)) if p.as_char() == '\'');
+                if !named {
+                    out.extend(quote! { 'static });
+                }
+            }
+            TokenTree::Punct(punct)
+                if punct.as_char() == '\''
+                    && matches!(tokens.peek(), Some(TokenTree::Ident(ident)) if ident == "_") =>
+            {
+                tokens.next();
+                out.extend(quote! { 'static });
+            }
+            token => out.push(token),
+        }
+        prev_dash = is_dash;
+        after_ident = is_ident;
+    }
+    out.into_iter().collect()
+}
+
 fn generate_fn_shape_call(parsed: ParsedFnShapeInput) -> TokenStream {
     let fn_name = parsed.name;
     let generic_args = parsed.generics;
@@ -153,3 +312,62 @@ fn generate_fn_shape_call(parsed: ParsedFnShapeInput) -> TokenStream {
         quote! { #shape_name() }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::with_static_lifetimes;
+    use facet_macros_parse::TokenStream;
+
+    // Token spacing depends on how tokens were built, so it's left out of comparisons
+    fn rewrite(ty: &str) -> String {
+        let rewritten = with_static_lifetimes(ty.parse::<TokenStream>().unwrap());
+        rewritten.to_string().replace(' ', "")
+    }
+
+    fn tokens(ty: &str) -> String {
+        ty.parse::<TokenStream>()
+            .unwrap()
+            .to_string()
+            .replace(' ', "")
+    }
+
+    #[test]
+    fn elided_references_are_static() {
+        assert_eq!(rewrite("&str"), tokens("&'static str"));
+        assert_eq!(rewrite("&mut Vec<u8>"), tokens("&'static mut Vec<u8>"));
+        assert_eq!(
+            rewrite("Option<&[&str]>"),
+            tokens("Option<&'static [&'static str]>")
+        );
+        assert_eq!(rewrite("(&str, u32)"), tokens("(&'static str, u32)"));
+    }
+
+    #[test]
+    fn anonymous_lifetimes_are_static() {
+        assert_eq!(rewrite("Cow<'_, str>"), tokens("Cow<'static, str>"));
+        assert_eq!(rewrite("&'_ str"), tokens("&'static str"));
+    }
+
+    #[test]
+    fn named_lifetimes_are_kept() {
+        assert_eq!(rewrite("&'a str"), tokens("&'a str"));
+        assert_eq!(rewrite("Cow<'static, str>"), tokens("Cow<'static, str>"));
+    }
+
+    #[test]
+    fn signatures_are_kept() {
+        assert_eq!(rewrite("fn(&str) -> &str"), tokens("fn(&str) -> &str"));
+        assert_eq!(
+            rewrite("(fn(&str) -> &str, &str)"),
+            tokens("(fn(&str) -> &str, &'static str)")
+        );
+        assert_eq!(
+            rewrite("Box<dyn Fn(&str) -> Vec<&str>>"),
+            tokens("Box<dyn Fn(&str) -> Vec<&str>>")
+        );
+        assert_eq!(
+            rewrite("(Box<dyn Fn(&u8)>, &u8)"),
+            tokens("(Box<dyn Fn(&u8)>, &'static u8)")
+        );
+    }
+}
@@ -88,6 +88,44 @@ pub enum PFacetAttr {
     /// Valid in container
     /// `#[facet(type_tag = "com.example.MyType")]` — identify type by tag and serialize with this tag
     TypeTag { content: String },
+
+    /// Valid in field
+    /// `#[facet(min = 0)]` — the smallest value a number may take
+    Min { expr: TokenStream },
+
+    /// Valid in field
+    /// `#[facet(max = 100)]` — the largest value a number may take
+    Max { expr: TokenStream },
+
+    /// Valid in field
+    /// `#[facet(min_len = 1)]` — the minimum length of a string, list or map
+    MinLen { expr: TokenStream },
+
+    /// Valid in field
+    /// `#[facet(max_len = 64)]` — the maximum length of a string, list or map
+    MaxLen { expr: TokenStream },
+
+    /// Valid in field
+    /// `#[facet(pattern = "^[a-z]+$")]` — a regular expression strings must match
+    Pattern { literal: TokenStream },
+
+    /// Valid in field
+    /// `#[facet(non_empty)]` — strings, lists and maps must not be empty
+    NonEmpty,
+
+    /// Valid in field
+    /// `#[facet(serialize_with = func)]` — serialize the value returned by `func(&field)` instead
+    SerializeWith { expr: TokenStream },
+
+    /// Valid in field
+    /// `#[facet(deserialize_with = func)]` — deserialize the type `func` takes, then convert it
+    /// into the field with `func`
+    DeserializeWith { expr: TokenStream },
+
+    /// Valid in field
+    /// `#[facet(proxy = Type)]` — serialize as and deserialize from `Type`, converting with
+    /// `From<&Field>` and `TryFrom<Type>`
+    Proxy { ty: TokenStream },
 }
 
 impl PFacetAttr {
@@ -146,6 +184,33 @@ impl PFacetAttr {
                         content: type_tag.expr.as_str().to_string(),
                     });
                 }
+                FacetInner::Min(min) => dest.push(PFacetAttr::Min {
+                    expr: min.expr.to_token_stream(),
+                }),
+                FacetInner::Max(max) => dest.push(PFacetAttr::Max {
+                    expr: max.expr.to_token_stream(),
+                }),
+                FacetInner::MinLen(min_len) => dest.push(PFacetAttr::MinLen {
+                    expr: min_len.expr.to_token_stream(),
+                }),
+                FacetInner::MaxLen(max_len) => dest.push(PFacetAttr::MaxLen {
+                    expr: max_len.expr.to_token_stream(),
+                }),
+                FacetInner::Pattern(pattern) => dest.push(PFacetAttr::Pattern {
+                    literal: pattern.value.to_token_stream(),
+                }),
+                FacetInner::NonEmpty(_) => dest.push(PFacetAttr::NonEmpty),
+                FacetInner::SerializeWith(serialize_with) => dest.push(PFacetAttr::SerializeWith {
+                    expr: serialize_with.expr.to_token_stream(),
+                }),
+                FacetInner::DeserializeWith(deserialize_with) => {
+                    dest.push(PFacetAttr::DeserializeWith {
+                        expr: deserialize_with.expr.to_token_stream(),
+                    })
+                }
+                FacetInner::Proxy(proxy) => dest.push(PFacetAttr::Proxy {
+                    ty: proxy.ty.to_token_stream(),
+                }),
             }
         }
     }
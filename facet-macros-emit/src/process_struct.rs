@@ -115,6 +115,50 @@ pub(crate) fn gen_field_from_pfield(
                     .skip_serializing_if(unsafe { ::core::mem::transmute((#predicate) as fn(&#field_ty) -> bool) })
                 });
             }
+            PFacetAttr::Min { expr } => {
+                attribute_list.push(quote! { ::facet::FieldAttribute::Min((#expr) as f64) });
+            }
+            PFacetAttr::Max { expr } => {
+                attribute_list.push(quote! { ::facet::FieldAttribute::Max((#expr) as f64) });
+            }
+            PFacetAttr::MinLen { expr } => {
+                attribute_list.push(quote! { ::facet::FieldAttribute::MinLen(#expr) });
+            }
+            PFacetAttr::MaxLen { expr } => {
+                attribute_list.push(quote! { ::facet::FieldAttribute::MaxLen(#expr) });
+            }
+            PFacetAttr::Pattern { literal } => {
+                attribute_list.push(quote! { ::facet::FieldAttribute::Pattern(#literal) });
+            }
+            PFacetAttr::NonEmpty => {
+                attribute_list.push(quote! { ::facet::FieldAttribute::NonEmpty });
+            }
+            PFacetAttr::SerializeWith { expr } => {
+                vtable_items.push(quote! {
+                    .serialize_with(::facet::SerializeWith::new(
+                        || ::facet::SerializeWith::shape_returned_by((#expr) as fn(&#field_type) -> _),
+                        |field, target| unsafe {
+                            ::facet::SerializeWith::call((#expr) as fn(&#field_type) -> _, field, target)
+                        },
+                    ))
+                });
+            }
+            PFacetAttr::DeserializeWith { expr } => {
+                vtable_items.push(quote! {
+                    .deserialize_with(::facet::DeserializeWith::new(
+                        || ::facet::DeserializeWith::shape_taken_by((#expr) as fn(_) -> ::core::result::Result<#field_type, _>),
+                        |source, field| unsafe {
+                            ::facet::DeserializeWith::call((#expr) as fn(_) -> ::core::result::Result<#field_type, _>, source, field)
+                        },
+                    ))
+                });
+            }
+            PFacetAttr::Proxy { ty } => {
+                vtable_items.push(quote! {
+                    .serialize_with(::facet::SerializeWith::proxy::<#field_type, #ty>())
+                    .deserialize_with(::facet::DeserializeWith::proxy::<#field_type, #ty>())
+                });
+            }
             // These are handled by PName or are container-level, so ignore them for field attributes.
             PFacetAttr::RenameAll { .. } => {} // Explicitly ignore rename attributes here
             PFacetAttr::Transparent
@@ -282,7 +326,16 @@ pub(crate) fn process_struct(parsed: Struct) -> TokenStream {
                 | PFacetAttr::SkipSerializingIf { .. }
                 | PFacetAttr::Flatten
                 | PFacetAttr::Child
-                | PFacetAttr::TypeTag { .. } => {}
+                | PFacetAttr::TypeTag { .. }
+                | PFacetAttr::Min { .. }
+                | PFacetAttr::Max { .. }
+                | PFacetAttr::MinLen { .. }
+                | PFacetAttr::MaxLen { .. }
+                | PFacetAttr::Pattern { .. }
+                | PFacetAttr::NonEmpty
+                | PFacetAttr::SerializeWith { .. }
+                | PFacetAttr::DeserializeWith { .. }
+                | PFacetAttr::Proxy { .. } => {}
             }
         }
         if items.is_empty() {
@@ -58,6 +58,24 @@ keyword! {
     pub KSkipSerializingIf = "skip_serializing_if";
     /// The "type_tag" keyword.
     pub KTypeTag = "type_tag";
+    /// The "min" keyword.
+    pub KMin = "min";
+    /// The "max" keyword.
+    pub KMax = "max";
+    /// The "min_len" keyword.
+    pub KMinLen = "min_len";
+    /// The "max_len" keyword.
+    pub KMaxLen = "max_len";
+    /// The "pattern" keyword.
+    pub KPattern = "pattern";
+    /// The "non_empty" keyword.
+    pub KNonEmpty = "non_empty";
+    /// The "serialize_with" keyword.
+    pub KSerializeWith = "serialize_with";
+    /// The "deserialize_with" keyword.
+    pub KDeserializeWith = "deserialize_with";
+    /// The "proxy" keyword.
+    pub KProxy = "proxy";
 }
 
 operator! {
@@ -165,6 +183,24 @@ unsynn! {
         SkipSerializingIf(SkipSerializingIfInner),
         /// A type_tag attribute that specifies the identifying tag for self describing formats
         TypeTag(TypeTagInner),
+        /// A min attribute that specifies the smallest value a number may take
+        Min(MinInner),
+        /// A max attribute that specifies the largest value a number may take
+        Max(MaxInner),
+        /// A min_len attribute that specifies the minimum length of a string, list or map
+        MinLen(MinLenInner),
+        /// A max_len attribute that specifies the maximum length of a string, list or map
+        MaxLen(MaxLenInner),
+        /// A pattern attribute that specifies a regular expression strings must match
+        Pattern(PatternInner),
+        /// A non_empty attribute that forbids empty strings, lists and maps
+        NonEmpty(KNonEmpty),
+        /// A serialize_with attribute that names a function converting the field before serializing it
+        SerializeWith(SerializeWithInner),
+        /// A deserialize_with attribute that names a function converting the field after deserializing it
+        DeserializeWith(DeserializeWithInner),
+        /// A proxy attribute that names a type the field is serialized as and deserialized from
+        Proxy(ProxyInner),
         /// Any other attribute represented as a sequence of token trees.
         Arbitrary(VerbatimUntil<Comma>),
     }
@@ -207,6 +243,86 @@ unsynn! {
         pub expr: LiteralString,
     }
 
+    /// Inner value for #[facet(min = ...)]
+    pub struct MinInner {
+        /// The "min" keyword.
+        pub _kw_min: KMin,
+        /// The equals sign '='.
+        pub _eq: Eq,
+        /// The bound, as verbatim until comma.
+        pub expr: VerbatimUntil<Comma>,
+    }
+
+    /// Inner value for #[facet(max = ...)]
+    pub struct MaxInner {
+        /// The "max" keyword.
+        pub _kw_max: KMax,
+        /// The equals sign '='.
+        pub _eq: Eq,
+        /// The bound, as verbatim until comma.
+        pub expr: VerbatimUntil<Comma>,
+    }
+
+    /// Inner value for #[facet(min_len = ...)]
+    pub struct MinLenInner {
+        /// The "min_len" keyword.
+        pub _kw_min_len: KMinLen,
+        /// The equals sign '='.
+        pub _eq: Eq,
+        /// The length, as verbatim until comma.
+        pub expr: VerbatimUntil<Comma>,
+    }
+
+    /// Inner value for #[facet(max_len = ...)]
+    pub struct MaxLenInner {
+        /// The "max_len" keyword.
+        pub _kw_max_len: KMaxLen,
+        /// The equals sign '='.
+        pub _eq: Eq,
+        /// The length, as verbatim until comma.
+        pub expr: VerbatimUntil<Comma>,
+    }
+
+    /// Inner value for #[facet(pattern = ...)]
+    pub struct PatternInner {
+        /// The "pattern" keyword.
+        pub _kw_pattern: KPattern,
+        /// The equals sign '='.
+        pub _eq: Eq,
+        /// The regular expression, as a literal string.
+        pub value: LiteralString,
+    }
+
+    /// Inner value for #[facet(serialize_with = ...)]
+    pub struct SerializeWithInner {
+        /// The "serialize_with" keyword.
+        pub _kw_serialize_with: KSerializeWith,
+        /// The equals sign '='.
+        pub _eq: Eq,
+        /// The path of the function, as verbatim until comma.
+        pub expr: VerbatimUntil<Comma>,
+    }
+
+    /// Inner value for #[facet(deserialize_with = ...)]
+    pub struct DeserializeWithInner {
+        /// The "deserialize_with" keyword.
+        pub _kw_deserialize_with: KDeserializeWith,
+        /// The equals sign '='.
+        pub _eq: Eq,
+        /// The path of the function, as verbatim until comma.
+        pub expr: VerbatimUntil<Comma>,
+    }
+
+    /// Inner value for #[facet(proxy = ...)]
+    pub struct ProxyInner {
+        /// The "proxy" keyword.
+        pub _kw_proxy: KProxy,
+        /// The equals sign '='.
+        pub _eq: Eq,
+        /// The proxy type, as verbatim until comma.
+        pub ty: VerbatimUntil<Comma>,
+    }
+
     /// Inner value for #[facet(default = ...)]
     pub struct DefaultEqualsInner {
         /// The "default" keyword.
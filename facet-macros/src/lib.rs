@@ -5,6 +5,12 @@ pub fn facet_macros(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     facet_macros_emit::facet_macros(input.into()).into()
 }
 
+/// Records a function's name, parameter names and documentation, available through
+/// `fn_shape!`.
+///
+/// With `#[facet_fn(dynamic)]`, the shape is a `facet::FunctionShape` instead: it also records
+/// parameter and return shapes, and can call the function through type erasure. Every parameter
+/// and the return type must then implement `Facet`.
 #[cfg(feature = "function")]
 #[proc_macro_attribute]
 pub fn facet_fn(
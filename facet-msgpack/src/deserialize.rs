@@ -1,7 +1,9 @@
 use crate::constants::*;
 use crate::errors::Error as DecodeError;
 
-use facet_core::{Def, Facet, Type, UserType};
+use std::ffi::{CString, OsString};
+
+use facet_core::{Def, Facet, ScalarAffinity, ShapeAttribute, Type, UserType};
 use facet_reflect::Partial;
 use log::trace;
 
@@ -198,6 +200,30 @@ impl<'input, 'shape> Decoder<'input> {
         Ok(value)
     }
 
+    /// Decodes MessagePack-encoded binary data.
+    /// Handles the following MessagePack types:
+    /// - bin8 (0xc4): byte array up to 255 bytes
+    /// - bin16 (0xc5): byte array up to 65535 bytes
+    /// - bin32 (0xc6): byte array up to 4294967295 bytes
+    ///
+    /// Ref: <https://github.com/msgpack/msgpack/blob/master/spec.md#formats-bin>
+    fn decode_bin(&mut self) -> Result<Vec<u8>, DecodeError<'static>> {
+        let len = match self.decode_u8()? {
+            MSGPACK_BIN8 => self.decode_u8()? as usize,
+            MSGPACK_BIN16 => self.decode_u16()? as usize,
+            MSGPACK_BIN32 => self.decode_u32()? as usize,
+            _ => return Err(DecodeError::UnexpectedType),
+        };
+
+        if self.offset + len > self.input.len() {
+            return Err(DecodeError::InsufficientData);
+        }
+
+        let value = self.input[self.offset..self.offset + len].to_vec();
+        self.offset += len;
+        Ok(value)
+    }
+
     /// Decodes a MessagePack-encoded map length.
     /// Handles the following MessagePack types:
     /// - fixmap (0x80 - 0x8f): map with up to 15 elements
@@ -285,6 +311,16 @@ impl<'input, 'shape> Decoder<'input> {
             || prefix == MSGPACK_STR32)
     }
 
+    /// Peeks at the next byte to check if it's binary data without advancing the offset.
+    /// Returns true if the next value is binary data, false otherwise.
+    fn peek_bin(&mut self) -> Result<bool, DecodeError<'static>> {
+        if self.offset >= self.input.len() {
+            return Err(DecodeError::InsufficientData);
+        }
+        let prefix = self.input[self.offset];
+        Ok(prefix == MSGPACK_BIN8 || prefix == MSGPACK_BIN16 || prefix == MSGPACK_BIN32)
+    }
+
     /// Skips a MessagePack value of any type.
     /// This is used when encountering unknown field names in a struct.
     fn skip_value(&mut self) -> Result<(), DecodeError<'static>> {
@@ -423,6 +459,24 @@ impl<'input, 'shape> Decoder<'input> {
         let shape = wip.shape();
         trace!("Deserializing {:?}", shape);
 
+        // Fields with `deserialize_with` or `proxy` are read as the shape they're converted from
+        if wip.deserialize_with_shape().is_some() {
+            self.deserialize_value(wip.begin_deserialize_with()?)?;
+            wip.end()?;
+            return Ok(());
+        }
+
+        // Atomics and transparent wrappers are built from the value they hold
+        let is_atomic = matches!(shape.def, Def::Scalar(sd) if sd.atomic_load.is_some());
+        if shape.inner.is_some()
+            && (is_atomic || shape.attributes.contains(&ShapeAttribute::Transparent))
+        {
+            trace!("Deserializing the inner value of {}", shape);
+            self.deserialize_value(wip.begin_inner()?)?;
+            wip.end()?;
+            return Ok(());
+        }
+
         // First check the type system (Type)
         match &shape.ty {
             Type::User(UserType::Struct(struct_type))
@@ -598,7 +652,7 @@ impl<'input, 'shape> Decoder<'input> {
         }
 
         // Then check the def system (Def)
-        if let Def::Scalar(_) = shape.def {
+        if let Def::Scalar(sd) = shape.def {
             trace!("Deserializing scalar");
             if shape.is_type::<String>() {
                 let s = self.decode_string()?;
@@ -662,6 +716,20 @@ impl<'input, 'shape> Decoder<'input> {
             } else if shape.is_type::<bool>() {
                 let b = self.decode_bool()?;
                 wip.set(b)?;
+            } else if matches!(sd.affinity, ScalarAffinity::PlatformString(_)) && self.peek_bin()? {
+                // Platform strings that aren't valid UTF-8 are encoded as binary data
+                let bytes = self.decode_bin()?;
+                if shape.is_type::<CString>() {
+                    wip.set(CString::new(bytes).map_err(|_| DecodeError::InvalidData)?)?;
+                } else if shape.is_type::<OsString>() {
+                    wip.set(os_string_from_bytes(bytes)?)?;
+                } else {
+                    return Err(DecodeError::UnsupportedType(format!("{}", shape)));
+                }
+            } else if self.peek_string()? {
+                // Other scalars, like network addresses, are parsed from their string form
+                let s = self.decode_string()?;
+                wip.parse_from_str(&s)?;
             } else {
                 return Err(DecodeError::UnsupportedType(format!("{}", shape)));
             }
@@ -715,3 +783,19 @@ impl<'input, 'shape> Decoder<'input> {
         Ok(())
     }
 }
+
+/// Rebuilds an `OsString` from the bytes of `OsStr::as_encoded_bytes`. Outside of Unix, the
+/// encoding isn't portable, so only UTF-8 is accepted
+fn os_string_from_bytes(bytes: Vec<u8>) -> Result<OsString, DecodeError<'static>> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStringExt;
+        Ok(OsString::from_vec(bytes))
+    }
+    #[cfg(not(unix))]
+    {
+        String::from_utf8(bytes)
+            .map(OsString::from)
+            .map_err(|_| DecodeError::InvalidData)
+    }
+}
@@ -1,17 +1,23 @@
 use facet_core::Facet;
-use facet_reflect::Peek;
-use facet_serialize::{Serializer, serialize_iterative}; // Import the necessary items from facet-serialize
+use facet_reflect::{Peek, ReflectError};
+pub use facet_serialize::{REDACTED, Redaction, SerializeOptions};
+use facet_serialize::{Serializer, serialize_iterative_with_options}; // Import the necessary items from facet-serialize
 use log::trace;
 use std::io::{self, Write};
 
 /// Serializes any Facet type to MessagePack bytes
 pub fn to_vec<'a, T: Facet<'a>>(value: &'a T) -> Vec<u8> {
+    to_vec_with_options(value, &SerializeOptions::default())
+}
+
+/// Serializes any Facet type to MessagePack bytes, as configured by `options`
+pub fn to_vec_with_options<'a, T: Facet<'a>>(value: &'a T, options: &SerializeOptions) -> Vec<u8> {
     let mut buffer = Vec::new();
     let peek = Peek::new(value);
     let mut serializer = MessagePackSerializer {
         writer: &mut buffer,
     }; // Create the serializer
-    serialize_iterative(peek, &mut serializer).unwrap(); // Use the iterative serializer
+    serialize_iterative_with_options(peek, &mut serializer, options).unwrap(); // Use the iterative serializer
     buffer
 }
 
@@ -24,6 +30,10 @@ struct MessagePackSerializer<'w, W: Write> {
 impl<'shape, W: Write> Serializer<'shape> for MessagePackSerializer<'_, W> {
     type Error = io::Error; // Use io::Error as the error type
 
+    fn serialize_unreadable(&mut self, error: ReflectError<'shape>) -> Result<(), Self::Error> {
+        Err(io::Error::other(error.to_plain_string()))
+    }
+
     // Implement all methods required by the Serializer trait
     // Most implementations will simply call the existing write_* helper functions.
 
@@ -51,3 +51,59 @@ fn test_str_escaped() -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(unix)]
+#[test]
+fn msgpack_platform_strings_and_socket_addrs() -> Result<()> {
+    facet_testhelpers::setup();
+
+    use std::ffi::{CString, OsString};
+    use std::net::SocketAddrV6;
+    use std::os::unix::ffi::OsStringExt;
+
+    #[derive(Facet, Debug, PartialEq)]
+    struct Endpoint {
+        path: OsString,
+        name: CString,
+        addr: SocketAddrV6,
+    }
+
+    let endpoint = Endpoint {
+        // Not valid UTF-8, so it's encoded as binary data
+        path: OsString::from_vec(vec![b'a', 0xff]),
+        name: CString::new("sock").unwrap(),
+        addr: "[::1]:443".parse().unwrap(),
+    };
+    let data = facet_msgpack::to_vec(&endpoint);
+    let back: Endpoint = from_slice(&data)?;
+    assert_eq!(back, endpoint);
+    Ok(())
+}
+
+#[test]
+fn msgpack_atomics_and_wrappers() -> Result<()> {
+    facet_testhelpers::setup();
+
+    use std::cmp::Reverse;
+    use std::num::Wrapping;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[derive(Facet, Debug)]
+    struct Snapshot {
+        hits: AtomicU32,
+        hash: Wrapping<u32>,
+        rank: Reverse<u8>,
+    }
+
+    let snapshot = Snapshot {
+        hits: AtomicU32::new(42),
+        hash: Wrapping(u32::MAX),
+        rank: Reverse(3),
+    };
+    let data = facet_msgpack::to_vec(&snapshot);
+    let back: Snapshot = from_slice(&data)?;
+    assert_eq!(back.hits.load(Ordering::SeqCst), 42);
+    assert_eq!(back.hash, Wrapping(u32::MAX));
+    assert_eq!(back.rank, Reverse(3));
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use std::num::ParseIntError;
+
+use eyre::Result;
+use facet::Facet;
+use facet_msgpack::{from_slice, to_vec};
+
+/// A `u64` written as a hexadecimal string
+#[derive(Debug, Facet)]
+#[facet(transparent)]
+struct Hex(String);
+
+impl From<&u64> for Hex {
+    fn from(value: &u64) -> Self {
+        Hex(format!("{value:x}"))
+    }
+}
+
+impl TryFrom<Hex> for u64 {
+    type Error = ParseIntError;
+
+    fn try_from(hex: Hex) -> Result<Self, Self::Error> {
+        u64::from_str_radix(&hex.0, 16)
+    }
+}
+
+// `serialize_with` functions take a reference to the field's type
+#[allow(clippy::ptr_arg)]
+fn join(tags: &Vec<String>) -> String {
+    tags.join(",")
+}
+
+fn split(tags: String) -> Result<Vec<String>, &'static str> {
+    Ok(tags.split(',').map(String::from).collect())
+}
+
+#[derive(Debug, PartialEq, Facet)]
+struct Commit {
+    #[facet(proxy = Hex)]
+    id: u64,
+    #[facet(serialize_with = join, deserialize_with = split)]
+    tags: Vec<String>,
+}
+
+#[test]
+fn msgpack_proxy_and_with_functions_round_trip() -> Result<()> {
+    facet_testhelpers::setup();
+
+    let commit = Commit {
+        id: 0xbeef,
+        tags: vec!["fix".to_string(), "docs".to_string()],
+    };
+    let bytes = to_vec(&commit);
+    assert_eq!(
+        bytes,
+        [
+            0x82, // Fixmap with 2 elements
+            0xa2, b'i', b'd', // "id"
+            0xa4, b'b', b'e', b'e', b'f', // "beef"
+            0xa4, b't', b'a', b'g', b's', // "tags"
+            0xa8, b'f', b'i', b'x', b',', b'd', b'o', b'c', b's', // "fix,docs"
+        ]
+    );
+    assert_eq!(from_slice::<Commit>(&bytes)?, commit);
+
+    Ok(())
+}
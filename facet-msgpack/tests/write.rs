@@ -1,5 +1,5 @@
 use facet::Facet;
-use facet_msgpack::to_vec;
+use facet_msgpack::{SerializeOptions, to_vec, to_vec_with_options};
 
 #[test]
 fn test_integers() {
@@ -120,3 +120,32 @@ fn test_struct() {
 
     assert_eq!(msgpack, expected);
 }
+
+#[test]
+fn test_struct_omit_sensitive() {
+    facet_testhelpers::setup();
+
+    #[derive(Debug, PartialEq, Clone, Facet)]
+    struct TestStruct {
+        name: String,
+        #[facet(sensitive)]
+        password: String,
+        age: u64,
+    }
+
+    let test_struct = TestStruct {
+        name: "Alice".to_string(),
+        password: "hunter2".to_string(),
+        age: 30,
+    };
+
+    let msgpack = to_vec_with_options(&test_struct, &SerializeOptions::new().omit_sensitive());
+
+    // Same as `test_struct`: the map header only counts the fields that are kept
+    let expected = [
+        0x82, 0xa4, 0x6e, 0x61, 0x6d, 0x65, 0xa5, 0x41, 0x6c, 0x69, 0x63, 0x65, 0xa3, 0x61, 0x67,
+        0x65, 0x1e,
+    ];
+
+    assert_eq!(msgpack, expected);
+}
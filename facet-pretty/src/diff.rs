@@ -0,0 +1,104 @@
+//! Rendering of structural diffs between Facet values
+
+use core::fmt::{self, Write};
+
+use facet_core::Facet;
+use facet_reflect::{Change, Diff, Peek};
+
+use crate::printer::PrettyPrinter;
+
+impl PrettyPrinter {
+    /// Format the changes between two values to a string
+    pub fn format_diff_of<'a, T: Facet<'a>>(&self, from: &T, to: &T) -> String {
+        self.format_diff(&Diff::new(Peek::new(from), Peek::new(to)))
+    }
+
+    /// Format a diff to a string, one change per line
+    ///
+    /// Modified values are prefixed with `~`, added ones with `+` and removed ones with `-`.
+    pub fn format_diff(&self, diff: &Diff<'_, '_, '_>) -> String {
+        let mut output = String::new();
+        self.format_diff_to(diff, &mut output)
+            .expect("Formatting failed");
+        output
+    }
+
+    /// Format a diff to a writer, one change per line
+    ///
+    /// Values under a `#[facet(sensitive)]` field are shown as `[REDACTED]`.
+    pub fn format_diff_to<W: Write>(&self, diff: &Diff<'_, '_, '_>, f: &mut W) -> fmt::Result {
+        for entry in diff {
+            let path = entry.path.to_string();
+            let format_value = |value| {
+                if entry.path.is_sensitive() {
+                    let mut redacted = String::new();
+                    self.write_redacted(&mut redacted, "[REDACTED]")?;
+                    Ok(redacted)
+                } else {
+                    Ok(self.format_peek(value))
+                }
+            };
+            match entry.change {
+                Change::Modified { from, to } | Change::VariantChanged { from, to } => {
+                    let from = format_value(from)?;
+                    let to = format_value(to)?;
+                    self.write_sign(f, '~')?;
+                    self.write_field_name(f, &path)?;
+                    self.write_punctuation(f, ":")?;
+                    if from.contains('\n') || to.contains('\n') {
+                        writeln!(f)?;
+                        write!(f, "    ")?;
+                        self.write_sign(f, '-')?;
+                        writeln!(f, "{}", indent(&from, 6))?;
+                        write!(f, "    ")?;
+                        self.write_sign(f, '+')?;
+                        writeln!(f, "{}", indent(&to, 6))?;
+                    } else {
+                        write!(f, " {from} ")?;
+                        self.write_punctuation(f, "→")?;
+                        writeln!(f, " {to}")?;
+                    }
+                }
+                Change::Added(value) => {
+                    self.write_sign(f, '+')?;
+                    self.write_field_name(f, &path)?;
+                    self.write_punctuation(f, ":")?;
+                    writeln!(f, " {}", indent(&format_value(value)?, 2))?;
+                }
+                Change::Removed(value) => {
+                    self.write_sign(f, '-')?;
+                    self.write_field_name(f, &path)?;
+                    self.write_punctuation(f, ":")?;
+                    writeln!(f, " {}", indent(&format_value(value)?, 2))?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Write a change marker, followed by a space
+    fn write_sign<W: Write>(&self, f: &mut W, sign: char) -> fmt::Result {
+        if self.use_colors {
+            let color = match sign {
+                '+' => 32, // green
+                '-' => 31, // red
+                _ => 33,   // yellow
+            };
+            write!(f, "\x1b[{color};1m{sign}\x1b[0m ")
+        } else {
+            write!(f, "{sign} ")
+        }
+    }
+}
+
+/// Indent every line but the first of a multi-line value
+fn indent(value: &str, width: usize) -> String {
+    let mut lines = value.lines();
+    let mut result = String::from(lines.next().unwrap_or_default());
+    for line in lines {
+        result.push('\n');
+        result.extend(core::iter::repeat_n(' ', width));
+        result.push_str(line);
+    }
+    result
+}
@@ -7,6 +7,7 @@
 extern crate alloc;
 
 mod color;
+mod diff;
 mod display;
 mod printer;
 
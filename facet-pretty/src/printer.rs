@@ -21,7 +21,7 @@ pub struct PrettyPrinter {
     indent_size: usize,
     max_depth: Option<usize>,
     color_generator: ColorGenerator,
-    use_colors: bool,
+    pub(crate) use_colors: bool,
     list_u8_as_bytes: bool,
 }
 
@@ -126,6 +126,18 @@ impl PrettyPrinter {
         initial_value: Peek<'_, '_, 'shape>,
         f: &mut impl Write,
         visited: &mut HashMap<ValueId<'shape>, usize>,
+    ) -> fmt::Result {
+        self.format_peek_at_depth(initial_value, f, visited, 0, 0)
+    }
+
+    /// Format a Peek value that starts at the given depths
+    fn format_peek_at_depth<'shape>(
+        &self,
+        initial_value: Peek<'_, '_, 'shape>,
+        f: &mut impl Write,
+        visited: &mut HashMap<ValueId<'shape>, usize>,
+        format_depth: usize,
+        type_depth: usize,
     ) -> fmt::Result {
         // Create a queue for our stack items
         let mut stack = VecDeque::new();
@@ -133,8 +145,8 @@ impl PrettyPrinter {
         // Push the initial item
         stack.push_back(StackItem {
             value: initial_value,
-            format_depth: 0,
-            type_depth: 0,
+            format_depth,
+            type_depth,
             state: StackState::Start,
         });
 
@@ -218,6 +230,35 @@ impl PrettyPrinter {
                                 self.write_punctuation(f, "::None")?;
                             }
                         }
+                        // Handle result types
+                        (Def::Result(_def), _) => {
+                            let (variant, inner_value) =
+                                match item.value.into_result().unwrap().value() {
+                                    Ok(value) => ("::Ok(", value),
+                                    Err(error) => ("::Err(", error),
+                                };
+
+                            // Print the Result name
+                            self.write_type_name(f, &item.value)?;
+                            self.write_punctuation(f, variant)?;
+
+                            // The closing parenthesis is the same as for `Option::Some`
+                            stack.push_back(StackItem {
+                                value: item.value,
+                                format_depth: item.format_depth,
+                                type_depth: item.type_depth,
+                                state: StackState::OptionFinish,
+                            });
+                            stack.push_back(StackItem {
+                                value: inner_value,
+                                format_depth: item.format_depth,
+                                type_depth: item.type_depth + 1,
+                                state: StackState::Start,
+                            });
+
+                            // Skip to next item
+                            continue;
+                        }
                         // Handle tuple struct types
                         (_, Type::User(UserType::Struct(struct_def)))
                             if struct_def.kind == StructKind::Tuple =>
@@ -420,6 +461,51 @@ impl PrettyPrinter {
                             self.write_type_name(f, &item.value)?;
                             write!(f, " /* function pointer (not yet supported) */")?;
                         }
+                        (Def::SmartPointer(_), _) => {
+                            let smart_pointer = item.value.into_smart_pointer().unwrap();
+                            self.write_type_name(f, &item.value)?;
+
+                            if let Some(inner_value) = smart_pointer.borrow_inner() {
+                                self.write_punctuation(f, "(")?;
+
+                                // The closing parenthesis is the same as for `Option::Some`
+                                stack.push_back(StackItem {
+                                    value: item.value,
+                                    format_depth: item.format_depth,
+                                    type_depth: item.type_depth,
+                                    state: StackState::OptionFinish,
+                                });
+                                stack.push_back(StackItem {
+                                    value: inner_value,
+                                    format_depth: item.format_depth,
+                                    type_depth: item.type_depth + 1,
+                                    state: StackState::Start,
+                                });
+                                continue;
+                            }
+
+                            // Locks (`Mutex`, `RwLock`, `RefCell`...) only give access to their
+                            // value while the guard is alive, so the value is printed right away
+                            // instead of being pushed on the stack.
+                            match smart_pointer.lock() {
+                                Ok(guard) => {
+                                    self.write_punctuation(f, "(")?;
+                                    self.format_peek_at_depth(
+                                        guard.peek(),
+                                        f,
+                                        visited,
+                                        item.format_depth,
+                                        item.type_depth + 1,
+                                    )?;
+                                    self.write_punctuation(f, ")")?;
+                                }
+                                Err(_) => {
+                                    self.write_punctuation(f, " { ")?;
+                                    self.write_comment(f, "/* locked */")?;
+                                    self.write_punctuation(f, " }")?;
+                                }
+                            }
+                        }
                         _ => {
                             write!(f, "unsupported peek variant: {:?}", item.value)?;
                         }
@@ -766,7 +852,7 @@ impl PrettyPrinter {
                     writeln!(f)?;
                 }
                 StackState::OptionFinish => {
-                    // Just close the Option::Some parenthesis, with no comma
+                    // Just close the Option::Some (or Result::Ok/Err) parenthesis, with no comma
                     self.write_punctuation(f, ")")?;
                 }
             }
@@ -887,7 +973,7 @@ impl PrettyPrinter {
     }
 
     /// Write styled field name to formatter
-    fn write_field_name<W: fmt::Write>(&self, f: &mut W, name: &str) -> fmt::Result {
+    pub(crate) fn write_field_name<W: fmt::Write>(&self, f: &mut W, name: &str) -> fmt::Result {
         if self.use_colors {
             // Use cyan color for field names (approximating original RGB color)
             write!(f, "\x1b[36m{}\x1b[0m", name) // cyan
@@ -897,7 +983,7 @@ impl PrettyPrinter {
     }
 
     /// Write styled punctuation to formatter
-    fn write_punctuation<W: fmt::Write>(&self, f: &mut W, text: &str) -> fmt::Result {
+    pub(crate) fn write_punctuation<W: fmt::Write>(&self, f: &mut W, text: &str) -> fmt::Result {
         if self.use_colors {
             write!(f, "\x1b[2m{}\x1b[0m", text) // dim
         } else {
@@ -929,7 +1015,7 @@ impl PrettyPrinter {
     }
 
     /// Write styled redacted value to formatter
-    fn write_redacted<W: fmt::Write>(&self, f: &mut W, text: &str) -> fmt::Result {
+    pub(crate) fn write_redacted<W: fmt::Write>(&self, f: &mut W, text: &str) -> fmt::Result {
         if self.use_colors {
             // Use bright red and bold for redacted values
             if self.use_colors {
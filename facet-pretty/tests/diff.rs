@@ -0,0 +1,69 @@
+use facet::Facet;
+use facet_pretty::PrettyPrinter;
+use facet_testhelpers::test;
+
+#[derive(Debug, Facet)]
+struct Server {
+    host: String,
+    port: u16,
+    replicas: Vec<u32>,
+}
+
+#[test]
+fn test_format_diff() {
+    let old = Server {
+        host: "localhost".to_string(),
+        port: 8080,
+        replicas: vec![1, 2],
+    };
+    let new = Server {
+        host: "localhost".to_string(),
+        port: 9090,
+        replicas: vec![1, 2, 3],
+    };
+
+    let printer = PrettyPrinter::new().with_colors(false);
+    assert_eq!(
+        printer.format_diff_of(&old, &new),
+        "~ port: 8080 → 9090\n+ replicas[2]: 3\n"
+    );
+    assert_eq!(printer.format_diff_of(&old, &old), "");
+}
+
+#[test]
+fn test_format_diff_colors() {
+    let printer = PrettyPrinter::new().with_colors(true);
+    let output = printer.format_diff_of(&vec![1u32], &vec![]);
+    assert!(output.contains("\x1b[31;1m-\x1b[0m"));
+}
+
+#[derive(Debug, Facet)]
+struct Credentials {
+    user: String,
+    #[facet(sensitive)]
+    password: String,
+    #[facet(sensitive)]
+    recovery_codes: Vec<String>,
+}
+
+#[test]
+fn test_format_diff_redacts_sensitive_fields() {
+    let old = Credentials {
+        user: "alice".to_string(),
+        password: "hunter2".to_string(),
+        recovery_codes: vec!["1234".to_string()],
+    };
+    let new = Credentials {
+        user: "bob".to_string(),
+        password: "correct horse".to_string(),
+        recovery_codes: vec!["1234".to_string(), "5678".to_string()],
+    };
+
+    let printer = PrettyPrinter::new().with_colors(false);
+    assert_eq!(
+        printer.format_diff_of(&old, &new),
+        "~ user: \"alice\" → \"bob\"\n\
+         ~ password: [REDACTED] → [REDACTED]\n\
+         + recovery_codes[1]: [REDACTED]\n"
+    );
+}
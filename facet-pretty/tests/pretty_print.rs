@@ -183,3 +183,22 @@ fn test_u32_slice() {
     let nums = [1u32, 2u32, 3u32, 4u32];
     assert_snapshot!(printer.format(&&nums[..]));
 }
+
+#[test]
+fn test_locks() {
+    let printer = PrettyPrinter::new().with_colors(false);
+    let value = (
+        std::sync::Mutex::new(vec![1u32, 2u32]),
+        std::sync::RwLock::new(3u8),
+        core::cell::RefCell::new(4u16),
+    );
+    assert_snapshot!(printer.format(&value));
+}
+
+#[test]
+fn test_borrowed_refcell() {
+    let printer = PrettyPrinter::new().with_colors(false);
+    let cell = core::cell::RefCell::new(1u8);
+    let _borrow = cell.borrow_mut();
+    assert_snapshot!(printer.format(&cell));
+}
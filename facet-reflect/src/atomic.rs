@@ -0,0 +1,36 @@
+//! Loads atomics, which can only be read by picking a memory ordering
+
+use core::sync::atomic::Ordering;
+
+use facet_core::Def;
+
+use crate::{HeapValue, Peek, ReflectError};
+
+impl<'mem, 'facet, 'shape> Peek<'mem, 'facet, 'shape> {
+    /// Loads the value of this atomic with the given ordering, as the non-atomic type from
+    /// `Shape::inner` (e.g. `u64` for `AtomicU64`).
+    ///
+    /// Returns `None` if this isn't an atomic. Like `AtomicU64::load`, this panics if `ordering`
+    /// is `Release` or `AcqRel`.
+    pub fn load_atomic(
+        &self,
+        ordering: Ordering,
+    ) -> Result<Option<HeapValue<'facet, 'shape>>, ReflectError<'shape>> {
+        let (Def::Scalar(scalar_def), Some(inner)) = (self.shape().def, self.shape().inner) else {
+            return Ok(None);
+        };
+        let Some(load) = scalar_def.atomic_load else {
+            return Ok(None);
+        };
+        let src = self.data().thin().ok_or(ReflectError::Unsized {
+            shape: self.shape(),
+        })?;
+        // Safety: `load` takes a value of this shape, and initializes a value of its inner shape
+        unsafe {
+            HeapValue::try_init_with(inner(), |target| {
+                load(src, ordering, target);
+                true
+            })
+        }
+    }
+}
@@ -0,0 +1,188 @@
+//! Checks values against the constraints set on their fields, like `#[facet(max = 10)]`
+
+use facet_core::{Def, Field, FieldAttribute};
+
+use crate::{Peek, ReflectError, ScalarType};
+
+impl<'mem, 'facet, 'shape> Peek<'mem, 'facet, 'shape> {
+    /// Checks this value against the constraints set on `field`: `min`, `max`, `min_len`,
+    /// `max_len`, `pattern` and `non_empty`. Options that are `None` satisfy every constraint,
+    /// while `Some` values are checked in their stead.
+    ///
+    /// Checking `pattern` requires the `regex` feature: without it, fields that have one fail
+    /// with [`ReflectError::OperationFailed`] instead of being assumed to match.
+    ///
+    /// `path` locates the field in errors, see [`Partial::path`](crate::Partial::path).
+    pub fn check_constraints(
+        &self,
+        field: &Field<'shape>,
+        path: &str,
+    ) -> Result<(), ReflectError<'shape>> {
+        for constraint in field.attributes.iter().filter(|a| a.is_constraint()) {
+            if !satisfies(*self, constraint)? {
+                return Err(ReflectError::ConstraintViolation {
+                    path: path.into(),
+                    constraint: *constraint,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+fn satisfies<'shape>(
+    value: Peek<'_, '_, 'shape>,
+    constraint: &FieldAttribute<'shape>,
+) -> Result<bool, ReflectError<'shape>> {
+    if let Ok(option) = value.into_option() {
+        return match option.value() {
+            Some(inner) => satisfies(inner, constraint),
+            None => Ok(true),
+        };
+    }
+
+    match *constraint {
+        FieldAttribute::Min(min) => Ok(match number(value)? {
+            Number::Int(n) => at_least(n, min),
+            Number::Float(n) => n >= min,
+        }),
+        FieldAttribute::Max(max) => Ok(match number(value)? {
+            Number::Int(n) => at_most(n, max),
+            Number::Float(n) => n <= max,
+        }),
+        FieldAttribute::MinLen(min) => Ok(len(value)? >= min),
+        FieldAttribute::MaxLen(max) => Ok(len(value)? <= max),
+        FieldAttribute::NonEmpty => Ok(len(value)? > 0),
+        FieldAttribute::Pattern(pattern) => matches_pattern(value, pattern),
+        _ => Ok(true),
+    }
+}
+
+/// Compares an integer with a bound without rounding the integer to an `f64`
+fn at_least(n: i128, min: f64) -> bool {
+    let truncated = min as i128;
+    n > truncated || (n == truncated && truncated as f64 >= min)
+}
+
+fn at_most(n: i128, max: f64) -> bool {
+    let truncated = max as i128;
+    n < truncated || (n == truncated && truncated as f64 <= max)
+}
+
+enum Number {
+    Int(i128),
+    Float(f64),
+}
+
+fn number<'shape>(value: Peek<'_, '_, 'shape>) -> Result<Number, ReflectError<'shape>> {
+    let value = value.innermost_peek();
+    let not_a_number = || ReflectError::WasNotA {
+        expected: "number",
+        actual: value.shape(),
+    };
+    let Some(scalar) = value.scalar_type() else {
+        return Err(not_a_number());
+    };
+    // Safety: the scalar type tells us what type the value is
+    let number = unsafe {
+        let data = value.data().thin().ok_or_else(not_a_number)?;
+        match scalar {
+            ScalarType::U8 => Number::Int(*data.get::<u8>() as i128),
+            ScalarType::U16 => Number::Int(*data.get::<u16>() as i128),
+            ScalarType::U32 => Number::Int(*data.get::<u32>() as i128),
+            ScalarType::U64 => Number::Int(*data.get::<u64>() as i128),
+            ScalarType::U128 => {
+                Number::Int(i128::try_from(*data.get::<u128>()).unwrap_or(i128::MAX))
+            }
+            ScalarType::USize => Number::Int(*data.get::<usize>() as i128),
+            ScalarType::I8 => Number::Int(*data.get::<i8>() as i128),
+            ScalarType::I16 => Number::Int(*data.get::<i16>() as i128),
+            ScalarType::I32 => Number::Int(*data.get::<i32>() as i128),
+            ScalarType::I64 => Number::Int(*data.get::<i64>() as i128),
+            ScalarType::I128 => Number::Int(*data.get::<i128>()),
+            ScalarType::ISize => Number::Int(*data.get::<isize>() as i128),
+            ScalarType::F32 => Number::Float(*data.get::<f32>() as f64),
+            ScalarType::F64 => Number::Float(*data.get::<f64>()),
+            _ => return Err(not_a_number()),
+        }
+    };
+    Ok(number)
+}
+
+/// The number of characters in a string, or of items in a list, array, slice, map or set
+fn len<'shape>(value: Peek<'_, '_, 'shape>) -> Result<usize, ReflectError<'shape>> {
+    if let Some(s) = value.as_str() {
+        return Ok(s.chars().count());
+    }
+    if let Ok(list) = value.into_list_like() {
+        return Ok(list.len());
+    }
+    if let Ok(map) = value.into_map() {
+        return Ok(map.len());
+    }
+    match value.shape().def {
+        Def::Set(def) => {
+            if let Some(data) = value.data().thin() {
+                // Safety: the value is a set, as described by its def
+                return Ok(unsafe { (def.vtable.len_fn)(data) });
+            }
+        }
+        Def::SmartPointer(_) => {
+            if let Some(inner) = value
+                .into_smart_pointer()
+                .ok()
+                .and_then(|ptr| ptr.borrow_inner())
+            {
+                return len(inner);
+            }
+        }
+        _ => {}
+    }
+    Err(ReflectError::WasNotA {
+        expected: "string, list, map or set",
+        actual: value.shape(),
+    })
+}
+
+#[cfg(feature = "regex")]
+fn matches_pattern<'shape>(
+    value: Peek<'_, '_, 'shape>,
+    pattern: &str,
+) -> Result<bool, ReflectError<'shape>> {
+    use std::collections::HashMap;
+    use std::sync::{Mutex, OnceLock};
+
+    // Patterns are compiled once, the first time they're used
+    static REGEXES: OnceLock<Mutex<HashMap<String, regex::Regex>>> = OnceLock::new();
+
+    let Some(s) = value.as_str() else {
+        return Err(ReflectError::WasNotA {
+            expected: "string",
+            actual: value.shape(),
+        });
+    };
+    let mut regexes = REGEXES
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if !regexes.contains_key(pattern) {
+        let regex = regex::Regex::new(pattern).map_err(|_| ReflectError::OperationFailed {
+            shape: value.shape(),
+            operation: "compiling the regular expression of a `pattern` attribute",
+        })?;
+        regexes.insert(pattern.into(), regex);
+    }
+    Ok(regexes[pattern].is_match(s))
+}
+
+/// Without the `regex` feature, patterns can't be checked: that's an error rather than a pass
+#[cfg(not(feature = "regex"))]
+fn matches_pattern<'shape>(
+    value: Peek<'_, '_, 'shape>,
+    _pattern: &str,
+) -> Result<bool, ReflectError<'shape>> {
+    Err(ReflectError::OperationFailed {
+        shape: value.shape(),
+        operation: "checking a `pattern` attribute, which requires the `regex` feature",
+    })
+}
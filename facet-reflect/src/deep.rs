@@ -0,0 +1,380 @@
+//! Structural equality, ordering, hashing and cloning, for values whose types don't implement
+//! `PartialEq`, `PartialOrd`, `Hash` or `Clone` themselves.
+//!
+//! Wherever a shape's vtable provides the trait, it is used. Otherwise, structs and enum variants
+//! are walked field by field, lists and arrays item by item, maps entry by entry, and options and
+//! smart pointers through their contents.
+
+use core::cmp::Ordering;
+use core::hash::Hasher;
+
+use facet_core::{Characteristic, Def, Facet, Type, UserType};
+
+use crate::{HasFields, HeapValue, Partial, Peek, ReflectError, ScalarType};
+
+/// Compares two values structurally, see [`Peek::deep_eq`]
+pub fn deep_eq<'facet, T: Facet<'facet> + ?Sized>(a: &T, b: &T) -> bool {
+    Peek::new(a).deep_eq(&Peek::new(b))
+}
+
+/// Orders two values structurally, see [`Peek::deep_cmp`]
+pub fn deep_cmp<'facet, T: Facet<'facet> + ?Sized>(a: &T, b: &T) -> Option<Ordering> {
+    Peek::new(a).deep_cmp(&Peek::new(b))
+}
+
+/// Hashes a value structurally, see [`Peek::deep_hash`]
+pub fn deep_hash<'facet, T: Facet<'facet> + ?Sized, H: Hasher>(
+    value: &T,
+    hasher: &mut H,
+) -> Result<(), ReflectError<'static>> {
+    Peek::new(value).deep_hash(hasher)
+}
+
+/// Clones a value structurally, see [`HeapValue::deep_clone_from_peek`]
+pub fn deep_clone<'facet, T: Facet<'facet>>(value: &T) -> Result<T, ReflectError<'static>> {
+    HeapValue::deep_clone_from_peek(Peek::new(value))?.materialize()
+}
+
+impl<'facet, 'shape> Peek<'_, 'facet, 'shape> {
+    /// Compares two values structurally
+    ///
+    /// Values of different shapes are never equal, and neither are values that can't be
+    /// compared at all (opaque types that don't implement `PartialEq`).
+    pub fn deep_eq(&self, other: &Peek<'_, 'facet, 'shape>) -> bool {
+        eq(*self, *other)
+    }
+
+    /// Orders two values structurally
+    ///
+    /// Structs and lists are ordered lexicographically, enums by variant then by fields, and
+    /// `None` comes before `Some`. Returns `None` for values of different shapes, for maps
+    /// without a `PartialOrd` implementation, and for values that can't be ordered at all.
+    pub fn deep_cmp(&self, other: &Peek<'_, 'facet, 'shape>) -> Option<Ordering> {
+        cmp(*self, *other)
+    }
+
+    /// Hashes a value structurally, consistently with [`Peek::deep_eq`]
+    ///
+    /// Maps are hashed independently of their iteration order, and floats by value.
+    pub fn deep_hash<H: Hasher>(&self, hasher: &mut H) -> Result<(), ReflectError<'shape>> {
+        hash(*self, hasher)
+    }
+}
+
+impl<'facet, 'shape> HeapValue<'facet, 'shape> {
+    /// Clones the value behind a [`Peek`] onto the heap, structurally
+    ///
+    /// Fails with [`ReflectError::MissingCharacteristic`] when the value contains an opaque type
+    /// that doesn't implement `Clone`.
+    pub fn deep_clone_from_peek(
+        peek: Peek<'_, 'facet, 'shape>,
+    ) -> Result<Self, ReflectError<'shape>> {
+        let mut partial = Partial::alloc_shape(peek.shape())?;
+        clone_into(&mut partial, peek)?;
+        partial.build()
+    }
+}
+
+/// The parts a value is made of, when its vtable can't handle it directly
+enum Parts<'mem, 'facet, 'shape> {
+    /// The contents of an option or a smart pointer
+    Inner(Option<Peek<'mem, 'facet, 'shape>>),
+    /// Index of a result's variant (0 for `Ok`, 1 for `Err`) and its contents
+    Variant(usize, Peek<'mem, 'facet, 'shape>),
+    /// Entries of a map
+    Map(crate::PeekMap<'mem, 'facet, 'shape>),
+    /// Items of a list, array or slice
+    Items(crate::PeekListLike<'mem, 'facet, 'shape>),
+    /// Index of an enum variant (0 for structs) and its fields
+    Fields(usize, crate::FieldIter<'mem, 'facet, 'shape>),
+    /// A value that can't be broken down
+    Leaf,
+}
+
+fn parts<'mem, 'facet, 'shape>(peek: Peek<'mem, 'facet, 'shape>) -> Parts<'mem, 'facet, 'shape> {
+    match (peek.shape().def, peek.shape().ty) {
+        (Def::Option(_), _) => Parts::Inner(peek.into_option().unwrap().value()),
+        (Def::Result(_), _) => match peek.into_result().unwrap().value() {
+            Ok(value) => Parts::Variant(0, value),
+            Err(error) => Parts::Variant(1, error),
+        },
+        (Def::SmartPointer(_), _) => {
+            Parts::Inner(peek.into_smart_pointer().unwrap().borrow_inner())
+        }
+        (Def::Map(_), _) => Parts::Map(peek.into_map().unwrap()),
+        (Def::List(_) | Def::Array(_), _) => Parts::Items(peek.into_list_like().unwrap()),
+        (Def::Scalar(_), _) => Parts::Leaf,
+        (_, Type::User(UserType::Struct(_))) => {
+            Parts::Fields(0, peek.into_struct().unwrap().fields())
+        }
+        (_, Type::User(UserType::Enum(_))) => {
+            let peek = peek.into_enum().unwrap();
+            match peek.variant_index() {
+                Ok(index) => Parts::Fields(index, peek.fields()),
+                Err(_) => Parts::Leaf,
+            }
+        }
+        (_, Type::Pointer(_)) => match peek.into_list_like() {
+            Ok(items) if peek.as_bytes().is_none() => Parts::Items(items),
+            _ => Parts::Leaf,
+        },
+        _ => Parts::Leaf,
+    }
+}
+
+fn eq<'facet, 'shape>(a: Peek<'_, 'facet, 'shape>, b: Peek<'_, 'facet, 'shape>) -> bool {
+    if a.shape() != b.shape() {
+        return false;
+    }
+    if let Some(equal) = a.partial_eq(&b) {
+        return equal;
+    }
+
+    match (parts(a), parts(b)) {
+        (Parts::Inner(a), Parts::Inner(b)) => match (a, b) {
+            (Some(a), Some(b)) => eq(a, b),
+            (None, None) => true,
+            _ => false,
+        },
+        (Parts::Variant(a_index, a), Parts::Variant(b_index, b)) => a_index == b_index && eq(a, b),
+        (Parts::Map(a), Parts::Map(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .all(|(key, a)| b.get_peek(key).is_some_and(|b| eq(a, b)))
+        }
+        (Parts::Items(a), Parts::Items(b)) => {
+            a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| eq(a, b))
+        }
+        (Parts::Fields(a_index, a), Parts::Fields(b_index, b)) => {
+            a_index == b_index && a.zip(b).all(|((_, a), (_, b))| eq(a, b))
+        }
+        _ => false,
+    }
+}
+
+fn cmp<'facet, 'shape>(
+    a: Peek<'_, 'facet, 'shape>,
+    b: Peek<'_, 'facet, 'shape>,
+) -> Option<Ordering> {
+    if a.shape() != b.shape() {
+        return None;
+    }
+    if let Some(ordering) = a.partial_cmp(&b) {
+        return ordering;
+    }
+
+    match (parts(a), parts(b)) {
+        (Parts::Inner(a), Parts::Inner(b)) => match (a, b) {
+            (Some(a), Some(b)) => cmp(a, b),
+            (a, b) => Some(a.is_some().cmp(&b.is_some())),
+        },
+        (Parts::Variant(a_index, a), Parts::Variant(b_index, b)) => match a_index.cmp(&b_index) {
+            Ordering::Equal => cmp(a, b),
+            ordering => Some(ordering),
+        },
+        (Parts::Items(a), Parts::Items(b)) => {
+            let lengths = a.len().cmp(&b.len());
+            lexicographic(a.iter(), b.iter()).map(|ordering| ordering.then(lengths))
+        }
+        (Parts::Fields(a_index, a), Parts::Fields(b_index, b)) => match a_index.cmp(&b_index) {
+            Ordering::Equal => lexicographic(a.map(|(_, a)| a), b.map(|(_, b)| b)),
+            ordering => Some(ordering),
+        },
+        _ => None,
+    }
+}
+
+/// Compares items pairwise, up to the shortest sequence
+fn lexicographic<'a, 'b, 'facet: 'a + 'b, 'shape: 'a + 'b>(
+    a: impl Iterator<Item = Peek<'a, 'facet, 'shape>>,
+    b: impl Iterator<Item = Peek<'b, 'facet, 'shape>>,
+) -> Option<Ordering> {
+    for (a, b) in a.zip(b) {
+        match cmp(a, b)? {
+            Ordering::Equal => {}
+            ordering => return Some(ordering),
+        }
+    }
+    Some(Ordering::Equal)
+}
+
+fn hash<'facet, 'shape, H: Hasher>(
+    peek: Peek<'_, 'facet, 'shape>,
+    hasher: &mut H,
+) -> Result<(), ReflectError<'shape>> {
+    if peek.hash(hasher).is_ok() {
+        return Ok(());
+    }
+
+    match parts(peek) {
+        Parts::Inner(inner) => {
+            hasher.write_u8(inner.is_some() as u8);
+            if let Some(inner) = inner {
+                hash(inner, hasher)?;
+            }
+        }
+        Parts::Variant(index, inner) => {
+            hasher.write_usize(index);
+            hash(inner, hasher)?;
+        }
+        Parts::Map(map) => {
+            // Entries are hashed on their own and combined with a commutative operation, so
+            // that maps with the same entries in a different order hash the same
+            let len = map.len();
+            let mut entries = 0u64;
+            for (key, value) in map.iter() {
+                let mut entry = Fnv1a::default();
+                hash(key, &mut entry)?;
+                hash(value, &mut entry)?;
+                entries = entries.wrapping_add(entry.finish());
+            }
+            hasher.write_usize(len);
+            hasher.write_u64(entries);
+        }
+        Parts::Items(items) => {
+            hasher.write_usize(items.len());
+            for item in items.iter() {
+                hash(item, hasher)?;
+            }
+        }
+        Parts::Fields(index, fields) => {
+            hasher.write_usize(index);
+            for (_, field) in fields {
+                hash(field, hasher)?;
+            }
+        }
+        Parts::Leaf => match peek.scalar_type() {
+            // -0.0 and 0.0 are equal, so they must hash the same
+            Some(ScalarType::F32) => {
+                let value = *peek.get::<f32>()?;
+                hasher.write_u32(if value == 0.0 { 0 } else { value.to_bits() });
+            }
+            Some(ScalarType::F64) => {
+                let value = *peek.get::<f64>()?;
+                hasher.write_u64(if value == 0.0 { 0 } else { value.to_bits() });
+            }
+            _ => {
+                return Err(ReflectError::OperationFailed {
+                    shape: peek.shape(),
+                    operation: "hash",
+                });
+            }
+        },
+    }
+    Ok(())
+}
+
+/// A small, deterministic hasher for map entries
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for Fnv1a {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ byte as u64).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+fn clone_into<'facet, 'shape>(
+    partial: &mut Partial<'facet, 'shape>,
+    peek: Peek<'_, 'facet, 'shape>,
+) -> Result<(), ReflectError<'shape>> {
+    let shape = peek.shape();
+    if let Some(clone_into) = shape.vtable.sized().and_then(|v| (v.clone_into)()) {
+        let src = peek.data().thin().ok_or(ReflectError::Unsized { shape })?;
+        partial.set_from_function(|dst| {
+            unsafe { clone_into(src, dst) };
+            Ok(())
+        })?;
+        return Ok(());
+    }
+
+    let missing_clone = ReflectError::MissingCharacteristic {
+        shape,
+        characteristic: Characteristic::Clone,
+    };
+    match parts(peek) {
+        Parts::Inner(inner) => match (shape.def, inner) {
+            (Def::Option(_), Some(inner)) => {
+                partial.begin_some()?;
+                clone_into(partial, inner)?;
+                partial.end()?;
+            }
+            (Def::Option(_), None) => {
+                partial.set_default()?;
+            }
+            (_, Some(inner)) => {
+                partial.begin_smart_ptr()?;
+                clone_into(partial, inner)?;
+                partial.end()?;
+            }
+            (_, None) => return Err(missing_clone),
+        },
+        Parts::Variant(index, inner) => {
+            if index == 0 {
+                partial.begin_ok()?;
+            } else {
+                partial.begin_err()?;
+            }
+            clone_into(partial, inner)?;
+            partial.end()?;
+        }
+        Parts::Map(map) => {
+            partial.begin_map()?;
+            for (key, value) in map.iter() {
+                partial.begin_key()?;
+                clone_into(partial, key)?;
+                partial.end()?;
+                partial.begin_value()?;
+                clone_into(partial, value)?;
+                partial.end()?;
+            }
+        }
+        Parts::Items(items) => match shape.def {
+            Def::List(_) => {
+                partial.begin_list()?;
+                for item in items.iter() {
+                    partial.begin_list_item()?;
+                    clone_into(partial, item)?;
+                    partial.end()?;
+                }
+            }
+            Def::Array(_) => {
+                for (index, item) in items.iter().enumerate() {
+                    partial.begin_nth_element(index)?;
+                    clone_into(partial, item)?;
+                    partial.end()?;
+                }
+            }
+            _ => return Err(missing_clone),
+        },
+        Parts::Fields(index, fields) => {
+            if let Type::User(UserType::Enum(_)) = shape.ty {
+                partial.select_nth_variant(index)?;
+            }
+            let mut any_field = false;
+            for (index, (_, field)) in fields.enumerate() {
+                partial.begin_nth_field(index)?;
+                clone_into(partial, field)?;
+                partial.end()?;
+                any_field = true;
+            }
+            if !any_field && matches!(shape.ty, Type::User(UserType::Struct(_))) {
+                // Unit structs have nothing to initialize
+                partial.set_from_function(|_| Ok(()))?;
+            }
+        }
+        Parts::Leaf => return Err(missing_clone),
+    }
+    Ok(())
+}
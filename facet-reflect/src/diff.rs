@@ -0,0 +1,566 @@
+//! Structural diffing of two values of the same shape
+
+use alloc::{format, vec, vec::Vec};
+use core::fmt;
+
+use facet_core::{Def, Facet, FieldFlags, StructKind, Type, UserType};
+
+use crate::{HasFields, Peek};
+
+/// Lists longer than this (in cells of the LCS table) are compared position by position
+/// instead of being aligned
+const MAX_LCS_CELLS: usize = 1 << 16;
+
+/// One step from a value to one of its children
+#[derive(Clone, Copy, Debug)]
+pub enum PathSegment<'mem, 'facet, 'shape> {
+    /// Field of a struct or of an enum variant (tuple fields are named `0`, `1`, ...)
+    Field(&'shape str),
+    /// Item of a list, array or slice
+    Index(usize),
+    /// Value of a map, by key
+    Key(Peek<'mem, 'facet, 'shape>),
+}
+
+/// Where a change happened, relative to the values being diffed
+#[derive(Clone, Debug, Default)]
+pub struct Path<'mem, 'facet, 'shape> {
+    segments: Vec<PathSegment<'mem, 'facet, 'shape>>,
+    sensitive: bool,
+}
+
+impl<'mem, 'facet, 'shape> Path<'mem, 'facet, 'shape> {
+    /// The segments of this path, outermost first
+    pub fn segments(&self) -> &[PathSegment<'mem, 'facet, 'shape>] {
+        &self.segments
+    }
+
+    /// Whether this path points at the values being diffed themselves
+    pub fn is_root(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Whether this path goes through a field marked `#[facet(sensitive)]`, in which case
+    /// the values it points at should not be shown
+    pub fn is_sensitive(&self) -> bool {
+        self.sensitive
+    }
+
+    fn child(&self, segment: PathSegment<'mem, 'facet, 'shape>) -> Self {
+        let mut segments = self.segments.clone();
+        segments.push(segment);
+        Self {
+            segments,
+            sensitive: self.sensitive,
+        }
+    }
+
+    fn field(&self, field: &facet_core::Field<'shape>) -> Self {
+        let mut path = self.child(PathSegment::Field(field.name));
+        path.sensitive |= field.flags.contains(FieldFlags::SENSITIVE);
+        path
+    }
+}
+
+impl fmt::Display for Path<'_, '_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.segments.is_empty() {
+            return write!(f, ".");
+        }
+        for (i, segment) in self.segments.iter().enumerate() {
+            match segment {
+                PathSegment::Field(name) if i == 0 => write!(f, "{name}")?,
+                PathSegment::Field(name) => write!(f, ".{name}")?,
+                PathSegment::Index(index) => write!(f, "[{index}]")?,
+                PathSegment::Key(key) => write!(f, "[{key:?}]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// What changed at a given [`Path`]
+#[derive(Clone, Copy, Debug)]
+pub enum Change<'mem, 'facet, 'shape> {
+    /// A value that has no smaller parts to compare changed
+    Modified {
+        /// Old value
+        from: Peek<'mem, 'facet, 'shape>,
+        /// New value
+        to: Peek<'mem, 'facet, 'shape>,
+    },
+    /// An enum (or a result) changed variants
+    VariantChanged {
+        /// Old value
+        from: Peek<'mem, 'facet, 'shape>,
+        /// New value
+        to: Peek<'mem, 'facet, 'shape>,
+    },
+    /// A list item or map entry was added
+    Added(Peek<'mem, 'facet, 'shape>),
+    /// A list item or map entry was removed
+    Removed(Peek<'mem, 'facet, 'shape>),
+}
+
+/// A single change, along with where it happened
+#[derive(Clone, Debug)]
+pub struct DiffEntry<'mem, 'facet, 'shape> {
+    /// Where the change happened. Removed list items are indexed as in the old value,
+    /// everything else as in the new value.
+    pub path: Path<'mem, 'facet, 'shape>,
+    /// What changed
+    pub change: Change<'mem, 'facet, 'shape>,
+}
+
+/// The changes between two values of the same shape, in the order they appear in the values
+#[derive(Clone, Debug, Default)]
+pub struct Diff<'mem, 'facet, 'shape> {
+    entries: Vec<DiffEntry<'mem, 'facet, 'shape>>,
+}
+
+/// Computes the changes between two values of the same type
+pub fn diff<'mem, 'facet, T: Facet<'facet> + ?Sized>(
+    from: &'mem T,
+    to: &'mem T,
+) -> Diff<'mem, 'facet, 'static> {
+    Diff::new(Peek::new(from), Peek::new(to))
+}
+
+enum DiffTask<'mem, 'facet, 'shape> {
+    Compare(
+        Path<'mem, 'facet, 'shape>,
+        Peek<'mem, 'facet, 'shape>,
+        Peek<'mem, 'facet, 'shape>,
+    ),
+    Emit(DiffEntry<'mem, 'facet, 'shape>),
+}
+
+impl<'mem, 'facet, 'shape> Diff<'mem, 'facet, 'shape> {
+    /// Computes the changes between two values
+    ///
+    /// Structs and enum variants are compared field by field, maps key by key, and lists are
+    /// aligned on their longest common subsequence so that insertions and deletions show up as
+    /// such. Values that share a variant but aren't otherwise made of parts, like scalars, are
+    /// compared with their `PartialEq` implementation, falling back to their `Debug` output.
+    pub fn new(from: Peek<'mem, 'facet, 'shape>, to: Peek<'mem, 'facet, 'shape>) -> Self {
+        let mut entries = Vec::new();
+        let mut stack = vec![DiffTask::Compare(Path::default(), from, to)];
+
+        while let Some(task) = stack.pop() {
+            match task {
+                DiffTask::Emit(entry) => entries.push(entry),
+                DiffTask::Compare(path, from, to) => {
+                    let mut tasks = compare(path, from, to);
+                    // Pop children in the order they appear
+                    tasks.reverse();
+                    stack.extend(tasks);
+                }
+            }
+        }
+
+        Self { entries }
+    }
+
+    /// Returns true if the values are structurally equal
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Number of changes
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// The changes, in the order they appear in the values
+    pub fn entries(&self) -> &[DiffEntry<'mem, 'facet, 'shape>] {
+        &self.entries
+    }
+
+    /// Iterates over the changes
+    pub fn iter(&self) -> core::slice::Iter<'_, DiffEntry<'mem, 'facet, 'shape>> {
+        self.entries.iter()
+    }
+}
+
+impl<'a, 'mem, 'facet, 'shape> IntoIterator for &'a Diff<'mem, 'facet, 'shape> {
+    type Item = &'a DiffEntry<'mem, 'facet, 'shape>;
+    type IntoIter = core::slice::Iter<'a, DiffEntry<'mem, 'facet, 'shape>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Values under a `#[facet(sensitive)]` field are shown as `[REDACTED]`, and so are sensitive
+/// fields of the values that are shown.
+impl fmt::Display for Diff<'_, '_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for entry in &self.entries {
+            let value = |value| {
+                if entry.path.is_sensitive() {
+                    Redacted::Sensitive
+                } else {
+                    Redacted::Value(value)
+                }
+            };
+            match entry.change {
+                Change::Modified { from, to } | Change::VariantChanged { from, to } => {
+                    writeln!(f, "~ {}: {:?} -> {:?}", entry.path, value(from), value(to))?
+                }
+                Change::Added(added) => writeln!(f, "+ {}: {:?}", entry.path, value(added))?,
+                Change::Removed(removed) => writeln!(f, "- {}: {:?}", entry.path, value(removed))?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Formats a value the way a derived `Debug` implementation would, except that the values of
+/// `#[facet(sensitive)]` fields are replaced with `[REDACTED]`
+enum Redacted<'mem, 'facet, 'shape> {
+    Value(Peek<'mem, 'facet, 'shape>),
+    Sensitive,
+}
+
+impl Redacted<'_, '_, '_> {
+    fn fields<'mem, 'facet, 'shape>(
+        fields: impl Iterator<Item = (facet_core::Field<'shape>, Peek<'mem, 'facet, 'shape>)>,
+    ) -> impl Iterator<Item = (&'shape str, Redacted<'mem, 'facet, 'shape>)> {
+        fields.map(|(field, value)| {
+            if field.flags.contains(FieldFlags::SENSITIVE) {
+                (field.name, Redacted::Sensitive)
+            } else {
+                (field.name, Redacted::Value(value))
+            }
+        })
+    }
+
+    fn write_fields<'shape>(
+        f: &mut fmt::Formatter<'_>,
+        name: &str,
+        kind: StructKind,
+        fields: impl Iterator<Item = (&'shape str, Self)>,
+    ) -> fmt::Result {
+        match kind {
+            StructKind::Unit => f.write_str(name),
+            StructKind::Tuple | StructKind::TupleStruct => {
+                let mut tuple = f.debug_tuple(if kind == StructKind::Tuple { "" } else { name });
+                for (_, value) in fields {
+                    tuple.field(&value);
+                }
+                tuple.finish()
+            }
+            _ => {
+                let mut debug = f.debug_struct(name);
+                for (field, value) in fields {
+                    debug.field(field, &value);
+                }
+                debug.finish()
+            }
+        }
+    }
+}
+
+impl fmt::Debug for Redacted<'_, '_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = match *self {
+            Redacted::Value(value) => value,
+            Redacted::Sensitive => return f.write_str("[REDACTED]"),
+        };
+        match (value.shape().def, value.shape().ty) {
+            (Def::Option(_), _) => match value.into_option().unwrap().value() {
+                Some(inner) => f
+                    .debug_tuple("Some")
+                    .field(&Redacted::Value(inner))
+                    .finish(),
+                None => f.write_str("None"),
+            },
+            (Def::SmartPointer(_), _) => match value.into_smart_pointer().unwrap().borrow_inner() {
+                Some(inner) => Redacted::Value(inner).fmt(f),
+                None => write!(f, "{value:?}"),
+            },
+            (Def::Map(_), _) => {
+                let mut map = f.debug_map();
+                for (key, value) in value.into_map().unwrap().iter() {
+                    map.entry(&Redacted::Value(key), &Redacted::Value(value));
+                }
+                map.finish()
+            }
+            (Def::List(_) | Def::Array(_) | Def::Slice(_), _) | (_, Type::Pointer(_))
+                if value.into_list_like().is_ok() =>
+            {
+                let mut list = f.debug_list();
+                for item in value.into_list_like().unwrap().iter() {
+                    list.entry(&Redacted::Value(item));
+                }
+                list.finish()
+            }
+            (Def::Scalar(_), _) => write!(f, "{value:?}"),
+            (_, Type::User(UserType::Struct(sd))) => {
+                let fields = Self::fields(value.into_struct().unwrap().fields());
+                Self::write_fields(f, value.shape().type_identifier, sd.kind, fields)
+            }
+            (_, Type::User(UserType::Enum(_))) => {
+                let value = value.into_enum().unwrap();
+                match value.active_variant() {
+                    Ok(variant) => {
+                        let fields = Self::fields(value.fields());
+                        Self::write_fields(f, variant.name, variant.data.kind, fields)
+                    }
+                    Err(_) => write!(f, "{:?}", value.value),
+                }
+            }
+            _ => write!(f, "{value:?}"),
+        }
+    }
+}
+
+/// Compares two values, returning the changes found right away and the children left to
+/// compare, in order
+fn compare<'mem, 'facet, 'shape>(
+    path: Path<'mem, 'facet, 'shape>,
+    from: Peek<'mem, 'facet, 'shape>,
+    to: Peek<'mem, 'facet, 'shape>,
+) -> Vec<DiffTask<'mem, 'facet, 'shape>> {
+    let modified = |path| {
+        vec![DiffTask::Emit(DiffEntry {
+            path,
+            change: Change::Modified { from, to },
+        })]
+    };
+
+    if from.shape() != to.shape() {
+        return modified(path);
+    }
+    if from.partial_eq(&to) == Some(true) {
+        return Vec::new();
+    }
+
+    match (from.shape().def, from.shape().ty) {
+        (Def::Option(_), _) => {
+            let from_value = from.into_option().unwrap().value();
+            let to_value = to.into_option().unwrap().value();
+            match (from_value, to_value) {
+                (Some(from), Some(to)) => vec![DiffTask::Compare(path, from, to)],
+                (None, None) => Vec::new(),
+                _ => modified(path),
+            }
+        }
+        (Def::Result(_), _) => {
+            let from_value = from.into_result().unwrap().value();
+            let to_value = to.into_result().unwrap().value();
+            match (from_value, to_value) {
+                (Ok(from), Ok(to)) | (Err(from), Err(to)) => {
+                    vec![DiffTask::Compare(path, from, to)]
+                }
+                _ => vec![DiffTask::Emit(DiffEntry {
+                    path,
+                    change: Change::VariantChanged { from, to },
+                })],
+            }
+        }
+        (Def::SmartPointer(_), _) => {
+            let from_inner = from.into_smart_pointer().unwrap().borrow_inner();
+            let to_inner = to.into_smart_pointer().unwrap().borrow_inner();
+            match (from_inner, to_inner) {
+                (Some(from), Some(to)) => vec![DiffTask::Compare(path, from, to)],
+                _ => compare_leaf(path, from, to),
+            }
+        }
+        (Def::Map(_), _) => compare_maps(path, from, to),
+        (Def::List(_) | Def::Array(_), _) => compare_lists(path, from, to),
+        (Def::Scalar(_), _) => compare_leaf(path, from, to),
+        (_, Type::User(UserType::Struct(sd))) if sd.kind != StructKind::Unit => {
+            let from_fields = from.into_struct().unwrap();
+            let to_fields = to.into_struct().unwrap();
+            compare_fields(path, from_fields.fields(), to_fields.fields())
+        }
+        (_, Type::User(UserType::Enum(_))) => {
+            let from_enum = from.into_enum().unwrap();
+            let to_enum = to.into_enum().unwrap();
+            match (from_enum.variant_index(), to_enum.variant_index()) {
+                (Ok(a), Ok(b)) if a == b => {
+                    compare_fields(path, from_enum.fields(), to_enum.fields())
+                }
+                (Ok(_), Ok(_)) => vec![DiffTask::Emit(DiffEntry {
+                    path,
+                    change: Change::VariantChanged { from, to },
+                })],
+                _ => compare_leaf(path, from, to),
+            }
+        }
+        (_, Type::Pointer(_)) if from.into_list_like().is_ok() && from.as_bytes().is_none() => {
+            compare_lists(path, from, to)
+        }
+        _ => compare_leaf(path, from, to),
+    }
+}
+
+/// Compares two values that can't be broken down any further
+fn compare_leaf<'mem, 'facet, 'shape>(
+    path: Path<'mem, 'facet, 'shape>,
+    from: Peek<'mem, 'facet, 'shape>,
+    to: Peek<'mem, 'facet, 'shape>,
+) -> Vec<DiffTask<'mem, 'facet, 'shape>> {
+    let equal = from
+        .partial_eq(&to)
+        .unwrap_or_else(|| format!("{from:?}") == format!("{to:?}"));
+    if equal {
+        Vec::new()
+    } else {
+        vec![DiffTask::Emit(DiffEntry {
+            path,
+            change: Change::Modified { from, to },
+        })]
+    }
+}
+
+fn compare_fields<'mem, 'facet, 'shape>(
+    path: Path<'mem, 'facet, 'shape>,
+    from: impl Iterator<Item = (facet_core::Field<'shape>, Peek<'mem, 'facet, 'shape>)>,
+    to: impl Iterator<Item = (facet_core::Field<'shape>, Peek<'mem, 'facet, 'shape>)>,
+) -> Vec<DiffTask<'mem, 'facet, 'shape>> {
+    from.zip(to)
+        .map(|((field, from), (_, to))| DiffTask::Compare(path.field(&field), from, to))
+        .collect()
+}
+
+fn compare_maps<'mem, 'facet, 'shape>(
+    path: Path<'mem, 'facet, 'shape>,
+    from: Peek<'mem, 'facet, 'shape>,
+    to: Peek<'mem, 'facet, 'shape>,
+) -> Vec<DiffTask<'mem, 'facet, 'shape>> {
+    let from = from.into_map().unwrap();
+    let to = to.into_map().unwrap();
+    let mut tasks = Vec::new();
+
+    for (key, from_value) in from.iter() {
+        let path = path.child(PathSegment::Key(key));
+        match to.get_peek(key) {
+            Some(to_value) => tasks.push(DiffTask::Compare(path, from_value, to_value)),
+            None => tasks.push(DiffTask::Emit(DiffEntry {
+                path,
+                change: Change::Removed(from_value),
+            })),
+        }
+    }
+    for (key, to_value) in to.iter() {
+        if from.get_peek(key).is_none() {
+            tasks.push(DiffTask::Emit(DiffEntry {
+                path: path.child(PathSegment::Key(key)),
+                change: Change::Added(to_value),
+            }));
+        }
+    }
+
+    tasks
+}
+
+fn compare_lists<'mem, 'facet, 'shape>(
+    path: Path<'mem, 'facet, 'shape>,
+    from: Peek<'mem, 'facet, 'shape>,
+    to: Peek<'mem, 'facet, 'shape>,
+) -> Vec<DiffTask<'mem, 'facet, 'shape>> {
+    let from: Vec<_> = from.into_list_like().unwrap().iter().collect();
+    let to: Vec<_> = to.into_list_like().unwrap().iter().collect();
+    let mut tasks = Vec::new();
+
+    // Between two items kept in both lists, items only in `from` and items only in `to` are
+    // paired up and compared; the rest were removed or added
+    let gap = |tasks: &mut Vec<_>, removed: &[usize], added: &[usize]| {
+        for (&i, &j) in removed.iter().zip(added) {
+            tasks.push(DiffTask::Compare(
+                path.child(PathSegment::Index(j)),
+                from[i],
+                to[j],
+            ));
+        }
+        for &i in removed.iter().skip(added.len()) {
+            tasks.push(DiffTask::Emit(DiffEntry {
+                path: path.child(PathSegment::Index(i)),
+                change: Change::Removed(from[i]),
+            }));
+        }
+        for &j in added.iter().skip(removed.len()) {
+            tasks.push(DiffTask::Emit(DiffEntry {
+                path: path.child(PathSegment::Index(j)),
+                change: Change::Added(to[j]),
+            }));
+        }
+    };
+
+    let (mut removed, mut added) = (Vec::new(), Vec::new());
+    for op in align(&from, &to) {
+        match op {
+            Align::Keep => {
+                gap(&mut tasks, &removed, &added);
+                removed.clear();
+                added.clear();
+            }
+            Align::Remove(i) => removed.push(i),
+            Align::Add(j) => added.push(j),
+        }
+    }
+    gap(&mut tasks, &removed, &added);
+
+    tasks
+}
+
+enum Align {
+    Keep,
+    Remove(usize),
+    Add(usize),
+}
+
+/// Aligns two lists on their longest common subsequence of structurally equal items
+fn align<'mem, 'facet, 'shape>(
+    from: &[Peek<'mem, 'facet, 'shape>],
+    to: &[Peek<'mem, 'facet, 'shape>],
+) -> Vec<Align> {
+    let (n, m) = (from.len(), to.len());
+    if (n + 1).saturating_mul(m + 1) > MAX_LCS_CELLS {
+        let mut ops: Vec<_> = (0..n.min(m)).map(Align::Remove).collect();
+        ops.extend((0..n.min(m)).map(Align::Add));
+        ops.extend((m..n).map(Align::Remove));
+        ops.extend((n..m).map(Align::Add));
+        return ops;
+    }
+
+    let equal = |a: Peek<'mem, 'facet, 'shape>, b: Peek<'mem, 'facet, 'shape>| {
+        a.partial_eq(&b)
+            .unwrap_or_else(|| a.shape() == b.shape() && Diff::new(a, b).is_empty())
+    };
+
+    // lengths[i][j] is the length of the LCS of from[i..] and to[j..]
+    let mut lengths = vec![0u32; (n + 1) * (m + 1)];
+    let mut same = vec![false; n * m];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            same[i * m + j] = equal(from[i], to[j]);
+            lengths[i * (m + 1) + j] = if same[i * m + j] {
+                lengths[(i + 1) * (m + 1) + j + 1] + 1
+            } else {
+                lengths[(i + 1) * (m + 1) + j].max(lengths[i * (m + 1) + j + 1])
+            };
+        }
+    }
+
+    let mut ops = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if same[i * m + j] {
+            ops.push(Align::Keep);
+            i += 1;
+            j += 1;
+        } else if lengths[(i + 1) * (m + 1) + j] >= lengths[i * (m + 1) + j + 1] {
+            ops.push(Align::Remove(i));
+            i += 1;
+        } else {
+            ops.push(Align::Add(j));
+            j += 1;
+        }
+    }
+    ops.extend((i..n).map(Align::Remove));
+    ops.extend((j..m).map(Align::Add));
+    ops
+}
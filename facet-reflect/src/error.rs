@@ -1,4 +1,7 @@
-use facet_core::{Characteristic, EnumType, FieldError, Shape, TryFromError};
+use alloc::string::String;
+use facet_core::{
+    Characteristic, EnumType, FieldAttribute, FieldConversionError, FieldError, Shape, TryFromError,
+};
 use owo_colors::OwoColorize;
 
 /// Errors that can occur when reflecting on types.
@@ -147,6 +150,27 @@ pub enum ReflectError<'shape> {
 
     /// No active frame in Partial
     NoActiveFrame,
+
+    /// A field's value doesn't satisfy one of its constraints, like `#[facet(max = 10)]`
+    ConstraintViolation {
+        /// Where the field is, like `Config.port`
+        path: String,
+        /// The constraint that isn't satisfied
+        constraint: FieldAttribute<'shape>,
+    },
+
+    /// Converting a field from the type it's deserialized from failed, see
+    /// `#[facet(deserialize_with = ..)]` and `#[facet(proxy = ..)]`
+    FieldConversionFailed {
+        /// The shape of the value being converted from.
+        src_shape: &'shape Shape<'shape>,
+
+        /// The shape of the field being converted to.
+        dst_shape: &'shape Shape<'shape>,
+
+        /// The error returned by the conversion
+        error: FieldConversionError,
+    },
 }
 
 impl core::fmt::Display for ReflectError<'_> {
@@ -272,7 +296,61 @@ impl core::fmt::Display for ReflectError<'_> {
             ReflectError::NoActiveFrame => {
                 write!(f, "No active frame in Partial")
             }
+            ReflectError::ConstraintViolation { path, constraint } => {
+                write!(f, "{} ", path.blue())?;
+                match constraint {
+                    FieldAttribute::Min(min) => write!(f, "must be at least {min}"),
+                    FieldAttribute::Max(max) => write!(f, "must be at most {max}"),
+                    FieldAttribute::MinLen(len) => {
+                        write!(f, "must have a length of at least {len}")
+                    }
+                    FieldAttribute::MaxLen(len) => {
+                        write!(f, "must have a length of at most {len}")
+                    }
+                    FieldAttribute::Pattern(pattern) => {
+                        write!(f, "must match the pattern {}", pattern.yellow())
+                    }
+                    FieldAttribute::NonEmpty => write!(f, "must not be empty"),
+                    _ => write!(f, "must satisfy {}", constraint.red()),
+                }
+            }
+            ReflectError::FieldConversionFailed {
+                src_shape,
+                dst_shape,
+                error,
+            } => {
+                write!(
+                    f,
+                    "While converting {} into a {}: {}",
+                    src_shape.green(),
+                    dst_shape.blue(),
+                    error.red()
+                )
+            }
+        }
+    }
+}
+
+impl ReflectError<'_> {
+    /// The error message without the colors of its `Display` output, e.g. to keep it in the
+    /// error type of a format
+    pub fn to_plain_string(&self) -> String {
+        let colored = alloc::format!("{self}");
+        let mut plain = String::with_capacity(colored.len());
+        let mut chars = colored.chars();
+        while let Some(c) = chars.next() {
+            if c == '\x1b' {
+                // Skip the escape sequence, which ends with a letter
+                for c in chars.by_ref() {
+                    if c.is_ascii_alphabetic() {
+                        break;
+                    }
+                }
+            } else {
+                plain.push(c);
+            }
         }
+        plain
     }
 }
 
@@ -0,0 +1,39 @@
+//! Dynamic invocation of functions annotated with `#[facet_fn(dynamic)]`
+
+use facet_core::FunctionShape;
+
+use crate::{HeapValue, ReflectError};
+
+/// Calls a function through its [`FunctionShape`], passing it a tuple of arguments.
+///
+/// The arguments must be of shape [`FunctionShape::args_shape`] — they're typically built with
+/// [`Partial::alloc_shape`](crate::Partial::alloc_shape), one field per parameter. They are moved
+/// into the function, and its return value comes back as a [`HeapValue`] of shape
+/// [`FunctionShape::return_shape`].
+///
+/// Function shapes describe their parameters with `'static` lifetimes, so the arguments can't
+/// borrow anything shorter-lived, like the input they were deserialized from.
+pub fn call_function(
+    function: &FunctionShape,
+    args: HeapValue<'static, 'static>,
+) -> Result<HeapValue<'static, 'static>, ReflectError<'static>> {
+    if args.shape() != function.args_shape {
+        return Err(ReflectError::WrongShape {
+            expected: function.args_shape,
+            actual: args.shape(),
+        });
+    }
+
+    let call = function.call;
+    let ret = unsafe {
+        HeapValue::try_init_with(function.return_shape, |ret| {
+            // SAFETY: the arguments have the shape the function expects, and `call` moves them
+            // out before the allocation is freed. `ret` is sized for the return shape.
+            args.move_out(|args| {
+                call(args, ret);
+            });
+            true
+        })?
+    };
+    Ok(ret.unwrap())
+}
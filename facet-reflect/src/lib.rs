@@ -20,6 +20,45 @@ pub use peek::*;
 mod scalar;
 pub use scalar::*;
 
+#[cfg(feature = "alloc")]
+mod poke;
+#[cfg(feature = "alloc")]
+pub use poke::*;
+
+#[cfg(feature = "alloc")]
+mod constraints;
+
+#[cfg(feature = "alloc")]
+mod serialize_with;
+
+#[cfg(feature = "alloc")]
+mod atomic;
+
+#[cfg(feature = "alloc")]
+mod diff;
+#[cfg(feature = "alloc")]
+pub use diff::*;
+
+#[cfg(feature = "alloc")]
+mod deep;
+#[cfg(feature = "alloc")]
+pub use deep::*;
+
+#[cfg(feature = "alloc")]
+mod minimal;
+#[cfg(feature = "alloc")]
+pub use minimal::*;
+
+#[cfg(feature = "alloc")]
+mod function;
+#[cfg(feature = "alloc")]
+pub use function::*;
+
+#[cfg(feature = "alloc")]
+mod registry;
+#[cfg(feature = "alloc")]
+pub use registry::*;
+
 #[cfg(feature = "log")]
 #[allow(unused_imports)]
 pub(crate) use log::{debug, trace};
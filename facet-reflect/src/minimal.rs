@@ -0,0 +1,215 @@
+//! Builds the minimal valid value of a shape, without requiring `Default`
+
+use alloc::vec::Vec;
+
+use facet_core::{
+    Characteristic, Def, Facet, Field, FieldFlags, NumberAffinity, NumberBits, ScalarAffinity,
+    Shape, Type, UserType,
+};
+
+use crate::{Partial, ReflectError, TypedPartial};
+
+/// The shapes whose minimal value is being built, outermost first
+type Ancestors<'shape> = Vec<&'shape Shape<'shape>>;
+
+/// Builds the minimal valid value of `T`, see [`Partial::set_minimal`]
+pub fn minimal<'facet, T: Facet<'facet>>() -> Result<T, ReflectError<'static>> {
+    Partial::alloc_shape(T::SHAPE)?
+        .set_minimal()?
+        .build()?
+        .materialize()
+}
+
+impl<'facet, 'shape> Partial<'facet, 'shape> {
+    /// Sets the current frame to the minimal valid value of its shape: useful to generate
+    /// templates or example payloads for types that don't implement `Default`.
+    ///
+    /// Types that implement `Default` get their default value, and fields get their
+    /// `#[facet(default)]` value if they have one. Otherwise, structs get the minimal value of
+    /// each of their fields, enums their variant with the fewest fields that doesn't recurse
+    /// forever (so the first unit variant, if there is one), options are `None`, results are
+    /// `Ok` of the minimal value (`Err` if `Ok` recurses forever), collections are empty, smart
+    /// pointers point to the minimal value of their pointee, and `NonZero` integers are 1.
+    ///
+    /// Fails for opaque types that don't implement `Default`, and for types whose every value
+    /// would be infinitely large.
+    pub fn set_minimal(&mut self) -> Result<&mut Self, ReflectError<'shape>> {
+        self.fill_minimal(&mut Vec::new())?;
+        Ok(self)
+    }
+
+    fn fill_minimal(
+        &mut self,
+        ancestors: &mut Ancestors<'shape>,
+    ) -> Result<(), ReflectError<'shape>> {
+        let shape = self.shape();
+        if has_default(shape) {
+            self.set_default()?;
+            return Ok(());
+        }
+        if ancestors.contains(&shape) {
+            return Err(ReflectError::OperationFailed {
+                shape,
+                operation: "type has no finite minimal value",
+            });
+        }
+
+        ancestors.push(shape);
+        match (shape.def, shape.ty) {
+            (Def::Option(_), _) => {
+                self.set_default()?;
+            }
+            (Def::List(_), _) => {
+                self.begin_list()?;
+            }
+            (Def::Map(_), _) => {
+                self.begin_map()?;
+            }
+            (Def::Array(def), _) => {
+                for index in 0..def.n {
+                    self.begin_nth_element(index)?;
+                    self.fill_minimal(ancestors)?;
+                    self.end()?;
+                }
+            }
+            (Def::Result(def), _) => {
+                if !has_minimal(def.t, ancestors) && has_minimal(def.e, ancestors) {
+                    self.begin_err()?;
+                } else {
+                    self.begin_ok()?;
+                }
+                self.fill_minimal(ancestors)?;
+                self.end()?;
+            }
+            (Def::SmartPointer(_), _) => {
+                self.begin_smart_ptr()?;
+                self.fill_minimal(ancestors)?;
+                self.end()?;
+            }
+            (Def::Scalar(_), _) if is_non_zero(shape) => {
+                self.begin_inner()?;
+                self.parse_from_str("1")?;
+                self.end()?;
+            }
+            (_, Type::User(UserType::Struct(sd))) => {
+                if sd.fields.is_empty() {
+                    self.set_from_function(|_| Ok(()))?;
+                }
+                self.fill_minimal_fields(sd.fields, ancestors)?;
+            }
+            (_, Type::User(UserType::Enum(ed))) => {
+                // Variants that recurse forever are skipped, but if they all do, the first one
+                // is filled anyway so the error says why
+                let mut variants: Vec<_> = ed.variants.iter().enumerate().collect();
+                variants.sort_by_key(|(_, variant)| variant.data.fields.len());
+                let Some(&(index, variant)) = variants
+                    .iter()
+                    .find(|(_, variant)| fields_have_minimal(variant.data.fields, ancestors))
+                    .or(variants.first())
+                else {
+                    return Err(ReflectError::OperationFailed {
+                        shape,
+                        operation: "enum has no variants",
+                    });
+                };
+                self.select_nth_variant(index)?;
+                self.fill_minimal_fields(variant.data.fields, ancestors)?;
+            }
+            _ => {
+                return Err(ReflectError::MissingCharacteristic {
+                    shape,
+                    characteristic: Characteristic::Default,
+                });
+            }
+        }
+        ancestors.pop();
+        Ok(())
+    }
+
+    fn fill_minimal_fields(
+        &mut self,
+        fields: &'shape [Field<'shape>],
+        ancestors: &mut Ancestors<'shape>,
+    ) -> Result<(), ReflectError<'shape>> {
+        for (index, field) in fields.iter().enumerate() {
+            self.begin_nth_field(index)?;
+            match field.vtable.default_fn {
+                Some(default_fn) if field.flags.contains(FieldFlags::DEFAULT) => {
+                    self.set_field_default(default_fn)?;
+                }
+                _ => self.fill_minimal(ancestors)?,
+            }
+            self.end()?;
+        }
+        Ok(())
+    }
+}
+
+fn has_default(shape: &Shape<'_>) -> bool {
+    shape
+        .vtable
+        .sized()
+        .is_some_and(|v| (v.default_in_place)().is_some())
+}
+
+/// Whether the shape is an integer that can't be zero, like `NonZero<u32>`
+fn is_non_zero(shape: &Shape<'_>) -> bool {
+    let Def::Scalar(sd) = shape.def else {
+        return false;
+    };
+    shape.inner.is_some()
+        && matches!(
+            sd.affinity,
+            ScalarAffinity::Number(NumberAffinity {
+                bits: NumberBits::Integer { .. },
+                ..
+            })
+        )
+}
+
+/// Whether `fill_minimal` would succeed for the shape, below the given ancestors
+fn has_minimal<'shape>(shape: &'shape Shape<'shape>, ancestors: &mut Ancestors<'shape>) -> bool {
+    if has_default(shape) {
+        return true;
+    }
+    if ancestors.contains(&shape) {
+        return false;
+    }
+
+    ancestors.push(shape);
+    let found = match (shape.def, shape.ty) {
+        (Def::Option(_) | Def::List(_) | Def::Map(_), _) => true,
+        (Def::Array(def), _) => def.n == 0 || has_minimal(def.t, ancestors),
+        (Def::Result(def), _) => has_minimal(def.t, ancestors) || has_minimal(def.e, ancestors),
+        (Def::SmartPointer(def), _) => def
+            .pointee()
+            .is_some_and(|pointee| has_minimal(pointee, ancestors)),
+        (Def::Scalar(_), _) if is_non_zero(shape) => true,
+        (_, Type::User(UserType::Struct(sd))) => fields_have_minimal(sd.fields, ancestors),
+        (_, Type::User(UserType::Enum(ed))) => ed
+            .variants
+            .iter()
+            .any(|variant| fields_have_minimal(variant.data.fields, ancestors)),
+        _ => false,
+    };
+    ancestors.pop();
+    found
+}
+
+fn fields_have_minimal<'shape>(
+    fields: &'shape [Field<'shape>],
+    ancestors: &mut Ancestors<'shape>,
+) -> bool {
+    fields.iter().all(|field| {
+        (field.flags.contains(FieldFlags::DEFAULT) && field.vtable.default_fn.is_some())
+            || has_minimal(field.shape, ancestors)
+    })
+}
+
+impl<'facet, 'shape, T> TypedPartial<'facet, 'shape, T> {
+    /// Forwards set_minimal to the inner wip instance.
+    pub fn set_minimal(&mut self) -> Result<&mut Self, ReflectError<'shape>> {
+        self.inner_mut().set_minimal()?;
+        Ok(self)
+    }
+}
@@ -1,13 +1,22 @@
 use crate::Peek;
+use crate::Poke;
 use crate::ReflectError;
 use crate::trace;
 use alloc::boxed::Box;
 use core::{alloc::Layout, marker::PhantomData};
-use facet_core::{Facet, PtrConst, PtrMut, Shape};
+use facet_core::{Characteristic, ConstTypeId, Facet, PtrConst, PtrMut, PtrUninit, Shape};
 #[cfg(feature = "log")]
 use owo_colors::OwoColorize as _;
 
 /// A type-erased value stored on the heap
+///
+/// This is the reflection equivalent of `Box<dyn Any>`: values of different types can be stored
+/// side by side, inspected with [`HeapValue::peek`], modified with [`HeapValue::poke`], cloned,
+/// compared and hashed through their vtables, and downcast back to their concrete type.
+///
+/// Since not every shape supports comparing or hashing, `HeapValue` doesn't implement
+/// `PartialEq`, `PartialOrd` or `Hash`: use [`HeapValue::try_eq`],
+/// [`HeapValue::try_partial_cmp`] and [`HeapValue::try_hash`] instead.
 pub struct HeapValue<'facet, 'shape> {
     pub(crate) guard: Option<Guard>,
     pub(crate) shape: &'shape Shape<'shape>,
@@ -26,6 +35,19 @@ impl<'facet, 'shape> Drop for HeapValue<'facet, 'shape> {
 }
 
 impl<'facet, 'shape> HeapValue<'facet, 'shape> {
+    /// Moves a value onto the heap, erasing its type.
+    pub fn new<T: Facet<'facet>>(value: T) -> Self {
+        let ptr = Box::into_raw(Box::new(value));
+        HeapValue {
+            guard: Some(Guard {
+                ptr: ptr as *mut u8,
+                layout: Layout::new::<T>(),
+            }),
+            shape: T::SHAPE,
+            phantom: PhantomData,
+        }
+    }
+
     /// Returns a peek that allows exploring the heap value.
     pub fn peek(&self) -> Peek<'_, 'facet, 'shape> {
         unsafe { Peek::unchecked_new(PtrConst::new(self.guard.as_ref().unwrap().ptr), self.shape) }
@@ -36,6 +58,126 @@ impl<'facet, 'shape> HeapValue<'facet, 'shape> {
         self.shape
     }
 
+    /// Returns a poke that allows modifying the heap value in place.
+    pub fn poke(&mut self) -> Poke<'_, 'facet, 'shape> {
+        unsafe { Poke::unchecked_new(PtrMut::new(self.guard.as_ref().unwrap().ptr), self.shape) }
+    }
+
+    /// Returns the type identifier of the value.
+    pub fn type_id(&self) -> ConstTypeId {
+        self.shape.id
+    }
+
+    /// Returns true if the value is of type `T`.
+    pub fn is<T: Facet<'facet>>(&self) -> bool {
+        self.shape.id == ConstTypeId::of::<T>()
+    }
+
+    /// Returns a reference to the value if it is of type `T`.
+    pub fn downcast_ref<T: Facet<'facet>>(&self) -> Option<&T> {
+        if self.is::<T>() {
+            Some(unsafe { self.as_ref::<T>() })
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the value if it is of type `T`.
+    pub fn downcast_mut<T: Facet<'facet>>(&mut self) -> Option<&mut T> {
+        if self.is::<T>() {
+            Some(unsafe { &mut *(self.guard.as_ref().unwrap().ptr as *mut T) })
+        } else {
+            None
+        }
+    }
+
+    /// Moves the value out if it is of type `T`, or gives the heap value back otherwise.
+    pub fn downcast<T: Facet<'facet>>(self) -> Result<T, Self> {
+        if self.is::<T>() {
+            Ok(self.materialize::<T>().unwrap_or_else(|_| unreachable!()))
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Clones the value using its `Clone` implementation.
+    pub fn try_clone(&self) -> Result<Self, ReflectError<'shape>> {
+        Self::clone_from_peek(self.peek())
+    }
+
+    /// Compares the value with another one using its `PartialEq` implementation.
+    ///
+    /// Values of different shapes are never equal. Fails if the shape doesn't implement
+    /// `PartialEq`.
+    pub fn try_eq(&self, other: &Self) -> Result<bool, ReflectError<'shape>> {
+        if self.shape != other.shape {
+            return Ok(false);
+        }
+        self.peek()
+            .partial_eq(&other.peek())
+            .ok_or(ReflectError::MissingCharacteristic {
+                shape: self.shape,
+                characteristic: Characteristic::PartialEq,
+            })
+    }
+
+    /// Compares the value with another one using its `PartialOrd` implementation.
+    ///
+    /// Values of different shapes are unordered. Fails if the shape doesn't implement
+    /// `PartialOrd`.
+    pub fn try_partial_cmp(
+        &self,
+        other: &Self,
+    ) -> Result<Option<core::cmp::Ordering>, ReflectError<'shape>> {
+        if self.shape != other.shape {
+            return Ok(None);
+        }
+        self.peek()
+            .partial_cmp(&other.peek())
+            .ok_or(ReflectError::MissingCharacteristic {
+                shape: self.shape,
+                characteristic: Characteristic::PartialOrd,
+            })
+    }
+
+    /// Hashes the value using its `Hash` implementation, along with its type identifier.
+    ///
+    /// Fails if the shape doesn't implement `Hash`, without writing anything to `hasher`.
+    pub fn try_hash<H: core::hash::Hasher>(
+        &self,
+        hasher: &mut H,
+    ) -> Result<(), ReflectError<'shape>> {
+        if !self.shape.is(Characteristic::Hash) {
+            return Err(ReflectError::MissingCharacteristic {
+                shape: self.shape,
+                characteristic: Characteristic::Hash,
+            });
+        }
+        core::hash::Hash::hash(&self.shape.id, hasher);
+        self.peek()
+            .hash(hasher)
+            .map_err(|_| ReflectError::MissingCharacteristic {
+                shape: self.shape,
+                characteristic: Characteristic::Hash,
+            })
+    }
+
+    /// Detaches the value from the lifetime of the data it was built from, e.g. the input it
+    /// was deserialized from, so that it can outlive it.
+    ///
+    /// # Safety
+    ///
+    /// The value must not borrow anything that doesn't live for `'static`: no references,
+    /// `Cow::Borrowed`, or other borrowing types pointing into that data, anywhere in it.
+    /// Shapes don't carry lifetimes, so this can't be checked.
+    pub unsafe fn into_owned(mut self) -> HeapValue<'static, 'shape> {
+        HeapValue {
+            guard: self.guard.take(),
+            shape: self.shape,
+            phantom: PhantomData,
+        }
+    }
+
     /// Turn this heapvalue into a concrete type
     pub fn materialize<T: Facet<'facet>>(mut self) -> Result<T, ReflectError<'shape>> {
         trace!(
@@ -97,42 +239,6 @@ impl<'facet, 'shape> core::fmt::Debug for HeapValue<'facet, 'shape> {
     }
 }
 
-impl<'facet, 'shape> PartialEq for HeapValue<'facet, 'shape> {
-    fn eq(&self, other: &Self) -> bool {
-        if self.shape != other.shape {
-            return false;
-        }
-        if let Some(eq_fn) = self.shape.vtable.sized().and_then(|v| (v.partial_eq)()) {
-            unsafe {
-                eq_fn(
-                    PtrConst::new(self.guard.as_ref().unwrap().ptr),
-                    PtrConst::new(other.guard.as_ref().unwrap().ptr),
-                )
-            }
-        } else {
-            false
-        }
-    }
-}
-
-impl<'facet, 'shape> PartialOrd for HeapValue<'facet, 'shape> {
-    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
-        if self.shape != other.shape {
-            return None;
-        }
-        if let Some(partial_ord_fn) = self.shape.vtable.sized().and_then(|v| (v.partial_ord)()) {
-            unsafe {
-                partial_ord_fn(
-                    PtrConst::new(self.guard.as_ref().unwrap().ptr),
-                    PtrConst::new(other.guard.as_ref().unwrap().ptr),
-                )
-            }
-        } else {
-            None
-        }
-    }
-}
-
 /// A guard structure to manage memory allocation and deallocation.
 ///
 /// This struct holds a raw pointer to the allocated memory and the layout
@@ -174,6 +280,70 @@ impl<'facet, 'shape> HeapValue<'facet, 'shape> {
         unsafe { Box::from_raw(ptr) }
     }
 
+    /// Allocates a value of the given shape and lets `init` initialize it in place.
+    ///
+    /// If `init` returns false, the memory is freed and `None` is returned.
+    ///
+    /// # Safety
+    ///
+    /// When it returns true, `init` must have fully initialized the memory as a value of `shape`.
+    pub(crate) unsafe fn try_init_with(
+        shape: &'shape Shape<'shape>,
+        init: impl FnOnce(PtrUninit<'_>) -> bool,
+    ) -> Result<Option<Self>, ReflectError<'shape>> {
+        let layout = shape
+            .layout
+            .sized_layout()
+            .map_err(|_| ReflectError::Unsized { shape })?;
+        let data = shape
+            .allocate()
+            .map_err(|_| ReflectError::Unsized { shape })?;
+        let guard = Guard {
+            ptr: data.as_mut_byte_ptr(),
+            layout,
+        };
+        if !init(data) {
+            drop(guard); // free memory (there's nothing to drop in place)
+            return Ok(None);
+        }
+        Ok(Some(HeapValue {
+            guard: Some(guard),
+            shape,
+            phantom: PhantomData,
+        }))
+    }
+
+    /// Hands the value to `take`, which must move it out (e.g. with [`core::ptr::read`]), then
+    /// frees the heap allocation without dropping the value.
+    ///
+    /// # Safety
+    ///
+    /// `take` must move the value out of the pointer it's given.
+    pub(crate) unsafe fn move_out(mut self, take: impl FnOnce(PtrMut<'_>)) {
+        let guard = self.guard.take().unwrap();
+        take(PtrMut::new(guard.ptr));
+        drop(guard); // free memory (but don't drop in place)
+    }
+
+    /// Clones the value behind a [`Peek`] onto the heap, using its `Clone` implementation.
+    pub fn clone_from_peek(peek: Peek<'_, 'facet, 'shape>) -> Result<Self, ReflectError<'shape>> {
+        let shape = peek.shape();
+        let clone_into = shape.vtable.sized().and_then(|v| (v.clone_into)()).ok_or(
+            ReflectError::MissingCharacteristic {
+                shape,
+                characteristic: Characteristic::Clone,
+            },
+        )?;
+        let src = peek.data().thin().ok_or(ReflectError::Unsized { shape })?;
+        let value = unsafe {
+            Self::try_init_with(shape, |dst| {
+                clone_into(src, dst);
+                true
+            })?
+        };
+        Ok(value.unwrap())
+    }
+
     /// Unsafely get a reference to the underlying value as type T.
     ///
     /// # Safety
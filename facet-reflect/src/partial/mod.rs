@@ -132,7 +132,7 @@ use alloc::vec;
 mod iset;
 
 use crate::{Peek, ReflectError, trace};
-use facet_core::DefaultInPlaceFn;
+use facet_core::{DefaultInPlaceFn, DeserializeWithFn};
 
 use core::marker::PhantomData;
 
@@ -141,8 +141,8 @@ use alloc::vec::Vec;
 pub use heap_value::*;
 
 use facet_core::{
-    Def, EnumRepr, Facet, KnownSmartPointer, PtrConst, PtrMut, PtrUninit, Shape, Type, UserType,
-    Variant,
+    Def, EnumRepr, Facet, Field, KnownSmartPointer, PtrConst, PtrMut, PtrUninit, Shape, Type,
+    UserType, Variant,
 };
 use iset::ISet;
 
@@ -213,6 +213,14 @@ struct Frame<'shape> {
 
     /// Whether this frame owns the allocation or is just a field pointer
     ownership: FrameOwnership,
+
+    /// Set for frames started with [`Partial::begin_deserialize_with`]: converts the value of
+    /// this frame into the parent frame when it ends
+    deserialize_with: Option<DeserializeWithFn>,
+
+    /// Set for frames started with [`Partial::begin_try_from`]: converts the value of this
+    /// frame into the parent frame with the parent's `try_from` when it ends
+    try_from_parent: bool,
 }
 
 enum Tracker<'shape> {
@@ -271,11 +279,27 @@ enum Tracker<'shape> {
         insert_state: MapInsertState,
     },
 
+    /// Partially initialized set (HashSet, BTreeSet, etc.)
+    Set {
+        /// The set has been initialized with capacity
+        is_initialized: bool,
+        /// If we're pushing another frame for an item
+        current_child: bool,
+    },
+
     /// Option being initialized with Some(inner_value)
     Option {
         /// Whether we're currently building the inner value
         building_inner: bool,
     },
+
+    /// Result being initialized with Ok(value) or Err(error)
+    Result {
+        /// Whether the variant being built is Ok (as opposed to Err)
+        is_ok: bool,
+        /// Whether we're currently building the inner value
+        building_inner: bool,
+    },
 }
 
 impl<'shape> Frame<'shape> {
@@ -298,6 +322,8 @@ impl<'shape> Frame<'shape> {
             shape,
             tracker,
             ownership,
+            deserialize_with: None,
+            try_from_parent: false,
         }
     }
 
@@ -381,6 +407,16 @@ impl<'shape> Frame<'shape> {
                     Err(ReflectError::UninitializedValue { shape: self.shape })
                 }
             }
+            Tracker::Set {
+                is_initialized,
+                current_child,
+            } => {
+                if is_initialized && !current_child {
+                    Ok(())
+                } else {
+                    Err(ReflectError::UninitializedValue { shape: self.shape })
+                }
+            }
             Tracker::Map {
                 is_initialized,
                 insert_state,
@@ -391,7 +427,7 @@ impl<'shape> Frame<'shape> {
                     Err(ReflectError::UninitializedValue { shape: self.shape })
                 }
             }
-            Tracker::Option { building_inner } => {
+            Tracker::Option { building_inner } | Tracker::Result { building_inner, .. } => {
                 if building_inner {
                     Err(ReflectError::UninitializedValue { shape: self.shape })
                 } else {
@@ -1193,6 +1229,12 @@ impl<'facet, 'shape> Partial<'facet, 'shape> {
     }
 
     /// Pushes a frame to initialize the inner value of a smart pointer (`Box<T>`, `Arc<T>`, etc.)
+    ///
+    /// Interior mutability wrappers (`Cell<T>`, `RefCell<T>`, `Mutex<T>`, `RwLock<T>`) are built
+    /// the same way: the inner value is moved into the wrapper once its frame is ended.
+    ///
+    /// For unsized pointees, the frame is for their owned form instead: a `String` for
+    /// `Box<str>`/`Arc<str>`/`Rc<str>`, a list of items for `Box<[T]>`/`Arc<[T]>`/`Rc<[T]>`.
     pub fn begin_smart_ptr(&mut self) -> Result<&mut Self, ReflectError<'shape>> {
         self.require_active()?;
         let frame = self.frames.last_mut().unwrap();
@@ -1202,19 +1244,27 @@ impl<'facet, 'shape> Partial<'facet, 'shape> {
             Def::SmartPointer(smart_ptr_def) => {
                 // Check for supported smart pointer types
                 match smart_ptr_def.known {
-                    Some(KnownSmartPointer::Box) | Some(KnownSmartPointer::Arc) => {
+                    Some(KnownSmartPointer::Box)
+                    | Some(KnownSmartPointer::Arc)
+                    | Some(KnownSmartPointer::Rc)
+                    | Some(KnownSmartPointer::Cell)
+                    | Some(KnownSmartPointer::RefCell)
+                    | Some(KnownSmartPointer::Mutex)
+                    | Some(KnownSmartPointer::RwLock) => {
                         // Supported types, continue
                     }
                     _ => {
                         return Err(ReflectError::OperationFailed {
                             shape: frame.shape,
-                            operation: "only Box and Arc smart pointers are currently supported",
+                            operation: "only Box, Arc, Rc, Cell, RefCell, Mutex and RwLock smart pointers are currently supported",
                         });
                     }
                 }
 
-                // Get the pointee shape
-                let pointee_shape = match smart_ptr_def.pointee() {
+                // Get the shape of the value to build: the pointee, or its owned form if it's
+                // unsized (a `String` for an `Arc<str>`, a `Vec<T>` for a `Box<[T]>`...)
+                let pointee_shape = match smart_ptr_def.owned().or_else(|| smart_ptr_def.pointee())
+                {
                     Some(shape) => shape,
                     None => {
                         return Err(ReflectError::OperationFailed {
@@ -1567,6 +1617,84 @@ impl<'facet, 'shape> Partial<'facet, 'shape> {
         Ok(self)
     }
 
+    /// Begins a set initialization operation
+    /// This initializes the set with default capacity and allows inserting items
+    pub fn begin_set(&mut self) -> Result<&mut Self, ReflectError<'shape>> {
+        self.require_active()?;
+        let frame = self.frames.last_mut().unwrap();
+
+        // Check that we have a Set
+        let set_def = match &frame.shape.def {
+            Def::Set(set_def) => set_def,
+            _ => {
+                return Err(ReflectError::OperationFailed {
+                    shape: frame.shape,
+                    operation: "begin_set can only be called on Set types",
+                });
+            }
+        };
+
+        // Initialize the set with default capacity (0)
+        unsafe {
+            (set_def.vtable.init_in_place_with_capacity_fn)(frame.data, 0);
+        }
+
+        frame.tracker = Tracker::Set {
+            is_initialized: true,
+            current_child: false,
+        };
+
+        Ok(self)
+    }
+
+    /// Pushes a frame for an item of the set
+    /// The item should be set using `set()` or similar methods, then `end()` to insert it
+    pub fn begin_set_item(&mut self) -> Result<&mut Self, ReflectError<'shape>> {
+        self.require_active()?;
+        let frame = self.frames.last_mut().unwrap();
+
+        let set_def = match &frame.shape.def {
+            Def::Set(set_def) => set_def,
+            _ => {
+                return Err(ReflectError::OperationFailed {
+                    shape: frame.shape,
+                    operation: "begin_set_item can only be called on Set types",
+                });
+            }
+        };
+
+        match &mut frame.tracker {
+            Tracker::Set {
+                is_initialized: true,
+                current_child,
+            } => {
+                if *current_child {
+                    return Err(ReflectError::OperationFailed {
+                        shape: frame.shape,
+                        operation: "already pushing an item, call end() first",
+                    });
+                }
+                *current_child = true;
+            }
+            _ => {
+                return Err(ReflectError::OperationFailed {
+                    shape: frame.shape,
+                    operation: "must call begin_set() before begin_set_item()",
+                });
+            }
+        }
+
+        let item_shape = set_def.t();
+        let item_data = item_shape
+            .allocate()
+            .map_err(|_| ReflectError::Unsized { shape: item_shape })?;
+
+        self.frames
+            .push(Frame::new(item_data, item_shape, FrameOwnership::Owned));
+
+        Ok(self)
+    }
+
     /// Pops the current frame off the stack, indicating we're done initializing the current field.
     pub fn end(&mut self) -> Result<&mut Self, ReflectError<'shape>> {
         self.require_active()?;
@@ -1587,6 +1715,18 @@ impl<'facet, 'shape> Partial<'facet, 'shape> {
             frame.require_full_initialization()?
         }
 
+        // Check the constraints of the field we're done with, like `#[facet(max = 10)]`
+        if let Some(field) = self.current_field() {
+            if field.attributes.iter().any(|attr| attr.is_constraint()) {
+                let frame = self.frames.last().unwrap();
+                // Safety: the frame was just checked to be fully initialized
+                let value = unsafe {
+                    Peek::unchecked_new(frame.data.assume_init().as_const(), frame.shape)
+                };
+                value.check_constraints(field, &self.path_to(self.frames.len() - 1))?;
+            }
+        }
+
         // Pop the frame and save its data pointer for SmartPointer handling
         let popped_frame = self.frames.pop().unwrap();
         let _is_conversion = false;
@@ -1598,13 +1738,38 @@ impl<'facet, 'shape> Partial<'facet, 'shape> {
         // Update parent frame's tracking when popping from a child
         let parent_frame = self.frames.last_mut().unwrap();
 
+        if let Some(convert) = popped_frame.deserialize_with {
+            trace!(
+                "Converting {} into the field it's deserialized for, {}",
+                popped_frame.shape, parent_frame.shape
+            );
+            // Safety: the popped frame is fully initialized, and holds a value of the type
+            // `convert` takes. `convert` moves the value out, so it's not dropped afterwards.
+            let result = unsafe { convert(popped_frame.data.assume_init(), parent_frame.data) };
+            if let Ok(layout) = popped_frame.shape.layout.sized_layout() {
+                if layout.size() > 0 {
+                    unsafe { alloc::alloc::dealloc(popped_frame.data.as_mut_byte_ptr(), layout) };
+                }
+            }
+            if let Err(error) = result {
+                return Err(ReflectError::FieldConversionFailed {
+                    src_shape: popped_frame.shape,
+                    dst_shape: parent_frame.shape,
+                    error,
+                });
+            }
+            parent_frame.tracker = Tracker::Init;
+            return Ok(self);
+        }
+
         // Check if we need to do a conversion - this happens when:
         // 1. The parent frame has an inner type that matches the popped frame's shape
         // 2. The parent frame has try_from
         // 3. The parent frame is not yet initialized
         let needs_conversion = matches!(parent_frame.tracker, Tracker::Uninit)
-            && parent_frame.shape.inner.is_some()
-            && parent_frame.shape.inner.unwrap()() == popped_frame.shape
+            && (popped_frame.try_from_parent
+                || parent_frame.shape.inner.is_some()
+                    && parent_frame.shape.inner.unwrap()() == popped_frame.shape)
             && parent_frame
                 .shape
                 .vtable
@@ -1789,6 +1954,38 @@ impl<'facet, 'shape> Partial<'facet, 'shape> {
                     }
                 }
             }
+            Tracker::Set {
+                is_initialized: true,
+                current_child,
+            } => {
+                if *current_child {
+                    // We just popped an item frame, now insert it into the set
+                    if let Def::Set(set_def) = parent_frame.shape.def {
+                        unsafe {
+                            (set_def.vtable.insert_fn)(
+                                PtrMut::new(parent_frame.data.as_mut_byte_ptr()),
+                                PtrMut::new(popped_frame.data.as_mut_byte_ptr()),
+                            );
+                        }
+
+                        // Deallocate the item's memory since insert moved it
+                        if let FrameOwnership::Owned = popped_frame.ownership {
+                            if let Ok(layout) = popped_frame.shape.layout.sized_layout() {
+                                if layout.size() > 0 {
+                                    unsafe {
+                                        alloc::alloc::dealloc(
+                                            popped_frame.data.as_mut_byte_ptr(),
+                                            layout,
+                                        );
+                                    }
+                                }
+                            }
+                        }
+
+                        *current_child = false;
+                    }
+                }
+            }
             Tracker::Map {
                 is_initialized: true,
                 insert_state,
@@ -1891,12 +2088,206 @@ impl<'facet, 'shape> Partial<'facet, 'shape> {
                     }
                 }
             }
+            Tracker::Result {
+                is_ok,
+                building_inner,
+            } => {
+                // We just popped the inner value frame for a Result's Ok or Err variant
+                if *building_inner {
+                    if let Def::Result(result_def) = parent_frame.shape.def {
+                        let init_fn = if *is_ok {
+                            result_def.vtable.init_ok_fn
+                        } else {
+                            result_def.vtable.init_err_fn
+                        };
+
+                        // The popped frame contains the inner value, which init_fn moves out of
+                        let inner_value_ptr = unsafe { popped_frame.data.assume_init().as_const() };
+                        unsafe {
+                            init_fn(parent_frame.data, inner_value_ptr);
+                        }
+
+                        if let FrameOwnership::Owned = popped_frame.ownership {
+                            if let Ok(layout) = popped_frame.shape.layout.sized_layout() {
+                                if layout.size() > 0 {
+                                    unsafe {
+                                        alloc::alloc::dealloc(
+                                            popped_frame.data.as_mut_byte_ptr(),
+                                            layout,
+                                        );
+                                    }
+                                }
+                            }
+                        }
+
+                        *building_inner = false;
+                    } else {
+                        return Err(ReflectError::OperationFailed {
+                            shape: parent_frame.shape,
+                            operation: "Result frame without Result definition",
+                        });
+                    }
+                }
+            }
             _ => {}
         }
 
         Ok(self)
     }
 
+    /// Returns the struct or enum field the current frame is building, if any
+    fn current_field(&self) -> Option<&'shape Field<'shape>> {
+        let parent = self.frames.len().checked_sub(2).map(|i| &self.frames[i])?;
+        match (&parent.tracker, parent.shape.ty) {
+            (
+                Tracker::Struct {
+                    current_child: Some(idx),
+                    ..
+                },
+                Type::User(UserType::Struct(struct_type)),
+            ) => struct_type.fields.get(*idx),
+            (
+                Tracker::Enum {
+                    variant,
+                    current_child: Some(idx),
+                    ..
+                },
+                _,
+            ) => variant.data.fields.get(*idx),
+            _ => None,
+        }
+    }
+
+    /// The shape the current field is deserialized from, if it has a
+    /// `#[facet(deserialize_with = ..)]` or `#[facet(proxy = ..)]` attribute
+    pub fn deserialize_with_shape(&self) -> Option<&'shape Shape<'shape>> {
+        let deserialize_with = self.current_field()?.vtable.deserialize_with?;
+        Some((deserialize_with.shape)())
+    }
+
+    /// Begins building the value the current field is deserialized from, for fields with a
+    /// `#[facet(deserialize_with = ..)]` or `#[facet(proxy = ..)]` attribute (see
+    /// [`Partial::deserialize_with_shape`]). Ending that frame converts its value into the field.
+    pub fn begin_deserialize_with(&mut self) -> Result<&mut Self, ReflectError<'shape>> {
+        self.require_active()?;
+
+        let Some(deserialize_with) = self.current_field().and_then(|f| f.vtable.deserialize_with)
+        else {
+            return Err(ReflectError::OperationFailed {
+                shape: self.shape(),
+                operation: "begin_deserialize_with: the current field has no `deserialize_with` or `proxy` attribute",
+            });
+        };
+
+        let frame = self.frames.last_mut().unwrap();
+        match frame.tracker {
+            Tracker::Uninit => {}
+            Tracker::Init => {
+                // The field is about to be overwritten
+                if let Some(drop_fn) = frame.shape.vtable.sized().and_then(|v| (v.drop_in_place)())
+                {
+                    unsafe { drop_fn(PtrMut::new(frame.data.as_mut_byte_ptr())) };
+                }
+                frame.tracker = Tracker::Uninit;
+            }
+            _ => {
+                return Err(ReflectError::InvalidOperation {
+                    operation: "begin_deserialize_with",
+                    reason: "the field is already partially initialized",
+                });
+            }
+        }
+
+        let shape: &'shape Shape<'shape> = (deserialize_with.shape)();
+        let layout = shape
+            .layout
+            .sized_layout()
+            .map_err(|_| ReflectError::Unsized { shape })?;
+        let data = if layout.size() == 0 {
+            PtrUninit::new(core::ptr::NonNull::<u8>::dangling().as_ptr())
+        } else {
+            let ptr = unsafe { alloc::alloc::alloc(layout) };
+            if ptr.is_null() {
+                alloc::alloc::handle_alloc_error(layout);
+            }
+            PtrUninit::new(ptr)
+        };
+
+        trace!(
+            "begin_deserialize_with: Creating frame for {} (field is {})",
+            shape, frame.shape
+        );
+        let mut frame = Frame::new(data, shape, FrameOwnership::Owned);
+        frame.deserialize_with = Some(deserialize_with.convert);
+        self.frames.push(frame);
+        Ok(self)
+    }
+
+    /// Begins building a value of `source_shape`, which is converted into the current frame's
+    /// type with its `try_from` when that frame ends — like a `Vec<u8>` for a `CString`.
+    pub fn begin_try_from(
+        &mut self,
+        source_shape: &'shape Shape<'shape>,
+    ) -> Result<&mut Self, ReflectError<'shape>> {
+        self.require_active()?;
+
+        let frame = self.frames.last_mut().unwrap();
+        if frame
+            .shape
+            .vtable
+            .sized()
+            .and_then(|v| (v.try_from)())
+            .is_none()
+        {
+            return Err(ReflectError::OperationFailed {
+                shape: frame.shape,
+                operation: "begin_try_from: type doesn't support conversions",
+            });
+        }
+        match frame.tracker {
+            Tracker::Uninit => {}
+            Tracker::Init => {
+                // The value is about to be overwritten
+                if let Some(drop_fn) = frame.shape.vtable.sized().and_then(|v| (v.drop_in_place)())
+                {
+                    unsafe { drop_fn(PtrMut::new(frame.data.as_mut_byte_ptr())) };
+                }
+                frame.tracker = Tracker::Uninit;
+            }
+            _ => {
+                return Err(ReflectError::InvalidOperation {
+                    operation: "begin_try_from",
+                    reason: "the value is already partially initialized",
+                });
+            }
+        }
+
+        let layout = source_shape
+            .layout
+            .sized_layout()
+            .map_err(|_| ReflectError::Unsized {
+                shape: source_shape,
+            })?;
+        let data = if layout.size() == 0 {
+            PtrUninit::new(core::ptr::NonNull::<u8>::dangling().as_ptr())
+        } else {
+            let ptr = unsafe { alloc::alloc::alloc(layout) };
+            if ptr.is_null() {
+                alloc::alloc::handle_alloc_error(layout);
+            }
+            PtrUninit::new(ptr)
+        };
+
+        trace!(
+            "begin_try_from: Creating frame for {} (converted into {})",
+            source_shape, frame.shape
+        );
+        let mut frame = Frame::new(data, source_shape, FrameOwnership::Owned);
+        frame.try_from_parent = true;
+        self.frames.push(frame);
+        Ok(self)
+    }
+
     /// Builds the value
     pub fn build(&mut self) -> Result<HeapValue<'facet, 'shape>, ReflectError<'shape>> {
         self.require_active()?;
@@ -1962,12 +2353,17 @@ impl<'facet, 'shape> Partial<'facet, 'shape> {
     /// Returns a human-readable path representing the current traversal in the builder,
     /// e.g., `RootStruct.fieldName[index].subfield`.
     pub fn path(&self) -> String {
+        self.path_to(self.frames.len())
+    }
+
+    /// Like [`Self::path`], but only considers the first `depth` frames
+    fn path_to(&self, depth: usize) -> String {
         let mut out = String::new();
 
         let mut path_components = Vec::new();
         // The stack of enum/struct/sequence names currently in context.
         // Start from root and build upwards.
-        for (i, frame) in self.frames.iter().enumerate() {
+        for (i, frame) in self.frames[..depth].iter().enumerate() {
             match frame.shape.ty {
                 Type::User(user_type) => match user_type {
                     UserType::Struct(struct_type) => {
@@ -2211,6 +2607,77 @@ impl<'facet, 'shape> Partial<'facet, 'shape> {
         Ok(self)
     }
 
+    /// Begin building the Ok variant of a Result
+    pub fn begin_ok(&mut self) -> Result<&mut Self, ReflectError<'shape>> {
+        self.begin_result_variant(true)
+    }
+
+    /// Begin building the Err variant of a Result
+    pub fn begin_err(&mut self) -> Result<&mut Self, ReflectError<'shape>> {
+        self.begin_result_variant(false)
+    }
+
+    fn begin_result_variant(&mut self, is_ok: bool) -> Result<&mut Self, ReflectError<'shape>> {
+        self.require_active()?;
+        let frame = self.frames.last_mut().unwrap();
+
+        // Verify we're working with a Result
+        let result_def = match frame.shape.def {
+            Def::Result(def) => def,
+            _ => {
+                return Err(ReflectError::WasNotA {
+                    expected: "Result",
+                    actual: frame.shape,
+                });
+            }
+        };
+
+        match frame.tracker {
+            Tracker::Uninit => {}
+            Tracker::Init | Tracker::Result { .. } => {
+                // The result is about to be overwritten
+                if let Some(drop_fn) = frame.shape.vtable.sized().and_then(|v| (v.drop_in_place)())
+                {
+                    unsafe { drop_fn(PtrMut::new(frame.data.as_mut_byte_ptr())) };
+                }
+            }
+            _ => {
+                return Err(ReflectError::OperationFailed {
+                    shape: frame.shape,
+                    operation: "begin_ok/begin_err: unexpected frame state",
+                });
+            }
+        }
+        frame.tracker = Tracker::Result {
+            is_ok,
+            building_inner: true,
+        };
+
+        let inner_shape = if is_ok { result_def.t } else { result_def.e };
+
+        // Allocate memory for the inner value
+        let inner_layout = inner_shape
+            .layout
+            .sized_layout()
+            .map_err(|_| ReflectError::Unsized { shape: inner_shape })?;
+
+        let inner_data = if inner_layout.size() == 0 {
+            // For ZST, use a non-null but unallocated pointer
+            PtrUninit::new(core::ptr::NonNull::<u8>::dangling().as_ptr())
+        } else {
+            let ptr = unsafe { alloc::alloc::alloc(inner_layout) };
+            if ptr.is_null() {
+                alloc::alloc::handle_alloc_error(inner_layout);
+            }
+            PtrUninit::new(ptr)
+        };
+
+        let inner_frame = Frame::new(inner_data, inner_shape, FrameOwnership::Owned);
+        self.frames.push(inner_frame);
+
+        Ok(self)
+    }
+
     /// Begin building the inner value of a wrapper type
     pub fn begin_inner(&mut self) -> Result<&mut Self, ReflectError<'shape>> {
         self.require_active()?;
@@ -2301,6 +2768,31 @@ impl<'facet, 'shape> Partial<'facet, 'shape> {
         unsafe { self.set_shape(src_ptr.thin().unwrap(), src_shape) }
     }
 
+    /// Move a [`HeapValue`] into the current position
+    pub fn set_from_heap_value(
+        &mut self,
+        value: HeapValue<'facet, 'shape>,
+    ) -> Result<&mut Self, ReflectError<'shape>> {
+        self.require_active()?;
+
+        let src_shape = value.shape();
+        let fr = self.frames.last().unwrap();
+        if !fr.shape.is_shape(src_shape) {
+            return Err(ReflectError::WrongShape {
+                expected: fr.shape,
+                actual: src_shape,
+            });
+        }
+
+        // Safety: the shapes match, and set_shape moves the value out of the heap allocation
+        unsafe {
+            value.move_out(|src| {
+                self.set_shape(src.as_const(), src_shape).unwrap();
+            });
+        }
+        Ok(self)
+    }
+
     /// Copy a field from a struct's default value (safe wrapper for deserialization)
     /// This method creates the Peek internally to avoid exposing unsafe code to callers
     pub fn set_field_from_default(
@@ -2604,6 +3096,18 @@ impl<'facet, 'shape, T> TypedPartial<'facet, 'shape, T> {
         Ok(self)
     }
 
+    /// Forwards begin_set to the inner wip instance.
+    pub fn begin_set(&mut self) -> Result<&mut Self, ReflectError<'shape>> {
+        self.inner.begin_set()?;
+        Ok(self)
+    }
+
+    /// Forwards begin_set_item to the inner wip instance.
+    pub fn begin_set_item(&mut self) -> Result<&mut Self, ReflectError<'shape>> {
+        self.inner.begin_set_item()?;
+        Ok(self)
+    }
+
     /// Forwards begin_key to the inner wip instance.
     pub fn begin_key(&mut self) -> Result<&mut Self, ReflectError<'shape>> {
         self.inner.begin_key()?;
@@ -2712,11 +3216,38 @@ impl<'facet, 'shape, T> TypedPartial<'facet, 'shape, T> {
         Ok(self)
     }
 
+    /// Forwards begin_ok to the inner wip instance.
+    pub fn begin_ok(&mut self) -> Result<&mut Self, ReflectError<'shape>> {
+        self.inner.begin_ok()?;
+        Ok(self)
+    }
+
+    /// Forwards begin_err to the inner wip instance.
+    pub fn begin_err(&mut self) -> Result<&mut Self, ReflectError<'shape>> {
+        self.inner.begin_err()?;
+        Ok(self)
+    }
+
     /// Forwards begin_inner to the inner wip instance.
     pub fn begin_inner(&mut self) -> Result<&mut Self, ReflectError<'shape>> {
         self.inner.begin_inner()?;
         Ok(self)
     }
+
+    /// Forwards begin_deserialize_with to the inner wip instance.
+    pub fn begin_deserialize_with(&mut self) -> Result<&mut Self, ReflectError<'shape>> {
+        self.inner.begin_deserialize_with()?;
+        Ok(self)
+    }
+
+    /// Forwards begin_try_from to the inner wip instance.
+    pub fn begin_try_from(
+        &mut self,
+        source_shape: &'shape Shape<'shape>,
+    ) -> Result<&mut Self, ReflectError<'shape>> {
+        self.inner.begin_try_from(source_shape)?;
+        Ok(self)
+    }
 }
 
 impl<'facet, 'shape, T> core::fmt::Debug for TypedPartial<'facet, 'shape, T> {
@@ -2809,8 +3340,8 @@ impl<'facet, 'shape> Drop for Partial<'facet, 'shape> {
                     // Note: we don't deallocate the inner value here because
                     // the Box's drop will handle that
                 }
-                Tracker::List { is_initialized, .. } => {
-                    // Drop the initialized List
+                Tracker::List { is_initialized, .. } | Tracker::Set { is_initialized, .. } => {
+                    // Drop the initialized List or Set
                     if *is_initialized {
                         if let Some(drop_fn) =
                             frame.shape.vtable.sized().and_then(|v| (v.drop_in_place)())
@@ -2889,7 +3420,7 @@ impl<'facet, 'shape> Drop for Partial<'facet, 'shape> {
                         MapInsertState::Idle => {}
                     }
                 }
-                Tracker::Option { building_inner } => {
+                Tracker::Option { building_inner } | Tracker::Result { building_inner, .. } => {
                     // If we're building the inner value, it will be handled by the Option vtable
                     // No special cleanup needed here as the Option will either be properly
                     // initialized or remain uninitialized
@@ -1,4 +1,4 @@
-use facet_core::{IterVTable, PtrConst, PtrMut, Shape, ShapeLayout};
+use facet_core::{GenericPtr, IterVTable, PtrConst, PtrMut, Shape, ShapeLayout};
 
 use super::Peek;
 use core::{fmt::Debug, marker::PhantomData};
@@ -18,7 +18,7 @@ pub enum ListLikeDef<'shape> {
 
     /// Slice — a reference to a contiguous sequence of elements
     ///
-    /// e.g. `&[T]`
+    /// e.g. `&[T]`, or the `[T]` in a `Box<[T]>`
     Slice(facet_core::SliceDef<'shape>),
 }
 
@@ -122,7 +122,11 @@ impl<'mem, 'facet, 'shape> PeekListLike<'mem, 'facet, 'shape> {
     pub fn new(value: Peek<'mem, 'facet, 'shape>, def: ListLikeDef<'shape>) -> Self {
         let len = match def {
             ListLikeDef::List(v) => unsafe { (v.vtable.len)(value.data().thin().unwrap()) },
-            ListLikeDef::Slice(v) => unsafe { (v.vtable.len)(value.data().thin().unwrap()) },
+            ListLikeDef::Slice(v) => match value.data() {
+                GenericPtr::Thin(ptr) => unsafe { (v.vtable.len)(ptr) },
+                // See `base_ptr`
+                GenericPtr::Wide(ptr) => unsafe { (v.vtable.len)(PtrConst::new(&raw const ptr)) },
+            },
             ListLikeDef::Array(v) => v.n,
         };
         Self { value, def, len }
@@ -157,7 +161,7 @@ impl<'mem, 'facet, 'shape> PeekListLike<'mem, 'facet, 'shape> {
         }
 
         // Get the base pointer of the array
-        let base_ptr = unsafe { as_ptr(self.value.data().thin().unwrap()) };
+        let base_ptr = self.base_ptr(as_ptr);
 
         // Get the layout of the element type
         let elem_layout = match self.def.t().layout {
@@ -184,7 +188,7 @@ impl<'mem, 'facet, 'shape> PeekListLike<'mem, 'facet, 'shape> {
 
         let state = match (as_ptr_fn, iter_vtable) {
             (Some(as_ptr_fn), _) => {
-                let data = unsafe { as_ptr_fn(self.value.data().thin().unwrap()) };
+                let data = self.base_ptr(as_ptr_fn);
                 let layout = self
                     .def
                     .t()
@@ -212,6 +216,18 @@ impl<'mem, 'facet, 'shape> PeekListLike<'mem, 'facet, 'shape> {
         }
     }
 
+    /// Calls the `as_ptr` function of the vtable, to get a pointer to the first item
+    fn base_ptr(&self, as_ptr: unsafe fn(PtrConst) -> PtrConst) -> PtrConst<'mem> {
+        match self.value.data() {
+            GenericPtr::Thin(ptr) => unsafe { as_ptr(ptr) },
+            // Slices behind a smart pointer (like `Box<[T]>`) are peeked through a wide pointer to
+            // the slice itself: it has the same layout as the `&[T]` slice vtables expect
+            GenericPtr::Wide(ptr) => unsafe {
+                PtrConst::new(as_ptr(PtrConst::new(&raw const ptr)).as_byte_ptr())
+            },
+        }
+    }
+
     /// Def getter
     pub fn def(&self) -> ListLikeDef<'shape> {
         self.def
@@ -90,6 +90,21 @@ impl<'mem, 'facet, 'shape> PeekMap<'mem, 'facet, 'shape> {
         }
     }
 
+    /// Get a value from the map for a key that's only known through reflection
+    ///
+    /// Returns `None` if the key isn't in the map, or if its shape isn't the map's key shape.
+    pub fn get_peek(&self, key: Peek<'_, '_, '_>) -> Option<Peek<'mem, 'facet, 'shape>> {
+        if key.shape() != self.def.k() {
+            return None;
+        }
+        let key_ptr = key.data().thin()?;
+        unsafe {
+            let value_ptr =
+                (self.def.vtable.get_value_ptr_fn)(self.value.data().thin().unwrap(), key_ptr)?;
+            Some(Peek::unchecked_new(value_ptr, self.def.v()))
+        }
+    }
+
     /// Returns an iterator over the key-value pairs in the map
     pub fn iter(self) -> PeekMapIter<'mem, 'facet, 'shape> {
         let iter_init_with_value_fn = self.def.vtable.iter_vtable.init_with_value.unwrap();
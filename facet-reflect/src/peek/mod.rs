@@ -21,9 +21,15 @@ pub use list_like::*;
 mod map;
 pub use map::*;
 
+mod set;
+pub use set::*;
+
 mod option;
 pub use option::*;
 
+mod result;
+pub use result::*;
+
 mod smartptr;
 pub use smartptr::*;
 
@@ -0,0 +1,63 @@
+use facet_core::{ResultDef, ResultVTable};
+
+/// Lets you read from a result (implements read-only result operations)
+#[derive(Clone, Copy)]
+pub struct PeekResult<'mem, 'facet, 'shape> {
+    /// the underlying value
+    pub(crate) value: crate::Peek<'mem, 'facet, 'shape>,
+
+    /// the definition of the result
+    pub(crate) def: ResultDef<'shape>,
+}
+
+impl<'mem, 'facet, 'shape> PeekResult<'mem, 'facet, 'shape> {
+    /// Returns the result definition
+    #[inline(always)]
+    pub fn def(self) -> ResultDef<'shape> {
+        self.def
+    }
+
+    /// Returns the result vtable
+    #[inline(always)]
+    pub fn vtable(self) -> &'shape ResultVTable {
+        self.def.vtable
+    }
+
+    /// Returns whether the result is Ok
+    #[inline]
+    pub fn is_ok(self) -> bool {
+        unsafe { (self.vtable().is_ok_fn)(self.value.data().thin().unwrap()) }
+    }
+
+    /// Returns whether the result is Err
+    #[inline]
+    pub fn is_err(self) -> bool {
+        !self.is_ok()
+    }
+
+    /// Returns the value as a Peek if the result is Ok, None otherwise
+    pub fn ok(self) -> Option<crate::Peek<'mem, 'facet, 'shape>> {
+        unsafe {
+            (self.vtable().get_ok_fn)(self.value.data().thin().unwrap())
+                .map(|inner_data| crate::Peek::unchecked_new(inner_data, self.def.t()))
+        }
+    }
+
+    /// Returns the error as a Peek if the result is Err, None otherwise
+    pub fn err(self) -> Option<crate::Peek<'mem, 'facet, 'shape>> {
+        unsafe {
+            (self.vtable().get_err_fn)(self.value.data().thin().unwrap())
+                .map(|inner_data| crate::Peek::unchecked_new(inner_data, self.def.e()))
+        }
+    }
+
+    /// Returns the value or the error, whichever the result holds
+    pub fn value(
+        self,
+    ) -> Result<crate::Peek<'mem, 'facet, 'shape>, crate::Peek<'mem, 'facet, 'shape>> {
+        match self.ok() {
+            Some(value) => Ok(value),
+            None => Err(self.err().unwrap()),
+        }
+    }
+}
@@ -1,4 +1,8 @@
-use facet_core::SmartPointerDef;
+use core::marker::PhantomData;
+
+use facet_core::{LockResult, Shape, SmartPointerDef};
+
+use crate::ReflectError;
 
 use super::Peek;
 
@@ -35,4 +39,63 @@ impl<'mem, 'facet, 'shape> PeekSmartPointer<'mem, 'facet, 'shape> {
 
         Some(inner_peek)
     }
+
+    /// Locks the smart pointer and returns a guard giving access to the inner value.
+    ///
+    /// This is how the value inside a `Mutex`, `RwLock` or `RefCell` is read: reader-writer locks
+    /// are locked for reading, mutexes are locked exclusively. The lock is released when the
+    /// returned guard is dropped. A `Cell` can't be locked, so its guard holds a copy of the value.
+    ///
+    /// Returns an error if the smart pointer can't be locked, or if locking fails (for example
+    /// when the mutex is poisoned, or the `RefCell` is already mutably borrowed).
+    pub fn lock(&self) -> Result<PeekLockGuard<'mem, 'facet, 'shape>, ReflectError<'shape>> {
+        let shape = self.value.shape();
+        let lock_fn = self.def.vtable.read_fn.or(self.def.vtable.lock_fn).ok_or(
+            ReflectError::OperationFailed {
+                shape,
+                operation: "smart pointer does not support locking",
+            },
+        )?;
+        let pointee_shape = self.def.pointee().ok_or(ReflectError::OperationFailed {
+            shape,
+            operation: "smart pointer has no pointee shape",
+        })?;
+
+        // SAFETY: We have a valid smart pointer and lock_fn is provided by the vtable
+        let lock = unsafe { lock_fn(self.value.data.thin().unwrap()) }.map_err(|()| {
+            ReflectError::OperationFailed {
+                shape,
+                operation: "failed to acquire lock",
+            }
+        })?;
+
+        Ok(PeekLockGuard {
+            lock,
+            shape: pointee_shape,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+/// A lock held on a smart pointer (see [`PeekSmartPointer::lock`]).
+///
+/// The inner value can be peeked at for as long as the guard is alive; the lock is released
+/// when the guard is dropped.
+pub struct PeekLockGuard<'mem, 'facet, 'shape> {
+    /// The lock, released on drop
+    lock: LockResult<'mem>,
+
+    /// The shape of the locked value
+    shape: &'shape Shape<'shape>,
+
+    _phantom: PhantomData<&'facet ()>,
+}
+
+impl<'facet, 'shape> PeekLockGuard<'_, 'facet, 'shape> {
+    /// Peeks at the locked value.
+    pub fn peek(&self) -> Peek<'_, 'facet, 'shape> {
+        // SAFETY: The lock is held for as long as `self` is borrowed, and the lock function
+        // returns a pointer to a value of the pointee shape
+        unsafe { Peek::unchecked_new(self.lock.data().as_const(), self.shape) }
+    }
 }
@@ -1,13 +1,14 @@
-use core::{cmp::Ordering, marker::PhantomData, mem::transmute};
+use core::{cmp::Ordering, marker::PhantomData};
 use facet_core::{
-    Def, Facet, PointerType, PtrConst, PtrConstWide, PtrMut, Shape, StructKind, Type, TypeNameOpts,
-    UserType, ValueVTable,
+    Def, Facet, PointerType, PtrMut, Shape, StructKind, Type, TypeNameOpts, UserType, ValueVTable,
 };
 
+pub use facet_core::GenericPtr;
+
 use crate::{ReflectError, ScalarType};
 
 use super::{
-    ListLikeDef, PeekEnum, PeekList, PeekListLike, PeekMap, PeekSmartPointer, PeekStruct,
+    ListLikeDef, PeekEnum, PeekList, PeekListLike, PeekMap, PeekSet, PeekSmartPointer, PeekStruct,
     PeekTuple, tuple::TupleType,
 };
 
@@ -36,89 +37,6 @@ impl core::fmt::Debug for ValueId<'_> {
     }
 }
 
-/// A generic wrapper for either a thin or wide constant pointer.
-/// This enables working with both sized and unsized types using a single enum.
-#[derive(Clone, Copy)]
-pub enum GenericPtr<'mem> {
-    /// A thin pointer, used for sized types.
-    Thin(PtrConst<'mem>),
-    /// A wide pointer, used for unsized types such as slices and trait objects.
-    Wide(PtrConstWide<'mem>),
-}
-
-impl<'a> From<PtrConst<'a>> for GenericPtr<'a> {
-    fn from(value: PtrConst<'a>) -> Self {
-        GenericPtr::Thin(value)
-    }
-}
-
-impl<'a> From<PtrConstWide<'a>> for GenericPtr<'a> {
-    fn from(value: PtrConstWide<'a>) -> Self {
-        GenericPtr::Wide(value)
-    }
-}
-
-impl<'mem> GenericPtr<'mem> {
-    #[inline(always)]
-    fn new<T: ?Sized>(ptr: *const T) -> Self {
-        if size_of_val(&ptr) == size_of::<PtrConst>() {
-            GenericPtr::Thin(PtrConst::new(ptr.cast::<()>()))
-        } else if size_of_val(&ptr) == size_of::<PtrConstWide>() {
-            GenericPtr::Wide(PtrConstWide::new(ptr))
-        } else {
-            panic!("Couldn't determine if pointer to T is thin or wide");
-        }
-    }
-
-    /// Returns the inner [`PtrConst`] if this is a thin pointer, or `None` if this is a wide pointer.
-    #[inline(always)]
-    pub fn thin(self) -> Option<PtrConst<'mem>> {
-        match self {
-            GenericPtr::Thin(ptr) => Some(ptr),
-            GenericPtr::Wide(_ptr) => None,
-        }
-    }
-
-    #[inline(always)]
-    unsafe fn get<T: ?Sized>(self) -> &'mem T {
-        match self {
-            GenericPtr::Thin(ptr) => {
-                let ptr = ptr.as_byte_ptr();
-                let ptr_ref = &ptr;
-
-                (unsafe { transmute::<&*const u8, &&T>(ptr_ref) }) as _
-            }
-            GenericPtr::Wide(ptr) => unsafe { ptr.get() },
-        }
-    }
-
-    #[inline(always)]
-    fn as_byte_ptr(self) -> *const u8 {
-        match self {
-            GenericPtr::Thin(ptr) => ptr.as_byte_ptr(),
-            GenericPtr::Wide(ptr) => ptr.as_byte_ptr(),
-        }
-    }
-
-    /// Returns a pointer with the given offset added
-    ///
-    /// # Safety
-    ///
-    /// Offset must be within the bounds of the allocated memory,
-    /// and the resulting pointer must be properly aligned.
-    #[inline(always)]
-    pub unsafe fn field(self, offset: usize) -> GenericPtr<'mem> {
-        match self {
-            GenericPtr::Thin(ptr) => GenericPtr::Thin(unsafe { ptr.field(offset) }),
-            GenericPtr::Wide(_ptr) => {
-                // For wide pointers, we can't do field access safely without more context
-                // This is a limitation of the current design
-                panic!("Field access on wide pointers is not supported")
-            }
-        }
-    }
-}
-
 /// Lets you read from a value (implements read-only [`ValueVTable`] proxies)
 #[derive(Clone, Copy)]
 pub struct Peek<'mem, 'facet, 'shape> {
@@ -290,7 +208,7 @@ impl<'mem, 'facet, 'shape> Peek<'mem, 'facet, 'shape> {
     ///
     /// Panics if the shape doesn't match the type `T`.
     #[inline]
-    pub fn get<T: Facet<'facet>>(&self) -> Result<&T, ReflectError<'shape>> {
+    pub fn get<T: Facet<'facet> + ?Sized>(&self) -> Result<&T, ReflectError<'shape>> {
         if self.shape != T::SHAPE {
             Err(ReflectError::WrongShape {
                 expected: self.shape,
@@ -309,6 +227,9 @@ impl<'mem, 'facet, 'shape> Peek<'mem, 'facet, 'shape> {
             unsafe { Some(peek.data.get::<&str>()) }
         } else if let Some(ScalarType::String) = peek.scalar_type() {
             unsafe { Some(peek.data.get::<alloc::string::String>().as_str()) }
+        } else if peek.shape.is_type::<str>() {
+            // A `str` behind a smart pointer (like `Arc<str>`), peeked through a wide pointer
+            unsafe { Some(peek.data.get::<str>()) }
         } else if let Type::Pointer(PointerType::Reference(vpt)) = peek.shape.ty {
             let target_shape = (vpt.target)();
             if let Some(ScalarType::Str) = ScalarType::try_from_shape(target_shape) {
@@ -324,6 +245,11 @@ impl<'mem, 'facet, 'shape> Peek<'mem, 'facet, 'shape> {
     /// Try to get the value as a byte slice if it's a &[u8] type
     /// Returns None if the value is not a byte slice or couldn't be extracted
     pub fn as_bytes(&self) -> Option<&'mem [u8]> {
+        // Check if it's a `[u8]` behind a smart pointer (like `Box<[u8]>`)
+        if self.shape.is_type::<[u8]>() {
+            return unsafe { Some(self.data.get::<[u8]>()) };
+        }
+
         // Check if it's a direct &[u8]
         if let Type::Pointer(PointerType::Reference(vpt)) = self.shape.ty {
             let target_shape = (vpt.target)();
@@ -372,6 +298,18 @@ impl<'mem, 'facet, 'shape> Peek<'mem, 'facet, 'shape> {
         }
     }
 
+    /// Tries to identify this value as a set
+    pub fn into_set(self) -> Result<PeekSet<'mem, 'facet, 'shape>, ReflectError<'shape>> {
+        if let Def::Set(def) = self.shape.def {
+            Ok(PeekSet { value: self, def })
+        } else {
+            Err(ReflectError::WasNotA {
+                expected: "set",
+                actual: self.shape,
+            })
+        }
+    }
+
     /// Tries to identify this value as a list
     pub fn into_list(self) -> Result<PeekList<'mem, 'facet, 'shape>, ReflectError<'shape>> {
         if let Def::List(def) = self.shape.def {
@@ -391,6 +329,8 @@ impl<'mem, 'facet, 'shape> Peek<'mem, 'facet, 'shape> {
         match self.shape.def {
             Def::List(def) => Ok(PeekListLike::new(self, ListLikeDef::List(def))),
             Def::Array(def) => Ok(PeekListLike::new(self, ListLikeDef::Array(def))),
+            // A slice behind a smart pointer (like `Box<[T]>`), peeked through a wide pointer
+            Def::Slice(def) => Ok(PeekListLike::new(self, ListLikeDef::Slice(def))),
             _ => {
                 // &[i32] is actually a _pointer_ to a slice.
                 match self.shape.ty {
@@ -451,6 +391,20 @@ impl<'mem, 'facet, 'shape> Peek<'mem, 'facet, 'shape> {
         }
     }
 
+    /// Tries to identify this value as a result
+    pub fn into_result(
+        self,
+    ) -> Result<super::PeekResult<'mem, 'facet, 'shape>, ReflectError<'shape>> {
+        if let Def::Result(def) = self.shape.def {
+            Ok(super::PeekResult { value: self, def })
+        } else {
+            Err(ReflectError::WasNotA {
+                expected: "result",
+                actual: self.shape,
+            })
+        }
+    }
+
     /// Tries to identify this value as a tuple
     pub fn into_tuple(self) -> Result<PeekTuple<'mem, 'facet, 'shape>, ReflectError<'shape>> {
         if let Type::User(UserType::Struct(struct_type)) = self.shape.ty {
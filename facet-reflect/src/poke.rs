@@ -0,0 +1,401 @@
+//! Allows writing to values in place
+
+use core::marker::PhantomData;
+
+use facet_core::{
+    Def, Facet, FieldError, ListDef, MapDef, OptionDef, PtrConst, PtrMut, PtrUninit, Shape, Type,
+    UserType,
+};
+
+use crate::{HeapValue, Peek, ReflectError};
+
+/// Lets you modify a value in place: walk into its fields, elements, map values and options,
+/// replace it wholesale, or grow and shrink the lists and maps it contains.
+///
+/// Where [`Partial`](crate::Partial) builds new values, `Poke` edits existing ones. Values that
+/// are written through a `Poke` are passed as [`HeapValue`]s, which are moved in.
+pub struct Poke<'mem, 'facet, 'shape> {
+    data: PtrMut<'mem>,
+    shape: &'shape Shape<'shape>,
+    invariant: PhantomData<fn(&'facet ()) -> &'facet ()>,
+}
+
+impl<'mem, 'facet, 'shape> core::fmt::Debug for Poke<'mem, 'facet, 'shape> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Poke")
+            .field("shape", &self.shape)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'mem, 'facet, 'shape> Poke<'mem, 'facet, 'shape> {
+    /// Creates a new `Poke` for a value of type `T`.
+    pub fn new<T: Facet<'facet>>(t: &'mem mut T) -> Self {
+        Self {
+            data: PtrMut::new(t as *mut T),
+            shape: T::SHAPE,
+            invariant: PhantomData,
+        }
+    }
+
+    /// Creates a new `Poke` without checking the type.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `data` points to an initialized value of the given shape, and
+    /// that nothing else accesses it for `'mem`.
+    pub unsafe fn unchecked_new(data: PtrMut<'mem>, shape: &'shape Shape<'shape>) -> Self {
+        Self {
+            data,
+            shape,
+            invariant: PhantomData,
+        }
+    }
+
+    /// Returns the shape of the value
+    #[inline(always)]
+    pub fn shape(&self) -> &'shape Shape<'shape> {
+        self.shape
+    }
+
+    /// Returns a read-only view of the value
+    #[inline]
+    pub fn as_peek(&self) -> Peek<'_, 'facet, 'shape> {
+        unsafe { Peek::unchecked_new(self.data.as_const(), self.shape) }
+    }
+
+    /// Borrows this `Poke` for a shorter time, so walking into the value doesn't consume it
+    #[inline]
+    pub fn reborrow(&mut self) -> Poke<'_, 'facet, 'shape> {
+        Poke {
+            data: self.data,
+            shape: self.shape,
+            invariant: PhantomData,
+        }
+    }
+
+    /// Walks into the field with the given name: of a struct, or of the active variant of an enum
+    pub fn into_field(self, name: &str) -> Result<Self, ReflectError<'shape>> {
+        let fields = match self.shape.ty {
+            Type::User(UserType::Struct(ty)) => ty.fields,
+            Type::User(UserType::Enum(_)) => {
+                let variant = self.as_peek().into_enum()?.active_variant().map_err(|_| {
+                    ReflectError::OperationFailed {
+                        shape: self.shape,
+                        operation: "read the active variant",
+                    }
+                })?;
+                variant.data.fields
+            }
+            _ => {
+                return Err(ReflectError::WasNotA {
+                    expected: "struct or enum",
+                    actual: self.shape,
+                });
+            }
+        };
+        let field =
+            fields
+                .iter()
+                .find(|field| field.name == name)
+                .ok_or(ReflectError::FieldError {
+                    shape: self.shape,
+                    field_error: FieldError::NoSuchField,
+                })?;
+        Ok(unsafe { Self::unchecked_new(self.at(field.offset), field.shape()) })
+    }
+
+    /// Walks into the element at `index` of a list or array
+    pub fn into_index(self, index: usize) -> Result<Self, ReflectError<'shape>> {
+        match self.shape.def {
+            Def::List(def) => {
+                let len = self.list_len(def);
+                let get_mut = def.vtable.get_mut.ok_or(ReflectError::OperationFailed {
+                    shape: self.shape,
+                    operation: "get a mutable element",
+                })?;
+                let item =
+                    unsafe { get_mut(self.data, index) }.ok_or(ReflectError::FieldError {
+                        shape: self.shape,
+                        field_error: FieldError::IndexOutOfBounds { index, bound: len },
+                    })?;
+                Ok(unsafe { Self::unchecked_new(item, def.t()) })
+            }
+            Def::Array(def) => {
+                if index >= def.n {
+                    return Err(ReflectError::ArrayIndexOutOfBounds {
+                        shape: self.shape,
+                        index,
+                        size: def.n,
+                    });
+                }
+                let stride = def
+                    .t()
+                    .layout
+                    .sized_layout()
+                    .map_err(|_| ReflectError::Unsized { shape: def.t() })?
+                    .size();
+                let items = unsafe { (def.vtable.as_mut_ptr)(self.data) };
+                let item = PtrMut::new(unsafe { items.as_mut_byte_ptr().add(index * stride) });
+                Ok(unsafe { Self::unchecked_new(item, def.t()) })
+            }
+            _ => Err(ReflectError::WasNotA {
+                expected: "list or array",
+                actual: self.shape,
+            }),
+        }
+    }
+
+    /// Walks into the value stored under `key` in a map, if there is one
+    pub fn into_map_value(
+        self,
+        key: Peek<'_, '_, '_>,
+    ) -> Result<Option<Self>, ReflectError<'shape>> {
+        let def = self.map_def()?;
+        let key = self.map_key(def, key)?;
+        let get_value_ptr_mut =
+            def.vtable
+                .get_value_ptr_mut_fn
+                .ok_or(ReflectError::OperationFailed {
+                    shape: self.shape,
+                    operation: "get a mutable map value",
+                })?;
+        let value = unsafe { get_value_ptr_mut(self.data, key) };
+        Ok(value.map(|value| unsafe { Self::unchecked_new(value, def.v()) }))
+    }
+
+    /// Walks into the value of an option, if it is `Some`
+    pub fn into_some(self) -> Result<Option<Self>, ReflectError<'shape>> {
+        let def = self.option_def()?;
+        let inner = unsafe { (def.vtable.get_value_fn)(self.data.as_const()) };
+        Ok(inner.map(|inner| {
+            // The vtable only hands out shared pointers: locate the value relative to ours.
+            let offset = inner.as_byte_ptr() as usize - self.data.as_byte_ptr() as usize;
+            unsafe { Self::unchecked_new(self.at(offset), def.t()) }
+        }))
+    }
+
+    /// Replaces the value with `value`, dropping the old one
+    pub fn set(&mut self, value: HeapValue<'facet, 'shape>) -> Result<(), ReflectError<'shape>> {
+        self.check_shape(self.shape, &value)?;
+        unsafe {
+            let target = self.drop_in_place();
+            value.move_out(|src| {
+                target.copy_from(src.as_const(), self.shape).unwrap();
+            });
+        }
+        Ok(())
+    }
+
+    /// Replaces the contents of an option, dropping the old ones
+    pub fn option_replace(
+        &mut self,
+        value: Option<HeapValue<'facet, 'shape>>,
+    ) -> Result<(), ReflectError<'shape>> {
+        let def = self.option_def()?;
+        match value {
+            Some(value) => {
+                self.check_shape(def.t(), &value)?;
+                unsafe {
+                    value.move_out(|src| {
+                        (def.vtable.replace_with_fn)(self.data, Some(src.as_const()))
+                    })
+                };
+            }
+            None => unsafe { (def.vtable.replace_with_fn)(self.data, None) },
+        }
+        Ok(())
+    }
+
+    /// Takes the value out of an option, leaving `None` in its place
+    pub fn option_take(
+        &mut self,
+    ) -> Result<Option<HeapValue<'facet, 'shape>>, ReflectError<'shape>> {
+        let def = self.option_def()?;
+        let Some(inner) = (unsafe { (def.vtable.get_value_fn)(self.data.as_const()) }) else {
+            return Ok(None);
+        };
+        let value = unsafe {
+            HeapValue::try_init_with(def.t(), |dst| {
+                dst.copy_from(inner, def.t()).unwrap();
+                true
+            })?
+        };
+        // The value was moved out, so overwrite the option without dropping it
+        unsafe { (def.vtable.init_none_fn)(PtrUninit::new(self.data.as_mut_byte_ptr())) };
+        Ok(value)
+    }
+
+    /// Inserts a value at `index` in a list, shifting all elements after it to the right
+    pub fn list_insert(
+        &mut self,
+        index: usize,
+        value: HeapValue<'facet, 'shape>,
+    ) -> Result<(), ReflectError<'shape>> {
+        let def = self.list_def()?;
+        self.check_shape(def.t(), &value)?;
+        let len = self.list_len(def);
+        if index > len {
+            return Err(ReflectError::FieldError {
+                shape: self.shape,
+                field_error: FieldError::IndexOutOfBounds {
+                    index,
+                    bound: len + 1,
+                },
+            });
+        }
+        let insert = def.vtable.insert.ok_or(ReflectError::OperationFailed {
+            shape: self.shape,
+            operation: "insert into list",
+        })?;
+        unsafe { value.move_out(|item| insert(self.data, index, item)) };
+        Ok(())
+    }
+
+    /// Appends a value to the end of a list
+    pub fn list_push(
+        &mut self,
+        value: HeapValue<'facet, 'shape>,
+    ) -> Result<(), ReflectError<'shape>> {
+        let def = self.list_def()?;
+        self.check_shape(def.t(), &value)?;
+        let push = def.vtable.push.ok_or(ReflectError::OperationFailed {
+            shape: self.shape,
+            operation: "push to list",
+        })?;
+        unsafe { value.move_out(|item| push(self.data, item)) };
+        Ok(())
+    }
+
+    /// Removes the element at `index` of a list, shifting all elements after it to the left
+    pub fn list_remove(
+        &mut self,
+        index: usize,
+    ) -> Result<HeapValue<'facet, 'shape>, ReflectError<'shape>> {
+        let def = self.list_def()?;
+        let len = self.list_len(def);
+        if index >= len {
+            return Err(ReflectError::FieldError {
+                shape: self.shape,
+                field_error: FieldError::IndexOutOfBounds { index, bound: len },
+            });
+        }
+        let remove = def.vtable.remove.ok_or(ReflectError::OperationFailed {
+            shape: self.shape,
+            operation: "remove from list",
+        })?;
+        let value = unsafe {
+            HeapValue::try_init_with(def.t(), |item| {
+                remove(self.data, index, item);
+                true
+            })?
+        };
+        Ok(value.unwrap())
+    }
+
+    /// Inserts an entry into a map, replacing (and dropping) any previous value for the key
+    pub fn map_insert(
+        &mut self,
+        key: HeapValue<'facet, 'shape>,
+        value: HeapValue<'facet, 'shape>,
+    ) -> Result<(), ReflectError<'shape>> {
+        let def = self.map_def()?;
+        self.check_shape(def.k(), &key)?;
+        self.check_shape(def.v(), &value)?;
+        unsafe {
+            key.move_out(|key| {
+                value.move_out(|value| (def.vtable.insert_fn)(self.data, key, value));
+            })
+        };
+        Ok(())
+    }
+
+    /// Removes the entry for `key` from a map, returning its value if there was one
+    pub fn map_remove(
+        &mut self,
+        key: Peek<'_, '_, '_>,
+    ) -> Result<Option<HeapValue<'facet, 'shape>>, ReflectError<'shape>> {
+        let def = self.map_def()?;
+        let key = self.map_key(def, key)?;
+        let remove = def.vtable.remove_fn.ok_or(ReflectError::OperationFailed {
+            shape: self.shape,
+            operation: "remove from map",
+        })?;
+        unsafe { HeapValue::try_init_with(def.v(), |value| remove(self.data, key, value)) }
+    }
+
+    fn at(&self, offset: usize) -> PtrMut<'mem> {
+        PtrMut::new(unsafe { self.data.as_mut_byte_ptr().add(offset) })
+    }
+
+    unsafe fn drop_in_place(&mut self) -> PtrUninit<'mem> {
+        if let Some(drop_fn) = self.shape.vtable.sized().and_then(|v| (v.drop_in_place)()) {
+            unsafe { drop_fn(self.data) };
+        }
+        PtrUninit::new(self.data.as_mut_byte_ptr())
+    }
+
+    fn check_shape(
+        &self,
+        expected: &'shape Shape<'shape>,
+        value: &HeapValue<'facet, 'shape>,
+    ) -> Result<(), ReflectError<'shape>> {
+        if value.shape() != expected {
+            return Err(ReflectError::WrongShape {
+                expected,
+                actual: value.shape(),
+            });
+        }
+        Ok(())
+    }
+
+    fn list_def(&self) -> Result<ListDef<'shape>, ReflectError<'shape>> {
+        match self.shape.def {
+            Def::List(def) => Ok(def),
+            _ => Err(ReflectError::WasNotA {
+                expected: "list",
+                actual: self.shape,
+            }),
+        }
+    }
+
+    fn list_len(&self, def: ListDef<'shape>) -> usize {
+        unsafe { (def.vtable.len)(self.data.as_const()) }
+    }
+
+    fn map_def(&self) -> Result<MapDef<'shape>, ReflectError<'shape>> {
+        match self.shape.def {
+            Def::Map(def) => Ok(def),
+            _ => Err(ReflectError::WasNotA {
+                expected: "map",
+                actual: self.shape,
+            }),
+        }
+    }
+
+    fn map_key<'key>(
+        &self,
+        def: MapDef<'shape>,
+        key: Peek<'key, '_, '_>,
+    ) -> Result<PtrConst<'key>, ReflectError<'shape>> {
+        if key.shape() != def.k() {
+            return Err(ReflectError::InvalidOperation {
+                operation: "map key lookup",
+                reason: "the key's shape is not the map's key shape",
+            });
+        }
+        key.data()
+            .thin()
+            .ok_or(ReflectError::Unsized { shape: def.k() })
+    }
+
+    fn option_def(&self) -> Result<OptionDef<'shape>, ReflectError<'shape>> {
+        match self.shape.def {
+            Def::Option(def) => Ok(def),
+            _ => Err(ReflectError::WasNotA {
+                expected: "option",
+                actual: self.shape,
+            }),
+        }
+    }
+}
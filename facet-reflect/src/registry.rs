@@ -0,0 +1,54 @@
+//! Resolves type tags and type names back to shapes
+
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+
+use facet_core::{Facet, Shape};
+
+/// A set of shapes that can be looked up by type tag (`#[facet(type_tag = "...")]`) or by type
+/// name, for polymorphic payloads whose concrete type is only known at runtime.
+///
+/// Type names include generic parameters (`Vec<u32>`, not `Vec`), so different instantiations
+/// of a generic type don't collide. Registering two shapes under the same tag or type name keeps
+/// the last one.
+#[derive(Debug, Clone, Default)]
+pub struct ShapeRegistry<'shape> {
+    by_tag: BTreeMap<&'shape str, &'shape Shape<'shape>>,
+    by_type_name: BTreeMap<String, &'shape Shape<'shape>>,
+}
+
+impl<'shape> ShapeRegistry<'shape> {
+    /// Creates an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the shape of `T`
+    pub fn register<'facet, T: Facet<'facet>>(&mut self) -> &mut Self {
+        self.register_shape(T::SHAPE)
+    }
+
+    /// Registers a shape under its type tag (if it has one) and its type name
+    pub fn register_shape(&mut self, shape: &'shape Shape<'shape>) -> &mut Self {
+        if let Some(tag) = shape.type_tag {
+            self.by_tag.insert(tag, shape);
+        }
+        self.by_type_name.insert(shape.to_string(), shape);
+        self
+    }
+
+    /// Returns the shape registered with that type tag
+    pub fn by_tag(&self, tag: &str) -> Option<&'shape Shape<'shape>> {
+        self.by_tag.get(tag).copied()
+    }
+
+    /// Returns the shape registered with that type name, e.g. `Order` or `Page<Order>`
+    pub fn by_type_name(&self, type_name: &str) -> Option<&'shape Shape<'shape>> {
+        self.by_type_name.get(type_name).copied()
+    }
+
+    /// Resolves a name to a shape, as a type tag first, then as a type name
+    pub fn lookup(&self, name: &str) -> Option<&'shape Shape<'shape>> {
+        self.by_tag(name).or_else(|| self.by_type_name(name))
+    }
+}
@@ -1,4 +1,4 @@
-use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6};
 
 use facet_core::{ConstTypeId, Shape};
 
@@ -18,6 +18,10 @@ pub enum ScalarType {
     String,
     /// `alloc::borrow::Cow<'_, str>`.
     CowStr,
+    /// `alloc::ffi::CString`.
+    CString,
+    /// `std::ffi::OsString`.
+    OsString,
     /// Primitive type `f32`.
     F32,
     /// Primitive type `f64`.
@@ -48,6 +52,10 @@ pub enum ScalarType {
     ISize,
     /// `core::net::SocketAddr`.
     SocketAddr,
+    /// `core::net::SocketAddrV4`.
+    SocketAddrV4,
+    /// `core::net::SocketAddrV6`.
+    SocketAddrV6,
     /// `core::net::IpAddr`.
     IpAddr,
     /// `core::net::Ipv4Addr`.
@@ -68,6 +76,13 @@ impl ScalarType {
             return Some(ScalarType::CowStr);
         } else if shape.id == ConstTypeId::of::<core::net::SocketAddr>() {
             return Some(ScalarType::SocketAddr);
+        } else if shape.id == ConstTypeId::of::<alloc::ffi::CString>() {
+            return Some(ScalarType::CString);
+        }
+
+        #[cfg(feature = "std")]
+        if shape.id == ConstTypeId::of::<std::ffi::OsString>() {
+            return Some(ScalarType::OsString);
         }
 
         if shape.id == ConstTypeId::of::<()>() {
@@ -112,6 +127,10 @@ impl ScalarType {
             Some(ScalarType::Ipv4Addr)
         } else if shape.id == ConstTypeId::of::<Ipv6Addr>() {
             Some(ScalarType::Ipv6Addr)
+        } else if shape.id == ConstTypeId::of::<SocketAddrV4>() {
+            Some(ScalarType::SocketAddrV4)
+        } else if shape.id == ConstTypeId::of::<SocketAddrV6>() {
+            Some(ScalarType::SocketAddrV6)
         } else if shape.id == ConstTypeId::of::<ConstTypeId>() {
             Some(ScalarType::ConstTypeId)
         } else {
@@ -124,7 +143,7 @@ impl ScalarType {
 mod tests {
     use super::*;
 
-    use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+    use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6};
 
     use facet_core::Facet;
 
@@ -153,6 +172,16 @@ mod tests {
             ScalarType::CowStr,
             ScalarType::try_from_shape(alloc::borrow::Cow::SHAPE).unwrap()
         );
+        #[cfg(feature = "std")]
+        assert_eq!(
+            ScalarType::CString,
+            ScalarType::try_from_shape(alloc::ffi::CString::SHAPE).unwrap()
+        );
+        #[cfg(feature = "std")]
+        assert_eq!(
+            ScalarType::OsString,
+            ScalarType::try_from_shape(std::ffi::OsString::SHAPE).unwrap()
+        );
         assert_eq!(
             ScalarType::F32,
             ScalarType::try_from_shape(f32::SHAPE).unwrap()
@@ -214,6 +243,14 @@ mod tests {
             ScalarType::SocketAddr,
             ScalarType::try_from_shape(core::net::SocketAddr::SHAPE).unwrap()
         );
+        assert_eq!(
+            ScalarType::SocketAddrV4,
+            ScalarType::try_from_shape(SocketAddrV4::SHAPE).unwrap()
+        );
+        assert_eq!(
+            ScalarType::SocketAddrV6,
+            ScalarType::try_from_shape(SocketAddrV6::SHAPE).unwrap()
+        );
         assert_eq!(
             ScalarType::IpAddr,
             ScalarType::try_from_shape(IpAddr::SHAPE).unwrap()
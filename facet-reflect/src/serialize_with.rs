@@ -0,0 +1,38 @@
+//! Converts fields into the type they're serialized as, like `#[facet(serialize_with = ..)]`
+
+use facet_core::Field;
+
+use crate::{HeapValue, Peek, ReflectError};
+
+impl<'mem, 'facet, 'shape> Peek<'mem, 'facet, 'shape> {
+    /// Converts this value, the value of `field`, into the type it's serialized as with
+    /// `#[facet(serialize_with = ..)]` or `#[facet(proxy = ..)]`.
+    ///
+    /// Returns `None` for fields that are serialized as they are.
+    pub fn serialize_with(
+        &self,
+        field: &Field<'shape>,
+    ) -> Result<Option<HeapValue<'facet, 'shape>>, ReflectError<'shape>> {
+        let Some(serialize_with) = field.vtable.serialize_with else {
+            return Ok(None);
+        };
+        if !self.shape().is_shape(field.shape()) {
+            return Err(ReflectError::WrongShape {
+                expected: field.shape(),
+                actual: self.shape(),
+            });
+        }
+        let src = self.data().thin().ok_or(ReflectError::Unsized {
+            shape: self.shape(),
+        })?;
+        let shape = (serialize_with.shape)();
+        // Safety: the value has the shape of the field, which `convert` takes, and `convert`
+        // initializes a value of `shape`
+        unsafe {
+            HeapValue::try_init_with(shape, |target| {
+                (serialize_with.convert)(src, target);
+                true
+            })
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use facet::{Facet, Type, UserType};
+use facet_reflect::{Partial, Peek, ReflectError};
+use facet_testhelpers::test;
+
+#[derive(Debug, PartialEq, Facet)]
+struct Server {
+    #[facet(min = 1, max = 65535)]
+    port: u32,
+    #[facet(non_empty, max_len = 8)]
+    name: String,
+    #[facet(max_len = 2)]
+    aliases: Vec<String>,
+    #[facet(min = 0.0, max = 1.0)]
+    load: Option<f64>,
+    listener: Listener,
+}
+
+#[derive(Debug, PartialEq, Facet)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Listener {
+    Tcp {
+        #[facet(min = 1)]
+        backlog: u16,
+    },
+    Unix(#[facet(non_empty)] String),
+}
+
+fn build(
+    port: u32,
+    name: &str,
+    aliases: &[&str],
+    load: Option<f64>,
+) -> Result<Server, ReflectError<'static>> {
+    let mut partial = Partial::alloc::<Server>()?;
+    partial.set_field("port", port)?;
+    partial.set_field("name", name.to_string())?;
+    partial.begin_field("aliases")?.begin_list()?;
+    for alias in aliases {
+        partial.push(alias.to_string())?;
+    }
+    partial.end()?;
+    partial.set_field("load", load)?;
+    partial
+        .begin_field("listener")?
+        .select_variant_named("Tcp")?
+        .set_field("backlog", 16u16)?
+        .end()?;
+    Ok(*partial.build()?)
+}
+
+fn violation(err: ReflectError<'_>) -> (String, String) {
+    match err {
+        ReflectError::ConstraintViolation { path, constraint } => (path, constraint.to_string()),
+        other => panic!("expected a constraint violation, got {other}"),
+    }
+}
+
+#[test]
+fn test_constraints_satisfied() {
+    let server = build(8080, "web", &["www"], Some(0.5))?;
+    assert_eq!(server.port, 8080);
+    build(1, "12345678", &[], None)?;
+    build(65535, "x", &["a", "b"], Some(1.0))?;
+}
+
+#[test]
+fn test_number_bounds() {
+    let (path, constraint) = violation(build(0, "web", &[], None).unwrap_err());
+    assert_eq!(path, "Server.port");
+    assert_eq!(constraint, "min = 1");
+
+    let (_, constraint) = violation(build(65536, "web", &[], None).unwrap_err());
+    assert_eq!(constraint, "max = 65535");
+
+    let (path, constraint) = violation(build(80, "web", &[], Some(-0.1)).unwrap_err());
+    assert_eq!(path, "Server.load");
+    assert_eq!(constraint, "min = 0");
+}
+
+#[test]
+fn test_length_bounds() {
+    let (path, constraint) = violation(build(80, "", &[], None).unwrap_err());
+    assert_eq!(path, "Server.name");
+    assert_eq!(constraint, "non_empty");
+
+    // Lengths of strings are counted in characters, not bytes
+    build(80, "ééééé", &[], None)?;
+    let (_, constraint) = violation(build(80, "ééééééééé", &[], None).unwrap_err());
+    assert_eq!(constraint, "max_len = 8");
+
+    let (path, _) = violation(build(80, "web", &["a", "b", "c"], None).unwrap_err());
+    assert_eq!(path, "Server.aliases");
+}
+
+#[test]
+fn test_enum_field_constraints() {
+    let mut partial = Partial::alloc::<Listener>()?;
+    partial.select_variant_named("Tcp")?;
+    let (path, _) = violation(partial.set_field("backlog", 0u16).unwrap_err());
+    assert_eq!(path, "Listener::Tcp.backlog");
+
+    let mut partial = Partial::alloc::<Listener>()?;
+    partial.select_variant_named("Unix")?;
+    let (path, constraint) = violation(partial.set_nth_enum_field(0, String::new()).unwrap_err());
+    assert_eq!(path, "Listener::Unix.0");
+    assert_eq!(constraint, "non_empty");
+}
+
+#[test]
+fn test_check_constraints_on_peek() {
+    let Type::User(UserType::Struct(server)) = Server::SHAPE.ty else {
+        unreachable!()
+    };
+    let field = server.fields[0];
+    assert!(Peek::new(&80u32).check_constraints(&field, "port").is_ok());
+    let err = Peek::new(&0u32)
+        .check_constraints(&field, "port")
+        .unwrap_err();
+    assert_eq!(violation(err).0, "port");
+}
+
+#[test]
+fn test_constraint_on_wrong_type() {
+    #[derive(Facet)]
+    struct Weird {
+        #[facet(min_len = 1)]
+        count: u32,
+    }
+
+    let mut partial = Partial::alloc::<Weird>()?;
+    let err = partial.set_field("count", 3u32).unwrap_err();
+    assert!(matches!(err, ReflectError::WasNotA { .. }));
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn test_pattern() {
+    #[derive(Debug, Facet)]
+    struct Tag {
+        #[facet(pattern = r"^[a-z]+(-[a-z]+)*$")]
+        slug: String,
+    }
+
+    let mut partial = Partial::alloc::<Tag>()?;
+    partial.set_field("slug", "hello-world".to_string())?;
+
+    let mut partial = Partial::alloc::<Tag>()?;
+    let (path, constraint) = violation(
+        partial
+            .set_field("slug", "Hello World".to_string())
+            .unwrap_err(),
+    );
+    assert_eq!(path, "Tag.slug");
+    assert_eq!(constraint, r#"pattern = "^[a-z]+(-[a-z]+)*$""#);
+}
+
+#[cfg(not(feature = "regex"))]
+#[test]
+fn test_pattern_without_regex() {
+    #[derive(Debug, Facet)]
+    struct Tag {
+        #[facet(pattern = r"^[a-z]+$")]
+        slug: String,
+    }
+
+    let mut partial = Partial::alloc::<Tag>()?;
+    let err = partial.set_field("slug", "hello".to_string()).unwrap_err();
+    assert!(matches!(err, ReflectError::OperationFailed { .. }));
+}
@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hasher, RandomState};
+use std::sync::Arc;
+
+use facet::Facet;
+use facet_reflect::{ReflectError, deep_clone, deep_cmp, deep_eq, deep_hash};
+use facet_testhelpers::test;
+
+// None of these implement Clone, PartialEq, PartialOrd or Hash
+#[derive(Debug, Facet)]
+struct Resource {
+    id: u64,
+    labels: HashMap<String, String>,
+    spec: Option<Box<Spec>>,
+    weights: Vec<f64>,
+    state: State,
+    owner: Arc<Owner>,
+    marker: Marker,
+}
+
+#[derive(Debug, Facet)]
+struct Spec {
+    replicas: u32,
+    image: String,
+}
+
+#[derive(Debug, Facet)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum State {
+    Pending,
+    Running { since: u64 },
+    Failed(String),
+}
+
+#[derive(Debug, Facet)]
+struct Owner(String);
+
+#[derive(Debug, Facet)]
+struct Marker;
+
+fn resource() -> Resource {
+    Resource {
+        id: 1,
+        labels: HashMap::from([
+            ("app".to_string(), "web".to_string()),
+            ("tier".to_string(), "front".to_string()),
+        ]),
+        spec: Some(Box::new(Spec {
+            replicas: 3,
+            image: "nginx".to_string(),
+        })),
+        weights: vec![0.5, -0.0],
+        state: State::Running { since: 42 },
+        owner: Arc::new(Owner("ops".to_string())),
+        marker: Marker,
+    }
+}
+
+fn hash(value: &Resource, state: &RandomState) -> u64 {
+    let mut hasher = state.build_hasher();
+    deep_hash(value, &mut hasher).unwrap();
+    hasher.finish()
+}
+
+#[test]
+fn test_deep_eq_and_hash() {
+    let state = RandomState::new();
+    let a = resource();
+    let mut b = resource();
+    b.weights[1] = 0.0;
+    assert!(deep_eq(&a, &b));
+    assert_eq!(hash(&a, &state), hash(&b, &state));
+
+    b.spec.as_mut().unwrap().replicas = 4;
+    assert!(!deep_eq(&a, &b));
+
+    let mut c = resource();
+    c.labels.insert("zone".to_string(), "eu".to_string());
+    assert!(!deep_eq(&a, &c));
+    assert_ne!(hash(&a, &state), hash(&c, &state));
+
+    let mut d = resource();
+    d.state = State::Failed("oom".to_string());
+    assert!(!deep_eq(&a, &d));
+    assert_ne!(hash(&a, &state), hash(&d, &state));
+}
+
+#[test]
+fn test_deep_cmp() {
+    let a = Spec {
+        replicas: 3,
+        image: "b".to_string(),
+    };
+    let b = Spec {
+        replicas: 3,
+        image: "a".to_string(),
+    };
+    assert_eq!(deep_cmp(&a, &b), Some(std::cmp::Ordering::Greater));
+    assert_eq!(deep_cmp(&a, &a), Some(std::cmp::Ordering::Equal));
+
+    assert_eq!(
+        deep_cmp(&State::Pending, &State::Running { since: 0 }),
+        Some(std::cmp::Ordering::Less)
+    );
+    assert_eq!(
+        deep_cmp(
+            &vec![Owner("a".into())],
+            &vec![Owner("a".into()), Owner("b".into())]
+        ),
+        Some(std::cmp::Ordering::Less)
+    );
+    assert_eq!(deep_cmp(&resource(), &resource()), None);
+}
+
+#[test]
+fn test_deep_clone() {
+    let a = resource();
+    let b = deep_clone(&a)?;
+    assert!(deep_eq(&a, &b));
+
+    let state = deep_clone(&State::Failed("oom".to_string()))?;
+    assert!(matches!(state, State::Failed(reason) if reason == "oom"));
+}
+
+#[test]
+fn test_deep_clone_opaque() {
+    #[allow(dead_code)]
+    struct Handle(u32);
+
+    #[derive(Facet)]
+    struct Holder {
+        #[facet(opaque)]
+        handle: Handle,
+    }
+
+    let err = deep_clone(&Holder { handle: Handle(1) }).unwrap_err();
+    assert!(matches!(err, ReflectError::MissingCharacteristic { .. }));
+}
@@ -0,0 +1,110 @@
+use std::cmp::Ordering;
+use std::hash::{BuildHasher, Hasher, RandomState};
+
+use facet::Facet;
+use facet_reflect::{HeapValue, ReflectError};
+use facet_testhelpers::test;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Facet)]
+struct Tag {
+    name: String,
+    weight: u8,
+}
+
+#[derive(Debug, Facet)]
+struct Opaque {
+    id: u32,
+}
+
+#[test]
+fn test_heterogeneous_values() {
+    let values = vec![
+        HeapValue::new(42u32),
+        HeapValue::new("hello".to_string()),
+        HeapValue::new(Tag {
+            name: "a".to_string(),
+            weight: 1,
+        }),
+    ];
+
+    assert!(values[0].is::<u32>());
+    assert!(!values[0].is::<u64>());
+    assert_eq!(values[0].type_id(), u32::SHAPE.id);
+    assert_eq!(values[1].downcast_ref::<String>().unwrap(), "hello");
+    assert_eq!(values[1].downcast_ref::<u32>(), None);
+    assert_eq!(
+        values[2]
+            .peek()
+            .into_struct()?
+            .field_by_name("weight")?
+            .get::<u8>()?,
+        &1
+    );
+    assert_eq!(format!("{:?}", values[0]), "42");
+}
+
+#[test]
+fn test_downcast() {
+    let mut value = HeapValue::new(vec![1, 2, 3]);
+    value.downcast_mut::<Vec<i32>>().unwrap().push(4);
+
+    let value = value.downcast::<String>().unwrap_err();
+    assert_eq!(value.downcast::<Vec<i32>>().unwrap(), [1, 2, 3, 4]);
+
+    let unit = HeapValue::new(());
+    assert!(unit.downcast::<()>().is_ok());
+}
+
+#[test]
+fn test_poke_into_heap_value() {
+    let mut value = HeapValue::new(Tag {
+        name: "a".to_string(),
+        weight: 1,
+    });
+    value
+        .poke()
+        .into_field("weight")?
+        .set(HeapValue::new(9u8))?;
+    assert_eq!(value.downcast_ref::<Tag>().unwrap().weight, 9);
+}
+
+#[test]
+fn test_clone_eq_hash() {
+    let tag = HeapValue::new(Tag {
+        name: "a".to_string(),
+        weight: 1,
+    });
+    let copy = tag.try_clone()?;
+    assert!(tag.try_eq(&copy)?);
+    assert!(!tag.try_eq(&HeapValue::new(1u8))?);
+    assert_eq!(
+        HeapValue::new(1u8).try_partial_cmp(&HeapValue::new(2u8))?,
+        Some(Ordering::Less)
+    );
+    assert_eq!(tag.try_partial_cmp(&HeapValue::new(1u8))?, None);
+
+    let hasher = RandomState::new();
+    let hash_one = |value: &HeapValue| {
+        let mut hasher = hasher.build_hasher();
+        value.try_hash(&mut hasher).map(|()| hasher.finish())
+    };
+    assert_eq!(hash_one(&tag)?, hash_one(&copy)?);
+    assert_ne!(
+        hash_one(&HeapValue::new(1u8))?,
+        hash_one(&HeapValue::new(1u16))?
+    );
+
+    let opaque = HeapValue::new(Opaque { id: 1 });
+    assert!(matches!(
+        opaque.try_clone(),
+        Err(ReflectError::MissingCharacteristic { .. })
+    ));
+    assert!(matches!(
+        opaque.try_eq(&HeapValue::new(Opaque { id: 1 })),
+        Err(ReflectError::MissingCharacteristic { .. })
+    ));
+    assert!(matches!(
+        hash_one(&opaque),
+        Err(ReflectError::MissingCharacteristic { .. })
+    ));
+}
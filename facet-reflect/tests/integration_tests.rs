@@ -1,2 +1,7 @@
+mod constraints;
+mod deep;
+mod heap_value;
+mod minimal;
 mod partial;
 mod peek;
+mod poke;
@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+use std::num::NonZero;
+
+use facet::Facet;
+use facet_reflect::{Partial, ReflectError, minimal};
+use facet_testhelpers::test;
+
+// Deliberately doesn't implement Default
+#[derive(Debug, PartialEq, Facet)]
+struct Config {
+    name: String,
+    #[facet(default = default_port())]
+    port: u16,
+    tls: Option<Tls>,
+    hosts: Vec<String>,
+    env: HashMap<String, String>,
+    limits: Box<Limits>,
+    mode: Mode,
+    checksum: [u8; 4],
+    marker: Marker,
+}
+
+fn default_port() -> u16 {
+    8080
+}
+
+#[derive(Debug, PartialEq, Facet)]
+struct Tls {
+    cert: String,
+}
+
+#[derive(Debug, PartialEq, Facet)]
+struct Limits(u32, f64);
+
+#[derive(Debug, PartialEq, Facet)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Mode {
+    Custom { threads: u32 },
+    Auto,
+    Fixed(u32),
+}
+
+#[derive(Debug, PartialEq, Facet)]
+struct Marker;
+
+#[test]
+fn test_minimal_struct() {
+    let config: Config = minimal()?;
+    assert_eq!(
+        config,
+        Config {
+            name: String::new(),
+            port: 8080,
+            tls: None,
+            hosts: Vec::new(),
+            env: HashMap::new(),
+            limits: Box::new(Limits(0, 0.0)),
+            mode: Mode::Auto,
+            checksum: [0; 4],
+            marker: Marker,
+        }
+    );
+}
+
+#[test]
+fn test_minimal_enum_without_unit_variant() {
+    #[derive(Debug, PartialEq, Facet)]
+    #[repr(u8)]
+    #[allow(dead_code)]
+    enum Shape {
+        Rect { w: u32, h: u32 },
+        Circle(u32),
+    }
+
+    assert_eq!(minimal::<Shape>()?, Shape::Circle(0));
+}
+
+#[test]
+fn test_minimal_skips_recursive_variants() {
+    #[derive(Debug, PartialEq, Facet)]
+    #[repr(u8)]
+    #[allow(dead_code)]
+    enum Tree {
+        Node(Box<Tree>),
+        Leaf(u32, u32),
+    }
+
+    assert_eq!(minimal::<Tree>()?, Tree::Leaf(0, 0));
+    assert_eq!(
+        minimal::<Result<Box<Tree>, u8>>()?,
+        Ok(Box::new(Tree::Leaf(0, 0)))
+    );
+}
+
+#[test]
+fn test_minimal_infinitely_recursive() {
+    #[derive(Debug, Facet)]
+    struct Chain {
+        next: Box<Chain>,
+    }
+
+    let err = minimal::<Chain>().unwrap_err();
+    assert!(matches!(err, ReflectError::OperationFailed { .. }));
+}
+
+#[test]
+fn test_minimal_non_zero() {
+    assert_eq!(minimal::<NonZero<u32>>()?, NonZero::new(1).unwrap());
+    assert_eq!(minimal::<NonZero<i8>>()?, NonZero::new(1).unwrap());
+    assert_eq!(
+        minimal::<(NonZero<u64>, [NonZero<u16>; 2])>()?,
+        (NonZero::new(1).unwrap(), [NonZero::new(1).unwrap(); 2])
+    );
+}
+
+#[test]
+fn test_minimal_prefers_default() {
+    #[derive(Debug, PartialEq, Facet)]
+    struct Retries(u8);
+
+    impl Default for Retries {
+        fn default() -> Self {
+            Retries(3)
+        }
+    }
+
+    #[derive(Debug, PartialEq, Facet)]
+    struct Job {
+        retries: Retries,
+        id: u64,
+    }
+
+    assert_eq!(
+        minimal::<Job>()?,
+        Job {
+            retries: Retries(3),
+            id: 0
+        }
+    );
+}
+
+#[test]
+fn test_set_minimal_on_field() {
+    let mut partial = Partial::alloc::<(u32, Config)>()?;
+    partial.set_nth_field(0, 7u32)?;
+    partial.begin_nth_field(1)?.set_minimal()?.end()?;
+    let (n, config) = *partial.build()?;
+    assert_eq!(n, 7);
+    assert_eq!(config.port, 8080);
+}
+
+#[test]
+fn test_minimal_opaque() {
+    #[allow(dead_code)]
+    struct Handle(u32);
+
+    #[derive(Facet)]
+    struct Holder {
+        #[facet(opaque)]
+        handle: Handle,
+    }
+
+    let err = minimal::<Holder>().unwrap_err();
+    assert!(matches!(err, ReflectError::MissingCharacteristic { .. }));
+}
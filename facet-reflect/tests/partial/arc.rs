@@ -78,3 +78,44 @@ fn outer_yes_arc_field_named_twice_error() {
         "Error message should mention 'opaque types cannot be reflected upon', got: {err_string}"
     );
 }
+
+#[test]
+fn arc_str_from_string() {
+    let mut partial = Partial::alloc::<Arc<str>>()?;
+    partial.begin_smart_ptr()?;
+    partial.set(String::from("interned"))?;
+    partial.end()?;
+    let built: Box<Arc<str>> = partial.build()?;
+    assert_eq!(&**built, "interned");
+}
+
+#[test]
+fn arc_slice_from_items() {
+    let mut partial = Partial::alloc::<Arc<[Inner]>>()?;
+    partial.begin_smart_ptr()?;
+    partial.begin_list()?;
+    partial.begin_list_item()?;
+    partial.set_field("value", 1_i32)?;
+    partial.end()?;
+    partial.begin_list_item()?;
+    partial.set_field("value", 2_i32)?;
+    partial.end()?;
+    partial.end()?;
+    let built: Box<Arc<[Inner]>> = partial.build()?;
+    assert_eq!(&**built, [Inner { value: 1 }, Inner { value: 2 }]);
+}
+
+#[test]
+fn rc_and_box_slices() {
+    let mut partial = Partial::alloc::<std::rc::Rc<[u8]>>()?;
+    partial.begin_smart_ptr()?;
+    partial.set(vec![1_u8, 2, 3])?;
+    partial.end()?;
+    assert_eq!(&**partial.build()?, [1, 2, 3]);
+
+    let mut partial = Partial::alloc::<Box<str>>()?;
+    partial.begin_smart_ptr()?;
+    partial.set(String::from("boxed"))?;
+    partial.end()?;
+    assert_eq!(&**partial.build()?, "boxed");
+}
@@ -0,0 +1,54 @@
+use std::collections::{BTreeSet, BinaryHeap, HashSet, LinkedList, VecDeque};
+
+use facet_reflect::Partial;
+use facet_testhelpers::test;
+
+#[test]
+fn build_vec_deque() {
+    let mut wip = Partial::alloc::<VecDeque<u32>>()?;
+    wip.begin_list()?;
+    wip.begin_list_item()?.set(1u32)?.end()?;
+    wip.push(2u32)?;
+    let deque: VecDeque<u32> = *wip.build()?;
+    assert_eq!(deque, VecDeque::from([1, 2]));
+}
+
+#[test]
+fn build_linked_list() {
+    let mut wip = Partial::alloc::<LinkedList<String>>()?;
+    wip.begin_list()?;
+    wip.begin_list_item()?.set("a".to_string())?.end()?;
+    wip.begin_list_item()?.set("b".to_string())?.end()?;
+    let list: LinkedList<String> = *wip.build()?;
+    assert_eq!(list, LinkedList::from(["a".to_string(), "b".to_string()]));
+}
+
+#[test]
+fn build_binary_heap() {
+    let mut wip = Partial::alloc::<BinaryHeap<i32>>()?;
+    wip.begin_list()?;
+    for item in [3, 1, 4, 1, 5] {
+        wip.push(item)?;
+    }
+    let heap: BinaryHeap<i32> = *wip.build()?;
+    assert_eq!(heap.into_sorted_vec(), [1, 1, 3, 4, 5]);
+}
+
+#[test]
+fn build_hash_set() {
+    let mut wip = Partial::alloc::<HashSet<String>>()?;
+    wip.begin_set()?;
+    for item in ["a", "b", "a"] {
+        wip.begin_set_item()?.set(item.to_string())?.end()?;
+    }
+    let set: HashSet<String> = *wip.build()?;
+    assert_eq!(set, HashSet::from(["a".to_string(), "b".to_string()]));
+}
+
+#[test]
+fn set_items_are_built_one_at_a_time() {
+    let mut wip = Partial::alloc::<BTreeSet<u8>>()?;
+    wip.begin_set()?;
+    wip.begin_set_item()?;
+    assert!(wip.begin_set_item().is_err());
+}
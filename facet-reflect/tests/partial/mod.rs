@@ -2,6 +2,7 @@
 
 mod arc;
 mod array_building;
+mod collections;
 mod empty_tuples;
 mod invariant;
 mod list_leak;
@@ -12,6 +13,7 @@ mod no_uninit;
 mod option_building;
 mod option_leak;
 mod put_vec_leak;
+mod result_building;
 mod struct_leak;
 mod tuples;
 mod variance;
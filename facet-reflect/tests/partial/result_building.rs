@@ -0,0 +1,54 @@
+use facet_reflect::Partial;
+use facet_testhelpers::test;
+
+#[test]
+fn build_ok() {
+    let mut wip = Partial::alloc::<Result<u32, String>>()?;
+    wip.begin_ok()?.set(42u32)?.end()?;
+    let result: Result<u32, String> = *wip.build()?;
+    assert_eq!(result, Ok(42));
+}
+
+#[test]
+fn build_err() {
+    let mut wip = Partial::alloc::<Result<u32, String>>()?;
+    wip.begin_err()?.set("boom".to_string())?.end()?;
+    let result: Result<u32, String> = *wip.build()?;
+    assert_eq!(result, Err("boom".to_string()));
+}
+
+#[test]
+fn build_nested() {
+    let mut wip = Partial::alloc::<Result<Option<Vec<u8>>, ()>>()?;
+    wip.begin_ok()?
+        .begin_some()?
+        .begin_list()?
+        .push(1u8)?
+        .push(2u8)?
+        .end()?
+        .end()?;
+    let result: Result<Option<Vec<u8>>, ()> = *wip.build()?;
+    assert_eq!(result, Ok(Some(vec![1, 2])));
+}
+
+#[test]
+fn switch_variant() {
+    let mut wip = Partial::alloc::<Result<String, String>>()?;
+    wip.begin_ok()?.set("first".to_string())?.end()?;
+    wip.begin_err()?.set("second".to_string())?.end()?;
+    let result: Result<String, String> = *wip.build()?;
+    assert_eq!(result, Err("second".to_string()));
+}
+
+#[test]
+fn unfinished_variant_fails_to_build() {
+    let mut wip = Partial::alloc::<Result<u32, String>>()?;
+    wip.begin_ok()?;
+    assert!(wip.build().is_err());
+}
+
+#[test]
+fn begin_ok_on_non_result() {
+    let mut wip = Partial::alloc::<Option<u32>>()?;
+    assert!(wip.begin_ok().is_err());
+}
@@ -0,0 +1,153 @@
+use facet::Facet;
+use facet_reflect::{Change, PathSegment, diff};
+use facet_testhelpers::test;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Facet)]
+struct Config {
+    name: String,
+    port: u16,
+    mode: Mode,
+    tags: Vec<String>,
+    env: BTreeMap<String, String>,
+    upstream: Option<Upstream>,
+}
+
+#[derive(Debug, Clone, Facet)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Mode {
+    Fast,
+    Careful { retries: u32 },
+}
+
+#[derive(Debug, Clone, Facet)]
+struct Upstream {
+    host: String,
+    weight: u32,
+}
+
+fn config() -> Config {
+    Config {
+        name: "api".to_string(),
+        port: 8080,
+        mode: Mode::Careful { retries: 3 },
+        tags: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+        env: BTreeMap::from([
+            ("HOME".to_string(), "/root".to_string()),
+            ("LANG".to_string(), "C".to_string()),
+        ]),
+        upstream: Some(Upstream {
+            host: "example.org".to_string(),
+            weight: 1,
+        }),
+    }
+}
+
+#[test]
+fn test_diff_equal() {
+    let old = config();
+    let new = config();
+    assert!(diff(&old, &new).is_empty());
+}
+
+#[test]
+fn test_diff_scalars_and_variant_fields() {
+    let old = config();
+    let mut new = config();
+    new.port = 9090;
+    new.mode = Mode::Careful { retries: 5 };
+    new.upstream.as_mut().unwrap().weight = 2;
+
+    let changes = diff(&old, &new);
+    let paths: Vec<String> = changes.iter().map(|e| e.path.to_string()).collect();
+    assert_eq!(paths, ["port", "mode.retries", "upstream.weight"]);
+
+    let Change::Modified { from, to } = changes.entries()[0].change else {
+        panic!("expected a modification");
+    };
+    assert_eq!(from.get::<u16>()?, &8080);
+    assert_eq!(to.get::<u16>()?, &9090);
+}
+
+#[test]
+fn test_diff_variant_changed() {
+    let old = config();
+    let mut new = config();
+    new.mode = Mode::Fast;
+    new.upstream = None;
+
+    let changes = diff(&old, &new);
+    assert_eq!(changes.len(), 2);
+    assert!(matches!(
+        changes.entries()[0].change,
+        Change::VariantChanged { .. }
+    ));
+    assert_eq!(changes.entries()[1].path.to_string(), "upstream");
+    assert!(matches!(
+        changes.entries()[1].change,
+        Change::Modified { .. }
+    ));
+}
+
+#[test]
+fn test_diff_list_insertions_and_deletions() {
+    let old = config();
+    let mut new = config();
+    new.tags = vec!["b".to_string(), "c".to_string(), "d".to_string()];
+
+    let changes = diff(&old, &new);
+    assert_eq!(changes.to_string(), "- tags[0]: \"a\"\n+ tags[2]: \"d\"\n");
+}
+
+#[test]
+fn test_diff_map_keys() {
+    let old = config();
+    let mut new = config();
+    new.env.remove("LANG");
+    new.env.insert("HOME".to_string(), "/home/amos".to_string());
+    new.env.insert("TERM".to_string(), "xterm".to_string());
+
+    let changes = diff(&old, &new);
+    assert_eq!(
+        changes.to_string(),
+        "~ env[\"HOME\"]: \"/root\" -> \"/home/amos\"\n\
+         - env[\"LANG\"]: \"C\"\n\
+         + env[\"TERM\"]: \"xterm\"\n"
+    );
+    assert!(matches!(
+        changes.entries()[0].path.segments(),
+        [PathSegment::Field("env"), PathSegment::Key(_)]
+    ));
+}
+
+#[derive(Debug, Facet)]
+struct Account {
+    user: String,
+    #[facet(sensitive)]
+    password: String,
+}
+
+#[test]
+fn test_diff_display_redacts_sensitive_fields() {
+    let old = vec![Account {
+        user: "alice".to_string(),
+        password: "hunter2".to_string(),
+    }];
+    let new = vec![
+        Account {
+            user: "alice".to_string(),
+            password: "correct horse".to_string(),
+        },
+        Account {
+            user: "bob".to_string(),
+            password: "battery staple".to_string(),
+        },
+    ];
+
+    assert_eq!(
+        diff(&old, &new).to_string(),
+        "~ [0].password: [REDACTED] -> [REDACTED]\n\
+         + [1]: Account { user: \"bob\", password: [REDACTED] }\n"
+    );
+}
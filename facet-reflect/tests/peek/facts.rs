@@ -485,8 +485,8 @@ fn test_string_traits() {
             .build(),
     );
     check_facts(
-        Cow::Owned("hello".to_string()),
-        Cow::Owned("world".to_string()),
+        Cow::<str>::Owned("hello".to_string()),
+        Cow::<str>::Owned("world".to_string()),
         FactBuilder::new()
             .debug()
             .display()
@@ -498,7 +498,7 @@ fn test_string_traits() {
     );
     check_facts(
         Cow::Borrowed("same"),
-        Cow::Owned("same".to_string()),
+        Cow::<str>::Owned("same".to_string()),
         FactBuilder::new()
             .debug()
             .display()
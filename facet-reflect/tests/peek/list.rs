@@ -31,3 +31,41 @@ fn peek_list() {
     // Test out of bounds
     assert!(peek_list.get(5).is_none());
 }
+
+#[test]
+fn peek_vec_deque() {
+    // Push to both ends so that the ring buffer wraps around
+    let mut deque = std::collections::VecDeque::with_capacity(4);
+    deque.push_back(3);
+    deque.push_back(4);
+    deque.push_front(2);
+    deque.push_front(1);
+
+    let peek_list = Peek::new(&deque).into_list()?;
+    assert_eq!(peek_list.len(), 4);
+    assert_eq!(*peek_list.get(0).unwrap().get::<i32>()?, 1);
+    assert_eq!(*peek_list.get(3).unwrap().get::<i32>()?, 4);
+    assert!(peek_list.get(4).is_none());
+
+    let items: Vec<i32> = peek_list
+        .iter()
+        .map(|item| *item.get::<i32>().unwrap())
+        .collect();
+    assert_eq!(items, [1, 2, 3, 4]);
+}
+
+#[test]
+fn peek_linked_list() {
+    let list: std::collections::LinkedList<&str> = ["a", "b", "c"].into_iter().collect();
+
+    let peek_list = Peek::new(&list).into_list()?;
+    assert_eq!(peek_list.len(), 3);
+    assert_eq!(*peek_list.get(1).unwrap().get::<&str>()?, "b");
+    assert!(peek_list.get(3).is_none());
+
+    let items: Vec<&str> = peek_list
+        .iter()
+        .map(|item| *item.get::<&str>().unwrap())
+        .collect();
+    assert_eq!(items, ["a", "b", "c"]);
+}
@@ -1,3 +1,4 @@
+mod diff;
 mod dst;
 mod enum_;
 #[cfg(feature = "std")]
@@ -7,6 +8,8 @@ mod list_like;
 mod map;
 mod option;
 mod reference;
+mod result;
 mod smartptr;
 mod struct_;
 mod value;
+mod wrapper;
@@ -0,0 +1,39 @@
+use facet_reflect::Peek;
+use facet_testhelpers::test;
+
+#[test]
+fn peek_result() {
+    let ok: Result<i32, String> = Ok(42);
+    let peek_result = Peek::new(&ok)
+        .into_result()
+        .expect("Should be convertible to result");
+    assert!(peek_result.is_ok());
+    assert!(!peek_result.is_err());
+    assert_eq!(*peek_result.ok().unwrap().get::<i32>()?, 42);
+    assert!(peek_result.err().is_none());
+
+    let err: Result<i32, String> = Err("boom".to_string());
+    let peek_result = Peek::new(&err)
+        .into_result()
+        .expect("Should be convertible to result");
+    assert!(!peek_result.is_ok());
+    assert!(peek_result.is_err());
+    assert!(peek_result.ok().is_none());
+    match peek_result.value() {
+        Ok(_) => panic!("expected an error"),
+        Err(error) => assert_eq!(error.get::<String>()?, "boom"),
+    }
+}
+
+#[test]
+fn peek_result_debug() {
+    let ok: Result<u8, String> = Ok(1);
+    assert_eq!(format!("{:?}", Peek::new(&ok)), "Ok(1)");
+    let err: Result<u8, String> = Err("no".to_string());
+    assert_eq!(format!("{:?}", Peek::new(&err)), r#"Err("no")"#);
+}
+
+#[test]
+fn peek_non_result() {
+    assert!(Peek::new(&Some(1)).into_result().is_err());
+}
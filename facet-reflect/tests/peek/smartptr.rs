@@ -1,8 +1,9 @@
 use facet::Facet;
 use facet_reflect::Peek;
 use facet_testhelpers::test;
+use std::cell::RefCell;
 use std::rc::Rc;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, RwLock};
 
 #[test]
 fn test_peek_arc() {
@@ -107,3 +108,94 @@ fn test_peek_rc_with_string() {
 
     assert_eq!(def.pointee(), Some(String::SHAPE));
 }
+
+#[test]
+fn test_peek_mutex_lock() {
+    let source = Mutex::new(42u32);
+    let peek_smart_pointer = Peek::new(&source).into_smart_pointer()?;
+    assert!(
+        peek_smart_pointer
+            .def()
+            .flags
+            .contains(facet_core::SmartPointerFlags::LOCK)
+    );
+    assert!(peek_smart_pointer.borrow_inner().is_none());
+
+    {
+        let guard = peek_smart_pointer.lock()?;
+        assert_eq!(*guard.peek().get::<u32>()?, 42);
+        assert!(source.try_lock().is_err());
+    }
+    assert!(source.try_lock().is_ok());
+}
+
+#[test]
+fn test_peek_rwlock_lock() {
+    let source = RwLock::new("Hello, world!".to_string());
+    let peek_smart_pointer = Peek::new(&source).into_smart_pointer()?;
+
+    let guard = peek_smart_pointer.lock()?;
+    assert_eq!(guard.peek().get::<String>()?, "Hello, world!");
+    // The lock is taken for reading, so other readers are fine
+    assert!(source.try_read().is_ok());
+    assert!(source.try_write().is_err());
+}
+
+#[test]
+fn test_peek_refcell_lock() {
+    let source = RefCell::new(vec![1, 2, 3]);
+    let peek_smart_pointer = Peek::new(&source).into_smart_pointer()?;
+
+    assert_eq!(peek_smart_pointer.lock()?.peek().into_list()?.len(), 3);
+
+    let borrowed = source.borrow_mut();
+    assert!(peek_smart_pointer.lock().is_err());
+    drop(borrowed);
+}
+
+#[test]
+fn test_peek_arc_lock_unsupported() {
+    let source = Arc::new(42);
+    assert!(Peek::new(&source).into_smart_pointer()?.lock().is_err());
+}
+
+#[test]
+fn test_peek_arc_str() {
+    let source: Arc<str> = Arc::from("interned");
+    let peek_smart_pointer = Peek::new(&source).into_smart_pointer()?;
+    assert_eq!(peek_smart_pointer.def().pointee(), Some(str::SHAPE));
+    assert_eq!(peek_smart_pointer.def().owned(), Some(String::SHAPE));
+
+    let inner = peek_smart_pointer.borrow_inner().unwrap();
+    assert_eq!(inner.as_str(), Some("interned"));
+    assert_eq!(inner.get::<str>()?, "interned");
+}
+
+#[test]
+fn test_peek_boxed_slice() {
+    let source: Box<[u16]> = vec![1, 2, 3].into_boxed_slice();
+    let peek_smart_pointer = Peek::new(&source).into_smart_pointer()?;
+    assert_eq!(peek_smart_pointer.def().owned(), Some(<Vec<u16>>::SHAPE));
+
+    let list = peek_smart_pointer
+        .borrow_inner()
+        .unwrap()
+        .into_list_like()?;
+    assert_eq!(list.len(), 3);
+    assert_eq!(*list.get(1).unwrap().get::<u16>()?, 2);
+    let items: Vec<u16> = list
+        .iter()
+        .map(|item| *item.get::<u16>().unwrap())
+        .collect();
+    assert_eq!(items, [1, 2, 3]);
+}
+
+#[test]
+fn test_peek_rc_bytes() {
+    let source: Rc<[u8]> = Rc::from(&b"abc"[..]);
+    let inner = Peek::new(&source)
+        .into_smart_pointer()?
+        .borrow_inner()
+        .unwrap();
+    assert_eq!(inner.as_bytes(), Some(&b"abc"[..]));
+}
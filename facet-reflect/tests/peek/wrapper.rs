@@ -0,0 +1,40 @@
+use std::cmp::{Ordering, Reverse};
+use std::num::Wrapping;
+use std::sync::atomic::{self, AtomicU16};
+
+use facet_reflect::Peek;
+use facet_testhelpers::test;
+
+#[test]
+fn peek_reverse_flips_ordering() {
+    let a = Reverse(1_i32);
+    let b = Reverse(2_i32);
+    assert_eq!(
+        Peek::new(&a).partial_cmp(&Peek::new(&b)),
+        Some(Some(Ordering::Greater))
+    );
+    assert_eq!(format!("{:?}", Peek::new(&a)), "Reverse(1)");
+}
+
+#[test]
+fn peek_wrapping_borrows_inner() {
+    let value = Wrapping(7_u32);
+    let inner = Peek::new(&value).innermost_peek();
+    assert_eq!(*inner.get::<u32>()?, 7);
+    assert_eq!(format!("{}", Peek::new(&value)), "7");
+}
+
+#[test]
+fn peek_load_atomic() {
+    let value = AtomicU16::new(513);
+    let loaded = Peek::new(&value)
+        .load_atomic(atomic::Ordering::Acquire)?
+        .unwrap();
+    assert_eq!(*loaded.peek().get::<u16>()?, 513);
+
+    assert!(
+        Peek::new(&513_u16)
+            .load_atomic(atomic::Ordering::Acquire)?
+            .is_none()
+    );
+}
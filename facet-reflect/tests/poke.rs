@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+
+use facet::Facet;
+use facet_reflect::{HeapValue, Peek, Poke};
+use facet_testhelpers::test;
+
+fn heap<'a, T: Facet<'a>>(value: T) -> HeapValue<'a, 'static> {
+    HeapValue::new(value)
+}
+
+#[derive(Debug, PartialEq, Facet)]
+struct Server {
+    name: String,
+    ports: Vec<u16>,
+}
+
+#[test]
+fn test_poke_struct_fields() {
+    let mut server = Server {
+        name: "web".to_string(),
+        ports: vec![80],
+    };
+
+    let mut poke = Poke::new(&mut server);
+    poke.reborrow()
+        .into_field("name")?
+        .set(heap("api".to_string()))?;
+    poke.reborrow()
+        .into_field("ports")?
+        .list_push(heap(443u16))?;
+    poke.reborrow()
+        .into_field("ports")?
+        .into_index(0)?
+        .set(heap(8080u16))?;
+    assert!(poke.reborrow().into_field("missing").is_err());
+
+    assert_eq!(server.name, "api");
+    assert_eq!(server.ports, [8080, 443]);
+}
+
+#[test]
+fn test_poke_list() {
+    let mut list = vec![1u32, 2, 3];
+    let mut poke = Poke::new(&mut list);
+    poke.list_insert(1, heap(10u32))?;
+    let removed = poke.list_remove(0)?;
+    assert_eq!(removed.materialize::<u32>()?, 1);
+    assert!(poke.list_remove(3).is_err());
+    assert!(poke.list_insert(1, heap("nope")).is_err());
+    assert_eq!(list, [10, 2, 3]);
+}
+
+#[test]
+fn test_poke_map() {
+    let mut map = HashMap::from([("a".to_string(), 1u8)]);
+    let key = "a".to_string();
+    let mut poke = Poke::new(&mut map);
+    poke.reborrow()
+        .into_map_value(Peek::new(&key))?
+        .unwrap()
+        .set(heap(2u8))?;
+    poke.map_insert(heap("b".to_string()), heap(3u8))?;
+    let removed = poke.map_remove(Peek::new(&key))?.unwrap();
+    assert_eq!(removed.materialize::<u8>()?, 2);
+    assert!(poke.map_remove(Peek::new(&key))?.is_none());
+    assert_eq!(map, HashMap::from([("b".to_string(), 3)]));
+}
+
+#[test]
+fn test_poke_option() {
+    let mut option = Some("hello".to_string());
+    let mut poke = Poke::new(&mut option);
+    poke.reborrow()
+        .into_some()?
+        .unwrap()
+        .set(heap("world".to_string()))?;
+    let taken = poke.option_take()?.unwrap();
+    assert_eq!(taken.materialize::<String>()?, "world");
+    assert!(poke.reborrow().into_some()?.is_none());
+    poke.option_replace(Some(heap("again".to_string())))?;
+    assert_eq!(option.as_deref(), Some("again"));
+}
@@ -1,6 +1,8 @@
 use crate::Serializer;
 
+use alloc::string::String;
 use alloc::vec::Vec;
+use facet_reflect::ReflectError;
 
 struct DebugSerializer<W> {
     writer: W,
@@ -10,11 +12,15 @@ struct DebugSerializer<W> {
 #[derive(Debug)]
 enum DebugError {
     Fmt(core::fmt::Error),
+    Reflect(String),
 }
 
 impl core::fmt::Display for DebugError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        core::fmt::Debug::fmt(self, f)
+        match self {
+            DebugError::Fmt(err) => write!(f, "{err}"),
+            DebugError::Reflect(message) => write!(f, "{message}"),
+        }
     }
 }
 
@@ -32,6 +38,10 @@ where
 {
     type Error = DebugError;
 
+    fn serialize_unreadable(&mut self, error: ReflectError<'shape>) -> Result<(), Self::Error> {
+        Err(DebugError::Reflect(error.to_plain_string()))
+    }
+
     fn serialize_u8(&mut self, value: u8) -> Result<(), Self::Error> {
         self.write_comma()?;
         write!(self.writer, "{}", value)?;
@@ -9,12 +9,15 @@ extern crate alloc;
 
 use alloc::string::String;
 use alloc::vec::Vec;
+use core::sync::atomic::Ordering;
 
 use facet_core::{
-    Def, Facet, Field, PointerType, ScalarAffinity, ShapeAttribute, StructKind, Type, UserType,
+    Def, Facet, Field, FieldFlags, PointerType, ScalarAffinity, ShapeAttribute, StructKind, Type,
+    UserType,
 };
 use facet_reflect::{
-    FieldIter, FieldsForSerializeIter, HasFields, Peek, PeekListLikeIter, PeekMapIter, ScalarType,
+    FieldIter, FieldsForSerializeIter, HasFields, Peek, PeekListLikeIter, PeekMapIter, PeekSetIter,
+    ReflectError, ScalarType,
 };
 use log::{debug, trace};
 
@@ -32,6 +35,11 @@ pub trait Serializer<'shape> {
     /// The error type returned by serialization methods
     type Error;
 
+    /// Called in place of serializing a value that can't be read: a field whose
+    /// `serialize_with` conversion failed, or a lock that's poisoned or already held. Most
+    /// formats return an error, but a format may also write a placeholder and carry on.
+    fn serialize_unreadable(&mut self, error: ReflectError<'shape>) -> Result<(), Self::Error>;
+
     /// Serialize an unsigned 64-bit integer.
     fn serialize_u64(&mut self, value: u64) -> Result<(), Self::Error>;
 
@@ -165,6 +173,14 @@ pub trait Serializer<'shape> {
         self.serialize_f64(value as f64)
     }
 
+    /// Serialize an arbitrary-precision number (like a decimal or a big integer), given as its
+    /// decimal literal. By default it's serialized as a string, which formats that can hold
+    /// numbers of any size and precision should override.
+    #[inline(always)]
+    fn serialize_number(&mut self, literal: &str) -> Result<(), Self::Error> {
+        self.serialize_str(literal)
+    }
+
     /// Begin serializing a map key value.
     #[inline(always)]
     fn begin_map_key(&mut self) -> Result<(), Self::Error> {
@@ -221,6 +237,94 @@ pub trait Serializer<'shape> {
     }
 }
 
+// --- Serialization Options ---
+
+/// Placeholder written in place of sensitive values by [`SerializeOptions::redact_sensitive`].
+pub const REDACTED: &str = "[REDACTED]";
+
+/// What to do with the value of a field marked `#[facet(sensitive)]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Redaction {
+    /// Serialize this string in place of the value.
+    Placeholder(&'static str),
+    /// Leave the field out. Positional fields (in tuples, tuple structs and tuple variants)
+    /// can't be left out without shifting the ones after them, so they're serialized as
+    /// `None` instead.
+    Omit,
+}
+
+/// Options for [`serialize_iterative_with_options`].
+///
+/// The defaults serialize every value as-is, and load atomics with [`Ordering::SeqCst`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SerializeOptions {
+    redaction: Option<Redaction>,
+    atomic_ordering: Ordering,
+}
+
+impl Default for SerializeOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SerializeOptions {
+    /// Creates options that serialize every value as-is.
+    pub const fn new() -> Self {
+        Self {
+            redaction: None,
+            atomic_ordering: Ordering::SeqCst,
+        }
+    }
+
+    /// Replace the value of sensitive fields with [`REDACTED`].
+    pub const fn redact_sensitive(self) -> Self {
+        self.with_redaction(Redaction::Placeholder(REDACTED))
+    }
+
+    /// Leave sensitive fields out entirely.
+    pub const fn omit_sensitive(self) -> Self {
+        self.with_redaction(Redaction::Omit)
+    }
+
+    /// Sets how sensitive fields are redacted.
+    pub const fn with_redaction(mut self, redaction: Redaction) -> Self {
+        self.redaction = Some(redaction);
+        self
+    }
+
+    /// Sets the ordering atomics are loaded with.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ordering` is `Release` or `AcqRel`, which aren't valid for loads.
+    pub const fn atomic_ordering(mut self, ordering: Ordering) -> Self {
+        assert!(
+            matches!(
+                ordering,
+                Ordering::Relaxed | Ordering::Acquire | Ordering::SeqCst
+            ),
+            "atomics can't be loaded with a `Release` or `AcqRel` ordering"
+        );
+        self.atomic_ordering = ordering;
+        self
+    }
+
+    /// Returns how the value of `field` must be redacted, or `None` if it's serialized as-is.
+    pub fn redaction_for(&self, field: &Field<'_>) -> Option<Redaction> {
+        if field.flags.contains(FieldFlags::SENSITIVE) {
+            self.redaction
+        } else {
+            None
+        }
+    }
+
+    /// Whether `field` is left out of named-field containers.
+    fn omits(&self, field: &Field<'_>) -> bool {
+        self.redaction_for(field) == Some(Redaction::Omit)
+    }
+}
+
 // --- Iterative Serialization Logic ---
 
 /// Task items for the serialization stack.
@@ -240,6 +344,11 @@ enum SerializeTask<'mem, 'facet, 'shape> {
         first: bool,
         len: usize,
     },
+    Set {
+        items: PeekSetIter<'mem, 'facet, 'shape>,
+        first: bool,
+        len: usize,
+    },
     TupleStruct {
         items: FieldsForSerializeIter<'mem, 'facet, 'shape>,
         first: bool,
@@ -268,6 +377,20 @@ pub fn serialize_iterative<'mem, 'facet, 'shape, S>(
     peek: Peek<'mem, 'facet, 'shape>,
     serializer: &mut S,
 ) -> Result<(), S::Error>
+where
+    S: Serializer<'shape>,
+{
+    serialize_iterative_with_options(peek, serializer, &SerializeOptions::default())
+}
+
+/// Serializes a `Peek` value using the provided `Serializer`, as configured by `options`.
+///
+/// This is how sensitive fields get redacted, e.g. to log values that hold secrets.
+pub fn serialize_iterative_with_options<'mem, 'facet, 'shape, S>(
+    peek: Peek<'mem, 'facet, 'shape>,
+    serializer: &mut S,
+    options: &SerializeOptions,
+) -> Result<(), S::Error>
 where
     S: Serializer<'shape>,
 {
@@ -279,6 +402,40 @@ where
             SerializeTask::Value(mut cpeek, maybe_field) => {
                 debug!("Serializing a value, shape is {}", cpeek.shape());
 
+                match maybe_field.and_then(|f| options.redaction_for(&f)) {
+                    Some(Redaction::Placeholder(placeholder)) => {
+                        serializer.serialize_str(placeholder)?;
+                        continue;
+                    }
+                    Some(Redaction::Omit) => {
+                        serializer.serialize_none()?;
+                        continue;
+                    }
+                    None => {}
+                }
+
+                // Fields with `#[facet(serialize_with = ..)]` or `#[facet(proxy = ..)]` are
+                // serialized as another type
+                #[cfg(feature = "alloc")]
+                if let Some(field) = maybe_field {
+                    match cpeek.serialize_with(&field) {
+                        Ok(Some(converted)) => {
+                            debug!(
+                                "Field {} is serialized as a {}",
+                                field.name,
+                                converted.shape()
+                            );
+                            serialize_held(converted.peek(), serializer, options)?;
+                            continue;
+                        }
+                        Ok(None) => {}
+                        Err(err) => {
+                            serializer.serialize_unreadable(err)?;
+                            continue;
+                        }
+                    }
+                }
+
                 if cpeek
                     .shape()
                     .attributes
@@ -304,6 +461,20 @@ where
                 );
                 match (cpeek.shape().def, cpeek.shape().ty) {
                     (Def::Scalar(sd), _) => {
+                        // Atomics are serialized as the value they hold
+                        #[cfg(feature = "alloc")]
+                        match cpeek.load_atomic(options.atomic_ordering) {
+                            Ok(Some(loaded)) => {
+                                serialize_held(loaded.peek(), serializer, options)?;
+                                continue;
+                            }
+                            Ok(None) => {}
+                            Err(err) => {
+                                serializer.serialize_unreadable(err)?;
+                                continue;
+                            }
+                        }
+
                         let cpeek = cpeek.innermost_peek();
 
                         // Dispatch to appropriate scalar serialization method based on type
@@ -372,12 +543,55 @@ where
                             Some(ScalarType::ISize) => {
                                 serializer.serialize_isize(*cpeek.get::<isize>().unwrap())?
                             }
+
+                            // Network addresses
+                            Some(
+                                ScalarType::SocketAddr
+                                | ScalarType::SocketAddrV4
+                                | ScalarType::SocketAddrV6
+                                | ScalarType::IpAddr
+                                | ScalarType::Ipv4Addr
+                                | ScalarType::Ipv6Addr,
+                            ) => serializer.serialize_str(&alloc::format!("{cpeek}"))?,
+
+                            // Platform strings are serialized as strings when they're valid
+                            // UTF-8, and as bytes otherwise
+                            Some(ScalarType::CString) => {
+                                let s = cpeek.get::<alloc::ffi::CString>().unwrap();
+                                match s.to_str() {
+                                    Ok(s) => serializer.serialize_str(s)?,
+                                    Err(_) => serializer.serialize_bytes(s.as_bytes())?,
+                                }
+                            }
+                            #[cfg(feature = "std")]
+                            Some(ScalarType::OsString) => {
+                                let s = cpeek.get::<std::ffi::OsString>().unwrap();
+                                match s.to_str() {
+                                    Some(s) => serializer.serialize_str(s)?,
+                                    // The encoding of these bytes is platform-specific: they can
+                                    // only be read back on the same kind of platform
+                                    None => serializer.serialize_bytes(s.as_encoded_bytes())?,
+                                }
+                            }
+
                             Some(unsupported) => {
                                 panic!("facet-serialize: unsupported scalar type: {unsupported:?}")
                             }
+                            None if cpeek.shape().is_type::<str>() => {
+                                // A `str` behind a smart pointer (like `Arc<str>`)
+                                serializer.serialize_str(cpeek.as_str().unwrap())?
+                            }
                             None => {
                                 match sd.affinity {
-                                    ScalarAffinity::Time(_)
+                                    ScalarAffinity::Number(na)
+                                        if na.bits.is_arbitrary_precision()
+                                            && cpeek.shape().vtable.has_display() =>
+                                    {
+                                        serializer.serialize_number(&alloc::format!("{cpeek}"))?
+                                    }
+                                    ScalarAffinity::String(_)
+                                    | ScalarAffinity::Time(_)
+                                    | ScalarAffinity::Duration(_)
                                     | ScalarAffinity::Path(_)
                                     | ScalarAffinity::ULID(_)
                                     | ScalarAffinity::UUID(_) => {
@@ -445,7 +659,7 @@ where
                     }
                     (Def::Slice(sd), _) => {
                         if sd.t().is_type::<u8>() {
-                            serializer.serialize_bytes(cpeek.get::<&[u8]>().unwrap())?
+                            serializer.serialize_bytes(cpeek.as_bytes().unwrap())?
                         } else {
                             let peek_list = cpeek.into_list_like().unwrap();
                             stack.push(SerializeTask::Array {
@@ -463,6 +677,16 @@ where
                             len,
                         });
                     }
+                    (Def::Set(_), _) => {
+                        // Sets are serialized as arrays, in the set's iteration order
+                        let peek_set = cpeek.into_set().unwrap();
+                        let len = peek_set.len();
+                        stack.push(SerializeTask::Set {
+                            items: peek_set.iter(),
+                            first: true,
+                            len,
+                        });
+                    }
                     (Def::Option(_), _) => {
                         let opt = cpeek.into_option().unwrap();
                         if let Some(inner_peek) = opt.value() {
@@ -471,6 +695,20 @@ where
                             serializer.serialize_none()?;
                         }
                     }
+                    (Def::Result(_), _) => {
+                        // Results are serialized like an externally tagged enum with two newtype
+                        // variants: `{"Ok": value}` or `{"Err": error}`
+                        let (discriminant, name, inner_peek) =
+                            match cpeek.into_result().unwrap().value() {
+                                Ok(value) => (0, "Ok", value),
+                                Err(error) => (1, "Err", error),
+                            };
+                        serializer.start_enum_variant(discriminant)?;
+                        serializer.start_object(Some(1))?;
+                        stack.push(SerializeTask::EndObject);
+                        serializer.serialize_field_name(name)?;
+                        stack.push(SerializeTask::Value(inner_peek, None));
+                    }
                     (Def::SmartPointer(_), _) => {
                         // For smart pointers, we need to borrow the inner value and serialize it
                         // This is similar to how transparent structs work - we serialize the inner value directly
@@ -479,6 +717,15 @@ where
                         if let Some(inner_peek) = sp.borrow_inner() {
                             // Push the inner value to be serialized
                             stack.push(SerializeTask::Value(inner_peek, None));
+                        } else if sp.def().vtable.read_fn.is_some()
+                            || sp.def().vtable.lock_fn.is_some()
+                        {
+                            // Locks (`Mutex`, `RwLock`, `RefCell`, `Cell`) only give access to their
+                            // value while the guard is alive
+                            match sp.lock() {
+                                Ok(guard) => serialize_held(guard.peek(), serializer, options)?,
+                                Err(err) => serializer.serialize_unreadable(err)?,
+                            }
                         } else {
                             // The smart pointer doesn't support borrowing or has an opaque pointee
                             // We can't serialize it
@@ -535,7 +782,10 @@ where
                             StructKind::Struct => {
                                 debug!("  Handling record struct");
                                 let peek_struct = cpeek.into_struct().unwrap();
-                                let fields = peek_struct.fields_for_serialize().count();
+                                let fields = peek_struct
+                                    .fields_for_serialize()
+                                    .filter(|(field, _)| !options.omits(field))
+                                    .count();
                                 debug!("  Serializing {} fields as object", fields);
 
                                 stack.push(SerializeTask::Object {
@@ -607,13 +857,14 @@ where
                                 }
                             } else {
                                 // Struct variant - serialize as object
-                                let fields = peek_enum.fields_for_serialize().count();
-                                serializer.start_object(Some(fields))?;
+                                let fields_for_serialize = peek_enum
+                                    .fields_for_serialize()
+                                    .filter(|(field, _)| !options.omits(field))
+                                    .collect::<Vec<_>>();
+                                serializer.start_object(Some(fields_for_serialize.len()))?;
                                 stack.push(SerializeTask::EndObject);
 
                                 // Push fields in reverse order for struct variant
-                                let fields_for_serialize =
-                                    peek_enum.fields_for_serialize().collect::<Vec<_>>();
                                 for (field, field_peek) in fields_for_serialize.into_iter().rev() {
                                     stack.push(SerializeTask::EndField);
                                     stack.push(SerializeTask::Value(field_peek, Some(field)));
@@ -665,7 +916,9 @@ where
                     serializer.start_object(Some(len))?;
                 }
 
-                let Some((field, value)) = entries.next() else {
+                let Some((field, value)) =
+                    entries.by_ref().find(|(field, _)| !options.omits(field))
+                else {
                     serializer.end_object()?;
                     continue;
                 };
@@ -717,6 +970,27 @@ where
                 stack.push(SerializeTask::SerializeMapValue(value));
                 stack.push(SerializeTask::SerializeMapKey(key));
             }
+            SerializeTask::Set {
+                mut items,
+                first,
+                len,
+            } => {
+                if first {
+                    serializer.start_array(Some(len))?;
+                }
+
+                let Some(value) = items.next() else {
+                    serializer.end_array()?;
+                    continue;
+                };
+
+                stack.push(SerializeTask::Set {
+                    items,
+                    first: false,
+                    len,
+                });
+                stack.push(SerializeTask::Value(value, None));
+            }
             SerializeTask::TupleStruct {
                 mut items,
                 first,
@@ -793,6 +1067,21 @@ where
     Ok(())
 }
 
+/// Serializes a value that only lives as long as the caller holds onto it: one produced by a
+/// `serialize_with` conversion or loaded from an atomic, or one behind a lock guard. It can't
+/// wait on the task stack, so it's serialized right away, which only recurses as deep as such
+/// values are nested in one another.
+fn serialize_held<'shape, S>(
+    peek: Peek<'_, '_, 'shape>,
+    serializer: &mut S,
+    options: &SerializeOptions,
+) -> Result<(), S::Error>
+where
+    S: Serializer<'shape>,
+{
+    serialize_iterative_with_options(peek, serializer, options)
+}
+
 // --- Helper Trait for Ergonomics ---
 
 /// Extension trait to simplify calling the generic serializer.
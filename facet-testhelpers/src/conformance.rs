@@ -0,0 +1,273 @@
+//! A round-trip conformance suite for format crates.
+//!
+//! A [`Format`] serializes a value and deserializes it back. A corpus is a function that
+//! feeds named values to [`Cases`]; [`SupportMatrix::run`] round-trips each of them through a
+//! format and records an [`Outcome`]. The resulting matrix renders as a markdown table, and
+//! [`SupportMatrix::check_against`] compares it with a baseline checked into the repository,
+//! failing when any outcome differs from it.
+
+use core::fmt;
+use core::panic::AssertUnwindSafe;
+use std::collections::BTreeMap;
+use std::panic::catch_unwind;
+use std::path::Path;
+
+use facet_core::Facet;
+use facet_pretty::PrettyPrinter;
+
+/// The environment variable that makes [`SupportMatrix::check_against`] rewrite its baseline
+pub const UPDATE_VAR: &str = "FACET_CONFORMANCE_UPDATE";
+
+/// A serialization format under test
+pub trait Format {
+    /// The name of the format, used as a column of the support matrix
+    fn name(&self) -> &'static str;
+
+    /// Serializes `value`, then deserializes the result
+    fn round_trip<T: for<'f> Facet<'f>>(&self, value: &T) -> Result<T, RoundTripError>;
+}
+
+/// Why a value couldn't make it through a format
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RoundTripError {
+    /// The format knowingly doesn't support this kind of value
+    Unsupported,
+    /// Serializing failed
+    Serialize(String),
+    /// Deserializing what was serialized failed
+    Deserialize(String),
+}
+
+impl fmt::Display for RoundTripError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RoundTripError::Unsupported => write!(f, "unsupported"),
+            RoundTripError::Serialize(msg) => write!(f, "serialize: {msg}"),
+            RoundTripError::Deserialize(msg) => write!(f, "deserialize: {msg}"),
+        }
+    }
+}
+
+impl core::error::Error for RoundTripError {}
+
+/// The result of round-tripping one case through one format
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// The value came back unchanged
+    Pass,
+    /// The format doesn't support the value
+    Unsupported,
+    /// The round trip failed, for the given reason
+    Fail(String),
+}
+
+impl Outcome {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Outcome::Pass => "✅",
+            Outcome::Unsupported => "➖",
+            Outcome::Fail(_) => "❌",
+        }
+    }
+}
+
+/// Collects the cases of a corpus for one format, see [`SupportMatrix::run`]
+pub struct Cases<'a, F: Format> {
+    format: &'a F,
+    matrix: &'a mut SupportMatrix,
+}
+
+impl<F: Format> Cases<'_, F> {
+    /// Round-trips `value` through the format, and records the outcome under `name`.
+    ///
+    /// Panics in the format count as failures.
+    pub fn case<T: for<'f> Facet<'f>>(&mut self, name: &'static str, value: T) -> &mut Self {
+        let outcome = match catch_unwind(AssertUnwindSafe(|| self.format.round_trip(&value))) {
+            Ok(Ok(back)) if facet_reflect::deep_eq(&value, &back) => Outcome::Pass,
+            Ok(Ok(back)) => Outcome::Fail(format!(
+                "mismatch: expected {}, got {}",
+                PrettyPrinter::new().with_colors(false).format(&value),
+                PrettyPrinter::new().with_colors(false).format(&back),
+            )),
+            Ok(Err(RoundTripError::Unsupported)) => Outcome::Unsupported,
+            Ok(Err(e)) => Outcome::Fail(e.to_string()),
+            Err(payload) => Outcome::Fail(format!(
+                "panicked: {}",
+                payload
+                    .downcast_ref::<&str>()
+                    .map(|msg| msg.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_default()
+            )),
+        };
+        self.matrix.record(name, self.format.name(), outcome);
+        self
+    }
+}
+
+/// The outcome of every case of a corpus, for every format it was run through
+#[derive(Debug, Default)]
+pub struct SupportMatrix {
+    formats: Vec<&'static str>,
+    cases: Vec<&'static str>,
+    outcomes: BTreeMap<(&'static str, &'static str), Outcome>,
+}
+
+impl SupportMatrix {
+    /// Creates an empty matrix
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs the cases of `corpus` through `format`
+    pub fn run<F: Format>(&mut self, format: &F, corpus: impl FnOnce(&mut Cases<'_, F>)) {
+        corpus(&mut Cases {
+            format,
+            matrix: self,
+        });
+    }
+
+    fn record(&mut self, case: &'static str, format: &'static str, outcome: Outcome) {
+        if !self.formats.contains(&format) {
+            self.formats.push(format);
+        }
+        if !self.cases.contains(&case) {
+            self.cases.push(case);
+        }
+        self.outcomes.insert((case, format), outcome);
+    }
+
+    /// The outcome of `case` for `format`, if it was run
+    pub fn outcome(&self, case: &str, format: &str) -> Option<&Outcome> {
+        self.outcomes
+            .iter()
+            .find(|((c, f), _)| *c == case && *f == format)
+            .map(|(_, outcome)| outcome)
+    }
+
+    /// Every failed round trip, as `(case, format, reason)`
+    pub fn failures(&self) -> impl Iterator<Item = (&'static str, &'static str, &str)> + '_ {
+        self.outcomes
+            .iter()
+            .filter_map(|(&(case, format), outcome)| match outcome {
+                Outcome::Fail(reason) => Some((case, format, reason.as_str())),
+                _ => None,
+            })
+    }
+
+    /// Renders the matrix as a markdown table, one row per case and one column per format
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::from("| case |");
+        for format in &self.formats {
+            out.push_str(&format!(" {format} |"));
+        }
+        out.push_str("\n|---|");
+        for _ in &self.formats {
+            out.push_str("---|");
+        }
+        out.push('\n');
+        for case in &self.cases {
+            out.push_str(&format!("| `{case}` |"));
+            for format in &self.formats {
+                let symbol = self
+                    .outcomes
+                    .get(&(*case, *format))
+                    .map_or(" ", Outcome::symbol);
+                out.push_str(&format!(" {symbol} |"));
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Compares the matrix with the markdown baseline at `path`.
+    ///
+    /// If `FACET_CONFORMANCE_UPDATE` is set, the baseline is (re)written instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the baseline doesn't exist (unless it's being written), or if it doesn't match
+    /// the matrix: a case or format is missing from either of them, or an outcome changed —
+    /// including cases that pass now but didn't in the baseline, which should be locked in by
+    /// updating it.
+    pub fn check_against(&self, path: impl AsRef<Path>) {
+        let path = path.as_ref();
+        let markdown = self.to_markdown();
+        if std::env::var_os(UPDATE_VAR).is_some() {
+            std::fs::write(path, markdown)
+                .unwrap_or_else(|e| panic!("couldn't write {}: {e}", path.display()));
+            return;
+        }
+        let baseline = std::fs::read_to_string(path).unwrap_or_else(|e| {
+            panic!(
+                "couldn't read {}: {e} (run with {UPDATE_VAR}=1 to create it)",
+                path.display()
+            )
+        });
+
+        let mut mismatches = Vec::new();
+        let baseline = parse_markdown(&baseline);
+        for (case, format, symbol) in &baseline {
+            let Some(outcome) = self.outcome(case, format) else {
+                mismatches.push(format!("- `{case}` in {format}: wasn't run"));
+                continue;
+            };
+            if outcome.symbol() == symbol.as_str() {
+                continue;
+            }
+            mismatches.push(match outcome {
+                Outcome::Pass => format!("- `{case}` in {format}: passes now"),
+                Outcome::Unsupported => format!("- `{case}` in {format}: now unsupported"),
+                Outcome::Fail(reason) => format!("- `{case}` in {format}: {reason}"),
+            });
+        }
+        for &(case, format) in self.outcomes.keys() {
+            if !baseline.iter().any(|(c, f, _)| c == case && f == format) {
+                mismatches.push(format!("- `{case}` in {format}: not in the baseline"));
+            }
+        }
+
+        if !mismatches.is_empty() {
+            panic!(
+                "the support matrix doesn't match {} (run with {UPDATE_VAR}=1 to update it):\n{}",
+                path.display(),
+                mismatches.join("\n")
+            );
+        }
+    }
+}
+
+impl fmt::Display for SupportMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_markdown())
+    }
+}
+
+/// Reads `(case, format, symbol)` triples back from a table written by
+/// [`SupportMatrix::to_markdown`]
+fn parse_markdown(markdown: &str) -> Vec<(String, String, String)> {
+    let mut rows = markdown
+        .lines()
+        .filter(|line| line.starts_with('|'))
+        .map(|line| {
+            line.trim_matches('|')
+                .split('|')
+                .map(|cell| cell.trim().trim_matches('`'))
+                .collect::<Vec<_>>()
+        });
+    let Some(header) = rows.next() else {
+        return Vec::new();
+    };
+
+    let mut cells = Vec::new();
+    // The separator row is skipped
+    for row in rows.skip(1) {
+        let Some((case, symbols)) = row.split_first() else {
+            continue;
+        };
+        for (format, symbol) in header.iter().skip(1).zip(symbols) {
+            cells.push((case.to_string(), format.to_string(), symbol.to_string()));
+        }
+    }
+    cells
+}
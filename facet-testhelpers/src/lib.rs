@@ -4,6 +4,8 @@
 #![forbid(unsafe_code)]
 #![doc = include_str!("../README.md")]
 
+pub mod conformance;
+
 pub use color_eyre::eyre;
 pub use facet_testhelpers_macros::test;
 
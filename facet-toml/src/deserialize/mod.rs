@@ -84,6 +84,14 @@ fn deserialize_item<'input, 'facet, 'shape>(
     wip: &mut Partial<'facet, 'shape>,
     item: &Item,
 ) -> Result<(), TomlDeError<'input, 'shape>> {
+    // Fields with `deserialize_with` or `proxy` are read as the shape they're converted from
+    if wip.deserialize_with_shape().is_some() {
+        reflect!(wip, toml, item.span(), begin_deserialize_with());
+        deserialize_item(toml, wip, item)?;
+        reflect!(wip, toml, item.span(), end());
+        return Ok(());
+    }
+
     // Check for Option before anything else, since it's a special case
     // Option is an enum in Rust, but we handle it specially
     if let Def::Option(_) = wip.shape().def {
@@ -514,7 +522,12 @@ fn deserialize_as_map<'input, 'a, 'shape>(
                 reflect!(wip, toml, item.span(), set(k.to_string()));
             }
             ScalarType::CowStr => {
-                reflect!(wip, toml, item.span(), set(Cow::Owned(k.to_string())));
+                reflect!(
+                    wip,
+                    toml,
+                    item.span(),
+                    set(Cow::<str>::Owned(k.to_string()))
+                );
             }
             _ => {
                 return Err(TomlDeError::new(
@@ -598,6 +611,14 @@ fn deserialize_as_scalar<'input, 'a, 'shape>(
         "scalar".blue()
     );
 
+    // Scalars without a dedicated scalar type (timestamps, durations, UUIDs, ...) are parsed
+    // from their string representation
+    if ScalarType::try_from_shape(wip.shape()).is_none() && wip.shape().is_from_str() {
+        to_scalar::put_from_str(toml, wip, item)?;
+        trace!("Finished deserializing {}", "scalar".blue());
+        return Ok(());
+    }
+
     match ScalarType::try_from_shape(wip.shape()).ok_or_else(|| {
         TomlDeError::new(
             toml,
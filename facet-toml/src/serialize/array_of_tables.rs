@@ -1,5 +1,6 @@
 use facet_core::{Def, StructKind, Type, UserType};
 use facet_reflect::{HasFields, Peek, PeekListLike, PeekStruct};
+use facet_serialize::{Redaction, SerializeOptions};
 use toml_edit::{ArrayOfTables, Item, Table};
 
 /// Check if a Peek value represents an array of structs/tables
@@ -26,13 +27,14 @@ pub fn is_array_of_tables(peek: &Peek) -> bool {
 /// Serialize an array of tables to TOML array of tables format
 pub fn serialize_array_of_tables<'mem, 'facet, 'shape>(
     list: PeekListLike<'mem, 'facet, 'shape>,
+    options: &SerializeOptions,
 ) -> Result<ArrayOfTables, super::TomlSerError> {
     let mut array_of_tables = ArrayOfTables::new();
 
     for item in list.iter() {
         // Each item should be a struct that we convert to a table
         if let Ok(struct_peek) = item.into_struct() {
-            let table = serialize_struct_as_table(struct_peek)?;
+            let table = serialize_struct_as_table(struct_peek, options)?;
             array_of_tables.push(table);
         } else {
             return Err(super::TomlSerError::InvalidArrayOfTables);
@@ -45,13 +47,25 @@ pub fn serialize_array_of_tables<'mem, 'facet, 'shape>(
 /// Serialize a struct as a TOML table
 fn serialize_struct_as_table<'mem, 'facet, 'shape>(
     struct_peek: PeekStruct<'mem, 'facet, 'shape>,
+    options: &SerializeOptions,
 ) -> Result<Table, super::TomlSerError> {
     let mut table = Table::new();
 
     // Serialize each field
     for (field, value) in struct_peek.fields_for_serialize() {
-        // Serialize the field value to a TOML value
-        let toml_value = serialize_value_to_toml(value)?;
+        let toml_value = match options.redaction_for(&field) {
+            Some(Redaction::Omit) => continue,
+            Some(Redaction::Placeholder(placeholder)) => Item::Value(placeholder.into()),
+            // Serialize the field value to a TOML value, converted first for fields with
+            // `#[facet(serialize_with = ..)]` or `#[facet(proxy = ..)]`
+            None => match value
+                .serialize_with(&field)
+                .expect("serialize_with conversions only fail on shape mismatches")
+            {
+                Some(converted) => serialize_value_to_toml(converted.peek(), options)?,
+                None => serialize_value_to_toml(value, options)?,
+            },
+        };
         table.insert(field.name, toml_value);
     }
 
@@ -61,10 +75,11 @@ fn serialize_struct_as_table<'mem, 'facet, 'shape>(
 /// Helper to serialize a value to a TOML Item
 fn serialize_value_to_toml<'mem, 'facet, 'shape>(
     value: Peek<'mem, 'facet, 'shape>,
+    options: &SerializeOptions,
 ) -> Result<Item, super::TomlSerError> {
     // Create a temporary serializer to serialize just this value
     let mut temp_serializer = super::TomlSerializer::new();
-    facet_serialize::serialize_iterative(value, &mut temp_serializer)?;
+    facet_serialize::serialize_iterative_with_options(value, &mut temp_serializer, options)?;
 
     // Get the serialized document
     let doc = temp_serializer.into_raw_document();
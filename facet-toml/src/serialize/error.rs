@@ -14,6 +14,11 @@ pub enum TomlSerError {
     },
     /// TOML doesn't support byte arrays.
     UnsupportedByteArray,
+    /// Could not read the value being serialized.
+    Reflect {
+        /// What went wrong.
+        message: alloc::string::String,
+    },
     /// Invalid array of tables (expected structs)
     InvalidArrayOfTables,
 }
@@ -30,6 +35,7 @@ impl core::fmt::Display for TomlSerError {
             Self::UnsupportedByteArray => {
                 write!(f, "TOML doesn't support byte arrays")
             }
+            Self::Reflect { message } => write!(f, "{message}"),
             Self::InvalidArrayOfTables => {
                 write!(f, "Invalid array of tables: expected array of structs")
             }
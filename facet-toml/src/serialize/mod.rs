@@ -16,7 +16,8 @@ use owo_colors::OwoColorize;
 
 pub use error::TomlSerError;
 use facet_reflect::HasFields;
-use facet_serialize::{Serialize, Serializer};
+use facet_serialize::Serializer;
+pub use facet_serialize::{REDACTED, Redaction, SerializeOptions};
 use log::trace;
 use toml_edit::{DocumentMut, Item, Table, Value};
 
@@ -120,6 +121,15 @@ impl<'shape> Default for TomlSerializer<'shape> {
 impl<'shape> Serializer<'shape> for TomlSerializer<'shape> {
     type Error = TomlSerError;
 
+    fn serialize_unreadable(
+        &mut self,
+        error: facet_reflect::ReflectError<'shape>,
+    ) -> Result<(), Self::Error> {
+        Err(TomlSerError::Reflect {
+            message: error.to_plain_string(),
+        })
+    }
+
     fn serialize_u64(&mut self, value: u64) -> Result<(), Self::Error> {
         let toml_number = TryInto::<i64>::try_into(value)
             .map_err(|_| TomlSerError::InvalidNumberToI64Conversion { source_type: "u64" })?;
@@ -295,6 +305,15 @@ impl core::fmt::Display for KeyStack<'_> {
 /// Serialize any `Facet` type to a TOML string.
 #[cfg(feature = "alloc")]
 pub fn to_string<'a, T: facet_core::Facet<'a>>(value: &'a T) -> Result<String, TomlSerError> {
+    to_string_with_options(value, &SerializeOptions::default())
+}
+
+/// Serialize any `Facet` type to a TOML string, as configured by `options`.
+#[cfg(feature = "alloc")]
+pub fn to_string_with_options<'a, T: facet_core::Facet<'a>>(
+    value: &'a T,
+    options: &SerializeOptions,
+) -> Result<String, TomlSerError> {
     // First peek at the value to understand its structure
     let peek = facet_reflect::Peek::new(value);
 
@@ -304,11 +323,29 @@ pub fn to_string<'a, T: facet_core::Facet<'a>>(value: &'a T) -> Result<String, T
 
         // Process each field
         for (field, field_value) in struct_peek.fields_for_serialize() {
+            match options.redaction_for(&field) {
+                Some(Redaction::Omit) => continue,
+                Some(Redaction::Placeholder(placeholder)) => {
+                    serializer.push_key(field.name);
+                    serializer.serialize_str(placeholder)?;
+                    serializer.pop_key();
+                    continue;
+                }
+                None => {}
+            }
+
+            // Fields with `#[facet(serialize_with = ..)]` or `#[facet(proxy = ..)]` are
+            // serialized as another type
+            let converted = field_value
+                .serialize_with(&field)
+                .expect("serialize_with conversions only fail on shape mismatches");
+            let field_value = converted.as_ref().map_or(field_value, |c| c.peek());
+
             // Check if this field is an array of tables
             if array_of_tables::is_array_of_tables(&field_value) {
                 // Handle array of tables specially
                 let list = field_value.into_list_like().unwrap();
-                let aot = array_of_tables::serialize_array_of_tables(list)?;
+                let aot = array_of_tables::serialize_array_of_tables(list, options)?;
                 serializer
                     .document
                     .insert(field.name, Item::ArrayOfTables(aot));
@@ -316,7 +353,11 @@ pub fn to_string<'a, T: facet_core::Facet<'a>>(value: &'a T) -> Result<String, T
                 // Normal field serialization
                 serializer.push_key(field.name);
                 trace!("Push field {}", field.name);
-                facet_serialize::serialize_iterative(field_value, &mut serializer)?;
+                facet_serialize::serialize_iterative_with_options(
+                    field_value,
+                    &mut serializer,
+                    options,
+                )?;
                 serializer.pop_key();
                 trace!("Pop field {}", field.name);
             }
@@ -326,7 +367,7 @@ pub fn to_string<'a, T: facet_core::Facet<'a>>(value: &'a T) -> Result<String, T
     } else {
         // Not a struct at root, use normal serialization
         let mut serializer = TomlSerializer::new();
-        value.serialize(&mut serializer)?;
+        facet_serialize::serialize_iterative_with_options(peek, &mut serializer, options)?;
         Ok(serializer.into_string())
     }
 }
@@ -539,3 +539,49 @@ fn test_unparsable_scalar() {
     let result = facet_toml::from_str::<ConstTypeId>("value = 1");
     assert!(result.is_err(), "Expected an error but got {:?}", result);
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn test_duration() {
+    use std::time::Duration;
+
+    #[derive(Debug, Facet, PartialEq)]
+    struct Root {
+        value: Duration,
+        #[facet(proxy = facet::Seconds)]
+        seconds: Duration,
+    }
+
+    assert_eq!(
+        facet_toml::from_str::<Root>("value = '1m30s'\nseconds = 90")?,
+        Root {
+            value: Duration::from_secs(90),
+            seconds: Duration::from_secs(90),
+        },
+    );
+    assert_eq!(
+        facet_toml::from_str::<Root>("value = 'PT0.5S'\nseconds = 0.5")?,
+        Root {
+            value: Duration::from_millis(500),
+            seconds: Duration::from_millis(500),
+        },
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_system_time() {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    #[derive(Debug, Facet, PartialEq)]
+    struct Root {
+        value: SystemTime,
+    }
+
+    assert_eq!(
+        facet_toml::from_str::<Root>("value = '2024-01-15T12:30:00Z'")?,
+        Root {
+            value: UNIX_EPOCH + Duration::from_secs(1_705_321_800),
+        },
+    );
+}
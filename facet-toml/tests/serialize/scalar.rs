@@ -326,3 +326,49 @@ fn test_i128_out_of_range() {
         TomlSerError::InvalidNumberToI64Conversion { .. }
     ));
 }
+
+#[test]
+fn test_duration() {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    #[derive(Debug, Facet, PartialEq)]
+    struct Root {
+        timeout: Duration,
+        #[facet(proxy = facet::Seconds)]
+        interval: Duration,
+        #[facet(proxy = facet::Seconds)]
+        at: SystemTime,
+    }
+
+    let root = Root {
+        timeout: Duration::from_millis(1500),
+        interval: Duration::from_secs(90),
+        at: UNIX_EPOCH + Duration::from_secs(1_705_321_800),
+    };
+    assert_eq!(
+        facet_toml::to_string(&root)?,
+        "timeout = \"1.5s\"\ninterval = 90.0\nat = 1705321800.0\n"
+    );
+    assert_serialize!(Root, root);
+}
+
+#[test]
+fn test_semver() {
+    use semver::{Version, VersionReq};
+
+    #[derive(Debug, Facet, PartialEq)]
+    struct Root {
+        version: Version,
+        requires: VersionReq,
+    }
+
+    let root = Root {
+        version: Version::parse("0.27.13")?,
+        requires: VersionReq::parse(">=1.0.100")?,
+    };
+    assert_eq!(
+        facet_toml::to_string(&root)?,
+        "version = \"0.27.13\"\nrequires = \">=1.0.100\"\n"
+    );
+    assert_serialize!(Root, root);
+}
@@ -204,3 +204,47 @@ fn test_optional_default_struct_fields() {
         },
     );
 }
+
+#[test]
+fn test_redact_sensitive() {
+    #[derive(Debug, Facet, PartialEq)]
+    struct Root {
+        name: String,
+        #[facet(sensitive)]
+        password: String,
+        servers: Vec<Server>,
+    }
+
+    #[derive(Debug, Facet, PartialEq)]
+    struct Server {
+        host: String,
+        #[facet(sensitive)]
+        api_key: String,
+    }
+
+    let root = Root {
+        name: "prod".to_string(),
+        password: "hunter2".to_string(),
+        servers: vec![Server {
+            host: "example.org".to_string(),
+            api_key: "s3cr3t".to_string(),
+        }],
+    };
+
+    let redacted = facet_toml::to_string_with_options(
+        &root,
+        &facet_toml::SerializeOptions::new().redact_sensitive(),
+    )?;
+    let deserialized: Root = facet_toml::from_str(&redacted).map_err(|err| eyre::eyre!("{err}"))?;
+    assert_eq!(deserialized.password, facet_toml::REDACTED);
+    assert_eq!(deserialized.servers[0].host, "example.org");
+    assert_eq!(deserialized.servers[0].api_key, facet_toml::REDACTED);
+
+    let omitted = facet_toml::to_string_with_options(
+        &root,
+        &facet_toml::SerializeOptions::new().omit_sensitive(),
+    )?;
+    assert!(!omitted.contains("password"));
+    assert!(!omitted.contains("api_key"));
+    assert!(omitted.contains("example.org"));
+}
@@ -2,13 +2,75 @@
 #![forbid(unsafe_code)]
 #![doc = include_str!("../README.md")]
 
+use std::ffi::{CString, OsString};
 use std::io::Write;
+use std::sync::atomic::Ordering;
 
 use facet_core::{
-    Def, Facet, IntegerSize, NumberBits, ScalarAffinity, Signedness, StructKind, Type, UserType,
+    Def, Facet, Field, FieldAttribute, IntegerSize, NumberBits, PointerType, ScalarAffinity,
+    Signedness, Type, UserType, Variant,
 };
-use facet_reflect::{HeapValue, Partial, Peek};
-use facet_serialize::{Serializer, serialize_iterative};
+use facet_reflect::{HasFields, HeapValue, Partial, Peek, ReflectError, ScalarType};
+
+#[cfg(feature = "rpc")]
+pub mod rpc;
+
+/// Field attribute bounding the length of a string, opaque data or variable-length array,
+/// like `string<255>` in XDR language: `#[facet(xdr_max = 255)]`
+const XDR_MAX_ATTR: &str = "xdr_max";
+
+/// Returns the maximum length set on a field via `#[facet(xdr_max = N)]`, if any
+fn field_bound(field: &Field<'_>) -> Option<u32> {
+    field.attributes.iter().find_map(|attr| {
+        let FieldAttribute::Arbitrary(content) = attr else {
+            return None;
+        };
+        let (key, value) = content.split_once('=')?;
+        if key.trim() != XDR_MAX_ATTR {
+            return None;
+        }
+        value.trim().parse().ok()
+    })
+}
+
+/// Rebuilds an `OsString` from the bytes of `OsStr::as_encoded_bytes`. Outside of Unix, the
+/// encoding isn't portable, so only UTF-8 is accepted
+fn os_string_from_bytes(bytes: Vec<u8>) -> Result<OsString, std::string::FromUtf8Error> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStringExt;
+        Ok(OsString::from_vec(bytes))
+    }
+    #[cfg(not(unix))]
+    {
+        String::from_utf8(bytes).map(OsString::from)
+    }
+}
+
+/// XDR discriminants are 32-bit, either `int` or `unsigned int`
+fn discriminant_to_u32(discriminant: i64) -> Option<u32> {
+    if (i32::MIN as i64..=u32::MAX as i64).contains(&discriminant) {
+        Some(discriminant as u32)
+    } else {
+        None
+    }
+}
+
+/// The discriminant of a union arm (enum variant): its explicit discriminant if it has one,
+/// and its index otherwise
+fn variant_discriminant(variant: &Variant<'_>, index: usize) -> i64 {
+    variant.discriminant.unwrap_or(index as i64)
+}
+
+/// Finds the union arm (enum variant) encoded by a 32-bit XDR discriminant, and its index
+fn variant_for_discriminant<'shape>(
+    variants: &'shape [Variant<'shape>],
+    discriminant: u32,
+) -> Option<(usize, &'shape Variant<'shape>)> {
+    variants.iter().enumerate().find(|(index, variant)| {
+        discriminant_to_u32(variant_discriminant(variant, *index)) == Some(discriminant)
+    })
+}
 
 /// Errors when serializing to XDR bytes
 #[derive(Debug)]
@@ -19,8 +81,20 @@ pub enum XdrSerError {
     TooManyBytes,
     /// Enum variant discriminant too large
     TooManyVariants,
+    /// Length exceeds the maximum set with `#[facet(xdr_max = N)]`
+    BoundExceeded {
+        /// Maximum length allowed for the field
+        bound: u32,
+        /// Actual length of the value
+        len: usize,
+    },
     /// Unsupported type
     UnsupportedType,
+    /// Error from reading the value, e.g. a failed `serialize_with` conversion
+    Reflect {
+        /// What went wrong
+        message: String,
+    },
 }
 
 impl core::fmt::Display for XdrSerError {
@@ -29,7 +103,11 @@ impl core::fmt::Display for XdrSerError {
             XdrSerError::Io(error) => write!(f, "IO error: {}", error),
             XdrSerError::TooManyBytes => write!(f, "Too many bytes for field"),
             XdrSerError::TooManyVariants => write!(f, "Enum variant discriminant too large"),
+            XdrSerError::BoundExceeded { bound, len } => {
+                write!(f, "Length {} exceeds maximum of {}", len, bound)
+            }
             XdrSerError::UnsupportedType => write!(f, "Unsupported type"),
+            XdrSerError::Reflect { message } => write!(f, "{}", message),
         }
     }
 }
@@ -50,145 +128,260 @@ pub fn to_vec<'f, F: Facet<'f>>(value: &'f F) -> Result<Vec<u8>, XdrSerError> {
     let mut serializer = XdrSerializer {
         writer: &mut buffer,
     };
-    serialize_iterative(peek, &mut serializer)?;
+    serializer.serialize(peek, None)?;
     Ok(buffer)
 }
 
+/// XDR is not self-describing: how a value is encoded depends on its declared type (fixed
+/// vs. variable-length arrays, union arms, bounds) rather than on its contents, so this walks
+/// the `Shape` directly instead of going through `facet_serialize`.
 struct XdrSerializer<'w, W: Write> {
     writer: &'w mut W,
 }
 
-impl<'shape, W: Write> Serializer<'shape> for XdrSerializer<'_, W> {
-    type Error = XdrSerError;
-
-    fn serialize_u32(&mut self, value: u32) -> Result<(), Self::Error> {
-        self.writer
-            .write_all(&value.to_be_bytes())
-            .map_err(Self::Error::Io)
-    }
-
-    fn serialize_u64(&mut self, value: u64) -> Result<(), Self::Error> {
+impl<W: Write> XdrSerializer<'_, W> {
+    fn write_u32(&mut self, value: u32) -> Result<(), XdrSerError> {
         self.writer
             .write_all(&value.to_be_bytes())
-            .map_err(Self::Error::Io)
+            .map_err(XdrSerError::Io)
     }
 
-    fn serialize_u128(&mut self, _value: u128) -> Result<(), Self::Error> {
-        Err(Self::Error::UnsupportedType)
-    }
-
-    fn serialize_i32(&mut self, value: i32) -> Result<(), Self::Error> {
+    fn write_u64(&mut self, value: u64) -> Result<(), XdrSerError> {
         self.writer
             .write_all(&value.to_be_bytes())
-            .map_err(Self::Error::Io)
+            .map_err(XdrSerError::Io)
     }
 
-    fn serialize_i64(&mut self, value: i64) -> Result<(), Self::Error> {
-        self.writer
-            .write_all(&value.to_be_bytes())
-            .map_err(Self::Error::Io)
-    }
-
-    fn serialize_i128(&mut self, _value: i128) -> Result<(), Self::Error> {
-        Err(Self::Error::UnsupportedType)
-    }
-
-    fn serialize_f32(&mut self, value: f32) -> Result<(), Self::Error> {
-        self.writer
-            .write_all(&value.to_be_bytes())
-            .map_err(Self::Error::Io)
-    }
-
-    fn serialize_f64(&mut self, value: f64) -> Result<(), Self::Error> {
-        self.writer
-            .write_all(&value.to_be_bytes())
-            .map_err(Self::Error::Io)
-    }
-
-    fn serialize_bool(&mut self, value: bool) -> Result<(), Self::Error> {
-        if value {
-            self.writer.write_all(&1u32.to_be_bytes())
-        } else {
-            self.writer.write_all(&0u32.to_be_bytes())
+    /// Writes the length prefix of variable-length data, checking it against the bound
+    fn write_len(&mut self, len: usize, bound: Option<u32>) -> Result<(), XdrSerError> {
+        if len > u32::MAX as usize {
+            return Err(XdrSerError::TooManyBytes);
         }
-        .map_err(Self::Error::Io)
-    }
-
-    fn serialize_char(&mut self, value: char) -> Result<(), Self::Error> {
-        self.serialize_u32(value as u32)
-    }
-
-    fn serialize_str(&mut self, value: &str) -> Result<(), Self::Error> {
-        let bytes = value.as_bytes();
-        self.serialize_bytes(bytes)
+        if let Some(bound) = bound {
+            if len > bound as usize {
+                return Err(XdrSerError::BoundExceeded { bound, len });
+            }
+        }
+        self.write_u32(len as u32)
     }
 
-    fn serialize_bytes(&mut self, value: &[u8]) -> Result<(), Self::Error> {
-        if value.len() > u32::MAX as usize {
-            return Err(Self::Error::TooManyBytes);
-        }
-        let len = value.len() as u32;
-        self.writer
-            .write_all(&len.to_be_bytes())
-            .map_err(Self::Error::Io)?;
+    /// Writes fixed-length opaque data (`opaque[n]`), padded to a multiple of four bytes
+    fn write_fixed_opaque(&mut self, value: &[u8]) -> Result<(), XdrSerError> {
+        self.writer.write_all(value).map_err(XdrSerError::Io)?;
         let pad_len = value.len() % 4;
-        self.writer.write_all(value).map_err(Self::Error::Io)?;
         if pad_len != 0 {
             let pad = vec![0u8; 4 - pad_len];
-            self.writer.write_all(&pad).map_err(Self::Error::Io)?;
+            self.writer.write_all(&pad).map_err(XdrSerError::Io)?;
         }
         Ok(())
     }
 
-    fn serialize_none(&mut self) -> Result<(), Self::Error> {
-        Ok(())
+    /// Writes variable-length opaque data (`opaque<>` and `string<>`)
+    fn write_opaque(&mut self, value: &[u8], bound: Option<u32>) -> Result<(), XdrSerError> {
+        self.write_len(value.len(), bound)?;
+        self.write_fixed_opaque(value)
     }
 
-    fn serialize_unit(&mut self) -> Result<(), Self::Error> {
-        Ok(())
-    }
-
-    fn serialize_unit_variant(
+    fn serialize_scalar(
         &mut self,
-        _variant_index: usize,
-        _variant_name: &'shape str,
-    ) -> Result<(), Self::Error> {
-        Ok(())
-    }
-
-    fn start_object(&mut self, _len: Option<usize>) -> Result<(), Self::Error> {
-        Ok(())
+        peek: Peek<'_, '_, '_>,
+        bound: Option<u32>,
+    ) -> Result<(), XdrSerError> {
+        match peek.scalar_type() {
+            Some(ScalarType::Unit) => Ok(()),
+            Some(ScalarType::Bool) => self.write_u32(*peek.get::<bool>().unwrap() as u32),
+            Some(ScalarType::Char) => self.write_u32(*peek.get::<char>().unwrap() as u32),
+            Some(ScalarType::Str) => {
+                self.write_opaque(peek.get::<&str>().unwrap().as_bytes(), bound)
+            }
+            Some(ScalarType::String) => {
+                self.write_opaque(peek.get::<String>().unwrap().as_bytes(), bound)
+            }
+            Some(ScalarType::CowStr) => self.write_opaque(
+                peek.get::<std::borrow::Cow<'_, str>>().unwrap().as_bytes(),
+                bound,
+            ),
+            Some(ScalarType::F32) => self.write_u32(peek.get::<f32>().unwrap().to_bits()),
+            Some(ScalarType::F64) => self.write_u64(peek.get::<f64>().unwrap().to_bits()),
+            Some(ScalarType::U8) => self.write_u32(*peek.get::<u8>().unwrap() as u32),
+            Some(ScalarType::U16) => self.write_u32(*peek.get::<u16>().unwrap() as u32),
+            Some(ScalarType::U32) => self.write_u32(*peek.get::<u32>().unwrap()),
+            Some(ScalarType::U64) => self.write_u64(*peek.get::<u64>().unwrap()),
+            Some(ScalarType::USize) => self.write_u64(*peek.get::<usize>().unwrap() as u64),
+            Some(ScalarType::I8) => self.write_u32(*peek.get::<i8>().unwrap() as i32 as u32),
+            Some(ScalarType::I16) => self.write_u32(*peek.get::<i16>().unwrap() as i32 as u32),
+            Some(ScalarType::I32) => self.write_u32(*peek.get::<i32>().unwrap() as u32),
+            Some(ScalarType::I64) => self.write_u64(*peek.get::<i64>().unwrap() as u64),
+            Some(ScalarType::ISize) => self.write_u64(*peek.get::<isize>().unwrap() as i64 as u64),
+            Some(
+                ScalarType::SocketAddr
+                | ScalarType::SocketAddrV4
+                | ScalarType::SocketAddrV6
+                | ScalarType::IpAddr
+                | ScalarType::Ipv4Addr
+                | ScalarType::Ipv6Addr,
+            ) => self.write_opaque(format!("{peek}").as_bytes(), bound),
+            // Platform strings aren't necessarily valid UTF-8, so they're written as is
+            Some(ScalarType::CString) => {
+                self.write_opaque(peek.get::<CString>().unwrap().as_bytes(), bound)
+            }
+            // The encoding of these bytes is platform-specific (see `os_string_from_bytes`)
+            Some(ScalarType::OsString) => {
+                self.write_opaque(peek.get::<OsString>().unwrap().as_encoded_bytes(), bound)
+            }
+            // A `str` behind a smart pointer (like `Arc<str>`)
+            None if peek.shape().is_type::<str>() => {
+                self.write_opaque(peek.as_str().unwrap().as_bytes(), bound)
+            }
+            _ => Err(XdrSerError::UnsupportedType),
+        }
     }
 
-    fn serialize_field_name(&mut self, _name: &'shape str) -> Result<(), Self::Error> {
-        Ok(())
-    }
+    fn serialize(&mut self, peek: Peek<'_, '_, '_>, bound: Option<u32>) -> Result<(), XdrSerError> {
+        // XDR has no delimiters, so every value can be scheduled up front, in reverse order,
+        // along with the field it's the value of
+        let mut stack = vec![(peek, bound, None)];
+
+        while let Some((peek, bound, field)) = stack.pop() {
+            // Fields with `serialize_with` or `proxy` are written as the type they're converted
+            // to. Like locked values below, the converted value only lives until it's written,
+            // so it's written right away (everything still on the stack comes after it)
+            if let Some(field) = field {
+                let converted = peek
+                    .serialize_with(&field)
+                    .map_err(|e| XdrSerError::Reflect {
+                        message: e.to_plain_string(),
+                    })?;
+                if let Some(converted) = converted {
+                    self.serialize(converted.peek(), bound)?;
+                    continue;
+                }
+            }
 
-    fn start_array(&mut self, len: Option<usize>) -> Result<(), Self::Error> {
-        if let Some(len) = len {
-            if len > u32::MAX as usize {
-                return Err(Self::Error::TooManyBytes);
+            match (peek.shape().def, peek.shape().ty) {
+                (Def::Scalar(_), _) => match peek.load_atomic(Ordering::SeqCst) {
+                    Ok(Some(loaded)) => self.serialize_scalar(loaded.peek(), bound)?,
+                    _ => self.serialize_scalar(peek.innermost_peek(), bound)?,
+                },
+                (Def::List(ld), _) if ld.t().is_type::<u8>() => {
+                    let bytes: Vec<u8> = peek
+                        .into_list_like()
+                        .unwrap()
+                        .iter()
+                        .map(|p| *p.get::<u8>().unwrap())
+                        .collect();
+                    self.write_opaque(&bytes, bound)?;
+                }
+                (Def::Slice(sd), _) if sd.t().is_type::<u8>() => {
+                    self.write_opaque(peek.as_bytes().unwrap(), bound)?;
+                }
+                (Def::Array(ad), _) if ad.t().is_type::<u8>() => {
+                    let bytes: Vec<u8> = peek
+                        .into_list_like()
+                        .unwrap()
+                        .iter()
+                        .map(|p| *p.get::<u8>().unwrap())
+                        .collect();
+                    self.write_fixed_opaque(&bytes)?;
+                }
+                (Def::List(_) | Def::Slice(_), _) => {
+                    let items: Vec<_> = peek.into_list_like().unwrap().iter().collect();
+                    self.write_len(items.len(), bound)?;
+                    stack.extend(items.into_iter().rev().map(|item| (item, None, None)));
+                }
+                (Def::Array(_), _) => {
+                    let items = peek.into_list_like().unwrap().iter().collect::<Vec<_>>();
+                    stack.extend(items.into_iter().rev().map(|item| (item, None, None)));
+                }
+                (Def::Set(_), _) => {
+                    // A set is a counted array of its items
+                    let items: Vec<_> = peek.into_set().unwrap().iter().collect();
+                    self.write_len(items.len(), bound)?;
+                    stack.extend(items.into_iter().rev().map(|item| (item, None, None)));
+                }
+                (Def::Map(_), _) => {
+                    // A map is a counted array of key/value pairs
+                    let map = peek.into_map().unwrap();
+                    self.write_len(map.len(), bound)?;
+                    let entries: Vec<_> = map.iter().collect();
+                    for (key, value) in entries.into_iter().rev() {
+                        stack.push((value, None, None));
+                        stack.push((key, None, None));
+                    }
+                }
+                (Def::Option(_), _) => match peek.into_option().unwrap().value() {
+                    Some(inner) => {
+                        self.write_u32(1)?;
+                        stack.push((inner, bound, None));
+                    }
+                    None => self.write_u32(0)?,
+                },
+                (Def::Result(_), _) => {
+                    // A result is a union with an `Ok` arm (0) and an `Err` arm (1)
+                    match peek.into_result().unwrap().value() {
+                        Ok(value) => {
+                            self.write_u32(0)?;
+                            stack.push((value, bound, None));
+                        }
+                        Err(error) => {
+                            self.write_u32(1)?;
+                            stack.push((error, bound, None));
+                        }
+                    }
+                }
+                (Def::SmartPointer(_), _) => {
+                    let sp = peek.into_smart_pointer().unwrap();
+                    match sp.borrow_inner() {
+                        Some(inner) => stack.push((inner, bound, None)),
+                        // Locked values can only be reached while the guard is alive, so they're
+                        // written right away (everything still on the stack comes after them)
+                        None => match sp.lock() {
+                            Ok(guard) => self.serialize(guard.peek(), bound)?,
+                            Err(_) => return Err(XdrSerError::UnsupportedType),
+                        },
+                    }
+                }
+                (_, Type::User(UserType::Struct(_))) => {
+                    let fields: Vec<_> = peek.into_struct().unwrap().fields().collect();
+                    for (field, value) in fields.into_iter().rev() {
+                        stack.push((value, field_bound(&field), Some(field)));
+                    }
+                }
+                (_, Type::User(UserType::Enum(_))) => {
+                    // Enums map to discriminated unions: the discriminant, then the arm's fields
+                    let peek_enum = peek.into_enum().unwrap();
+                    let variant = peek_enum.active_variant().unwrap();
+                    let discriminant =
+                        variant_discriminant(variant, peek_enum.variant_index().unwrap());
+                    let discriminant =
+                        discriminant_to_u32(discriminant).ok_or(XdrSerError::TooManyVariants)?;
+                    self.write_u32(discriminant)?;
+                    let fields: Vec<_> = peek_enum.fields().collect();
+                    for (field, value) in fields.into_iter().rev() {
+                        stack.push((value, field_bound(&field), Some(field)));
+                    }
+                }
+                (_, Type::Pointer(pointer_type)) => {
+                    if let Some(str_value) = peek.as_str() {
+                        self.write_opaque(str_value.as_bytes(), bound)?;
+                    } else if let Some(bytes) = peek.as_bytes() {
+                        self.write_opaque(bytes, bound)?;
+                    } else if let (PointerType::Reference(_), Ok(list)) =
+                        (pointer_type, peek.into_list_like())
+                    {
+                        let items: Vec<_> = list.iter().collect();
+                        self.write_len(items.len(), bound)?;
+                        stack.extend(items.into_iter().rev().map(|item| (item, None, None)));
+                    } else {
+                        return Err(XdrSerError::UnsupportedType);
+                    }
+                }
+                _ => return Err(XdrSerError::UnsupportedType),
             }
-            self.writer
-                .write_all(&(len as u32).to_be_bytes())
-                .map_err(Self::Error::Io)
-        } else {
-            panic!("array length missing");
         }
-    }
 
-    fn start_map(&mut self, _len: Option<usize>) -> Result<(), Self::Error> {
         Ok(())
     }
-
-    fn start_enum_variant(&mut self, discriminant: u64) -> Result<(), Self::Error> {
-        if discriminant > u32::MAX as u64 {
-            return Err(Self::Error::TooManyVariants);
-        }
-        self.writer
-            .write_all(&(discriminant as u32).to_be_bytes())
-            .map_err(Self::Error::Io)
-    }
 }
 
 /// Errors when deserializing from XDR bytes
@@ -215,6 +408,15 @@ pub enum XdrDeserError {
         /// Position of this error in bytes
         position: usize,
     },
+    /// Length exceeds the maximum set with `#[facet(xdr_max = N)]`
+    BoundExceeded {
+        /// Position of this error in bytes
+        position: usize,
+        /// Maximum length allowed for the field
+        bound: u32,
+        /// Length found in the input
+        len: usize,
+    },
     /// Invalid string
     InvalidString {
         /// Position of this error in bytes
@@ -222,6 +424,18 @@ pub enum XdrDeserError {
         /// Underlying UTF-8 error
         source: core::str::Utf8Error,
     },
+    /// Data that doesn't make a valid value of the expected type, like a malformed IP address
+    InvalidValue {
+        /// Position of this error in bytes
+        position: usize,
+    },
+    /// The value couldn't be built from the input, e.g. because a required field is missing
+    Reflect {
+        /// Position of this error in bytes
+        position: usize,
+        /// What went wrong while building the value
+        message: String,
+    },
 }
 
 impl core::fmt::Display for XdrDeserError {
@@ -241,9 +455,26 @@ impl core::fmt::Display for XdrDeserError {
             XdrDeserError::InvalidVariant { position } => {
                 write!(f, "Invalid enum discriminant at byte {}", position)
             }
+            XdrDeserError::BoundExceeded {
+                position,
+                bound,
+                len,
+            } => {
+                write!(
+                    f,
+                    "Length {} exceeds maximum of {} at byte {}",
+                    len, bound, position
+                )
+            }
             XdrDeserError::InvalidString { position, .. } => {
                 write!(f, "Invalid string at byte {}", position)
             }
+            XdrDeserError::InvalidValue { position } => {
+                write!(f, "Invalid value at byte {}", position)
+            }
+            XdrDeserError::Reflect { position, message } => {
+                write!(f, "Could not build value at byte {}: {}", position, message)
+            }
         }
     }
 }
@@ -266,9 +497,21 @@ enum PopReason {
 
 #[derive(Debug)]
 enum DeserializeTask {
-    Value,
-    Field(usize),
-    ListItem,
+    Value {
+        bound: Option<u32>,
+    },
+    Field {
+        index: usize,
+        bound: Option<u32>,
+    },
+    /// The given number of list items are left to read
+    ListItems(usize),
+    /// The given number of set items are left to read
+    SetItems(usize),
+    ArrayElement(usize),
+    /// The given number of map entries are left to read
+    MapEntries(usize),
+    MapValue,
     Pop(PopReason),
 }
 
@@ -278,6 +521,14 @@ struct XdrDeserializerStack<'input> {
     stack: Vec<DeserializeTask>,
 }
 
+/// Wraps an error from building the value, at the given position in the input
+fn reflect_err(position: usize, error: ReflectError<'_>) -> XdrDeserError {
+    XdrDeserError::Reflect {
+        position,
+        message: error.to_plain_string(),
+    }
+}
+
 impl<'shape, 'input> XdrDeserializerStack<'input> {
     fn next_u32(&mut self) -> Result<u32, XdrDeserError> {
         assert_eq!(self.pos % 4, 0);
@@ -299,78 +550,104 @@ impl<'shape, 'input> XdrDeserializerStack<'input> {
         Ok(u64::from_be_bytes(bytes.try_into().unwrap()))
     }
 
-    fn next_data(&mut self, expected_len: Option<u32>) -> Result<&'input [u8], XdrDeserError> {
-        let len = self.next_u32()? as usize;
-        if let Some(expected_len) = expected_len {
-            assert_eq!(len, expected_len as usize);
+    /// Reads the length prefix of variable-length data, checking it against the bound
+    fn next_len(&mut self, bound: Option<u32>) -> Result<usize, XdrDeserError> {
+        let len = self.next_u32()?;
+        if let Some(bound) = bound {
+            if len > bound {
+                return Err(XdrDeserError::BoundExceeded {
+                    position: self.pos - 4,
+                    bound,
+                    len: len as usize,
+                });
+            }
         }
-        self.pos += len;
-        let pad_len = len % 4;
-        let data = &self.input[self.pos - len..self.pos];
-        if pad_len != 0 {
-            self.pos += 4 - pad_len;
+        Ok(len as usize)
+    }
+
+    /// Reads `len` bytes of opaque data, skipping the padding that follows
+    fn next_fixed_data(&mut self, len: usize) -> Result<&'input [u8], XdrDeserError> {
+        let padded_len = len.div_ceil(4) * 4;
+        if self.input[self.pos..].len() < padded_len {
+            return Err(XdrDeserError::UnexpectedEof);
         }
+        let data = &self.input[self.pos..self.pos + len];
+        self.pos += padded_len;
         Ok(data)
     }
 
+    fn next_data(&mut self, bound: Option<u32>) -> Result<&'input [u8], XdrDeserError> {
+        let len = self.next_len(bound)?;
+        self.next_fixed_data(len)
+    }
+
     fn next<'f>(
         &mut self,
         mut wip: Partial<'f, 'shape>,
+        bound: Option<u32>,
     ) -> Result<Partial<'f, 'shape>, XdrDeserError> {
         match (wip.shape().def, wip.shape().ty) {
+            // Atomics are built from the value they hold
+            (Def::Scalar(sd), _) if sd.atomic_load.is_some() => {
+                self.stack
+                    .push(DeserializeTask::Pop(PopReason::ObjectOrListVal));
+                self.stack.push(DeserializeTask::Value { bound });
+                wip.begin_inner().map_err(|e| reflect_err(self.pos, e))?;
+                Ok(wip)
+            }
             (Def::Scalar(sd), _) => match sd.affinity {
                 ScalarAffinity::Number(na) => match na.bits {
                     NumberBits::Integer { size, sign } => match (size, sign) {
                         (IntegerSize::Fixed(8), Signedness::Unsigned) => {
                             let value = self.next_u32()? as u8;
-                            wip.set(value).unwrap();
+                            wip.set(value).map_err(|e| reflect_err(self.pos, e))?;
                             Ok(wip)
                         }
                         (IntegerSize::Fixed(16), Signedness::Unsigned) => {
                             let value = self.next_u32()? as u16;
-                            wip.set(value).unwrap();
+                            wip.set(value).map_err(|e| reflect_err(self.pos, e))?;
                             Ok(wip)
                         }
                         (IntegerSize::Fixed(32), Signedness::Unsigned) => {
                             let value = self.next_u32()?;
-                            wip.set(value).unwrap();
+                            wip.set(value).map_err(|e| reflect_err(self.pos, e))?;
                             Ok(wip)
                         }
                         (IntegerSize::Fixed(64), Signedness::Unsigned) => {
                             let value = self.next_u64()?;
-                            wip.set(value).unwrap();
+                            wip.set(value).map_err(|e| reflect_err(self.pos, e))?;
                             Ok(wip)
                         }
                         (IntegerSize::Fixed(8), Signedness::Signed) => {
                             let value = self.next_u32()? as i8;
-                            wip.set(value).unwrap();
+                            wip.set(value).map_err(|e| reflect_err(self.pos, e))?;
                             Ok(wip)
                         }
                         (IntegerSize::Fixed(16), Signedness::Signed) => {
                             let value = self.next_u32()? as i16;
-                            wip.set(value).unwrap();
+                            wip.set(value).map_err(|e| reflect_err(self.pos, e))?;
                             Ok(wip)
                         }
                         (IntegerSize::Fixed(32), Signedness::Signed) => {
                             let value = self.next_u32()? as i32;
-                            wip.set(value).unwrap();
+                            wip.set(value).map_err(|e| reflect_err(self.pos, e))?;
                             Ok(wip)
                         }
                         (IntegerSize::Fixed(64), Signedness::Signed) => {
                             let value = self.next_u64()? as i64;
-                            wip.set(value).unwrap();
+                            wip.set(value).map_err(|e| reflect_err(self.pos, e))?;
                             Ok(wip)
                         }
                         (IntegerSize::PointerSized, Signedness::Unsigned) => {
                             // Handle usize - use 64-bit on most platforms
                             let value = self.next_u64()? as usize;
-                            wip.set(value).unwrap();
+                            wip.set(value).map_err(|e| reflect_err(self.pos, e))?;
                             Ok(wip)
                         }
                         (IntegerSize::PointerSized, Signedness::Signed) => {
                             // Handle isize - use 64-bit on most platforms
                             let value = self.next_u64()? as isize;
-                            wip.set(value).unwrap();
+                            wip.set(value).map_err(|e| reflect_err(self.pos, e))?;
                             Ok(wip)
                         }
                         _ => Err(XdrDeserError::UnsupportedNumericType),
@@ -385,12 +662,12 @@ impl<'shape, 'input> XdrDeserializerStack<'input> {
                         if bits == 32 {
                             let bits = self.next_u32()?;
                             let float = f32::from_bits(bits);
-                            wip.set(float).unwrap();
+                            wip.set(float).map_err(|e| reflect_err(self.pos, e))?;
                             Ok(wip)
                         } else if bits == 64 {
                             let bits = self.next_u64()?;
                             let float = f64::from_bits(bits);
-                            wip.set(float).unwrap();
+                            wip.set(float).map_err(|e| reflect_err(self.pos, e))?;
                             Ok(wip)
                         } else {
                             Err(XdrDeserError::UnsupportedNumericType)
@@ -399,22 +676,23 @@ impl<'shape, 'input> XdrDeserializerStack<'input> {
                     _ => Err(XdrDeserError::UnsupportedNumericType),
                 },
                 ScalarAffinity::String(_) => {
-                    let string = core::str::from_utf8(self.next_data(None)?).map_err(|e| {
+                    let string = core::str::from_utf8(self.next_data(bound)?).map_err(|e| {
                         XdrDeserError::InvalidString {
                             position: self.pos - 1,
                             source: e,
                         }
                     })?;
-                    wip.set(string.to_owned()).unwrap();
+                    wip.set(string.to_owned())
+                        .map_err(|e| reflect_err(self.pos, e))?;
                     Ok(wip)
                 }
                 ScalarAffinity::Boolean(_) => match self.next_u32()? {
                     0 => {
-                        wip.set(false).unwrap();
+                        wip.set(false).map_err(|e| reflect_err(self.pos, e))?;
                         Ok(wip)
                     }
                     1 => {
-                        wip.set(true).unwrap();
+                        wip.set(true).map_err(|e| reflect_err(self.pos, e))?;
                         Ok(wip)
                     }
                     _ => Err(XdrDeserError::InvalidBoolean {
@@ -423,108 +701,152 @@ impl<'shape, 'input> XdrDeserializerStack<'input> {
                 },
                 ScalarAffinity::Char(_) => {
                     let value = self.next_u32()?;
-                    wip.set(char::from_u32(value).unwrap()).unwrap();
+                    let value = char::from_u32(value).ok_or(XdrDeserError::InvalidValue {
+                        position: self.pos - 4,
+                    })?;
+                    wip.set(value).map_err(|e| reflect_err(self.pos, e))?;
+                    Ok(wip)
+                }
+                ScalarAffinity::SocketAddr(_) | ScalarAffinity::IpAddr(_) => {
+                    let position = self.pos;
+                    let string = core::str::from_utf8(self.next_data(bound)?)
+                        .map_err(|source| XdrDeserError::InvalidString { position, source })?;
+                    wip.parse_from_str(string)
+                        .map_err(|_| XdrDeserError::InvalidValue { position })?;
+                    Ok(wip)
+                }
+                ScalarAffinity::PlatformString(_) => {
+                    let position = self.pos;
+                    let data = self.next_data(bound)?.to_vec();
+                    if wip.shape().is_type::<CString>() {
+                        let string = CString::new(data)
+                            .map_err(|_| XdrDeserError::InvalidValue { position })?;
+                        wip.set(string).map_err(|e| reflect_err(self.pos, e))?;
+                    } else if wip.shape().is_type::<OsString>() {
+                        let string = os_string_from_bytes(data).map_err(|e| {
+                            XdrDeserError::InvalidString {
+                                position,
+                                source: e.utf8_error(),
+                            }
+                        })?;
+                        wip.set(string).map_err(|e| reflect_err(self.pos, e))?;
+                    } else {
+                        return Err(XdrDeserError::UnsupportedType);
+                    }
                     Ok(wip)
                 }
                 _ => Err(XdrDeserError::UnsupportedType),
             },
             (Def::List(ld), _) => {
                 if ld.t().is_type::<u8>() {
-                    let data = self.next_data(None)?;
-                    wip.set(data.to_vec()).unwrap();
+                    let data = self.next_data(bound)?;
+                    wip.set(data.to_vec())
+                        .map_err(|e| reflect_err(self.pos, e))?;
                     Ok(wip)
                 } else {
-                    let len = self.next_u32()?;
-                    wip.begin_list().unwrap();
-                    if len == 0 {
-                        Ok(wip)
-                    } else {
-                        for _ in 0..len {
-                            self.stack.push(DeserializeTask::ListItem);
-                        }
-                        Ok(wip)
-                    }
+                    let len = self.next_len(bound)?;
+                    wip.begin_list().map_err(|e| reflect_err(self.pos, e))?;
+                    self.stack.push(DeserializeTask::ListItems(len));
+                    Ok(wip)
                 }
             }
             (Def::Array(ad), _) => {
                 let len = ad.n;
                 if ad.t().is_type::<u8>() {
-                    self.pos += len;
-                    let pad_len = len % 4;
-                    for byte in &self.input[self.pos - len..self.pos] {
-                        wip.begin_list_item().unwrap();
-                        wip.set(*byte).unwrap();
-                        wip.end().unwrap();
-                    }
-                    if pad_len != 0 {
-                        self.pos += 4 - pad_len;
+                    let data = self.next_fixed_data(len)?;
+                    for (index, byte) in data.iter().enumerate() {
+                        wip.begin_nth_element(index)
+                            .map_err(|e| reflect_err(self.pos, e))?;
+                        wip.set(*byte).map_err(|e| reflect_err(self.pos, e))?;
+                        wip.end().map_err(|e| reflect_err(self.pos, e))?;
                     }
                     Ok(wip)
                 } else {
-                    for _ in 0..len {
-                        self.stack.push(DeserializeTask::ListItem);
+                    for index in (0..len).rev() {
+                        self.stack.push(DeserializeTask::ArrayElement(index));
                     }
                     Ok(wip)
                 }
             }
-            (Def::Slice(sd), _) => {
-                if sd.t().is_type::<u8>() {
-                    let data = self.next_data(None)?;
-                    wip.set(data.to_vec()).unwrap();
-                    Ok(wip)
-                } else {
-                    let len = self.next_u32()?;
-                    for _ in 0..len {
-                        self.stack.push(DeserializeTask::ListItem);
-                    }
-                    Ok(wip)
-                }
+            (Def::Set(_), _) => {
+                // A set is a counted array of its items
+                let len = self.next_len(bound)?;
+                wip.begin_set().map_err(|e| reflect_err(self.pos, e))?;
+                self.stack.push(DeserializeTask::SetItems(len));
+                Ok(wip)
+            }
+            (Def::Map(_), _) => {
+                let len = self.next_len(bound)?;
+                wip.begin_map().map_err(|e| reflect_err(self.pos, e))?;
+                self.stack.push(DeserializeTask::MapEntries(len));
+                Ok(wip)
             }
             (Def::Option(_), _) => match self.next_u32()? {
                 0 => {
-                    wip.set_default().unwrap();
+                    wip.set_default().map_err(|e| reflect_err(self.pos, e))?;
                     Ok(wip)
                 }
                 1 => {
                     self.stack.push(DeserializeTask::Pop(PopReason::Some));
-                    self.stack.push(DeserializeTask::Value);
-                    wip.select_variant(1).unwrap();
+                    self.stack.push(DeserializeTask::Value { bound });
+                    wip.begin_some().map_err(|e| reflect_err(self.pos, e))?;
                     Ok(wip)
                 }
                 _ => Err(XdrDeserError::InvalidOptional {
                     position: self.pos - 4,
                 }),
             },
+            (Def::Result(_), _) => {
+                match self.next_u32()? {
+                    0 => wip.begin_ok().map_err(|e| reflect_err(self.pos, e))?,
+                    1 => wip.begin_err().map_err(|e| reflect_err(self.pos, e))?,
+                    _ => {
+                        return Err(XdrDeserError::InvalidVariant {
+                            position: self.pos - 4,
+                        });
+                    }
+                };
+                self.stack
+                    .push(DeserializeTask::Pop(PopReason::ObjectOrListVal));
+                self.stack.push(DeserializeTask::Value { bound });
+                Ok(wip)
+            }
+            (Def::SmartPointer(_), _) => {
+                self.stack
+                    .push(DeserializeTask::Pop(PopReason::ObjectOrListVal));
+                self.stack.push(DeserializeTask::Value { bound });
+                wip.begin_smart_ptr()
+                    .map_err(|e| reflect_err(self.pos, e))?;
+                Ok(wip)
+            }
             (_, Type::User(ut)) => match ut {
                 UserType::Struct(st) => {
-                    if st.kind == StructKind::Tuple {
-                        // Handle tuple structs
-                        for _field in st.fields.iter() {
-                            self.stack.push(DeserializeTask::ListItem);
+                    for (index, field) in st.fields.iter().enumerate().rev() {
+                        if !wip
+                            .is_field_set(index)
+                            .map_err(|e| reflect_err(self.pos, e))?
+                        {
+                            self.stack.push(DeserializeTask::Field {
+                                index,
+                                bound: field_bound(field),
+                            });
                         }
-                        Ok(wip)
-                    } else {
-                        // Handle regular structs
-                        for (index, _field) in st.fields.iter().enumerate().rev() {
-                            if !wip.is_field_set(index).unwrap() {
-                                self.stack.push(DeserializeTask::Field(index));
-                            }
-                        }
-                        Ok(wip)
                     }
+                    Ok(wip)
                 }
                 UserType::Enum(et) => {
                     let discriminant = self.next_u32()?;
-                    if let Some(variant) = et
-                        .variants
-                        .iter()
-                        .find(|v| v.discriminant == Some(discriminant as i64))
-                        .or(et.variants.get(discriminant as usize))
+                    if let Some((variant_index, variant)) =
+                        variant_for_discriminant(et.variants, discriminant)
                     {
-                        for (index, _field) in variant.data.fields.iter().enumerate().rev() {
-                            self.stack.push(DeserializeTask::Field(index));
+                        for (index, field) in variant.data.fields.iter().enumerate().rev() {
+                            self.stack.push(DeserializeTask::Field {
+                                index,
+                                bound: field_bound(field),
+                            });
                         }
-                        wip.select_variant(discriminant as i64).unwrap();
+                        wip.select_nth_variant(variant_index)
+                            .map_err(|e| reflect_err(self.pos, e))?;
                         Ok(wip)
                     } else {
                         Err(XdrDeserError::InvalidVariant {
@@ -542,14 +864,22 @@ impl<'shape, 'input> XdrDeserializerStack<'input> {
 /// Deserialize an XDR slice given some some [`Partial`] into a [`HeapValue`]
 pub fn deserialize_wip<'facet, 'shape>(
     input: &[u8],
-    mut wip: Partial<'facet, 'shape>,
+    wip: Partial<'facet, 'shape>,
 ) -> Result<HeapValue<'facet, 'shape>, XdrDeserError> {
+    deserialize_wip_prefix(input, wip).map(|(value, _)| value)
+}
+
+/// Deserializes the value at the start of `input`, also returning how many bytes it took up
+fn deserialize_wip_prefix<'facet, 'shape>(
+    input: &[u8],
+    mut wip: Partial<'facet, 'shape>,
+) -> Result<(HeapValue<'facet, 'shape>, usize), XdrDeserError> {
     let mut runner = XdrDeserializerStack {
         input,
         pos: 0,
         stack: vec![
             DeserializeTask::Pop(PopReason::TopLevel),
-            DeserializeTask::Value,
+            DeserializeTask::Value { bound: None },
         ],
     };
 
@@ -560,27 +890,81 @@ pub fn deserialize_wip<'facet, 'shape>(
         match runner.stack.pop() {
             Some(DeserializeTask::Pop(reason)) => {
                 if reason == PopReason::TopLevel {
-                    return Ok(wip.build().unwrap());
+                    return Ok((
+                        wip.build().map_err(|e| reflect_err(runner.pos, e))?,
+                        runner.pos,
+                    ));
                 } else {
-                    wip.end().unwrap();
+                    wip.end().map_err(|e| reflect_err(runner.pos, e))?;
+                }
+            }
+            Some(DeserializeTask::Value { bound }) => {
+                wip = runner.next(wip, bound)?;
+            }
+            Some(DeserializeTask::Field { index, bound }) => {
+                runner
+                    .stack
+                    .push(DeserializeTask::Pop(PopReason::ObjectOrListVal));
+                wip.begin_nth_field(index)
+                    .map_err(|e| reflect_err(runner.pos, e))?;
+                // Fields with `deserialize_with` or `proxy` are read as the shape they're
+                // converted from
+                if wip.deserialize_with_shape().is_some() {
+                    runner
+                        .stack
+                        .push(DeserializeTask::Pop(PopReason::ObjectOrListVal));
+                    wip.begin_deserialize_with()
+                        .map_err(|e| reflect_err(runner.pos, e))?;
                 }
+                runner.stack.push(DeserializeTask::Value { bound });
             }
-            Some(DeserializeTask::Value) => {
-                wip = runner.next(wip)?;
+            // Counted items are read one at a time, so a huge length read from the input
+            // can't make us queue up tasks for data that isn't there
+            Some(DeserializeTask::ListItems(0) | DeserializeTask::SetItems(0)) => {}
+            Some(DeserializeTask::ListItems(remaining)) => {
+                runner.stack.push(DeserializeTask::ListItems(remaining - 1));
+                runner
+                    .stack
+                    .push(DeserializeTask::Pop(PopReason::ObjectOrListVal));
+                runner.stack.push(DeserializeTask::Value { bound: None });
+                wip.begin_list_item()
+                    .map_err(|e| reflect_err(runner.pos, e))?;
             }
-            Some(DeserializeTask::Field(index)) => {
+            Some(DeserializeTask::SetItems(remaining)) => {
+                runner.stack.push(DeserializeTask::SetItems(remaining - 1));
                 runner
                     .stack
                     .push(DeserializeTask::Pop(PopReason::ObjectOrListVal));
-                runner.stack.push(DeserializeTask::Value);
-                wip.begin_nth_field(index).unwrap();
+                runner.stack.push(DeserializeTask::Value { bound: None });
+                wip.begin_set_item()
+                    .map_err(|e| reflect_err(runner.pos, e))?;
             }
-            Some(DeserializeTask::ListItem) => {
+            Some(DeserializeTask::ArrayElement(index)) => {
                 runner
                     .stack
                     .push(DeserializeTask::Pop(PopReason::ObjectOrListVal));
-                runner.stack.push(DeserializeTask::Value);
-                wip.begin_list_item().unwrap();
+                runner.stack.push(DeserializeTask::Value { bound: None });
+                wip.begin_nth_element(index)
+                    .map_err(|e| reflect_err(runner.pos, e))?;
+            }
+            Some(DeserializeTask::MapEntries(0)) => {}
+            Some(DeserializeTask::MapEntries(remaining)) => {
+                runner
+                    .stack
+                    .push(DeserializeTask::MapEntries(remaining - 1));
+                runner.stack.push(DeserializeTask::MapValue);
+                runner
+                    .stack
+                    .push(DeserializeTask::Pop(PopReason::ObjectOrListVal));
+                runner.stack.push(DeserializeTask::Value { bound: None });
+                wip.begin_key().map_err(|e| reflect_err(runner.pos, e))?;
+            }
+            Some(DeserializeTask::MapValue) => {
+                runner
+                    .stack
+                    .push(DeserializeTask::Pop(PopReason::ObjectOrListVal));
+                runner.stack.push(DeserializeTask::Value { bound: None });
+                wip.begin_value().map_err(|e| reflect_err(runner.pos, e))?;
             }
             None => unreachable!("Instruction stack is empty"),
         }
@@ -589,7 +973,21 @@ pub fn deserialize_wip<'facet, 'shape>(
 
 /// Deserialize a slice of XDR bytes into any Facet type
 pub fn deserialize<'f, F: facet_core::Facet<'f>>(input: &[u8]) -> Result<F, XdrDeserError> {
-    let v = deserialize_wip(input, Partial::alloc_shape(F::SHAPE).unwrap())?;
-    let f: F = v.materialize().unwrap();
-    Ok(f)
+    let wip = Partial::alloc_shape(F::SHAPE).map_err(|e| reflect_err(0, e))?;
+    let v = deserialize_wip(input, wip)?;
+    v.materialize().map_err(|e| reflect_err(input.len(), e))
+}
+
+/// Deserialize the XDR value at the start of `input` into any Facet type, returning it along
+/// with the bytes that follow it
+///
+/// Useful when a message is made of several XDR values back to back, like a header followed
+/// by a body whose type depends on the header.
+pub fn deserialize_prefix<'input, 'f, F: facet_core::Facet<'f>>(
+    input: &'input [u8],
+) -> Result<(F, &'input [u8]), XdrDeserError> {
+    let wip = Partial::alloc_shape(F::SHAPE).map_err(|e| reflect_err(0, e))?;
+    let (v, len) = deserialize_wip_prefix(input, wip)?;
+    let f: F = v.materialize().map_err(|e| reflect_err(len, e))?;
+    Ok((f, &input[len..]))
 }
@@ -0,0 +1,442 @@
+//! ONC-RPC ([RFC 5531](https://www.rfc-editor.org/rfc/rfc5531)) message envelopes and
+//! record-marking stream framing.
+//!
+//! An RPC message is an [`RpcMessage`] header immediately followed by the XDR encoding of the
+//! procedure's arguments (for calls) or results (for successful replies). Over stream
+//! transports like TCP, each message is sent as one record, split into fragments by
+//! [`RecordWriter`] and reassembled by [`RecordReader`].
+
+use std::io::{self, Read, Write};
+
+use facet::Facet;
+
+use crate::{XdrDeserError, XdrSerError, deserialize_prefix};
+
+/// Version of the RPC protocol described by RFC 5531
+pub const RPC_VERSION: u32 = 2;
+
+/// `AUTH_NONE` authentication flavor
+pub const AUTH_NONE: u32 = 0;
+
+/// Authentication credentials or verifier (`opaque_auth`)
+#[derive(Debug, Clone, PartialEq, Eq, Facet)]
+pub struct OpaqueAuth {
+    /// Authentication flavor, e.g. [`AUTH_NONE`]
+    pub flavor: u32,
+    /// Flavor-specific data
+    #[facet(xdr_max = 400)]
+    pub body: Vec<u8>,
+}
+
+impl OpaqueAuth {
+    /// Empty `AUTH_NONE` credentials
+    pub fn none() -> Self {
+        Self {
+            flavor: AUTH_NONE,
+            body: Vec::new(),
+        }
+    }
+}
+
+/// RPC message header (`rpc_msg`)
+#[derive(Debug, Clone, PartialEq, Eq, Facet)]
+pub struct RpcMessage {
+    /// Transaction id, used to match replies to calls
+    pub xid: u32,
+    /// Call or reply
+    pub body: MessageBody,
+}
+
+impl RpcMessage {
+    /// Header for a call to procedure `proc` of program `prog`, version `vers`, without
+    /// authentication
+    pub fn call(xid: u32, prog: u32, vers: u32, proc: u32) -> Self {
+        Self {
+            xid,
+            body: MessageBody::Call(CallBody {
+                rpcvers: RPC_VERSION,
+                prog,
+                vers,
+                proc,
+                cred: OpaqueAuth::none(),
+                verf: OpaqueAuth::none(),
+            }),
+        }
+    }
+
+    /// Header for an accepted reply to call `xid`, without authentication
+    pub fn accepted(xid: u32, stat: AcceptStat) -> Self {
+        Self {
+            xid,
+            body: MessageBody::Reply(ReplyBody::Accepted(AcceptedReply {
+                verf: OpaqueAuth::none(),
+                stat,
+            })),
+        }
+    }
+
+    /// Header for a rejected reply to call `xid`
+    pub fn denied(xid: u32, reply: RejectedReply) -> Self {
+        Self {
+            xid,
+            body: MessageBody::Reply(ReplyBody::Denied(reply)),
+        }
+    }
+
+    /// Returns the call body if this is a call
+    pub fn as_call(&self) -> Option<&CallBody> {
+        match &self.body {
+            MessageBody::Call(call) => Some(call),
+            MessageBody::Reply(_) => None,
+        }
+    }
+
+    /// Returns the reply body if this is a reply
+    pub fn as_reply(&self) -> Option<&ReplyBody> {
+        match &self.body {
+            MessageBody::Call(_) => None,
+            MessageBody::Reply(reply) => Some(reply),
+        }
+    }
+
+    /// Whether this message is followed by a body: arguments for a call, results for a
+    /// successful reply
+    pub fn has_body(&self) -> bool {
+        matches!(
+            self.body,
+            MessageBody::Call(_)
+                | MessageBody::Reply(ReplyBody::Accepted(AcceptedReply {
+                    stat: AcceptStat::Success,
+                    ..
+                }))
+        )
+    }
+}
+
+/// Body of an RPC message, discriminated by `msg_type`
+#[derive(Debug, Clone, PartialEq, Eq, Facet)]
+#[repr(u32)]
+pub enum MessageBody {
+    /// `CALL`
+    Call(CallBody) = 0,
+    /// `REPLY`
+    Reply(ReplyBody) = 1,
+}
+
+/// Body of a call (`call_body`), followed on the wire by the procedure's arguments
+#[derive(Debug, Clone, PartialEq, Eq, Facet)]
+pub struct CallBody {
+    /// RPC protocol version, must be [`RPC_VERSION`]
+    pub rpcvers: u32,
+    /// Remote program
+    pub prog: u32,
+    /// Remote program version
+    pub vers: u32,
+    /// Procedure within the remote program
+    pub proc: u32,
+    /// Caller credentials
+    pub cred: OpaqueAuth,
+    /// Caller verifier
+    pub verf: OpaqueAuth,
+}
+
+/// Body of a reply (`reply_body`), discriminated by `reply_stat`
+#[derive(Debug, Clone, PartialEq, Eq, Facet)]
+#[repr(u32)]
+pub enum ReplyBody {
+    /// `MSG_ACCEPTED`
+    Accepted(AcceptedReply) = 0,
+    /// `MSG_DENIED`
+    Denied(RejectedReply) = 1,
+}
+
+/// Reply to a call that the server accepted (`accepted_reply`)
+#[derive(Debug, Clone, PartialEq, Eq, Facet)]
+pub struct AcceptedReply {
+    /// Server verifier
+    pub verf: OpaqueAuth,
+    /// Outcome of the call
+    pub stat: AcceptStat,
+}
+
+/// Outcome of an accepted call (`accept_stat`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Facet)]
+#[repr(u32)]
+pub enum AcceptStat {
+    /// RPC executed successfully, results follow
+    Success = 0,
+    /// Remote hasn't exported the program
+    ProgUnavail = 1,
+    /// Remote can't support the version number
+    ProgMismatch {
+        /// Lowest version supported
+        low: u32,
+        /// Highest version supported
+        high: u32,
+    } = 2,
+    /// Program can't support the procedure
+    ProcUnavail = 3,
+    /// Procedure can't decode the arguments
+    GarbageArgs = 4,
+    /// Memory allocation failure, etc.
+    SystemErr = 5,
+}
+
+/// Reply to a call that the server rejected (`rejected_reply`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Facet)]
+#[repr(u32)]
+pub enum RejectedReply {
+    /// RPC version number is not [`RPC_VERSION`]
+    RpcMismatch {
+        /// Lowest version supported
+        low: u32,
+        /// Highest version supported
+        high: u32,
+    } = 0,
+    /// Remote can't authenticate the caller
+    AuthError(AuthStat) = 1,
+}
+
+/// Why authentication failed (`auth_stat`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Facet)]
+#[repr(u32)]
+pub enum AuthStat {
+    /// Success
+    Ok = 0,
+    /// Bad credential (seal broken)
+    BadCred = 1,
+    /// Client must begin new session
+    RejectedCred = 2,
+    /// Bad verifier (seal broken)
+    BadVerf = 3,
+    /// Verifier expired or replayed
+    RejectedVerf = 4,
+    /// Rejected for security reasons
+    TooWeak = 5,
+    /// Bogus response verifier
+    InvalidResp = 6,
+    /// Reason unknown
+    Failed = 7,
+    /// Kerberos generic error
+    KerbGeneric = 8,
+    /// Time of credential expired
+    TimeExpire = 9,
+    /// Problem with ticket file
+    TktFile = 10,
+    /// Can't decode authenticator
+    Decode = 11,
+    /// Wrong net address in ticket
+    NetAddr = 12,
+    /// No credentials for user
+    RpcsecGssCredProblem = 13,
+    /// Problem with context
+    RpcsecGssCtxProblem = 14,
+}
+
+/// Serialize an RPC message: the header followed by its arguments or results
+///
+/// Use `&()` as the body for messages that don't carry one.
+pub fn to_vec<'f, F: Facet<'f>>(header: &RpcMessage, body: &'f F) -> Result<Vec<u8>, XdrSerError> {
+    let mut buffer = crate::to_vec(header)?;
+    buffer.extend(crate::to_vec(body)?);
+    Ok(buffer)
+}
+
+/// Deserialize the header of an RPC message, returning it along with the encoded body
+///
+/// The body's type usually depends on the header (the called procedure, or whether the call
+/// succeeded), so it is left for the caller to pass to [`crate::deserialize`].
+pub fn deserialize_header(input: &[u8]) -> Result<(RpcMessage, &[u8]), XdrDeserError> {
+    deserialize_prefix(input)
+}
+
+/// Errors when sending or receiving RPC messages
+#[derive(Debug)]
+pub enum RpcError {
+    /// IO error
+    Io(io::Error),
+    /// Error serializing a message
+    Ser(XdrSerError),
+    /// Error deserializing a message
+    Deser(XdrDeserError),
+}
+
+impl core::fmt::Display for RpcError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RpcError::Io(error) => write!(f, "IO error: {}", error),
+            RpcError::Ser(error) => write!(f, "Serialization error: {}", error),
+            RpcError::Deser(error) => write!(f, "Deserialization error: {}", error),
+        }
+    }
+}
+
+impl core::error::Error for RpcError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            RpcError::Io(error) => Some(error),
+            RpcError::Ser(error) => Some(error),
+            RpcError::Deser(error) => Some(error),
+        }
+    }
+}
+
+impl From<io::Error> for RpcError {
+    fn from(error: io::Error) -> Self {
+        RpcError::Io(error)
+    }
+}
+
+impl From<XdrSerError> for RpcError {
+    fn from(error: XdrSerError) -> Self {
+        RpcError::Ser(error)
+    }
+}
+
+impl From<XdrDeserError> for RpcError {
+    fn from(error: XdrDeserError) -> Self {
+        RpcError::Deser(error)
+    }
+}
+
+/// Set in a record-marking header on the last fragment of a record
+const LAST_FRAGMENT: u32 = 0x8000_0000;
+
+/// Largest fragment a record-marking header can describe
+const MAX_FRAGMENT_SIZE: usize = 0x7fff_ffff;
+
+/// Largest record a [`RecordReader`] accepts unless configured otherwise (4 MiB)
+pub const DEFAULT_MAX_RECORD_SIZE: usize = 4 * 1024 * 1024;
+
+/// Writes records to a stream, using record marking (RFC 5531, section 11)
+pub struct RecordWriter<W: Write> {
+    writer: W,
+    max_fragment_size: usize,
+}
+
+impl<W: Write> RecordWriter<W> {
+    /// Creates a new record writer, sending each record as a single fragment when possible
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            max_fragment_size: MAX_FRAGMENT_SIZE,
+        }
+    }
+
+    /// Splits records into fragments of at most `size` bytes
+    pub fn with_max_fragment_size(mut self, size: usize) -> Self {
+        self.max_fragment_size = size.clamp(1, MAX_FRAGMENT_SIZE);
+        self
+    }
+
+    /// Writes a record and flushes the underlying writer
+    pub fn write_record(&mut self, record: &[u8]) -> io::Result<()> {
+        let mut chunks = record.chunks(self.max_fragment_size).peekable();
+        if chunks.peek().is_none() {
+            self.writer.write_all(&LAST_FRAGMENT.to_be_bytes())?;
+        }
+        while let Some(chunk) = chunks.next() {
+            let mut header = chunk.len() as u32;
+            if chunks.peek().is_none() {
+                header |= LAST_FRAGMENT;
+            }
+            self.writer.write_all(&header.to_be_bytes())?;
+            self.writer.write_all(chunk)?;
+        }
+        self.writer.flush()
+    }
+
+    /// Serializes an RPC message and writes it as one record
+    pub fn write_message<'f, F: Facet<'f>>(
+        &mut self,
+        header: &RpcMessage,
+        body: &'f F,
+    ) -> Result<(), RpcError> {
+        let record = to_vec(header, body)?;
+        self.write_record(&record)?;
+        Ok(())
+    }
+
+    /// Returns the underlying writer
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Reads records from a stream, using record marking (RFC 5531, section 11)
+pub struct RecordReader<R: Read> {
+    reader: R,
+    max_record_size: usize,
+}
+
+impl<R: Read> RecordReader<R> {
+    /// Creates a new record reader, accepting records of up to [`DEFAULT_MAX_RECORD_SIZE`] bytes
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            max_record_size: DEFAULT_MAX_RECORD_SIZE,
+        }
+    }
+
+    /// Fails with [`io::ErrorKind::InvalidData`] on records larger than `size` bytes, instead
+    /// of buffering whatever length the peer announces
+    pub fn with_max_record_size(mut self, size: usize) -> Self {
+        self.max_record_size = size;
+        self
+    }
+
+    /// Reads the next record, or returns `None` if the stream ended cleanly before it
+    pub fn read_record(&mut self) -> io::Result<Option<Vec<u8>>> {
+        let mut record = Vec::new();
+        let mut first = true;
+        loop {
+            let mut header = [0u8; 4];
+            if first {
+                // Tell a clean end of stream apart from one in the middle of a record
+                let n = self.reader.read(&mut header)?;
+                if n == 0 {
+                    return Ok(None);
+                }
+                self.reader.read_exact(&mut header[n..])?;
+                first = false;
+            } else {
+                self.reader.read_exact(&mut header)?;
+            }
+
+            let header = u32::from_be_bytes(header);
+            let len = (header & !LAST_FRAGMENT) as usize;
+            if record.len() + len > self.max_record_size {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "record exceeds maximum size of {} bytes",
+                        self.max_record_size
+                    ),
+                ));
+            }
+
+            let start = record.len();
+            record.resize(start + len, 0);
+            self.reader.read_exact(&mut record[start..])?;
+
+            if header & LAST_FRAGMENT != 0 {
+                return Ok(Some(record));
+            }
+        }
+    }
+
+    /// Reads the next record as an RPC message, returning its header and encoded body, or
+    /// `None` if the stream ended cleanly
+    pub fn read_message(&mut self) -> Result<Option<(RpcMessage, Vec<u8>)>, RpcError> {
+        let Some(record) = self.read_record()? else {
+            return Ok(None);
+        };
+        let (header, body) = deserialize_header(&record)?;
+        Ok(Some((header, body.to_vec())))
+    }
+
+    /// Returns the underlying reader
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
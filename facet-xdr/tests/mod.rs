@@ -1,6 +1,8 @@
+use std::collections::BTreeMap;
+
 use facet::Facet;
 use facet_testhelpers::test;
-use facet_xdr::{deserialize, to_vec};
+use facet_xdr::{XdrDeserError, XdrSerError, deserialize, to_vec};
 
 const FILE_EXAMPLE_BYTES: [u8; 48] = [
     0x00, 0x00, 0x00, 0x09, 0x73, 0x69, 0x6c, 0x6c, 0x79, 0x70, 0x72, 0x6f, 0x67, 0x00, 0x00, 0x00,
@@ -47,3 +49,234 @@ fn test_deserialize_file_example() {
     let file: File = deserialize(&FILE_EXAMPLE_BYTES)?;
     assert_eq!(file, file_example());
 }
+
+#[allow(unused)]
+#[derive(Debug, Facet, PartialEq)]
+#[repr(i32)]
+enum Status {
+    Ok(u32) = 0,
+    Moved { to: String } = 3,
+    Failed(i32, bool) = -1,
+    Unknown = 7,
+}
+
+#[test]
+fn test_union_round_trip() {
+    let cases: [(Status, &[u8]); 4] = [
+        (Status::Ok(42), &[0, 0, 0, 0, 0, 0, 0, 42]),
+        (
+            Status::Moved {
+                to: "ab".to_owned(),
+            },
+            &[0, 0, 0, 3, 0, 0, 0, 2, b'a', b'b', 0, 0],
+        ),
+        (
+            Status::Failed(-5, true),
+            &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfb, 0, 0, 0, 1],
+        ),
+        (Status::Unknown, &[0, 0, 0, 7]),
+    ];
+    for (status, bytes) in cases {
+        assert_eq!(&to_vec(&status)?[..], bytes);
+        assert_eq!(deserialize::<Status>(bytes)?, status);
+    }
+}
+
+#[test]
+fn test_union_unknown_discriminant() {
+    let result = deserialize::<Status>(&[0, 0, 0, 2]);
+    assert!(matches!(
+        result,
+        Err(XdrDeserError::InvalidVariant { position: 0 })
+    ));
+}
+
+#[test]
+fn test_huge_length_without_data() {
+    // The length prefix claims `u32::MAX` items, but none follow
+    let result = deserialize::<Vec<u32>>(&[0xff, 0xff, 0xff, 0xff]);
+    assert!(matches!(result, Err(XdrDeserError::UnexpectedEof)));
+    let result = deserialize::<BTreeMap<u32, u32>>(&[0xff, 0xff, 0xff, 0xff, 0, 0, 0, 1]);
+    assert!(matches!(result, Err(XdrDeserError::UnexpectedEof)));
+}
+
+#[derive(Debug, Facet, PartialEq)]
+struct Entry {
+    #[facet(xdr_max = 4)]
+    name: String,
+    hash: [u8; 5],
+    attrs: BTreeMap<String, u32>,
+}
+
+const ENTRY_BYTES: [u8; 32] = [
+    0x00, 0x00, 0x00, 0x02, b'a', b'b', 0x00, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, b'k', 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x09,
+];
+
+fn entry_example() -> Entry {
+    Entry {
+        name: "ab".to_owned(),
+        hash: [1, 2, 3, 4, 5],
+        attrs: BTreeMap::from([("k".to_owned(), 9)]),
+    }
+}
+
+#[test]
+fn test_serialize_map_and_fixed_opaque() {
+    assert_eq!(&to_vec(&entry_example())?[..], ENTRY_BYTES);
+}
+
+#[test]
+fn test_deserialize_map_and_fixed_opaque() {
+    let entry: Entry = deserialize(&ENTRY_BYTES)?;
+    assert_eq!(entry, entry_example());
+}
+
+#[test]
+fn test_bound_exceeded() {
+    let mut entry = entry_example();
+    entry.name = "abcde".to_owned();
+    assert!(matches!(
+        to_vec(&entry),
+        Err(XdrSerError::BoundExceeded { bound: 4, len: 5 })
+    ));
+
+    let mut bytes = ENTRY_BYTES.to_vec();
+    bytes[3] = 5;
+    assert!(matches!(
+        deserialize::<Entry>(&bytes),
+        Err(XdrDeserError::BoundExceeded {
+            position: 0,
+            bound: 4,
+            len: 5
+        })
+    ));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_platform_strings_and_socket_addrs() {
+    use std::ffi::{CString, OsString};
+    use std::net::{SocketAddrV4, SocketAddrV6};
+    use std::os::unix::ffi::OsStringExt;
+
+    #[derive(Facet, Debug, PartialEq)]
+    struct Endpoint {
+        path: OsString,
+        name: CString,
+        v4: SocketAddrV4,
+        v6: SocketAddrV6,
+    }
+
+    let endpoint = Endpoint {
+        path: OsString::from_vec(vec![b'a', 0xff]),
+        name: CString::new("sock").unwrap(),
+        v4: "10.0.0.1:80".parse().unwrap(),
+        v6: "[::1]:443".parse().unwrap(),
+    };
+    let bytes = to_vec(&endpoint)?;
+    // Platform strings are written as is, even when they aren't valid UTF-8
+    assert_eq!(
+        bytes[..16],
+        [
+            0, 0, 0, 2, b'a', 0xff, 0, 0, 0, 0, 0, 4, b's', b'o', b'c', b'k'
+        ]
+    );
+    assert_eq!(deserialize::<Endpoint>(&bytes)?, endpoint);
+
+    // C strings can't hold NUL bytes
+    assert!(matches!(
+        deserialize::<std::ffi::CString>(&[0, 0, 0, 3, b'a', 0, b'b', 0]),
+        Err(XdrDeserError::InvalidValue { position: 0 })
+    ));
+}
+
+#[test]
+fn test_atomics_and_wrappers() {
+    use std::cmp::Reverse;
+    use std::num::Wrapping;
+    use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+    #[derive(Facet, Debug)]
+    struct Snapshot {
+        hits: AtomicU64,
+        ready: AtomicBool,
+        hash: Wrapping<u32>,
+        rank: Reverse<i32>,
+    }
+
+    let snapshot = Snapshot {
+        hits: AtomicU64::new(7),
+        ready: AtomicBool::new(true),
+        hash: Wrapping(5),
+        rank: Reverse(-1),
+    };
+    let bytes = to_vec(&snapshot)?;
+    assert_eq!(
+        bytes,
+        [
+            0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 1, 0, 0, 0, 5, 0xff, 0xff, 0xff, 0xff
+        ]
+    );
+    let back = deserialize::<Snapshot>(&bytes)?;
+    assert_eq!(back.hits.load(Ordering::SeqCst), 7);
+    assert!(back.ready.load(Ordering::SeqCst));
+    assert_eq!(back.hash, Wrapping(5));
+    assert_eq!(back.rank, Reverse(-1));
+}
+
+#[test]
+fn test_sets() {
+    use std::collections::BTreeSet;
+
+    let set = BTreeSet::from([3u32, 1, 2]);
+    let bytes = to_vec(&set)?;
+    assert_eq!(bytes, [0, 0, 0, 3, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 3]);
+    assert_eq!(deserialize::<BTreeSet<u32>>(&bytes)?, set);
+}
+
+#[test]
+fn test_serialize_with_and_proxy() {
+    /// A `u32` written as a decimal string
+    #[derive(Debug, Facet)]
+    #[facet(transparent)]
+    struct Decimal(String);
+
+    impl From<&u32> for Decimal {
+        fn from(value: &u32) -> Self {
+            Decimal(value.to_string())
+        }
+    }
+
+    impl TryFrom<Decimal> for u32 {
+        type Error = std::num::ParseIntError;
+
+        fn try_from(decimal: Decimal) -> Result<Self, Self::Error> {
+            decimal.0.parse()
+        }
+    }
+
+    fn double(value: &u32) -> u32 {
+        value * 2
+    }
+
+    fn halve(value: u32) -> Result<u32, &'static str> {
+        Ok(value / 2)
+    }
+
+    #[derive(Debug, PartialEq, Facet)]
+    struct Counter {
+        #[facet(proxy = Decimal)]
+        count: u32,
+        #[facet(serialize_with = double, deserialize_with = halve)]
+        doubled: u32,
+    }
+
+    let counter = Counter {
+        count: 42,
+        doubled: 5,
+    };
+    let bytes = to_vec(&counter)?;
+    assert_eq!(bytes, [0, 0, 0, 2, b'4', b'2', 0, 0, 0, 0, 0, 10]);
+    assert_eq!(deserialize::<Counter>(&bytes)?, counter);
+}
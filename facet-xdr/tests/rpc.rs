@@ -0,0 +1,158 @@
+#![cfg(feature = "rpc")]
+
+use std::io::Cursor;
+use std::net::{TcpListener, TcpStream};
+
+use facet::Facet;
+use facet_testhelpers::test;
+use facet_xdr::deserialize;
+use facet_xdr::rpc::{
+    AcceptStat, AuthStat, MessageBody, RecordReader, RecordWriter, RejectedReply, ReplyBody,
+    RpcMessage, deserialize_header, to_vec,
+};
+
+const ADD_PROG: u32 = 0x2000_0001;
+const ADD_VERS: u32 = 1;
+const ADD_PROC: u32 = 1;
+
+#[derive(Debug, Facet, PartialEq)]
+struct AddArgs {
+    a: i32,
+    b: i32,
+}
+
+#[test]
+fn test_call_encoding() {
+    let bytes = to_vec(
+        &RpcMessage::call(7, ADD_PROG, ADD_VERS, ADD_PROC),
+        &AddArgs { a: 2, b: -3 },
+    )?;
+    assert_eq!(
+        bytes,
+        [
+            0, 0, 0, 7, // xid
+            0, 0, 0, 0, // CALL
+            0, 0, 0, 2, // rpcvers
+            0x20, 0, 0, 1, // prog
+            0, 0, 0, 1, // vers
+            0, 0, 0, 1, // proc
+            0, 0, 0, 0, 0, 0, 0, 0, // cred: AUTH_NONE, empty body
+            0, 0, 0, 0, 0, 0, 0, 0, // verf: AUTH_NONE, empty body
+            0, 0, 0, 2, // a
+            0xff, 0xff, 0xff, 0xfd, // b
+        ]
+    );
+
+    let (header, body) = deserialize_header(&bytes)?;
+    assert_eq!(header, RpcMessage::call(7, ADD_PROG, ADD_VERS, ADD_PROC));
+    assert_eq!(deserialize::<AddArgs>(body)?, AddArgs { a: 2, b: -3 });
+}
+
+#[test]
+fn test_reply_round_trip() {
+    let replies = [
+        RpcMessage::accepted(1, AcceptStat::Success),
+        RpcMessage::accepted(2, AcceptStat::ProgMismatch { low: 1, high: 3 }),
+        RpcMessage::accepted(3, AcceptStat::GarbageArgs),
+        RpcMessage::denied(4, RejectedReply::RpcMismatch { low: 2, high: 2 }),
+        RpcMessage::denied(5, RejectedReply::AuthError(AuthStat::TooWeak)),
+    ];
+    for reply in replies {
+        let bytes = to_vec(&reply, &())?;
+        let (header, body) = deserialize_header(&bytes)?;
+        assert_eq!(header, reply);
+        assert!(body.is_empty());
+    }
+}
+
+#[test]
+fn test_record_fragments() {
+    let mut writer = RecordWriter::new(Vec::new()).with_max_fragment_size(4);
+    writer.write_record(&[1, 2, 3, 4, 5, 6])?;
+    writer.write_record(&[])?;
+    let stream = writer.into_inner();
+    assert_eq!(
+        stream,
+        [
+            0x00, 0, 0, 4, 1, 2, 3, 4, // first fragment
+            0x80, 0, 0, 2, 5, 6, // last fragment
+            0x80, 0, 0, 0, // empty record
+        ]
+    );
+
+    let mut reader = RecordReader::new(Cursor::new(&stream));
+    assert_eq!(reader.read_record()?, Some(vec![1, 2, 3, 4, 5, 6]));
+    assert_eq!(reader.read_record()?, Some(vec![]));
+    assert_eq!(reader.read_record()?, None);
+
+    let mut reader = RecordReader::new(Cursor::new(&stream[..10]));
+    let err = reader.read_record().unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+
+    let mut reader = RecordReader::new(Cursor::new(&stream)).with_max_record_size(5);
+    let err = reader.read_record().unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+    // A peer announcing a huge fragment is rejected by default, before anything is buffered
+    let mut reader = RecordReader::new(Cursor::new([0xff, 0xff, 0xff, 0xff]));
+    let err = reader.read_record().unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_tcp_loopback() {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let addr = listener.local_addr()?;
+
+    let server = std::thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = RecordReader::new(stream.try_clone().unwrap());
+        let mut writer = RecordWriter::new(stream).with_max_fragment_size(8);
+        while let Some((header, body)) = reader.read_message().unwrap() {
+            let MessageBody::Call(call) = &header.body else {
+                panic!("expected a call, got {:?}", header);
+            };
+            if call.proc != ADD_PROC {
+                writer
+                    .write_message(
+                        &RpcMessage::accepted(header.xid, AcceptStat::ProcUnavail),
+                        &(),
+                    )
+                    .unwrap();
+                continue;
+            }
+            let args: AddArgs = deserialize(&body).unwrap();
+            writer
+                .write_message(
+                    &RpcMessage::accepted(header.xid, AcceptStat::Success),
+                    &(args.a + args.b),
+                )
+                .unwrap();
+        }
+    });
+
+    let stream = TcpStream::connect(addr)?;
+    let mut reader = RecordReader::new(stream.try_clone()?);
+    let mut writer = RecordWriter::new(stream);
+
+    writer.write_message(
+        &RpcMessage::call(1, ADD_PROG, ADD_VERS, ADD_PROC),
+        &AddArgs { a: 40, b: 2 },
+    )?;
+    let (header, body) = reader.read_message()?.unwrap();
+    assert_eq!(header.xid, 1);
+    assert!(header.has_body());
+    assert_eq!(deserialize::<i32>(&body)?, 42);
+
+    writer.write_message(&RpcMessage::call(2, ADD_PROG, ADD_VERS, 9), &())?;
+    let (header, _) = reader.read_message()?.unwrap();
+    assert_eq!(header.xid, 2);
+    assert!(matches!(
+        header.as_reply(),
+        Some(ReplyBody::Accepted(reply)) if reply.stat == AcceptStat::ProcUnavail
+    ));
+
+    drop(writer);
+    drop(reader);
+    server.join().unwrap();
+}
@@ -85,6 +85,15 @@ fn deserialize_value<'facet, 'shape>(
         log::debug!("YAML value: {:?}", value);
     }
 
+    // Fields with `deserialize_with` or `proxy` are read as the shape they're converted from
+    if wip.deserialize_with_shape().is_some() {
+        wip.begin_deserialize_with()
+            .map_err(|e| AnyErr(e.to_string()))?;
+        deserialize_value(wip, value)?;
+        wip.end().map_err(|e| AnyErr(e.to_string()))?;
+        return Ok(());
+    }
+
     // Handle transparent types - check if shape has the transparent attribute
     if shape
         .attributes
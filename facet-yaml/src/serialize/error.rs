@@ -14,6 +14,11 @@ pub enum YamlSerError {
     },
     /// YAML doesn't support byte arrays.
     UnsupportedByteArray,
+    /// Could not read the value being serialized.
+    Reflect {
+        /// What went wrong.
+        message: alloc::string::String,
+    },
 }
 
 impl core::fmt::Display for YamlSerError {
@@ -28,6 +33,7 @@ impl core::fmt::Display for YamlSerError {
             Self::UnsupportedByteArray => {
                 write!(f, "YAML doesn't support byte arrays")
             }
+            Self::Reflect { message } => write!(f, "{message}"),
         }
     }
 }
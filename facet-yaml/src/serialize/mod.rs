@@ -14,6 +14,7 @@ use alloc::{
 };
 
 pub use error::YamlSerError;
+pub use facet_serialize::{REDACTED, Redaction, SerializeOptions};
 use facet_serialize::{Serialize, Serializer};
 use yaml_rust2::{
     Yaml, YamlEmitter,
@@ -168,6 +169,15 @@ impl<'shape> Default for YamlSerializer<'shape> {
 impl<'shape> Serializer<'shape> for YamlSerializer<'shape> {
     type Error = YamlSerError;
 
+    fn serialize_unreadable(
+        &mut self,
+        error: facet_reflect::ReflectError<'shape>,
+    ) -> Result<(), Self::Error> {
+        Err(YamlSerError::Reflect {
+            message: error.to_plain_string(),
+        })
+    }
+
     fn serialize_u64(&mut self, value: u64) -> Result<(), Self::Error> {
         let yaml_number = TryInto::<i64>::try_into(value)
             .map_err(|_| YamlSerError::InvalidNumberToI64Conversion { source_type: "u64" })?;
@@ -299,6 +309,22 @@ pub fn to_string<'a, T: facet_core::Facet<'a>>(value: &'a T) -> Result<String, Y
     Ok(serializer.into_string())
 }
 
+/// Serialize any `Facet` type to a YAML string, as configured by `options`.
+#[cfg(feature = "alloc")]
+pub fn to_string_with_options<'a, T: facet_core::Facet<'a>>(
+    value: &'a T,
+    options: &SerializeOptions,
+) -> Result<String, YamlSerError> {
+    let mut serializer = YamlSerializer::new();
+    facet_serialize::serialize_iterative_with_options(
+        facet_reflect::Peek::new(value),
+        &mut serializer,
+        options,
+    )?;
+
+    Ok(serializer.into_string())
+}
+
 /// Static type name for a YAML type.
 fn type_name(yaml: &Yaml) -> &'static str {
     match yaml {
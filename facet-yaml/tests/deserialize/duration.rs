@@ -0,0 +1,40 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use facet::Facet;
+use facet_testhelpers::test;
+
+#[derive(Facet, Debug, PartialEq)]
+struct Schedule {
+    every: Duration,
+    #[facet(proxy = facet::Seconds)]
+    grace: Duration,
+    since: SystemTime,
+}
+
+#[test]
+fn test_deserialize_duration() {
+    let yaml = r#"
+every: 1h30m
+grace: 2.5
+since: 2024-01-15T12:30:00Z
+"#;
+    assert_eq!(
+        facet_yaml::from_str::<Schedule>(yaml)?,
+        Schedule {
+            every: Duration::from_secs(5400),
+            grace: Duration::from_millis(2500),
+            since: UNIX_EPOCH + Duration::from_secs(1_705_321_800),
+        }
+    );
+}
+
+#[test]
+fn test_duration_round_trip() {
+    let schedule = Schedule {
+        every: Duration::from_millis(250),
+        grace: Duration::from_secs(30),
+        since: UNIX_EPOCH,
+    };
+    let yaml = facet_yaml::to_string(&schedule)?;
+    assert_eq!(facet_yaml::from_str::<Schedule>(&yaml)?, schedule);
+}
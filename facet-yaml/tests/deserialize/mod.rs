@@ -1,5 +1,6 @@
 mod datetime;
 mod default;
+mod duration;
 mod from_str;
 mod ip_test;
 mod lists;
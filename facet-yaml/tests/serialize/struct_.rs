@@ -190,3 +190,42 @@ fn test_optional_default_struct_fields() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_redact_sensitive() -> Result<()> {
+    facet_testhelpers::setup();
+
+    #[derive(Debug, Facet, PartialEq)]
+    struct Root {
+        name: String,
+        #[facet(sensitive)]
+        password: String,
+    }
+
+    let root = Root {
+        name: "prod".to_string(),
+        password: "hunter2".to_string(),
+    };
+
+    let redacted = facet_yaml::to_string_with_options(
+        &root,
+        &facet_yaml::SerializeOptions::new().redact_sensitive(),
+    )?;
+    let deserialized: Root = facet_yaml::from_str(&redacted).map_err(|err| eyre::eyre!("{err}"))?;
+    assert_eq!(
+        deserialized,
+        Root {
+            name: "prod".to_string(),
+            password: facet_yaml::REDACTED.to_string(),
+        }
+    );
+
+    let omitted = facet_yaml::to_string_with_options(
+        &root,
+        &facet_yaml::SerializeOptions::new().omit_sensitive(),
+    )?;
+    assert!(!omitted.contains("password"));
+    assert!(!omitted.contains("hunter2"));
+
+    Ok(())
+}
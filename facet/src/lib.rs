@@ -78,6 +78,10 @@ pub use facet_core::*;
 ///
 /// * `skip_serializing_if = ".."` Ignore when serializing if the function returns `true`.
 ///
+/// * `min = ..`, `max = ..`, `min_len = ..`, `max_len = ..`, `non_empty` Constrain the value, checked when deserializing.
+///
+/// * `pattern = ".."` Require strings to match a regular expression, checked when deserializing. Needs the `regex` feature of `facet` or of the format crate.
+///
 /// # Variant Attributes
 ///
 /// ```rust
@@ -37,6 +37,34 @@ fn function_with_string_parameter() {
     assert_eq!(shape.param_names, &["name"]);
 }
 
+#[cfg(feature = "function")]
+#[test]
+fn function_with_str_parameter() {
+    use facet::Facet;
+    use facet_reflect::{Partial, call_function};
+
+    #[facet_fn(dynamic)]
+    fn shout(word: &str) -> String {
+        word.to_uppercase()
+    }
+
+    // Test function works normally, with a borrowed argument
+    let word = String::from("hello");
+    assert_eq!(shout(&word), "HELLO");
+
+    // Test shape metadata
+    let shape = fn_shape!(shout);
+    assert_eq!(shape.name, "shout");
+    assert_eq!(shape.param_names, &["word"]);
+    assert_eq!(shape.param_shapes, &[<&str>::SHAPE]);
+
+    // Dynamic calls take `'static` arguments
+    let mut args = Partial::alloc_shape(shape.args_shape).unwrap();
+    args.set_nth_field(0, "world").unwrap();
+    let ret = call_function(&shape, args.build().unwrap()).unwrap();
+    assert_eq!(ret.materialize::<String>().unwrap(), "WORLD");
+}
+
 #[cfg(feature = "function")]
 #[test]
 fn function_with_no_parameters() {
@@ -319,3 +347,137 @@ fn function_without_doc_comments() {
     // Test no documentation is captured
     assert!(shape.documentation.is_empty());
 }
+
+#[cfg(feature = "function")]
+#[test]
+fn function_param_and_return_shapes() {
+    use facet::Facet;
+
+    #[facet_fn(dynamic)]
+    fn repeat(word: String, times: u32) -> Vec<String> {
+        vec![word; times as usize]
+    }
+
+    let shape = fn_shape!(repeat);
+    assert_eq!(shape.param_shapes.len(), 2);
+    assert_eq!(shape.param_shapes[0], String::SHAPE);
+    assert_eq!(shape.param_shapes[1], u32::SHAPE);
+    assert_eq!(shape.args_shape, <(String, u32)>::SHAPE);
+    assert_eq!(shape.return_shape, <Vec<String>>::SHAPE);
+
+    #[facet_fn(dynamic)]
+    fn nothing() {}
+
+    let shape = fn_shape!(nothing);
+    assert!(shape.param_shapes.is_empty());
+    assert_eq!(shape.args_shape, <()>::SHAPE);
+    assert_eq!(shape.return_shape, <()>::SHAPE);
+
+    let shape = fn_shape!(generic_identity<u64>);
+    assert_eq!(shape.param_shapes, &[u64::SHAPE]);
+    assert_eq!(shape.return_shape, u64::SHAPE);
+}
+
+#[cfg(feature = "function")]
+#[facet_fn(dynamic)]
+fn generic_identity<T>(value: T) -> T {
+    value
+}
+
+#[cfg(feature = "function")]
+#[test]
+fn function_dynamic_call() {
+    use facet_reflect::{Partial, call_function};
+
+    #[facet_fn(dynamic)]
+    fn greet(greeting: String, name: String, times: u8) -> String {
+        format!("{greeting}, {name}{}", "!".repeat(times as usize))
+    }
+
+    let shape = fn_shape!(greet);
+    let mut args = Partial::alloc_shape(shape.args_shape).unwrap();
+    args.set_nth_field(0, String::from("Hello"))
+        .unwrap()
+        .set_nth_field(1, String::from("world"))
+        .unwrap()
+        .set_nth_field(2, 3u8)
+        .unwrap();
+    let ret = call_function(&shape, args.build().unwrap()).unwrap();
+    assert_eq!(ret.materialize::<String>().unwrap(), "Hello, world!!!");
+
+    let shape = fn_shape!(generic_identity<Vec<i32>>);
+    let mut args = Partial::alloc_shape(shape.args_shape).unwrap();
+    args.set_nth_field(0, vec![1, 2, 3]).unwrap();
+    let ret = call_function(&shape, args.build().unwrap()).unwrap();
+    assert_eq!(ret.materialize::<Vec<i32>>().unwrap(), [1, 2, 3]);
+}
+
+#[cfg(feature = "function")]
+#[test]
+fn function_dynamic_call_checks_args_shape() {
+    use facet::Facet;
+    use facet_reflect::{Partial, ReflectError, call_function};
+
+    #[facet_fn(dynamic)]
+    fn double(x: i32) -> i32 {
+        x * 2
+    }
+
+    let shape = fn_shape!(double);
+    let mut args = Partial::alloc_shape(<(i64,)>::SHAPE).unwrap();
+    args.set_nth_field(0, 21i64).unwrap();
+    let err = call_function(&shape, args.build().unwrap()).unwrap_err();
+    assert!(matches!(err, ReflectError::WrongShape { .. }));
+}
+
+#[cfg(feature = "function")]
+pub struct Celsius(f64);
+
+#[cfg(feature = "function")]
+pub struct Fahrenheit(f64);
+
+#[cfg(feature = "function")]
+#[test]
+fn function_with_non_facet_types() {
+    // Without `dynamic`, parameter and return types don't need to implement `Facet`
+    #[facet_fn]
+    fn to_fahrenheit(celsius: Celsius) -> Fahrenheit {
+        Fahrenheit(celsius.0 * 9.0 / 5.0 + 32.0)
+    }
+
+    assert_eq!(to_fahrenheit(Celsius(100.0)).0, 212.0);
+
+    let shape = fn_shape!(to_fahrenheit);
+    assert_eq!(shape.name, "to_fahrenheit");
+    assert_eq!(shape.param_count, 1);
+    assert_eq!(shape.param_names, &["celsius"]);
+}
+
+#[cfg(feature = "function")]
+#[test]
+fn function_with_elided_lifetimes() {
+    use facet::Facet;
+    use std::borrow::Cow;
+
+    #[facet_fn]
+    fn first_word(text: &str) -> &str {
+        text.split(' ').next().unwrap_or_default()
+    }
+
+    let text = String::from("hello world");
+    assert_eq!(first_word(&text), "hello");
+    assert_eq!(fn_shape!(first_word).param_names, &["text"]);
+
+    // The function is moved into a module, where `Cow` isn't imported
+    #[facet_fn(dynamic)]
+    fn longest(a: &str, b: std::borrow::Cow<'_, str>) -> usize {
+        a.len().max(b.len())
+    }
+
+    let b = String::from("four");
+    assert_eq!(longest("abc", Cow::Borrowed(&b)), 4);
+
+    let shape = fn_shape!(longest);
+    assert_eq!(shape.param_shapes, &[<&str>::SHAPE, <Cow<str>>::SHAPE]);
+    assert_eq!(shape.return_shape, usize::SHAPE);
+}